@@ -0,0 +1,250 @@
+use crate::Player;
+#[cfg(feature = "parser")]
+use crate::World;
+use crate::map::{ExitId, RoomId};
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use alloc::collections::{BTreeMap, BTreeSet};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Who may enter a restricted room or go through a restricted exit, such as a player's home or a staff-only area.
+///
+/// A player is allowed if they own it or have any of its roles.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Permission {
+    /// The names of the players who own it.
+    pub owners: BTreeSet<String>,
+    /// The [roles](crate::Player::roles) allowed in, such as "staff".
+    pub roles: BTreeSet<String>,
+    /// The message shown to players who aren't allowed, instead of the [default one](AccessControl::denied_message).
+    pub denied_message: Option<String>,
+}
+
+impl Permission {
+    /// Creates a new `Permission` that only lets the given player in.
+    pub fn owner(name: &str) -> Self {
+        Self {
+            owners: BTreeSet::from([name.into()]),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new `Permission` that only lets players with the given role in.
+    pub fn role(role: &str) -> Self {
+        Self {
+            roles: BTreeSet::from([role.into()]),
+            ..Self::default()
+        }
+    }
+
+    /// Checks whether a player is allowed.
+    pub fn allows(&self, player: &Player) -> bool {
+        self.owners.contains(&player.name) || !self.roles.is_disjoint(&player.roles)
+    }
+}
+
+/// The restricted rooms and exits of the world, enforced by [`World::move_player`](crate::World::move_player) and
+/// [`World::check_passage`](crate::World::check_passage).
+///
+/// Rooms and exits without a [`Permission`] are open to everyone. Restrictions are saved with the game in progress.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::access::Permission;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+///
+/// let mut world = World::new();
+/// let lobby = world.map.new_room("A busy lobby.".into());
+/// let office = world.map.new_room_in_direction(lobby, Direction::North, Box::new(RegularExit), "A back office.".into());
+/// world.access.restrict_room(office, Permission::role("staff"));
+/// world.player.current_room = lobby;
+///
+/// assert_eq!(world.move_player(Direction::North).map(|movement| movement.to), Err("You aren't allowed in there.".into()));
+/// assert_eq!(world.check_passage(lobby, Direction::North), Err("You aren't allowed in there.".into()));
+/// world.player.roles.insert("staff".into());
+/// assert_eq!(world.move_player(Direction::North).map(|movement| movement.to), Ok(office));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AccessControl {
+    /// The message shown to players who aren't allowed in, unless the [`Permission`] has its own.
+    pub denied_message: String,
+    rooms: BTreeMap<RoomId, Permission>,
+    exits: BTreeMap<ExitId, Permission>,
+}
+
+impl AccessControl {
+    /// Creates a new `AccessControl` without any restrictions.
+    pub fn new() -> Self {
+        Self {
+            denied_message: "You aren't allowed in there.".into(),
+            rooms: BTreeMap::new(),
+            exits: BTreeMap::new(),
+        }
+    }
+
+    /// Restricts a room to the players with the given [`Permission`], replacing any previous restriction.
+    pub fn restrict_room(&mut self, room_id: RoomId, permission: Permission) {
+        self.rooms.insert(room_id, permission);
+    }
+
+    /// Restricts an exit to the players with the given [`Permission`], replacing any previous restriction.
+    pub fn restrict_exit(&mut self, exit_id: ExitId, permission: Permission) {
+        self.exits.insert(exit_id, permission);
+    }
+
+    /// Opens a room to everyone, returning whether it was restricted.
+    pub fn open_room(&mut self, room_id: RoomId) -> bool {
+        self.rooms.remove(&room_id).is_some()
+    }
+
+    /// Opens an exit to everyone, returning whether it was restricted.
+    pub fn open_exit(&mut self, exit_id: ExitId) -> bool {
+        self.exits.remove(&exit_id).is_some()
+    }
+
+    /// Gets the [`Permission`] of a room, if it is restricted.
+    pub fn room(&self, room_id: RoomId) -> Option<&Permission> {
+        self.rooms.get(&room_id)
+    }
+
+    /// Gets a mutable reference to the [`Permission`] of a room, if it is restricted.
+    pub fn room_mut(&mut self, room_id: RoomId) -> Option<&mut Permission> {
+        self.rooms.get_mut(&room_id)
+    }
+
+    /// Gets the [`Permission`] of an exit, if it is restricted.
+    pub fn exit(&self, exit_id: ExitId) -> Option<&Permission> {
+        self.exits.get(&exit_id)
+    }
+
+    /// Gets a mutable reference to the [`Permission`] of an exit, if it is restricted.
+    pub fn exit_mut(&mut self, exit_id: ExitId) -> Option<&mut Permission> {
+        self.exits.get_mut(&exit_id)
+    }
+
+    /// Iterates over the restricted rooms with their [`Permission`]s.
+    pub fn rooms(&self) -> impl Iterator<Item = (RoomId, &Permission)> {
+        self.rooms
+            .iter()
+            .map(|(&room_id, permission)| (room_id, permission))
+    }
+
+    /// Iterates over the restricted exits with their [`Permission`]s.
+    pub fn exits(&self) -> impl Iterator<Item = (ExitId, &Permission)> {
+        self.exits
+            .iter()
+            .map(|(&exit_id, permission)| (exit_id, permission))
+    }
+
+    /// Opens every room and exit to everyone.
+    pub fn clear(&mut self) {
+        self.rooms.clear();
+        self.exits.clear();
+    }
+
+    /// Checks whether a player may go through an exit into a room, returning the denied message if they may not.
+    pub fn check(&self, player: &Player, exit_id: ExitId, room_id: RoomId) -> Result<(), String> {
+        for permission in [self.exits.get(&exit_id), self.rooms.get(&room_id)]
+            .into_iter()
+            .flatten()
+        {
+            if !permission.allows(player) {
+                return Err(permission
+                    .denied_message
+                    .clone()
+                    .unwrap_or_else(|| self.denied_message.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for AccessControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An admin command handler that restricts rooms, such as "restrict Back Office to staff", "restrict Attic to owner alice"
+/// or "unrestrict Back Office".
+///
+/// Games should only register it for administrators.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::access::restrict_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["restrict"], restrict_command);
+/// world.parser.add_verb(&["unrestrict"], restrict_command);
+/// let office = world.map.new_room_with_name("Back Office".into(), "A back office.".into());
+///
+/// world.execute("restrict back office to staff").unwrap();
+/// assert!(world.access.room(office).unwrap().roles.contains("staff"));
+/// world.execute("restrict back office to owner alice").unwrap();
+/// assert!(world.access.room(office).unwrap().owners.contains("alice"));
+/// world.execute("unrestrict back office").unwrap();
+/// assert!(world.access.room(office).is_none());
+/// assert_eq!(
+///     world.output.drain(),
+///     vec![
+///         "Back Office is now restricted to the role \"staff\".",
+///         "Back Office is now restricted to the owner \"alice\".",
+///         "Back Office is now open to everyone.",
+///     ]
+/// );
+/// ```
+#[cfg(feature = "parser")]
+pub fn restrict_command(world: &mut World, command: &Command) {
+    let words = &command.words;
+    let (room_words, rule) = match words.iter().rposition(|word| word == "to") {
+        Some(index) if command.verb == "restrict" => (&words[..index], &words[index + 1..]),
+        _ => (&words[..], &words[words.len()..]),
+    };
+    let name = room_words.join(" ");
+    let Some(room_id) = world.map.room_by_name(&name) else {
+        world
+            .output
+            .say(format!("There is no room called \"{name}\"."));
+        return;
+    };
+    let room_name = world.map.graph[room_id].name.clone().unwrap_or(name);
+
+    if command.verb == "unrestrict" {
+        world.access.open_room(room_id);
+        world
+            .output
+            .say(format!("{room_name} is now open to everyone."));
+        return;
+    }
+    let (kind, who) = match rule {
+        [owner, name] if owner == "owner" => ("owner", name),
+        [role] => ("role", role),
+        _ => {
+            world
+                .output
+                .say("Restrict the room to a role, or to an owner.".into());
+            return;
+        }
+    };
+    let permission = match world.access.room_mut(room_id) {
+        Some(permission) => permission,
+        None => {
+            world.access.restrict_room(room_id, Permission::default());
+            world.access.room_mut(room_id).unwrap()
+        }
+    };
+    match kind {
+        "owner" => permission.owners.insert(who.clone()),
+        _ => permission.roles.insert(who.clone()),
+    };
+    world.output.say(format!(
+        "{room_name} is now restricted to the {kind} \"{who}\"."
+    ));
+}
@@ -0,0 +1,255 @@
+use crate::World;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+
+/// A condition on the [`World`] that unlocks an [`Achievement`] once it is met.
+pub type AchievementCondition = Rc<dyn Fn(&World) -> bool>;
+
+/// An achievement, or trophy, the player can unlock.
+#[derive(Clone)]
+pub struct Achievement {
+    /// The unique ID of the achievement, such as "vault_opened", which is what saves refer to.
+    pub id: String,
+    /// The title shown to the player, such as "Safecracker".
+    pub title: String,
+    /// What the player has to do, such as "Open the vault without the key."
+    pub description: String,
+    /// Whether the achievement is kept secret until it is unlocked.
+    pub hidden: bool,
+    /// The condition that unlocks the achievement, or `None` if it is only [unlocked](World::unlock_achievement) by
+    /// the game's code.
+    pub condition: Option<AchievementCondition>,
+}
+
+impl core::fmt::Debug for Achievement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Achievement")
+            .field("id", &self.id)
+            .field("title", &self.title)
+            .field("description", &self.description)
+            .field("hidden", &self.hidden)
+            .field("condition", &self.condition.is_some())
+            .finish()
+    }
+}
+
+impl Achievement {
+    /// Creates a new `Achievement` that isn't hidden and is only unlocked by the game's code.
+    pub fn new(id: &str, title: &str, description: &str) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: description.into(),
+            hidden: false,
+            condition: None,
+        }
+    }
+
+    /// Keeps the achievement secret until it is unlocked.
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Unlocks the achievement once the condition is met.
+    pub fn unlocked_when(mut self, condition: AchievementCondition) -> Self {
+        self.condition = Some(condition);
+        self
+    }
+}
+
+/// The registry of every [`Achievement`] of the game, and the turn each one was unlocked on.
+///
+/// Achievements with a condition are [checked](World::update_achievements) every turn, and the others are
+/// [unlocked](World::unlock_achievement) by the game's code. The player is told when one is unlocked, such as
+/// "Achievement unlocked: Safecracker." The unlocked achievements are kept in saved games, and can also be kept
+/// across playthroughs with [`unlocked_ids`](Achievements::unlocked_ids) and [`restore`](Achievements::restore).
+///
+/// # Examples
+/// ```
+/// use std::rc::Rc;
+/// use worldwright::World;
+/// use worldwright::achievements::Achievement;
+///
+/// let mut world = World::new();
+/// world.achievements.add(Achievement::new("explorer", "Explorer", "Visit the attic.")
+///     .unlocked_when(Rc::new(|world| world.flag("attic_visited"))));
+/// world.achievements.add(Achievement::new("pacifist", "Pacifist", "Spare the troll.").hidden());
+///
+/// world.set_flag("attic_visited", true);
+/// assert_eq!(world.advance_turn().messages, vec!["Achievement unlocked: Explorer."]);
+/// assert!(world.unlock_achievement("pacifist"));
+/// assert!(!world.unlock_achievement("pacifist"));
+/// assert_eq!(world.output.drain(), vec!["Achievement unlocked: Pacifist."]);
+///
+/// assert_eq!(world.achievements.unlocked_on("explorer"), Some(1));
+/// assert_eq!(world.achievements.unlocked_ids(), vec!["explorer", "pacifist"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Achievements {
+    achievements: Vec<Achievement>,
+    unlocked: BTreeMap<String, u32>,
+}
+
+impl Achievements {
+    /// Creates a new, empty `Achievements` registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an [`Achievement`], replacing the one with the same ID if there is one.
+    pub fn add(&mut self, achievement: Achievement) {
+        match self
+            .achievements
+            .iter_mut()
+            .find(|existing| existing.id == achievement.id)
+        {
+            Some(existing) => *existing = achievement,
+            None => self.achievements.push(achievement),
+        }
+    }
+
+    /// Gets an [`Achievement`] by ID.
+    pub fn get(&self, id: &str) -> Option<&Achievement> {
+        self.achievements
+            .iter()
+            .find(|achievement| achievement.id == id)
+    }
+
+    /// Returns every [`Achievement`], in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &Achievement> {
+        self.achievements.iter()
+    }
+
+    /// Checks whether an achievement has been unlocked.
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains_key(id)
+    }
+
+    /// Gets the turn an achievement was unlocked on, if it has been.
+    pub fn unlocked_on(&self, id: &str) -> Option<u32> {
+        self.unlocked.get(id).copied()
+    }
+
+    /// Returns the IDs of the unlocked achievements, in alphabetical order.
+    pub fn unlocked_ids(&self) -> Vec<&str> {
+        self.unlocked.keys().map(String::as_str).collect()
+    }
+
+    /// Gets the turns every achievement was unlocked on, by ID, such as to save them.
+    pub fn unlocked(&self) -> &BTreeMap<String, u32> {
+        &self.unlocked
+    }
+
+    /// Marks the given achievements as unlocked, such as from a saved game or from the achievements of earlier
+    /// playthroughs, without telling the player. Achievements that were already unlocked keep their turn.
+    pub fn restore(&mut self, unlocked: impl IntoIterator<Item = (String, u32)>) {
+        for (id, turn) in unlocked {
+            self.unlocked.entry(id).or_insert(turn);
+        }
+    }
+
+    /// Unlocks an achievement on a turn, returning the notification if it wasn't unlocked before.
+    fn unlock(&mut self, id: &str, turn: u32) -> Option<String> {
+        let title = self.get(id)?.title.clone();
+        if self.is_unlocked(id) {
+            return None;
+        }
+        self.unlocked.insert(id.into(), turn);
+        Some(format!("Achievement unlocked: {title}."))
+    }
+
+    /// Describes the achievements for the player, such as "Explorer: Visit the attic. (unlocked)".
+    ///
+    /// Hidden achievements that haven't been unlocked are listed as "A hidden achievement."
+    pub fn describe(&self) -> Vec<String> {
+        self.achievements
+            .iter()
+            .map(|achievement| {
+                let unlocked = self.is_unlocked(&achievement.id);
+                if achievement.hidden && !unlocked {
+                    "A hidden achievement.".into()
+                } else {
+                    let status = if unlocked { " (unlocked)" } else { "" };
+                    format!("{}: {}{status}", achievement.title, achievement.description)
+                }
+            })
+            .collect()
+    }
+}
+
+impl World {
+    /// Unlocks an [`Achievement`] by ID and tells the player about it.
+    ///
+    /// Returns `false` if there is no such achievement or it was already unlocked.
+    pub fn unlock_achievement(&mut self, id: &str) -> bool {
+        match self.achievements.unlock(id, self.turn) {
+            Some(message) => {
+                self.output.say(message);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Unlocks the achievements whose conditions are met, returning a notification for each, such as
+    /// "Achievement unlocked: Explorer."
+    ///
+    /// This is called at the end of every [turn](World::advance_turn).
+    pub fn update_achievements(&mut self) -> Vec<String> {
+        let met: Vec<String> = self
+            .achievements
+            .iter()
+            .filter(|achievement| !self.achievements.is_unlocked(&achievement.id))
+            .filter(|achievement| {
+                achievement
+                    .condition
+                    .as_ref()
+                    .is_some_and(|condition| condition(self))
+            })
+            .map(|achievement| achievement.id.clone())
+            .collect();
+        met.iter()
+            .filter_map(|id| self.achievements.unlock(id, self.turn))
+            .collect()
+    }
+}
+
+/// A command handler that lists the achievements of the game and which ones the player has unlocked.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::achievements::{Achievement, achievements_command};
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["achievements", "trophies"], achievements_command);
+/// world.achievements.add(Achievement::new("explorer", "Explorer", "Visit the attic."));
+/// world.achievements.add(Achievement::new("pacifist", "Pacifist", "Spare the troll.").hidden());
+/// world.unlock_achievement("explorer");
+/// world.output.drain();
+///
+/// world.execute("trophies").unwrap();
+/// assert_eq!(
+///     world.output.drain(),
+///     vec!["You have unlocked 1 of 2 achievements.", "Explorer: Visit the attic. (unlocked)", "A hidden achievement."]
+/// );
+/// ```
+#[cfg(feature = "parser")]
+pub fn achievements_command(world: &mut World, _command: &Command) {
+    let total = world.achievements.iter().count();
+    if total == 0 {
+        world.output.say("There are no achievements.".into());
+        return;
+    }
+    let unlocked = world.achievements.unlocked_ids().len();
+    world.output.say(format!(
+        "You have unlocked {unlocked} of {total} achievements."
+    ));
+    for line in world.achievements.describe() {
+        world.output.say(line);
+    }
+}
@@ -0,0 +1,538 @@
+use crate::dialogue::Dialogue;
+use crate::item::ItemId;
+use crate::map::{ExitId, Map, Region, RoomId};
+use crate::prelude::*;
+use crate::{Rng, World};
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::rc::Rc;
+use petgraph::prelude::NodeIndex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A stop on a [`PatrolRoute`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PatrolStop {
+    /// The name of the [`Room`](crate::map::Room) to go to.
+    pub room: String,
+    /// How many turns to stay in the room before moving on to the next stop.
+    #[cfg_attr(feature = "serde", serde(default = "PatrolStop::default_dwell"))]
+    pub dwell: u32,
+    /// Lines the NPC may say on arriving in the room, one of which is said if the player can hear it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub barks: Vec<String>,
+}
+
+impl PatrolStop {
+    /// Creates a new `PatrolStop` in the room with the given name, staying there for one turn.
+    pub fn new(room: String) -> Self {
+        Self {
+            room,
+            dwell: Self::default_dwell(),
+            barks: Vec::new(),
+        }
+    }
+
+    fn default_dwell() -> u32 {
+        1
+    }
+}
+
+/// A declarative patrol route: a list of rooms, how long to stay in each, and what to say there.
+///
+/// Routes refer to rooms by name, so they can be written in data files by level designers
+/// and loaded with any [`serde`] format without recompiling the game.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use worldwright::actor::PatrolRoute;
+/// use worldwright::map::Map;
+///
+/// let route: PatrolRoute = serde_json::from_str(r#"{
+///     "stops": [
+///         { "room": "Gatehouse", "dwell": 2, "barks": ["All quiet at the gate."] },
+///         { "room": "Armory" }
+///     ]
+/// }"#).unwrap();
+/// assert!(route.looping);
+/// assert_eq!(route.stops[1].dwell, 1);
+///
+/// let mut map = Map::new();
+/// map.new_room_with_name("Gatehouse".into(), "A cold stone gatehouse.".into());
+/// assert_eq!(route.resolve(&map), Err(vec!["Armory".to_string()]));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PatrolRoute {
+    /// The stops of the route, in order.
+    pub stops: Vec<PatrolStop>,
+    /// Whether the route starts over after the last stop. Otherwise, the NPC stays at the last stop.
+    #[cfg_attr(feature = "serde", serde(default = "PatrolRoute::default_looping"))]
+    pub looping: bool,
+}
+
+impl PatrolRoute {
+    /// Creates a new, looping `PatrolRoute` with the given stops.
+    pub fn new(stops: Vec<PatrolStop>) -> Self {
+        Self {
+            stops,
+            looping: Self::default_looping(),
+        }
+    }
+
+    fn default_looping() -> bool {
+        true
+    }
+
+    /// Finds the room of every stop in the [`Map`].
+    ///
+    /// Returns the names of the rooms that don't exist if any are missing, so that mistakes in data files can be reported.
+    pub fn resolve(&self, map: &Map) -> Result<Vec<NodeIndex>, Vec<String>> {
+        let mut rooms = Vec::new();
+        let mut missing = Vec::new();
+        for stop in &self.stops {
+            match map.room_by_name(&stop.room) {
+                Some(room_id) => rooms.push(room_id),
+                None => missing.push(stop.room.clone()),
+            }
+        }
+        if missing.is_empty() {
+            Ok(rooms)
+        } else {
+            Err(missing)
+        }
+    }
+}
+
+/// An NPC following a [`PatrolRoute`], along with its progress.
+#[derive(Clone, Debug)]
+pub struct Patrol {
+    /// The route being followed.
+    pub route: PatrolRoute,
+    stop: usize,
+    turns_here: u32,
+}
+
+impl Patrol {
+    /// Starts following `route` from its first stop.
+    pub fn new(route: PatrolRoute) -> Self {
+        Self {
+            route,
+            stop: 0,
+            turns_here: 0,
+        }
+    }
+
+    /// Gets the [`PatrolStop`] the NPC is currently at.
+    pub fn current_stop(&self) -> Option<&PatrolStop> {
+        self.route.stops.get(self.stop)
+    }
+
+    /// Gets the index of the [`PatrolStop`] the NPC is currently at, and how many turns it has stayed there.
+    pub fn progress(&self) -> (usize, u32) {
+        (self.stop, self.turns_here)
+    }
+
+    /// Resumes the patrol at the given stop, having stayed there for the given number of turns, such as from a saved
+    /// game. Stops past the end of the route are ignored.
+    pub fn resume(&mut self, stop: usize, turns_here: u32) {
+        if stop < self.route.stops.len() {
+            self.stop = stop;
+            self.turns_here = turns_here;
+        }
+    }
+
+    /// Advances the patrol by one turn while the NPC is at its current stop, returning the next [`PatrolStop`] if it is
+    /// time to move on.
+    pub fn tick(&mut self) -> Option<&PatrolStop> {
+        let dwell = self.current_stop()?.dwell;
+        self.turns_here += 1;
+        if self.turns_here < dwell {
+            return None;
+        }
+        self.skip()
+    }
+
+    /// Moves on to the next [`PatrolStop`] at once, such as when the room of the current one doesn't exist, and returns
+    /// it. Returns `None` at the last stop of a route that doesn't loop.
+    pub fn skip(&mut self) -> Option<&PatrolStop> {
+        let next = if self.stop + 1 < self.route.stops.len() {
+            self.stop + 1
+        } else if self.route.looping {
+            0
+        } else {
+            return None;
+        };
+        if next == self.stop {
+            return None;
+        }
+        self.stop = next;
+        self.turns_here = 0;
+        self.current_stop()
+    }
+}
+
+/// Makes an NPC wander at random between the rooms of a [`Region`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Wander {
+    /// The rooms the NPC wanders between.
+    pub region: Region,
+    /// The chance that the NPC moves on each turn, between 0 and 1.
+    pub chance: f64,
+}
+
+impl Wander {
+    /// Creates a new `Wander` in the given region, moving every other turn on average.
+    pub fn new(region: Region) -> Self {
+        Self {
+            region,
+            chance: 0.5,
+        }
+    }
+}
+
+/// Where an NPC should be at given times of the day, such as the kitchen at 7:00 and the library at 20:00.
+///
+/// Rooms are referred to by [`RoomId`], so that rooms sharing a name, such as two corridors, can't be mixed up.
+///
+/// # Examples
+/// ```
+/// use worldwright::actor::NpcSchedule;
+/// use worldwright::map::Map;
+///
+/// let mut map = Map::new();
+/// let kitchen = map.new_room_with_name("Kitchen".into(), "A warm kitchen.".into());
+/// let library = map.new_room_with_name("Library".into(), "A quiet library.".into());
+///
+/// let schedule = NpcSchedule::new().at(7, 0, kitchen).at(20, 30, library);
+/// assert_eq!(schedule.room_at(12 * 60), Some(kitchen));
+/// assert_eq!(schedule.room_at(21 * 60), Some(library));
+/// // Before the first entry of the day, the NPC is still where the last one of the day before sent them.
+/// assert_eq!(schedule.room_at(3 * 60), Some(library));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NpcSchedule {
+    /// The entries of the schedule, as the minute of the day and the room, in order.
+    pub entries: Vec<(u32, RoomId)>,
+}
+
+impl NpcSchedule {
+    /// Creates a new, empty `NpcSchedule`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an entry sending the NPC to a room at the given time of the day.
+    pub fn at(mut self, hour: u32, minute: u32, room: RoomId) -> Self {
+        let time = hour * 60 + minute;
+        let index = self.entries.partition_point(|(entry, _)| *entry <= time);
+        self.entries.insert(index, (time, room));
+        self
+    }
+
+    /// Gets the room the NPC should be in at the given minute of the day.
+    pub fn room_at(&self, minute_of_day: u32) -> Option<RoomId> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(time, _)| *time <= minute_of_day)
+            .or(self.entries.last())
+            .map(|&(_, room)| room)
+    }
+}
+
+/// A pool of short lines an NPC says, such as "Halt! Who goes there?"
+///
+/// Lines are picked at random, but never one of the most recently said ones, so barks don't repeat too often.
+///
+/// # Examples
+/// ```
+/// use worldwright::Rng;
+/// use worldwright::actor::BarkPool;
+///
+/// let rng = Rng::new(7);
+/// let mut barks = BarkPool::new(vec!["Hm.".into(), "Cold tonight.".into(), "Where's my relief?".into()]);
+/// barks.memory = 2;
+///
+/// let first = barks.pick(&rng).unwrap();
+/// let second = barks.pick(&rng).unwrap();
+/// let third = barks.pick(&rng).unwrap();
+/// assert!(first != second && second != third && first != third);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BarkPool {
+    /// The lines of the pool.
+    pub lines: Vec<String>,
+    /// The chance, from `0.0` to `1.0`, that the NPC barks on a given turn.
+    #[cfg_attr(feature = "serde", serde(default = "BarkPool::default_chance"))]
+    pub chance: f64,
+    /// How many of the most recently said lines are avoided.
+    #[cfg_attr(feature = "serde", serde(default = "BarkPool::default_memory"))]
+    pub memory: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    recent: VecDeque<usize>,
+}
+
+impl BarkPool {
+    /// Creates a new `BarkPool` with the given lines, barking every turn and avoiding the last line said.
+    pub fn new(lines: Vec<String>) -> Self {
+        Self {
+            lines,
+            chance: Self::default_chance(),
+            memory: Self::default_memory(),
+            recent: VecDeque::new(),
+        }
+    }
+
+    fn default_chance() -> f64 {
+        1.0
+    }
+
+    fn default_memory() -> usize {
+        1
+    }
+
+    /// Picks a line from the pool, avoiding the most recently said ones, and remembers it.
+    pub fn pick(&mut self, rng: &Rng) -> Option<String> {
+        let candidates: Vec<usize> = (0..self.lines.len())
+            .filter(|index| !self.recent.contains(index))
+            .collect();
+        let index = if candidates.is_empty() {
+            // Every line was said recently, so fall back to the one said longest ago.
+            *self.recent.front()?
+        } else {
+            candidates[rng.below(candidates.len() as u32) as usize]
+        };
+
+        self.recent.retain(|&recent| recent != index);
+        self.recent.push_back(index);
+        while self.recent.len() > self.memory {
+            self.recent.pop_front();
+        }
+        Some(self.lines[index].clone())
+    }
+}
+
+/// The mood of an [`Npc`], which changes the lines it says and how it is described.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Mood {
+    /// The NPC is at ease.
+    #[default]
+    Calm,
+    /// The NPC is angry.
+    Angry,
+    /// The NPC is frightened.
+    Frightened,
+}
+
+impl core::fmt::Display for Mood {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = match self {
+            Mood::Calm => "calm",
+            Mood::Angry => "angry",
+            Mood::Frightened => "frightened",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Identifies an [`Npc`] in the [`Npcs`] registry of the [`World`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NpcId(usize);
+
+/// A callback run every turn for an [`Npc`] with mutable access to the [`World`] and the [`NpcId`] of the NPC.
+pub type NpcBehavior = Rc<dyn Fn(&mut World, NpcId)>;
+
+/// A callback run when the player tries to go through an exit an [`Npc`] guards, with the [`NpcId`] of the NPC.
+///
+/// Returns what the NPC says to stop the player, or `None` to let them pass.
+pub type GuardHook = Rc<dyn Fn(&World, NpcId) -> Option<String>>;
+
+/// A non-player character.
+///
+/// # Examples
+/// ```
+/// use std::rc::Rc;
+/// use worldwright::World;
+/// use worldwright::actor::Npc;
+/// use worldwright::item::Item;
+///
+/// let mut world = World::new();
+/// let market = world.map.new_room("A noisy market.".into());
+/// let apple = world.items.add(Item::new("apple".into(), "A red apple.".into()));
+/// let mut merchant = Npc::new("merchant".into(), market);
+/// merchant.inventory.push(apple);
+/// merchant.behaviors.push(Rc::new(|world, npc| {
+///     if world.npcs[npc].location == world.player.current_room {
+///         world.output.say("The merchant waves an apple at you.".into());
+///     }
+/// }));
+/// world.npcs.add(merchant);
+/// world.player.current_room = market;
+///
+/// world.run_npc_behaviors();
+/// assert_eq!(world.output.drain(), vec!["The merchant waves an apple at you."]);
+/// assert_eq!(world.describe_room(market).to_string(), "A noisy market.\nThe merchant is here.");
+/// ```
+#[derive(Clone)]
+pub struct Npc {
+    /// The name of the NPC, such as "guard".
+    pub name: String,
+    /// The room the NPC is in.
+    pub location: NodeIndex,
+    /// The description of the NPC, rendered as a template where `{name}` and `{mood}` can be used.
+    pub description: Option<String>,
+    /// The current [`Mood`] of the NPC.
+    pub mood: Mood,
+    /// The [`Patrol`] the NPC follows, if any.
+    pub patrol: Option<Patrol>,
+    /// The [`Wander`] the NPC moves at random with, if any. It is ignored while the NPC patrols or has a schedule.
+    pub wander: Option<Wander>,
+    /// The [`NpcSchedule`] of where the NPC should be, if any. It is ignored while the NPC patrols.
+    pub schedule: Option<NpcSchedule>,
+    /// The [`BarkPool`] of lines the NPC says from time to time.
+    pub barks: Option<BarkPool>,
+    /// The [`BarkPool`]s used instead of [`barks`](Npc::barks) while the NPC is in a given [`Mood`].
+    pub mood_barks: BTreeMap<Mood, BarkPool>,
+    /// The [`BarkPool`]s of lines the NPC says in reaction to events, by the name of the event.
+    pub reactions: BTreeMap<String, BarkPool>,
+    /// The items the NPC is carrying.
+    pub inventory: Vec<ItemId>,
+    /// The [`Dialogue`] topics the player can talk to the NPC about.
+    pub dialogue: Dialogue,
+    /// The [`NpcBehavior`]s run every turn by [`World::run_npc_behaviors`].
+    pub behaviors: Vec<NpcBehavior>,
+    /// The [`GuardHook`]s of the exits the NPC guards while it is in the room they lead from.
+    pub guards: BTreeMap<ExitId, GuardHook>,
+}
+
+impl Npc {
+    /// Creates a new `Npc` with the given `name` in the given room.
+    pub fn new(name: String, location: NodeIndex) -> Self {
+        Self {
+            name,
+            location,
+            description: None,
+            mood: Mood::Calm,
+            patrol: None,
+            wander: None,
+            schedule: None,
+            barks: None,
+            mood_barks: BTreeMap::new(),
+            reactions: BTreeMap::new(),
+            inventory: Vec::new(),
+            dialogue: Dialogue::new(),
+            behaviors: Vec::new(),
+            guards: BTreeMap::new(),
+        }
+    }
+
+    /// Gets the [`BarkPool`] for the current [`Mood`] of the NPC, falling back to its usual [`barks`](Npc::barks).
+    pub fn current_barks(&mut self) -> Option<&mut BarkPool> {
+        match self.mood_barks.get_mut(&self.mood) {
+            Some(barks) => Some(barks),
+            None => self.barks.as_mut(),
+        }
+    }
+}
+
+impl core::fmt::Debug for Npc {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Npc")
+            .field("name", &self.name)
+            .field("location", &self.location)
+            .field("description", &self.description)
+            .field("mood", &self.mood)
+            .field("patrol", &self.patrol)
+            .field("wander", &self.wander)
+            .field("schedule", &self.schedule)
+            .field("barks", &self.barks)
+            .field("mood_barks", &self.mood_barks)
+            .field("reactions", &self.reactions)
+            .field("inventory", &self.inventory)
+            .field("dialogue", &self.dialogue)
+            .field("behaviors", &self.behaviors.len())
+            .field("guards", &self.guards.keys())
+            .finish()
+    }
+}
+
+/// The registry of every [`Npc`] in the [`World`].
+///
+/// # Examples
+/// ```
+/// use worldwright::actor::{Npc, Npcs};
+/// use worldwright::map::Map;
+///
+/// let mut map = Map::new();
+/// let gate = map.new_room_with_name("Gatehouse".into(), "A cold stone gatehouse.".into());
+/// let armory = map.new_room_with_name("Armory".into(), "Racks of spears.".into());
+///
+/// let mut npcs = Npcs::new();
+/// let guard = npcs.add(Npc::new("guard".into(), gate));
+/// let smith = npcs.add(Npc::new("smith".into(), armory));
+///
+/// assert_eq!(npcs.in_room(gate), vec![guard]);
+/// npcs[guard].location = armory;
+/// assert_eq!(npcs.in_room(armory), vec![guard, smith]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Npcs {
+    npcs: Vec<Npc>,
+}
+
+impl Npcs {
+    /// Creates a new, empty `Npcs` registry.
+    pub fn new() -> Self {
+        Self { npcs: Vec::new() }
+    }
+
+    /// Adds an [`Npc`] to the registry and returns its [`NpcId`].
+    pub fn add(&mut self, npc: Npc) -> NpcId {
+        self.npcs.push(npc);
+        NpcId(self.npcs.len() - 1)
+    }
+
+    /// Gets the [`Npc`] with the given [`NpcId`].
+    pub fn get(&self, id: NpcId) -> Option<&Npc> {
+        self.npcs.get(id.0)
+    }
+
+    /// Gets a mutable reference to the [`Npc`] with the given [`NpcId`].
+    pub fn get_mut(&mut self, id: NpcId) -> Option<&mut Npc> {
+        self.npcs.get_mut(id.0)
+    }
+
+    /// Returns an iterator over every [`Npc`] and its [`NpcId`].
+    pub fn iter(&self) -> impl Iterator<Item = (NpcId, &Npc)> {
+        self.npcs.iter().enumerate().map(|(i, npc)| (NpcId(i), npc))
+    }
+
+    /// Returns the [`NpcId`]s of the NPCs in the given room.
+    pub fn in_room(&self, room_id: NodeIndex) -> Vec<NpcId> {
+        self.iter()
+            .filter(|(_, npc)| npc.location == room_id)
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
+
+impl core::ops::Index<NpcId> for Npcs {
+    type Output = Npc;
+
+    fn index(&self, id: NpcId) -> &Npc {
+        &self.npcs[id.0]
+    }
+}
+
+impl core::ops::IndexMut<NpcId> for Npcs {
+    fn index_mut(&mut self, id: NpcId) -> &mut Npc {
+        &mut self.npcs[id.0]
+    }
+}
@@ -0,0 +1,160 @@
+use crate::Rng;
+use crate::map::{Map, Region};
+use crate::prelude::*;
+use crate::weather::Weather;
+use petgraph::prelude::NodeIndex;
+
+/// The order in which the messages of an [`AmbientSet`] are shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmbientOrder {
+    /// A random message is picked each time, never the same one twice in a row.
+    Random,
+    /// The messages are shown in order, starting over after the last one.
+    Rotating,
+}
+
+/// A set of atmospheric messages shown in a [`Region`].
+#[derive(Clone, Debug)]
+pub struct AmbientSet {
+    /// Where the messages can be shown.
+    pub region: Region,
+    /// The messages, such as "A draft rattles the shutters."
+    pub messages: Vec<String>,
+    /// The probability, between `0.0` and `1.0`, of showing a message on a given turn.
+    pub chance: f64,
+    /// The order in which messages are shown.
+    pub order: AmbientOrder,
+    /// The [`Weather`] the messages are only shown in, if any.
+    pub weather: Option<Weather>,
+    last: Option<usize>,
+}
+
+/// Ambient messages that give rooms and regions atmosphere.
+///
+/// The game loop calls [`tick`](Ambience::tick) once per turn with the player's room, and it possibly returns a message to show.
+/// Messages are picked with an [`Rng`], so they are deterministic for a given seed.
+///
+/// # Examples
+/// ```
+/// use worldwright::Rng;
+/// use worldwright::ambience::{Ambience, AmbientOrder};
+/// use worldwright::map::{Map, Region};
+///
+/// let mut map = Map::new();
+/// let attic = map.new_room("A dusty attic.".into());
+///
+/// let mut ambience = Ambience::new();
+/// ambience.add(
+///     Region::from(attic),
+///     vec!["A draft rattles the shutters.".into(), "Something scurries in the rafters.".into()],
+///     1.0,
+///     AmbientOrder::Rotating,
+/// );
+///
+/// let rng = Rng::new(7);
+/// assert_eq!(ambience.tick(&map, attic, &rng).as_deref(), Some("A draft rattles the shutters."));
+/// assert_eq!(ambience.tick(&map, attic, &rng).as_deref(), Some("Something scurries in the rafters."));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Ambience {
+    sets: Vec<AmbientSet>,
+}
+
+impl Ambience {
+    /// Creates a new `Ambience` without any messages.
+    pub fn new() -> Self {
+        Self { sets: Vec::new() }
+    }
+
+    /// Adds an [`AmbientSet`] of messages shown in `region` with the given chance per turn.
+    pub fn add(&mut self, region: Region, messages: Vec<String>, chance: f64, order: AmbientOrder) {
+        self.sets.push(AmbientSet {
+            region,
+            messages,
+            chance,
+            order,
+            weather: None,
+            last: None,
+        });
+    }
+
+    /// Adds an [`AmbientSet`] of messages shown in `region` with the given chance per turn, but only in some
+    /// [`Weather`], such as "Rain drums on the roof."
+    pub fn add_for_weather(
+        &mut self,
+        region: Region,
+        weather: Weather,
+        messages: Vec<String>,
+        chance: f64,
+        order: AmbientOrder,
+    ) {
+        self.add(region, messages, chance, order);
+        if let Some(set) = self.sets.last_mut() {
+            set.weather = Some(weather);
+        }
+    }
+
+    /// Returns the [`AmbientSet`]s.
+    pub fn sets(&self) -> &[AmbientSet] {
+        &self.sets
+    }
+
+    /// Possibly returns an ambient message for the given room.
+    ///
+    /// Every set covering the room gets a chance to fire in the order they were added, and at most one message is returned.
+    /// Sets that are only shown in some [`Weather`] never fire.
+    pub fn tick(&mut self, map: &Map, room_id: NodeIndex, rng: &Rng) -> Option<String> {
+        self.tick_in_weather(map, room_id, None, rng)
+    }
+
+    /// Possibly returns an ambient message for the given room, in the given [`Weather`].
+    ///
+    /// Like [`tick`](Ambience::tick), but the sets that are only shown in the current weather can fire too.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::Rng;
+    /// use worldwright::ambience::{Ambience, AmbientOrder};
+    /// use worldwright::map::{Map, Region};
+    /// use worldwright::weather::Weather;
+    ///
+    /// let mut map = Map::new();
+    /// let barn = map.new_room("A draughty barn.".into());
+    /// let mut ambience = Ambience::new();
+    /// ambience.add_for_weather(Region::Everywhere, Weather::Rain, vec!["Rain drums on the roof.".into()], 1.0, AmbientOrder::Random);
+    ///
+    /// let rng = Rng::new(7);
+    /// assert_eq!(ambience.tick_in_weather(&map, barn, Some(Weather::Clear), &rng), None);
+    /// assert_eq!(ambience.tick_in_weather(&map, barn, Some(Weather::Rain), &rng).as_deref(), Some("Rain drums on the roof."));
+    /// ```
+    pub fn tick_in_weather(
+        &mut self,
+        map: &Map,
+        room_id: NodeIndex,
+        weather: Option<Weather>,
+        rng: &Rng,
+    ) -> Option<String> {
+        for set in &mut self.sets {
+            if set.messages.is_empty()
+                || set.weather.is_some_and(|only| Some(only) != weather)
+                || !set.region.contains(map, room_id)
+                || !rng.chance(set.chance)
+            {
+                continue;
+            }
+
+            let count = set.messages.len();
+            let index = match (set.order, set.last) {
+                (AmbientOrder::Rotating, Some(last)) => (last + 1) % count,
+                (AmbientOrder::Rotating, None) => 0,
+                (AmbientOrder::Random, Some(last)) if count > 1 => {
+                    (last + 1 + rng.below(count as u32 - 1) as usize) % count
+                }
+                (AmbientOrder::Random, _) => rng.below(count as u32) as usize,
+            };
+            set.last = Some(index);
+            return Some(set.messages[index].clone());
+        }
+        None
+    }
+}
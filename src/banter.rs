@@ -0,0 +1,166 @@
+use crate::actor::{NpcId, Npcs};
+use crate::map::{Map, Region};
+use crate::prelude::*;
+use petgraph::prelude::NodeIndex;
+
+/// When a [`Banter`] starts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BanterTrigger {
+    /// When the player is in a room of the [`Region`] with every speaker.
+    Location(Region),
+    /// When the event with the given name is [triggered](Banters::trigger).
+    Event(String),
+}
+
+/// A scripted conversation between NPCs, said one line per turn in front of the player.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Banter {
+    /// The lines of the conversation, in order, with the [`NpcId`] of the NPC saying each one.
+    pub lines: Vec<(NpcId, String)>,
+    /// When the conversation starts.
+    pub trigger: BanterTrigger,
+    /// Whether the conversation can start again once it is over.
+    ///
+    /// A conversation triggered by location only starts again once the player has left the region.
+    pub repeatable: bool,
+    next_line: Option<usize>,
+    finished: bool,
+    started_here: bool,
+}
+
+impl Banter {
+    /// Creates a new `Banter` with the given lines and trigger, which is only said once.
+    pub fn new(lines: Vec<(NpcId, String)>, trigger: BanterTrigger) -> Self {
+        Self {
+            lines,
+            trigger,
+            repeatable: false,
+            next_line: None,
+            finished: false,
+            started_here: false,
+        }
+    }
+
+    /// Checks whether the conversation is being said.
+    pub fn is_active(&self) -> bool {
+        self.next_line.is_some()
+    }
+
+    /// Checks whether the conversation is over and can't start again.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn start(&mut self) {
+        if !self.finished && self.next_line.is_none() {
+            self.next_line = Some(0);
+        }
+    }
+
+    fn speakers_present(&self, npcs: &Npcs, room_id: NodeIndex) -> bool {
+        self.lines.iter().all(|(speaker, _)| {
+            npcs.get(*speaker)
+                .is_some_and(|npc| npc.location == room_id)
+        })
+    }
+}
+
+/// The scripted conversations between NPCs of the [`World`](crate::World).
+///
+/// Each turn, every active conversation says its next line, so lines interleave with the rest of the game
+/// instead of being shown all at once. A conversation only goes on while every speaker is in the player's room,
+/// and picks up where it left off when they are together again.
+///
+/// # Examples
+/// ```
+/// use worldwright::actor::{Npc, Npcs};
+/// use worldwright::banter::{Banter, BanterTrigger, Banters};
+/// use worldwright::map::{Map, Region};
+///
+/// let mut map = Map::new();
+/// let tavern = map.new_room("A smoky tavern.".into());
+/// let street = map.new_room("A muddy street.".into());
+///
+/// let mut npcs = Npcs::new();
+/// let bard = npcs.add(Npc::new("bard".into(), tavern));
+/// let innkeeper = npcs.add(Npc::new("innkeeper".into(), tavern));
+///
+/// let mut banters = Banters::new();
+/// banters.add(Banter::new(
+///     vec![
+///         (bard, "Another song, friend?".into()),
+///         (innkeeper, "Only if you pay for your ale.".into()),
+///     ],
+///     BanterTrigger::Location(Region::from(tavern)),
+/// ));
+///
+/// assert!(banters.tick(&map, &npcs, street).is_empty());
+/// assert_eq!(banters.tick(&map, &npcs, tavern), vec![(bard, "Another song, friend?".into())]);
+/// assert_eq!(banters.tick(&map, &npcs, tavern), vec![(innkeeper, "Only if you pay for your ale.".into())]);
+/// assert!(banters.tick(&map, &npcs, tavern).is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Banters {
+    banters: Vec<Banter>,
+}
+
+impl Banters {
+    /// Creates a new, empty `Banters` collection.
+    pub fn new() -> Self {
+        Self {
+            banters: Vec::new(),
+        }
+    }
+
+    /// Adds a [`Banter`].
+    pub fn add(&mut self, banter: Banter) {
+        self.banters.push(banter);
+    }
+
+    /// Returns an iterator over every [`Banter`].
+    pub fn iter(&self) -> impl Iterator<Item = &Banter> {
+        self.banters.iter()
+    }
+
+    /// Starts every conversation triggered by the event with the given name.
+    pub fn trigger(&mut self, event: &str) {
+        for banter in &mut self.banters {
+            if matches!(&banter.trigger, BanterTrigger::Event(name) if name == event) {
+                banter.start();
+            }
+        }
+    }
+
+    /// Advances every conversation by one turn, given the room the player is in.
+    ///
+    /// Returns the lines said this turn, with the [`NpcId`] of the NPC saying each one.
+    pub fn tick(&mut self, map: &Map, npcs: &Npcs, room_id: NodeIndex) -> Vec<(NpcId, String)> {
+        let mut said = Vec::new();
+        for banter in &mut self.banters {
+            if let BanterTrigger::Location(region) = &banter.trigger {
+                if !region.contains(map, room_id) {
+                    banter.started_here = false;
+                } else if !banter.started_here && banter.speakers_present(npcs, room_id) {
+                    banter.started_here = true;
+                    banter.start();
+                }
+            }
+            if !banter.speakers_present(npcs, room_id) {
+                continue;
+            }
+            let Some(index) = banter.next_line else {
+                continue;
+            };
+            if let Some(line) = banter.lines.get(index) {
+                said.push(line.clone());
+            }
+            if index + 1 < banter.lines.len() {
+                banter.next_line = Some(index + 1);
+            } else {
+                banter.next_line = None;
+                banter.finished = !banter.repeatable;
+            }
+        }
+        said
+    }
+}
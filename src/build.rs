@@ -0,0 +1,47 @@
+use crate::data::WorldData;
+use crate::lint::{self, Severity};
+use crate::prelude::*;
+use std::path::Path;
+use std::println;
+
+/// Validates the game's data files from a build script, so that broken world data never ships.
+///
+/// Every file is [loaded](WorldData::load) as JSON [`WorldData`] with its includes, the files are merged, and the result
+/// is [linted](crate::lint::lint).
+/// Cargo is told to rerun the build script when any of the files change, and every warning is reported as a Cargo warning.
+///
+/// Returns every problem found, one per line, if a file can't be loaded or the linter finds any errors.
+///
+/// # Examples
+/// In the `main` function of `build.rs`:
+/// ```no_run
+/// let files = ["data/manor.json", "data/garden.json"];
+/// if let Err(errors) = worldwright::build::validate_data_files(&files) {
+///     panic!("invalid world data:\n{errors}");
+/// }
+/// ```
+pub fn validate_data_files(paths: &[impl AsRef<Path>]) -> Result<(), String> {
+    let mut data = WorldData::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        println!("cargo:rerun-if-changed={}", path.display());
+        match WorldData::load(path) {
+            Ok(file_data) => data.merge(file_data),
+            Err(error) => errors.push(format!("error: {error}")),
+        }
+    }
+
+    for diagnostic in lint::lint(&data) {
+        match diagnostic.severity {
+            Severity::Warning => println!("cargo:warning={}", diagnostic.message),
+            Severity::Error => errors.push(diagnostic.to_string()),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
@@ -0,0 +1,231 @@
+use crate::World;
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The number of minutes in a day.
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// A moment on the [`Clock`] of the world, counted in minutes since midnight of the first day.
+///
+/// It is displayed on a 24-hour clock, such as "20:05".
+///
+/// # Examples
+/// ```
+/// use worldwright::clock::{GameTime, PartOfDay};
+///
+/// let time = GameTime::new(2, 20, 5);
+/// assert_eq!((time.day(), time.hour(), time.minute()), (2, 20, 5));
+/// assert_eq!(time.to_string(), "20:05");
+/// assert_eq!(time.to_12_hour(), "8:05 pm");
+/// assert_eq!(GameTime::new(0, 0, 30).to_12_hour(), "12:30 am");
+/// assert_eq!(time.part_of_day(), PartOfDay::Evening);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameTime {
+    /// The minutes since midnight of the first day.
+    pub minutes: u32,
+}
+
+impl GameTime {
+    /// Creates a new `GameTime` at the given hour and minute of a day, counting the first day as day 0.
+    pub fn new(day: u32, hour: u32, minute: u32) -> Self {
+        Self {
+            minutes: day * MINUTES_PER_DAY + hour * 60 + minute,
+        }
+    }
+
+    /// Gets the day, counting the first day as day 0.
+    pub fn day(&self) -> u32 {
+        self.minutes / MINUTES_PER_DAY
+    }
+
+    /// Gets the minutes since midnight.
+    pub fn minute_of_day(&self) -> u32 {
+        self.minutes % MINUTES_PER_DAY
+    }
+
+    /// Gets the hour of the day, from 0 to 23.
+    pub fn hour(&self) -> u32 {
+        self.minute_of_day() / 60
+    }
+
+    /// Gets the minute of the hour.
+    pub fn minute(&self) -> u32 {
+        self.minutes % 60
+    }
+
+    /// Gets the [`PartOfDay`].
+    pub fn part_of_day(&self) -> PartOfDay {
+        match self.hour() {
+            5..=11 => PartOfDay::Morning,
+            12..=16 => PartOfDay::Afternoon,
+            17..=21 => PartOfDay::Evening,
+            _ => PartOfDay::Night,
+        }
+    }
+
+    /// Formats the time on a 12-hour clock, such as "8:05 pm".
+    pub fn to_12_hour(&self) -> String {
+        let hour = match self.hour() % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        let suffix = if self.hour() < 12 { "am" } else { "pm" };
+        format!("{hour}:{:02} {suffix}", self.minute())
+    }
+}
+
+impl core::fmt::Display for GameTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour(), self.minute())
+    }
+}
+
+/// A part of the day, as told by the hour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartOfDay {
+    /// From 5:00 until noon.
+    Morning,
+    /// From noon until 17:00.
+    Afternoon,
+    /// From 17:00 until 22:00.
+    Evening,
+    /// From 22:00 until 5:00.
+    Night,
+}
+
+impl core::fmt::Display for PartOfDay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = match self {
+            PartOfDay::Morning => "morning",
+            PartOfDay::Afternoon => "afternoon",
+            PartOfDay::Evening => "evening",
+            PartOfDay::Night => "night",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// The clock and calendar of the [`World`], which advance by [`minutes_per_turn`](World::minutes_per_turn) every turn.
+///
+/// The time of the world is also available to templates as `{time}`, `{day}`, `{weekday}` and `{part_of_day}`.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::clock::GameTime;
+///
+/// let mut world = World::new();
+/// world.minutes_per_turn = 30;
+/// world.clock.start = GameTime::new(0, 18, 0);
+/// assert_eq!(world.now().to_string(), "18:00");
+/// assert!(!world.is_night());
+///
+/// world.turn = 6;
+/// assert_eq!(world.now().to_string(), "21:00");
+/// assert!(world.is_night());
+/// assert_eq!(world.render_template("It is {time} on {weekday}, {part_of_day}.", &[]), "It is 21:00 on Monday, evening.");
+///
+/// world.turn = 30;
+/// assert_eq!(world.clock.date(world.now()), "Tuesday, day 2");
+/// assert_eq!(world.time_of_day(), 9 * 60);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Clock {
+    /// The time at the start of the game, on turn 0.
+    pub start: GameTime,
+    /// The minute of the day the sun rises, 6:00 by default.
+    pub dawn: u32,
+    /// The minute of the day the sun sets, 20:00 by default.
+    pub dusk: u32,
+    /// The names of the days of the week, starting with the first day of the game.
+    pub weekdays: Vec<String>,
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock {
+    /// Creates a new `Clock` starting at midnight on a Monday, with the sun up from 6:00 until 20:00.
+    pub fn new() -> Self {
+        let weekdays = [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ];
+        Self {
+            start: GameTime::default(),
+            dawn: 6 * 60,
+            dusk: 20 * 60,
+            weekdays: weekdays.into_iter().map(String::from).collect(),
+        }
+    }
+
+    /// Gets the time on a turn, when every turn lasts the given number of minutes.
+    pub fn time_at(&self, turn: u32, minutes_per_turn: u32) -> GameTime {
+        GameTime {
+            minutes: self.start.minutes + turn.saturating_mul(minutes_per_turn),
+        }
+    }
+
+    /// Checks whether it is night at a time, between dusk and dawn.
+    pub fn is_night(&self, time: GameTime) -> bool {
+        let minute = time.minute_of_day();
+        if self.dawn <= self.dusk {
+            !(self.dawn..self.dusk).contains(&minute)
+        } else {
+            (self.dusk..self.dawn).contains(&minute)
+        }
+    }
+
+    /// Gets the name of the day of the week of a time, or an empty string if there are no weekdays.
+    pub fn weekday(&self, time: GameTime) -> &str {
+        match self.weekdays.len() {
+            0 => "",
+            len => &self.weekdays[time.day() as usize % len],
+        }
+    }
+
+    /// Formats the date of a time, such as "Tuesday, day 2", counting the first day as day 1.
+    pub fn date(&self, time: GameTime) -> String {
+        match self.weekday(time) {
+            "" => format!("Day {}", time.day() + 1),
+            weekday => format!("{weekday}, day {}", time.day() + 1),
+        }
+    }
+}
+
+impl World {
+    /// Gets the current time on the [`Clock`].
+    pub fn now(&self) -> GameTime {
+        self.clock.time_at(self.turn, self.minutes_per_turn)
+    }
+
+    /// Checks whether it is night on the [`Clock`].
+    pub fn is_night(&self) -> bool {
+        self.clock.is_night(self.now())
+    }
+
+    /// Gets the value of a template placeholder about the time: `{time}`, `{day}`, `{weekday}` or `{part_of_day}`.
+    pub(crate) fn clock_placeholder(&self, name: &str) -> Option<String> {
+        let now = self.now();
+        match name {
+            "time" => Some(now.to_string()),
+            "day" => Some((now.day() + 1).to_string()),
+            "weekday" => Some(self.clock.weekday(now).into()),
+            "part_of_day" => Some(now.part_of_day().to_string()),
+            _ => None,
+        }
+    }
+}
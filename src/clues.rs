@@ -0,0 +1,177 @@
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+/// Identifies a [`Clue`] on a [`ClueBoard`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ClueId(usize);
+
+/// A piece of evidence the player can discover.
+#[derive(Clone, Debug)]
+pub struct Clue {
+    /// The name of the clue, such as "bloody glove".
+    pub name: String,
+    /// A description of the clue.
+    pub description: String,
+    /// Whether the player has discovered the clue.
+    pub discovered: bool,
+}
+
+/// The result of trying to connect two [`Clue`]s on a [`ClueBoard`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Deduction {
+    /// The clues are connected in the solution and are now linked on the board.
+    Correct,
+    /// The clues are not connected in the solution.
+    Incorrect,
+    /// The clues were already linked on the board.
+    AlreadyConnected,
+    /// At least one of the clues has not been discovered yet.
+    Undiscovered,
+}
+
+/// A board of [`Clue`]s for mystery games.
+///
+/// The author defines clues and the links between them that make up the correct theory.
+/// The player discovers clues and tries to connect them, and the board is solved once every link of the theory has been made.
+///
+/// # Examples
+/// ```
+/// use worldwright::clues::{ClueBoard, Deduction};
+///
+/// let mut board = ClueBoard::new();
+/// let glove = board.add_clue("bloody glove".into(), "A glove stained with blood.".into());
+/// let butler = board.add_clue("butler's alibi".into(), "The butler claims he was asleep.".into());
+/// let vase = board.add_clue("broken vase".into(), "Shards of a vase by the window.".into());
+/// board.add_link(glove, butler);
+///
+/// assert_eq!(board.connect(glove, butler), Deduction::Undiscovered);
+/// board.discover(glove);
+/// board.discover(butler);
+/// board.discover(vase);
+///
+/// assert_eq!(board.connect(glove, vase), Deduction::Incorrect);
+/// assert!(!board.is_solved());
+/// assert_eq!(board.connect(butler, glove), Deduction::Correct);
+/// assert!(board.is_solved());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClueBoard {
+    clues: Vec<Clue>,
+    theory: Vec<(ClueId, ClueId)>,
+    connections: Vec<(ClueId, ClueId)>,
+}
+
+impl ClueBoard {
+    /// Creates a new, empty `ClueBoard`.
+    pub fn new() -> Self {
+        Self {
+            clues: Vec::new(),
+            theory: Vec::new(),
+            connections: Vec::new(),
+        }
+    }
+
+    /// Adds an undiscovered [`Clue`] to the board and returns its [`ClueId`].
+    pub fn add_clue(&mut self, name: String, description: String) -> ClueId {
+        self.clues.push(Clue {
+            name,
+            description,
+            discovered: false,
+        });
+        ClueId(self.clues.len() - 1)
+    }
+
+    /// Adds a link between two [`Clue`]s to the correct theory.
+    pub fn add_link(&mut self, a: ClueId, b: ClueId) {
+        let link = Self::link(a, b);
+        if !self.theory.contains(&link) {
+            self.theory.push(link);
+        }
+    }
+
+    /// Gets the [`Clue`] with the given [`ClueId`].
+    pub fn clue(&self, id: ClueId) -> Option<&Clue> {
+        self.clues.get(id.0)
+    }
+
+    /// Marks a [`Clue`] as discovered.
+    ///
+    /// Returns `true` if the clue was not discovered before.
+    pub fn discover(&mut self, id: ClueId) -> bool {
+        match self.clues.get_mut(id.0) {
+            Some(clue) if !clue.discovered => {
+                clue.discovered = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the discovered [`Clue`]s along with their [`ClueId`]s.
+    pub fn discovered(&self) -> Vec<(ClueId, &Clue)> {
+        self.clues
+            .iter()
+            .enumerate()
+            .filter(|(_, clue)| clue.discovered)
+            .map(|(index, clue)| (ClueId(index), clue))
+            .collect()
+    }
+
+    /// Tries to connect two [`Clue`]s, as in "connect the glove to the alibi".
+    ///
+    /// The clues are only linked on the board if the link is part of the correct theory.
+    pub fn connect(&mut self, a: ClueId, b: ClueId) -> Deduction {
+        let discovered = |id: ClueId| self.clue(id).is_some_and(|clue| clue.discovered);
+        if !discovered(a) || !discovered(b) {
+            return Deduction::Undiscovered;
+        }
+
+        let link = Self::link(a, b);
+        if self.connections.contains(&link) {
+            Deduction::AlreadyConnected
+        } else if self.theory.contains(&link) {
+            self.connections.push(link);
+            Deduction::Correct
+        } else {
+            Deduction::Incorrect
+        }
+    }
+
+    /// Gets the pairs of [`Clue`]s the player has correctly connected, such as to save them.
+    pub fn connections(&self) -> &[(ClueId, ClueId)] {
+        &self.connections
+    }
+
+    /// Replaces the discovered clues and their connections, such as from a saved game. Connections between clues
+    /// that aren't discovered or aren't linked in the correct theory are ignored.
+    pub fn restore(
+        &mut self,
+        discovered: impl IntoIterator<Item = ClueId>,
+        connections: impl IntoIterator<Item = (ClueId, ClueId)>,
+    ) {
+        for clue in &mut self.clues {
+            clue.discovered = false;
+        }
+        for id in discovered {
+            self.discover(id);
+        }
+        self.connections.clear();
+        for (a, b) in connections {
+            self.connect(a, b);
+        }
+    }
+
+    /// Checks whether every link of the correct theory has been connected.
+    pub fn is_solved(&self) -> bool {
+        !self.theory.is_empty()
+            && self
+                .theory
+                .iter()
+                .all(|link| self.connections.contains(link))
+    }
+
+    fn link(a: ClueId, b: ClueId) -> (ClueId, ClueId) {
+        (a.min(b), a.max(b))
+    }
+}
@@ -0,0 +1,181 @@
+use crate::World;
+use crate::item::ItemId;
+use crate::map::{Direction, RoomId};
+use crate::prelude::*;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::rc::Rc;
+
+/// A condition on the [`World`] under which the player resists a [`Current`], such as holding on to a rope.
+pub type Resistance = Rc<dyn Fn(&World) -> bool>;
+
+/// A force that moves the player along an exit of a room at the end of every turn, such as a river current, a
+/// conveyor belt or a slide.
+#[derive(Clone)]
+pub struct Current {
+    /// The direction of the exit the current moves things through.
+    pub direction: Direction,
+    /// What the player is told when the current moves them, such as "The current sweeps you downstream."
+    pub message: String,
+    /// The condition under which the player resists the current, if they can.
+    pub resistance: Option<Resistance>,
+    /// What the player is told when they resist the current, if anything.
+    pub resist_message: Option<String>,
+}
+
+impl core::fmt::Debug for Current {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Current")
+            .field("direction", &self.direction)
+            .field("message", &self.message)
+            .field("resistance", &self.resistance.is_some())
+            .field("resist_message", &self.resist_message)
+            .finish()
+    }
+}
+
+impl Current {
+    /// Creates a new `Current` through the exit in the given direction, which can't be resisted.
+    pub fn new(direction: Direction, message: &str) -> Self {
+        Self {
+            direction,
+            message: message.into(),
+            resistance: None,
+            resist_message: None,
+        }
+    }
+
+    /// Lets the player resist the current under a condition, telling them the given message when they do.
+    pub fn resisted_when(mut self, resistance: Resistance, message: &str) -> Self {
+        self.resistance = Some(resistance);
+        self.resist_message = Some(message.into());
+        self
+    }
+}
+
+/// The [`Current`]s of the rooms of the [`World`], and the items that float along with them.
+///
+/// At the end of every turn, the floating items lying in a room with a current drift through its exit, and so does the
+/// player unless they resist it. Nothing is moved through an exit that doesn't let the player through, such as a
+/// closed grate.
+///
+/// # Examples
+/// ```
+/// use std::rc::Rc;
+/// use worldwright::World;
+/// use worldwright::currents::Current;
+/// use worldwright::item::Item;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+///
+/// let mut world = World::new();
+/// let ford = world.map.new_room_with_name("Ford".into(), "A shallow ford.".into());
+/// let rapids = world.map.new_room_in_direction(ford, Direction::East, Box::new(RegularExit), "Foaming rapids.".into());
+/// let pool = world.map.new_room_in_direction(rapids, Direction::East, Box::new(RegularExit), "A still pool.".into());
+/// let branch = world.items.add(Item::new("branch".into(), "A branch.".into()));
+/// let rope = world.items.add(Item::new("rope".into(), "A rope.".into()));
+/// world.map.place_item(ford, branch);
+/// world.currents.floating.insert(branch);
+/// world.currents.add(ford, Current::new(Direction::East, "The current sweeps you downstream.")
+///     .resisted_when(Rc::new(move |world| world.player.carries(rope)), "You cling to the rope."));
+/// world.currents.add(rapids, Current::new(Direction::East, "The rapids carry you on."));
+/// world.player.current_room = ford;
+/// world.player.inventory.push(rope);
+///
+/// assert_eq!(world.advance_turn().messages, vec!["You cling to the rope.", "The branch drifts away."]);
+/// assert_eq!(world.map.find_item(branch), Some(rapids));
+///
+/// world.player.inventory.clear();
+/// assert_eq!(world.advance_turn().messages, vec!["The current sweeps you downstream."]);
+/// assert_eq!(world.player.current_room, rapids);
+/// // The branch drifted on to the pool while the player was swept into the rapids.
+/// assert_eq!(world.map.find_item(branch), Some(pool));
+///
+/// world.advance_turn();
+/// assert_eq!(world.player.current_room, pool);
+/// assert_eq!(world.advance_turn().messages, Vec::<String>::new());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Currents {
+    currents: BTreeMap<RoomId, Current>,
+    /// The items that drift along with currents when they lie in a room with one.
+    pub floating: BTreeSet<ItemId>,
+}
+
+impl Currents {
+    /// Creates a new `Currents` without any currents or floating items.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a current to a room, replacing its current if it has one.
+    pub fn add(&mut self, room: RoomId, current: Current) {
+        self.currents.insert(room, current);
+    }
+
+    /// Removes the current of a room, such as when the river is dammed, and returns it.
+    pub fn remove(&mut self, room: RoomId) -> Option<Current> {
+        self.currents.remove(&room)
+    }
+
+    /// Gets the current of a room.
+    pub fn get(&self, room: RoomId) -> Option<&Current> {
+        self.currents.get(&room)
+    }
+}
+
+impl World {
+    /// Moves the floating items and the player along the [`Currents`] of the rooms they are in, by one room each.
+    ///
+    /// This is called at the end of every [turn](World::advance_turn). Returns what the player is told, such as
+    /// "The current sweeps you downstream." or "The branch drifts away."
+    pub fn apply_currents(&mut self) -> Vec<String> {
+        let mut drifted = Vec::new();
+        let here = self.player.current_room;
+
+        let mut drifts = Vec::new();
+        for (&room, current) in &self.currents.currents {
+            let Some(exit_id) = self.map.exit_id_in_direction(room, current.direction) else {
+                continue;
+            };
+            if self.map.graph[exit_id]
+                .exit_type
+                .check_passage_from(self, room)
+                .is_err()
+            {
+                continue;
+            }
+            let (source, target) = self.map.exit_endpoints(exit_id).unwrap();
+            let to = if source == room { target } else { source };
+            for &item in &self.map.graph[room].contents {
+                if self.currents.floating.contains(&item) {
+                    drifts.push((item, room, to));
+                }
+            }
+        }
+        for (item, from, to) in drifts {
+            self.map.move_item(item, from, to);
+            let name = &self.items[item].name;
+            if from == here {
+                drifted.push(format!("The {name} drifts away."));
+            } else if to == here {
+                drifted.push(format!("The {name} drifts in."));
+            }
+        }
+
+        let mut messages = Vec::new();
+        if let Some(current) = self.currents.get(here).cloned() {
+            if current
+                .resistance
+                .as_ref()
+                .is_some_and(|resistance| resistance(self))
+            {
+                messages.extend(current.resist_message);
+            } else if let Ok(movement) = self.move_player(current.direction) {
+                messages.push(current.message);
+                messages.extend(movement.messages);
+            }
+        }
+        messages.extend(drifted);
+        messages
+    }
+}
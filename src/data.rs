@@ -0,0 +1,829 @@
+use crate::World;
+use crate::actor::{BarkPool, Npc, Patrol, PatrolRoute};
+use crate::dialogue::{DialogueTopic, Response};
+use crate::item::Item;
+use crate::language::Localized;
+use crate::lint::{self, Diagnostic, Severity};
+use crate::map::exit_types::{Chasm, Door, NamedExit, RegularExit};
+use crate::map::{Annotations, Direction, ExitType, Room};
+use crate::prelude::*;
+use crate::reset::Persistence;
+use alloc::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+
+/// The most files deep [`include`](WorldData::include)s can be nested, so that include chains that never repeat a
+/// file still end.
+pub const MAX_INCLUDE_DEPTH: usize = 32;
+
+/// A declarative description of a [`World`], which can be written in data files and loaded with any [`serde`] format.
+///
+/// Rooms are referred to by name everywhere else in the data, so every room must have a unique name. Rooms, items and
+/// NPCs can also be given stable [`id`](RoomData::id)s, which other entries can refer to instead of names, so that
+/// data files written by several authors merge cleanly.
+///
+/// # Examples
+/// ```
+/// use worldwright::data::WorldData;
+///
+/// let data = WorldData::from_json(r#"{
+///     "rooms": [
+///         { "name": "Foyer", "description": "The dusty foyer of an old manor." },
+///         { "name": "Grand Hall", "description": "A roaring fireplace dominates the far wall." }
+///     ],
+///     "exits": [
+///         { "from": "Foyer", "to": "Grand Hall", "direction": "north",
+///           "exit": { "type": "door", "name": "heavy wooden door" } }
+///     ],
+///     "items": [
+///         { "name": "iron poker", "description": "A heavy iron poker.", "room": "Grand Hall" }
+///     ]
+/// }"#).unwrap();
+///
+/// let world = data.build().unwrap();
+/// let hall = world.map.room_by_name("Grand Hall").unwrap();
+/// assert_eq!(
+///     world.describe_room(hall).to_string(),
+///     "Grand Hall\nA roaring fireplace dominates the far wall.\nYou can see an iron poker here."
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorldData {
+    /// Other data files this one is built on, relative to it. See [`load_with`](WorldData::load_with).
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// The rooms of the world.
+    #[serde(default)]
+    pub rooms: Vec<RoomData>,
+    /// The exits between rooms.
+    #[serde(default)]
+    pub exits: Vec<ExitData>,
+    /// The items of the world.
+    #[serde(default)]
+    pub items: Vec<ItemData>,
+    /// The non-player characters of the world.
+    #[serde(default)]
+    pub npcs: Vec<NpcData>,
+    /// What the NPCs answer when asked about topics.
+    #[serde(default)]
+    pub dialogue: Vec<DialogueData>,
+    /// Simple verbs that answer with a message.
+    #[serde(default)]
+    pub verbs: Vec<VerbData>,
+}
+
+/// A [`Room`] in [`WorldData`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoomData {
+    /// The stable ID of the room, such as "manor.foyer", which can be used instead of its name to refer to it.
+    #[serde(default)]
+    pub id: Option<String>,
+    /// The unique name of the room.
+    pub name: String,
+    /// The description of the room.
+    pub description: String,
+    /// The brief description of the room, shown when returning to it.
+    #[serde(default)]
+    pub short_description: Option<String>,
+    /// The tags of the room.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The translations of the room, by language.
+    #[serde(default)]
+    pub translations: BTreeMap<String, RoomTranslation>,
+    /// Author-only [`Annotations`] of the room.
+    #[serde(default)]
+    pub annotations: Annotations,
+}
+
+/// The translation of a [`RoomData`] in one language. Anything left out is shown in the default language.
+///
+/// # Examples
+/// ```
+/// use worldwright::data::WorldData;
+///
+/// let data = WorldData::from_json(r#"{
+///     "rooms": [{
+///         "name": "Foyer",
+///         "description": "The dusty foyer of an old manor.",
+///         "translations": { "fr": { "description": "Le vestibule poussiéreux d'un vieux manoir." } }
+///     }],
+///     "items": [{
+///         "name": "lamp", "description": "A brass lamp.", "room": "Foyer",
+///         "translations": { "de": "Eine Messinglampe." }
+///     }]
+/// }"#).unwrap();
+///
+/// let mut world = data.build().unwrap();
+/// let foyer = world.map.room_by_name("Foyer").unwrap();
+/// let lamp = world.items.find("lamp").unwrap();
+/// world.language = Some("fr".into());
+/// assert_eq!(world.map.graph[foyer].description.render(&world), "Le vestibule poussiéreux d'un vieux manoir.");
+/// assert_eq!(world.items[lamp].description.render(&world), "A brass lamp.");
+/// world.language = Some("de".into());
+/// assert_eq!(world.items[lamp].description.render(&world), "Eine Messinglampe.");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoomTranslation {
+    /// The translated description of the room.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The translated brief description of the room.
+    #[serde(default)]
+    pub short_description: Option<String>,
+}
+
+/// An exit between two rooms in [`WorldData`].
+///
+/// # Examples
+/// ```
+/// use worldwright::data::WorldData;
+/// use worldwright::map::Direction;
+///
+/// let data = WorldData::from_json(r#"{
+///     "rooms": [
+///         { "name": "Foyer", "description": "The dusty foyer of an old manor." },
+///         { "name": "Attic", "description": "A cramped attic." }
+///     ],
+///     "exits": [{ "from": "Foyer", "to": "Attic", "direction": "up" }]
+/// }"#).unwrap();
+/// assert_eq!(data.exits[0].direction, Direction::Up);
+///
+/// let world = data.build().unwrap();
+/// let foyer = world.map.room_by_name("Foyer").unwrap();
+/// let attic = world.map.room_by_name("Attic").unwrap();
+/// assert_eq!(world.map.neighbor_in_direction(attic, Direction::Down), Some(foyer));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExitData {
+    /// The name or ID of the room the exit leads from.
+    pub from: String,
+    /// The name or ID of the room the exit leads to.
+    pub to: String,
+    /// The [`Direction`] of the exit from the `from` room.
+    pub direction: Direction,
+    /// The type of the exit.
+    #[serde(default)]
+    pub exit: ExitKind,
+    /// Author-only [`Annotations`] of the exit.
+    #[serde(default)]
+    pub annotations: Annotations,
+}
+
+/// The built-in [`exit types`](crate::map::exit_types) that can be used in [`WorldData`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExitKind {
+    /// A [`RegularExit`].
+    #[default]
+    Regular,
+    /// A [`NamedExit`].
+    Named {
+        /// The name of the exit.
+        name: String,
+    },
+    /// A [`Door`].
+    Door {
+        /// Whether the door is locked.
+        #[serde(default)]
+        locked: bool,
+        /// The name of the door.
+        #[serde(default)]
+        name: Option<String>,
+    },
+    /// A [`Chasm`].
+    Chasm,
+}
+
+impl ExitKind {
+    /// Creates the [`ExitType`] of this kind.
+    pub fn to_exit_type(&self) -> Box<dyn ExitType> {
+        match self {
+            ExitKind::Regular => Box::new(RegularExit),
+            ExitKind::Named { name } => Box::new(NamedExit::new(name.clone())),
+            ExitKind::Door { locked, name: None } => Box::new(Door::new(*locked)),
+            ExitKind::Door {
+                locked,
+                name: Some(name),
+            } => Box::new(Door::new_with_name(*locked, name.clone())),
+            ExitKind::Chasm => Box::new(Chasm),
+        }
+    }
+}
+
+/// An [`Item`] in [`WorldData`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemData {
+    /// The stable ID of the item, such as "manor.poker".
+    #[serde(default)]
+    pub id: Option<String>,
+    /// The name of the item.
+    pub name: String,
+    /// The description of the item.
+    pub description: String,
+    /// The name or ID of the room the item starts in, if any.
+    #[serde(default)]
+    pub room: Option<String>,
+    /// Whether the player can push the item from room to room.
+    #[serde(default)]
+    pub pushable: bool,
+    /// The translated descriptions of the item, by language.
+    #[serde(default)]
+    pub translations: BTreeMap<String, String>,
+    /// Whether the player can pick up the item. Items are takeable by default.
+    #[serde(default = "ItemData::default_takeable")]
+    pub takeable: bool,
+    /// How heavy the item is. Items weigh 1 by default.
+    #[serde(default = "ItemData::default_weight")]
+    pub weight: u32,
+    /// Whether the item respawns at its home when the world resets. Items reset by default.
+    #[serde(default)]
+    pub persistence: Persistence,
+}
+
+impl ItemData {
+    fn default_takeable() -> bool {
+        true
+    }
+
+    fn default_weight() -> u32 {
+        1
+    }
+}
+
+/// An [`Npc`] in [`WorldData`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NpcData {
+    /// The stable ID of the NPC, such as "manor.butler".
+    #[serde(default)]
+    pub id: Option<String>,
+    /// The name of the NPC.
+    pub name: String,
+    /// The name or ID of the room the NPC starts in.
+    pub room: String,
+    /// The [`PatrolRoute`] the NPC follows, if any.
+    #[serde(default)]
+    pub patrol: Option<PatrolRoute>,
+    /// What the NPC says when the player talks to them.
+    #[serde(default)]
+    pub greeting: Option<String>,
+    /// The description of the NPC, which can refer to its `{name}` and `{mood}`.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The lines the NPC says from time to time.
+    #[serde(default)]
+    pub barks: Vec<String>,
+    /// The translations of the NPC, by language.
+    #[serde(default)]
+    pub translations: BTreeMap<String, NpcTranslation>,
+}
+
+/// The translation of an [`NpcData`] in one language. Anything left out is shown in the default language.
+///
+/// Translations are added to the [`Translations`](crate::language::Translations) of the world, so they are shown in
+/// its [language](crate::World::language).
+///
+/// # Examples
+/// ```
+/// use worldwright::data::WorldData;
+///
+/// let data = WorldData::from_json(r#"{
+///     "rooms": [{ "name": "Kitchen", "description": "A warm kitchen." }],
+///     "npcs": [{
+///         "name": "cook", "room": "Kitchen", "description": "The {name} looks busy.", "barks": ["Who ate the pie?"],
+///         "translations": { "fr": { "description": "Le cuisinier a l'air occupé.", "barks": ["Qui a mangé la tarte ?"] } }
+///     }],
+///     "dialogue": [{
+///         "npc": "cook", "topic": "pie", "response": "\"It was for the master.\"",
+///         "translations": { "fr": "« C'était pour le maître. »" }
+///     }]
+/// }"#).unwrap();
+///
+/// let mut world = data.build().unwrap();
+/// let kitchen = world.map.room_by_name("Kitchen").unwrap();
+/// world.player.current_room = kitchen;
+/// let cook = world.npc_here("cook").unwrap();
+/// assert_eq!(world.describe_npc(cook), "The cook looks busy.");
+///
+/// world.language = Some("fr".into());
+/// assert_eq!(world.describe_npc(cook), "Le cuisinier a l'air occupé.");
+/// assert_eq!(world.ask_about(cook, "pie"), "« C'était pour le maître. »");
+/// assert_eq!(world.tick_barks(kitchen), vec!["The cook says, \"Qui a mangé la tarte ?\""]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NpcTranslation {
+    /// The translated greeting of the NPC.
+    #[serde(default)]
+    pub greeting: Option<String>,
+    /// The translated description of the NPC.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The translated lines of the NPC, in the same order as its barks.
+    #[serde(default)]
+    pub barks: Vec<String>,
+}
+
+/// What an [`Npc`] answers when asked about a topic, in [`WorldData`].
+///
+/// Each answer is a separate entry, so that overlays can add or replace single answers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DialogueData {
+    /// The name or ID of the NPC.
+    pub npc: String,
+    /// The name of the topic, such as "key".
+    pub topic: String,
+    /// What the NPC answers.
+    pub response: String,
+    /// The translations of the answer, by language.
+    #[serde(default)]
+    pub translations: BTreeMap<String, String>,
+}
+
+/// A simple verb in [`WorldData`], which answers with a message and may set a flag, such as "xyzzy" or "dance".
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "parser")] {
+/// use worldwright::data::WorldData;
+///
+/// let data = WorldData::from_json(r#"{
+///     "rooms": [{ "name": "Cave", "description": "A dark cave." }],
+///     "verbs": [{ "words": ["xyzzy", "plugh"], "response": "A hollow voice says \"Fool.\"", "set_flag": "said_xyzzy" }]
+/// }"#).unwrap();
+///
+/// let mut world = data.build().unwrap();
+/// world.execute("plugh").unwrap();
+/// assert_eq!(world.output.drain(), vec!["A hollow voice says \"Fool.\""]);
+/// assert!(world.flag("said_xyzzy"));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerbData {
+    /// The verb and its synonyms.
+    pub words: Vec<String>,
+    /// What the game answers, which can refer to [template](crate::template) variables.
+    pub response: String,
+    /// The flag set when the verb is used, if any.
+    #[serde(default)]
+    pub set_flag: Option<String>,
+}
+
+impl WorldData {
+    /// Creates new, empty `WorldData`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads `WorldData` from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Loads `WorldData` from a JSON file, following its [`include`](WorldData::include)s. See
+    /// [`load_with`](WorldData::load_with).
+    #[cfg(feature = "std")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, String> {
+        Self::load_with(&path.as_ref().to_string_lossy(), |path| {
+            std::fs::read_to_string(path).map_err(|error| error.to_string())
+        })
+    }
+
+    /// Loads `WorldData` from JSON, reading files with `read`, and following its [`include`](WorldData::include)s.
+    ///
+    /// Included files are loaded first, in order, and each one is [overlaid](WorldData::overlay) on the ones before
+    /// it. The including file is overlaid last, so a patch file can include a base world and override parts of it.
+    /// Included paths are relative to the file including them, and are read with their `.` and `..` components
+    /// collapsed.
+    ///
+    /// Returns the path of the file and the problem if a file can't be read or parsed, if files include each other, or
+    /// if includes are nested more than [`MAX_INCLUDE_DEPTH`] files deep.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::data::WorldData;
+    ///
+    /// let read = |path: &str| match path {
+    ///     "episodes/two.json" => Ok(r#"{
+    ///         "include": ["../base.json"],
+    ///         "rooms": [{ "name": "Foyer", "description": "The foyer, now flooded." }]
+    ///     }"#.to_string()),
+    ///     "base.json" => Ok(r#"{
+    ///         "rooms": [
+    ///             { "name": "Foyer", "description": "The dusty foyer of an old manor." },
+    ///             { "name": "Attic", "description": "A cramped attic." }
+    ///         ]
+    ///     }"#.to_string()),
+    ///     _ => Err("not found".to_string()),
+    /// };
+    ///
+    /// let data = WorldData::load_with("episodes/two.json", read).unwrap();
+    /// assert_eq!(data.rooms.len(), 2);
+    /// assert_eq!(data.rooms[0].description, "The foyer, now flooded.");
+    /// assert_eq!(WorldData::load_with("missing.json", read), Err("missing.json: not found".into()));
+    ///
+    /// let itself = |_: &str| Ok(r#"{ "include": ["./loop.json"] }"#.to_string());
+    /// assert_eq!(WorldData::load_with("loop.json", itself), Err("loop.json: included by itself".into()));
+    /// let deeper = |_: &str| Ok(r#"{ "include": ["deeper/world.json"] }"#.to_string());
+    /// assert!(WorldData::load_with("world.json", deeper).unwrap_err().ends_with("includes are nested too deeply"));
+    /// ```
+    pub fn load_with(
+        path: &str,
+        mut read: impl FnMut(&str) -> Result<String, String>,
+    ) -> Result<Self, String> {
+        Self::load_nested(path, &mut read, &mut Vec::new())
+    }
+
+    fn load_nested(
+        path: &str,
+        read: &mut impl FnMut(&str) -> Result<String, String>,
+        including: &mut Vec<String>,
+    ) -> Result<Self, String> {
+        let path = &normalize_path(path);
+        if including.iter().any(|file| file == path) {
+            return Err(format!("{path}: included by itself"));
+        }
+        if including.len() >= MAX_INCLUDE_DEPTH {
+            return Err(format!("{path}: includes are nested too deeply"));
+        }
+        let mut file = read(path)
+            .and_then(|json| Self::from_json(&json).map_err(|error| error.to_string()))
+            .map_err(|error| format!("{path}: {error}"))?;
+
+        let directory = path.rfind(['/', '\\']).map_or("", |end| &path[..=end]);
+        including.push(path.into());
+        let mut data = WorldData::new();
+        for include in core::mem::take(&mut file.include) {
+            data.overlay(Self::load_nested(
+                &format!("{directory}{include}"),
+                read,
+                including,
+            )?);
+        }
+        including.pop();
+        data.overlay(file);
+        Ok(data)
+    }
+
+    /// Adds the contents of another `WorldData`, such as one loaded from another data file.
+    pub fn merge(&mut self, other: WorldData) {
+        self.rooms.extend(other.rooms);
+        self.exits.extend(other.exits);
+        self.items.extend(other.items);
+        self.npcs.extend(other.npcs);
+        self.dialogue.extend(other.dialogue);
+        self.verbs.extend(other.verbs);
+    }
+
+    /// Lays a patch over the data, such as the data of an episode or a mod.
+    ///
+    /// Rooms, items and NPCs with the same ID, or the same name, as one in the data replace it, and the others are
+    /// added. Exits replace the exit in the same direction from the same room, dialogue replaces the answer of the
+    /// same NPC about the same topic, and verbs replace the verb with the same first word.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::data::WorldData;
+    ///
+    /// let mut data = WorldData::from_json(r#"{
+    ///     "rooms": [
+    ///         { "id": "foyer", "name": "Foyer", "description": "The dusty foyer of an old manor." },
+    ///         { "name": "Attic", "description": "A cramped attic." }
+    ///     ],
+    ///     "exits": [{ "from": "foyer", "to": "Attic", "direction": "up" }],
+    ///     "npcs": [{ "name": "butler", "room": "foyer" }],
+    ///     "dialogue": [{ "npc": "butler", "topic": "attic", "response": "Nobody goes up there." }]
+    /// }"#).unwrap();
+    /// data.overlay(WorldData::from_json(r#"{
+    ///     "rooms": [
+    ///         { "id": "foyer", "name": "Flooded Foyer", "description": "Water laps at the stairs." },
+    ///         { "name": "Cellar", "description": "A dark cellar." }
+    ///     ],
+    ///     "exits": [{ "from": "foyer", "to": "Cellar", "direction": "up", "exit": { "type": "chasm" } }],
+    ///     "dialogue": [{ "npc": "butler", "topic": "attic", "response": "The attic is dry, at least." }]
+    /// }"#).unwrap());
+    ///
+    /// assert_eq!(data.rooms.len(), 3);
+    /// assert_eq!(data.rooms[0].name, "Flooded Foyer");
+    /// assert_eq!(data.exits.len(), 1);
+    /// assert_eq!(data.exits[0].to, "Cellar");
+    ///
+    /// let mut world = data.build().unwrap();
+    /// let butler = world.npc_here("butler").unwrap();
+    /// assert_eq!(world.ask_about(butler, "attic"), "The attic is dry, at least.");
+    /// ```
+    pub fn overlay(&mut self, patch: WorldData) {
+        fn same(a: (&Option<String>, &str), b: (&Option<String>, &str)) -> bool {
+            (a.0.is_some() && a.0 == b.0) || a.1.eq_ignore_ascii_case(b.1)
+        }
+        fn replace_or_push<T>(entries: &mut Vec<T>, entry: T, same: impl Fn(&T, &T) -> bool) {
+            match entries.iter_mut().find(|existing| same(existing, &entry)) {
+                Some(existing) => *existing = entry,
+                None => entries.push(entry),
+            }
+        }
+
+        for room in patch.rooms {
+            replace_or_push(&mut self.rooms, room, |a, b| {
+                same((&a.id, &a.name), (&b.id, &b.name))
+            });
+        }
+        for item in patch.items {
+            replace_or_push(&mut self.items, item, |a, b| {
+                same((&a.id, &a.name), (&b.id, &b.name))
+            });
+        }
+        for npc in patch.npcs {
+            replace_or_push(&mut self.npcs, npc, |a, b| {
+                same((&a.id, &a.name), (&b.id, &b.name))
+            });
+        }
+        for exit in patch.exits {
+            let from = self.room_name(&exit.from).map(str::to_lowercase);
+            let index = self.exits.iter().position(|existing| {
+                existing.direction == exit.direction
+                    && self.room_name(&existing.from).map(str::to_lowercase) == from
+            });
+            match index {
+                Some(index) => self.exits[index] = exit,
+                None => self.exits.push(exit),
+            }
+        }
+        for dialogue in patch.dialogue {
+            let npc = self.npc_name(&dialogue.npc).map(str::to_lowercase);
+            let index = self.dialogue.iter().position(|existing| {
+                existing.topic.eq_ignore_ascii_case(&dialogue.topic)
+                    && self.npc_name(&existing.npc).map(str::to_lowercase) == npc
+            });
+            match index {
+                Some(index) => self.dialogue[index] = dialogue,
+                None => self.dialogue.push(dialogue),
+            }
+        }
+        for verb in patch.verbs {
+            replace_or_push(&mut self.verbs, verb, |a, b| {
+                a.words.first().map(|word| word.to_lowercase())
+                    == b.words.first().map(|word| word.to_lowercase())
+            });
+        }
+    }
+
+    /// Gets the name of the room with the given name or ID, preferring IDs.
+    pub fn room_name<'a>(&'a self, reference: &'a str) -> Option<&'a str> {
+        self.rooms
+            .iter()
+            .find(|room| room.id.as_deref() == Some(reference))
+            .or_else(|| {
+                self.rooms
+                    .iter()
+                    .find(|room| room.name.eq_ignore_ascii_case(reference))
+            })
+            .map(|room| room.name.as_str())
+    }
+
+    /// Gets the name of the NPC with the given name or ID, preferring IDs.
+    pub fn npc_name<'a>(&'a self, reference: &'a str) -> Option<&'a str> {
+        self.npcs
+            .iter()
+            .find(|npc| npc.id.as_deref() == Some(reference))
+            .or_else(|| {
+                self.npcs
+                    .iter()
+                    .find(|npc| npc.name.eq_ignore_ascii_case(reference))
+            })
+            .map(|npc| npc.name.as_str())
+    }
+
+    /// Sorts the parts of the data whose order doesn't matter, such as the tags of rooms, into a canonical order.
+    ///
+    /// Rooms, items, NPCs, exits and dialogue keep the order they were added in, since the [`World`] built from the
+    /// data refers to them by position, and so do its saves. Translations are always kept in order of language.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::data::WorldData;
+    ///
+    /// let mut data = WorldData::from_json(r#"{
+    ///     "rooms": [
+    ///         { "id": "manor.hall", "name": "Grand Hall", "description": "A roaring fireplace.", "tags": ["warm", "indoors"] },
+    ///         { "id": "manor.foyer", "name": "Foyer", "description": "A dusty foyer." }
+    ///     ]
+    /// }"#).unwrap();
+    /// data.sort();
+    /// assert_eq!(data.rooms[0].name, "Grand Hall");
+    /// assert_eq!(data.rooms[0].tags, vec!["indoors", "warm"]);
+    /// ```
+    pub fn sort(&mut self) {
+        for room in &mut self.rooms {
+            room.tags.sort();
+        }
+    }
+
+    /// Saves the data to pretty-printed JSON, [sorted](WorldData::sort) into a canonical order.
+    pub fn to_json(&self) -> String {
+        let mut sorted = self.clone();
+        sorted.sort();
+        serde_json::to_string_pretty(&sorted).unwrap_or_default()
+    }
+
+    /// Builds a new [`World`] from the data.
+    ///
+    /// The data is [linted](crate::lint::lint) first, and every [`Diagnostic`] is returned if any of them is an error.
+    /// The stable IDs of rooms, items and NPCs are recorded in the [`StableIds`](crate::ids::StableIds) of the world,
+    /// and the [`Annotations`] of rooms and exits are kept on them.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::data::WorldData;
+    ///
+    /// let data = WorldData::from_json(r#"{
+    ///     "rooms": [
+    ///         { "id": "manor.foyer", "name": "Foyer", "description": "The dusty foyer of an old manor." },
+    ///         { "id": "manor.hall", "name": "Grand Hall", "description": "A roaring fireplace." }
+    ///     ],
+    ///     "exits": [{ "from": "manor.foyer", "to": "manor.hall", "direction": "north" }],
+    ///     "items": [{ "id": "manor.poker", "name": "iron poker", "description": "A heavy poker.", "room": "manor.hall" }]
+    /// }"#).unwrap();
+    ///
+    /// let world = data.build().unwrap();
+    /// let hall = world.ids.room("manor.hall").unwrap();
+    /// let poker = world.ids.item("manor.poker").unwrap();
+    /// assert_eq!(world.map.graph[hall].name.as_deref(), Some("Grand Hall"));
+    /// assert!(world.map.graph[hall].contents.contains(&poker));
+    /// ```
+    ///
+    /// Annotations round-trip through the data:
+    /// ```
+    /// use worldwright::data::WorldData;
+    /// use worldwright::map::Direction;
+    ///
+    /// let data = WorldData::from_json(r#"{
+    ///     "rooms": [
+    ///         { "name": "Attic", "description": "A cramped attic." },
+    ///         { "name": "Foyer", "description": "A dusty foyer.", "annotations": { "todos": ["Add a coat rack."] } }
+    ///     ],
+    ///     "exits": [{ "from": "Foyer", "to": "Attic", "direction": "north",
+    ///                 "annotations": { "comments": ["The ladder creaks."], "color": "red" } }]
+    /// }"#).unwrap();
+    /// assert_eq!(WorldData::from_json(&data.to_json()).unwrap(), data);
+    ///
+    /// let world = data.build().unwrap();
+    /// let foyer = world.map.room_by_name("Foyer").unwrap();
+    /// assert_eq!(world.map.graph[foyer].annotations.todos, vec!["Add a coat rack."]);
+    /// let ladder = world.map.exit_id_in_direction(foyer, Direction::North).unwrap();
+    /// assert_eq!(world.map.graph[ladder].annotations.color.as_deref(), Some("red"));
+    /// ```
+    pub fn build(&self) -> Result<World, Vec<Diagnostic>> {
+        let diagnostics = lint::lint(self);
+        if diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error)
+        {
+            return Err(diagnostics);
+        }
+
+        let mut world = World::new();
+        for room_data in &self.rooms {
+            let mut room = Room::new_with_name(room_data.name.clone(), String::new());
+            let mut description = Localized::new(room_data.description.clone());
+            for (language, translation) in &room_data.translations {
+                if let Some(text) = &translation.description {
+                    description = description.with(language, text.clone());
+                }
+            }
+            room.description = description.into();
+            room.short_description = room_data.short_description.clone().map(|short| {
+                let mut short = Localized::new(short);
+                for (language, translation) in &room_data.translations {
+                    if let Some(text) = &translation.short_description {
+                        short = short.with(language, text.clone());
+                    }
+                }
+                short.into()
+            });
+            for tag in &room_data.tags {
+                room.add_tag(tag);
+            }
+            room.annotations = room_data.annotations.clone();
+            let room_id = world.map.add_room(room);
+            if let Some(id) = &room_data.id {
+                world.ids.add_room(id, room_id);
+            }
+        }
+        // The linter made sure every room below exists.
+        let room_id = |world: &World, reference: &str| {
+            world
+                .map
+                .room_by_name(self.room_name(reference).unwrap())
+                .unwrap()
+        };
+        for exit in &self.exits {
+            let from = room_id(&world, &exit.from);
+            let to = room_id(&world, &exit.to);
+            let exit_id =
+                world
+                    .map
+                    .connect_rooms(from, to, exit.direction, exit.exit.to_exit_type());
+            world.map.graph[exit_id].annotations = exit.annotations.clone();
+        }
+        for item_data in &self.items {
+            let mut item = Item::new(item_data.name.clone(), String::new());
+            item.description = Localized {
+                default: item_data.description.clone(),
+                translations: item_data.translations.clone(),
+            }
+            .into();
+            item.takeable = item_data.takeable;
+            item.pushable = item_data.pushable;
+            item.persistence = item_data.persistence;
+            item.weight = item_data.weight;
+            let item = world.items.add(item);
+            if let Some(id) = &item_data.id {
+                world.ids.add_item(id, item);
+            }
+            if let Some(room) = &item_data.room {
+                let room_id = room_id(&world, room);
+                world.map.place_item(room_id, item);
+            }
+        }
+        for npc_data in &self.npcs {
+            let mut npc = Npc::new(npc_data.name.clone(), room_id(&world, &npc_data.room));
+            npc.dialogue.greeting = npc_data.greeting.clone();
+            npc.description = npc_data.description.clone();
+            if !npc_data.barks.is_empty() {
+                npc.barks = Some(BarkPool::new(npc_data.barks.clone()));
+            }
+            for (language, translation) in &npc_data.translations {
+                let texts = [
+                    (&npc_data.greeting, &translation.greeting),
+                    (&npc_data.description, &translation.description),
+                ];
+                for (text, translated) in texts {
+                    if let (Some(text), Some(translated)) = (text, translated) {
+                        world.translations.add(language, text, translated.clone());
+                    }
+                }
+                for (bark, translated) in npc_data.barks.iter().zip(&translation.barks) {
+                    world.translations.add(language, bark, translated.clone());
+                }
+            }
+            for dialogue in &self.dialogue {
+                if self.npc_name(&dialogue.npc) == Some(npc_data.name.as_str()) {
+                    npc.dialogue.add(
+                        DialogueTopic::ask(&dialogue.topic)
+                            .response(Response::new(&dialogue.response)),
+                    );
+                    for (language, translated) in &dialogue.translations {
+                        world
+                            .translations
+                            .add(language, &dialogue.response, translated.clone());
+                    }
+                }
+            }
+            npc.patrol = npc_data.patrol.clone().map(|mut route| {
+                // Patrols are resolved by room name.
+                for stop in &mut route.stops {
+                    stop.room = self.room_name(&stop.room).unwrap().into();
+                }
+                Patrol::new(route)
+            });
+            let npc = world.npcs.add(npc);
+            if let Some(id) = &npc_data.id {
+                world.ids.add_npc(id, npc);
+            }
+        }
+        #[cfg(feature = "parser")]
+        for verb in &self.verbs {
+            let words: Vec<&str> = verb.words.iter().map(String::as_str).collect();
+            let (response, flag) = (verb.response.clone(), verb.set_flag.clone());
+            world.parser.add_verb(&words, move |world, _| {
+                if let Some(flag) = &flag {
+                    world.set_flag(flag, true);
+                }
+                let message = world.render_template(&response, &[]);
+                world.output.say(message);
+            });
+        }
+        Ok(world)
+    }
+}
+
+/// Collapses the `.` and `..` components of a path, so that a file is always named the same way.
+fn normalize_path(path: &str) -> String {
+    let absolute = path.starts_with(['/', '\\']);
+    let mut components: Vec<&str> = Vec::new();
+    for component in path.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." if components.last().is_some_and(|last| *last != "..") => {
+                components.pop();
+            }
+            ".." if absolute => {}
+            _ => components.push(component),
+        }
+    }
+    let joined = components.join("/");
+    if absolute {
+        format!("/{joined}")
+    } else {
+        joined
+    }
+}
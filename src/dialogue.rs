@@ -0,0 +1,333 @@
+use crate::World;
+use crate::actor::NpcId;
+use crate::journal::EntryKind;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use alloc::collections::BTreeSet;
+use alloc::rc::Rc;
+
+/// A condition checked against the [`World`] before a [`Response`] is given, such as a flag being set.
+pub type DialogueCondition = Rc<dyn Fn(&World) -> bool>;
+
+/// A callback run with mutable access to the [`World`] after a [`Response`] is given.
+pub type DialogueEffect = Rc<dyn Fn(&mut World)>;
+
+/// Whether the player asks an NPC about a topic or tells them about it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TopicKind {
+    /// "ask butler about key"
+    Ask,
+    /// "tell butler about key"
+    Tell,
+}
+
+/// What an NPC says about a [`DialogueTopic`], if its condition holds.
+#[derive(Clone)]
+pub struct Response {
+    /// What the NPC says.
+    pub text: String,
+    /// The condition under which the NPC says it, or `None` to always say it.
+    pub condition: Option<DialogueCondition>,
+    /// What happens once the NPC has said it.
+    pub effect: Option<DialogueEffect>,
+}
+
+impl Response {
+    /// Creates a new, unconditional `Response`.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.into(),
+            condition: None,
+            effect: None,
+        }
+    }
+
+    /// Only gives the response when `condition` holds.
+    pub fn when(mut self, condition: impl Fn(&World) -> bool + 'static) -> Self {
+        self.condition = Some(Rc::new(condition));
+        self
+    }
+
+    /// Runs `effect` after the response is given.
+    pub fn with_effect(mut self, effect: impl Fn(&mut World) + 'static) -> Self {
+        self.effect = Some(Rc::new(effect));
+        self
+    }
+}
+
+impl core::fmt::Debug for Response {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Response")
+            .field("text", &self.text)
+            .field("condition", &self.condition.is_some())
+            .field("effect", &self.effect.is_some())
+            .finish()
+    }
+}
+
+/// Something the player can ask or tell an NPC about, with its responses and the topics it leads to.
+#[derive(Clone, Debug)]
+pub struct DialogueTopic {
+    /// The name of the topic, such as "key".
+    pub name: String,
+    /// Other names the player can use for the topic, such as "brass key".
+    pub aliases: Vec<String>,
+    /// Whether the player asks or tells about the topic.
+    pub kind: TopicKind,
+    /// The responses of the NPC. The first one whose condition holds is given.
+    pub responses: Vec<Response>,
+    /// The topics that can only be brought up once this one has been discussed.
+    pub subtopics: Vec<DialogueTopic>,
+}
+
+impl DialogueTopic {
+    /// Creates a new topic to ask about, without responses.
+    pub fn ask(name: &str) -> Self {
+        Self::new(name, TopicKind::Ask)
+    }
+
+    /// Creates a new topic to tell about, without responses.
+    pub fn tell(name: &str) -> Self {
+        Self::new(name, TopicKind::Tell)
+    }
+
+    fn new(name: &str, kind: TopicKind) -> Self {
+        Self {
+            name: name.into(),
+            aliases: Vec::new(),
+            kind,
+            responses: Vec::new(),
+            subtopics: Vec::new(),
+        }
+    }
+
+    /// Adds another name for the topic.
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Adds a [`Response`], tried after the ones added before it.
+    pub fn response(mut self, response: Response) -> Self {
+        self.responses.push(response);
+        self
+    }
+
+    /// Adds a topic that can only be brought up once this one has been discussed.
+    pub fn subtopic(mut self, topic: DialogueTopic) -> Self {
+        self.subtopics.push(topic);
+        self
+    }
+
+    fn is_called(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+}
+
+/// The topic trees an [`Npc`](crate::actor::Npc) can talk about, and what has already been discussed.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::actor::Npc;
+/// use worldwright::dialogue::{DialogueTopic, Response};
+///
+/// let mut world = World::new();
+/// let hall = world.map.new_room("A grand hall.".into());
+/// let mut butler = Npc::new("butler".into(), hall);
+/// butler.dialogue.greeting = Some("\"Good evening.\"".into());
+/// butler.dialogue.add(
+///     DialogueTopic::ask("key")
+///         .alias("brass key")
+///         .response(Response::new("\"The master has it.\"").when(|world| world.variables.get("master dead").is_none()))
+///         .response(Response::new("\"Perhaps in the study.\""))
+///         .subtopic(DialogueTopic::ask("study").response(Response::new("\"Upstairs, sir.\""))),
+/// );
+/// let butler = world.npcs.add(butler);
+///
+/// assert_eq!(world.talk_to(butler), "\"Good evening.\" You could ask about key.");
+/// assert_eq!(world.ask_about(butler, "study"), "The butler has nothing to say about that.");
+/// assert_eq!(world.ask_about(butler, "brass key"), "\"The master has it.\"");
+/// assert!(world.has_discussed(butler, "key"));
+///
+/// world.variables.set("master dead", "yes");
+/// assert_eq!(world.ask_about(butler, "key"), "\"Perhaps in the study.\"");
+/// assert_eq!(world.ask_about(butler, "study"), "\"Upstairs, sir.\"");
+///
+/// // What NPCs say is logged in the journal.
+/// let recalled = world.journal.recall("study");
+/// assert_eq!(recalled.len(), 2);
+/// assert_eq!(recalled[1].text, "The butler, about study: \"Upstairs, sir.\"");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Dialogue {
+    /// What the NPC says when the player talks to them.
+    pub greeting: Option<String>,
+    /// What the NPC says about topics they don't know, or `None` for "The {name} has nothing to say about that."
+    pub fallback: Option<String>,
+    /// The topics at the root of the tree.
+    pub topics: Vec<DialogueTopic>,
+    /// The names of the topics that have been discussed.
+    pub discussed: BTreeSet<String>,
+}
+
+impl Dialogue {
+    /// Creates a new `Dialogue` without topics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a topic at the root of the tree.
+    pub fn add(&mut self, topic: DialogueTopic) {
+        self.topics.push(topic);
+    }
+
+    /// Returns the topics that can be brought up: the root ones, and the subtopics of the ones discussed.
+    pub fn available(&self) -> Vec<&DialogueTopic> {
+        let mut available = Vec::new();
+        let mut pending: Vec<&DialogueTopic> = self.topics.iter().rev().collect();
+        while let Some(topic) = pending.pop() {
+            available.push(topic);
+            if self.discussed.contains(&topic.name) {
+                pending.extend(topic.subtopics.iter().rev());
+            }
+        }
+        available
+    }
+
+    /// Finds an available topic of the given kind by name or alias.
+    pub fn find(&self, kind: TopicKind, name: &str) -> Option<&DialogueTopic> {
+        self.available()
+            .into_iter()
+            .find(|topic| topic.kind == kind && topic.is_called(name))
+    }
+}
+
+impl World {
+    /// Talks to an NPC, returning their greeting followed by what the player could ask them about.
+    pub fn talk_to(&self, npc: NpcId) -> String {
+        let npc = &self.npcs[npc];
+        let mut text = match &npc.dialogue.greeting {
+            Some(greeting) => self.translate(greeting),
+            None => format!("The {} looks at you.", npc.name),
+        };
+        let topics: Vec<String> = npc
+            .dialogue
+            .available()
+            .into_iter()
+            .filter(|topic| topic.kind == TopicKind::Ask)
+            .map(|topic| topic.name.clone())
+            .collect();
+        if !topics.is_empty() {
+            text.push_str(&format!(
+                " You could ask about {}.",
+                crate::join_list(&topics)
+            ));
+        }
+        text
+    }
+
+    /// Asks an NPC about a topic, returning their response.
+    pub fn ask_about(&mut self, npc: NpcId, topic: &str) -> String {
+        self.discuss(npc, TopicKind::Ask, topic)
+    }
+
+    /// Tells an NPC about a topic, returning their response.
+    pub fn tell_about(&mut self, npc: NpcId, topic: &str) -> String {
+        self.discuss(npc, TopicKind::Tell, topic)
+    }
+
+    /// Checks whether a topic has been discussed with an NPC.
+    pub fn has_discussed(&self, npc: NpcId, topic: &str) -> bool {
+        self.npcs[npc].dialogue.discussed.contains(topic)
+    }
+
+    fn discuss(&mut self, npc_id: NpcId, kind: TopicKind, name: &str) -> String {
+        let npc = &self.npcs[npc_id];
+        let found = npc.dialogue.find(kind, name).and_then(|topic| {
+            let response = topic.responses.iter().find(|response| {
+                response
+                    .condition
+                    .as_ref()
+                    .is_none_or(|condition| condition(self))
+            })?;
+            Some((topic.name.clone(), response.clone()))
+        });
+        let Some((topic, response)) = found else {
+            return match &npc.dialogue.fallback {
+                Some(fallback) => self.translate(fallback),
+                None => format!("The {} has nothing to say about that.", npc.name),
+            };
+        };
+        let text = self.translate(&response.text);
+        let entry = format!("The {}, about {topic}: {text}", npc.name);
+        self.journal.log(EntryKind::Conversation, entry);
+        self.npcs[npc_id].dialogue.discussed.insert(topic);
+        if let Some(effect) = &response.effect {
+            effect(self);
+        }
+        text
+    }
+
+    /// Finds an NPC in the player's room by name.
+    pub fn npc_here(&self, name: &str) -> Option<NpcId> {
+        self.npcs
+            .in_room(self.player.current_room)
+            .into_iter()
+            .find(|&npc| self.npcs[npc].name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// A command handler for conversations with the NPCs in the player's room, such as "talk to butler",
+/// "ask butler about key" or "tell butler about murder".
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::actor::Npc;
+/// use worldwright::dialogue::{DialogueTopic, Response, conversation_command};
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["talk"], conversation_command);
+/// world.parser.add_verb(&["ask"], conversation_command);
+/// world.parser.add_verb(&["tell"], conversation_command);
+/// let hall = world.map.new_room("A grand hall.".into());
+/// let mut butler = Npc::new("butler".into(), hall);
+/// butler.dialogue.add(DialogueTopic::tell("murder").response(Response::new("\"Good heavens!\"")));
+/// world.npcs.add(butler);
+/// world.player.current_room = hall;
+///
+/// world.execute("talk to the butler").unwrap();
+/// world.execute("tell butler about murder").unwrap();
+/// world.execute("ask gardener about roses").unwrap();
+/// assert_eq!(
+///     world.output.drain(),
+///     vec!["The butler looks at you.", "\"Good heavens!\"", "There is no gardener here."]
+/// );
+/// ```
+#[cfg(feature = "parser")]
+pub fn conversation_command(world: &mut World, command: &Command) {
+    let words: &[String] = match command.words.split_first() {
+        Some((first, rest)) if command.verb == "talk" && (first == "to" || first == "with") => rest,
+        _ => &command.words,
+    };
+    let (name, topic) = match words.iter().position(|word| word == "about") {
+        Some(index) => (words[..index].join(" "), Some(words[index + 1..].join(" "))),
+        None => (words.join(" "), None),
+    };
+    let Some(npc) = world.npc_here(&name) else {
+        world.output.say(format!("There is no {name} here."));
+        return;
+    };
+    let reply = match (command.verb.as_str(), topic) {
+        ("ask", Some(topic)) => world.ask_about(npc, &topic),
+        ("tell", Some(topic)) => world.tell_about(npc, &topic),
+        _ => world.talk_to(npc),
+    };
+    world.output.say(reply);
+}
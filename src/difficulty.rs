@@ -0,0 +1,193 @@
+use crate::World;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How eagerly the [`Tutorial`](crate::tutorial::Tutorial) offers its tips.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum HintLevel {
+    /// No tips are shown.
+    Off,
+    /// Each tip is shown once.
+    Gentle,
+    /// Tips are shown every time until they are acknowledged.
+    Eager,
+}
+
+/// The knobs of a difficulty level, which authors bind their game parameters to.
+///
+/// Switching difficulty with [`World::set_difficulty`] applies the [`HintLevel`] at once. Deadlines and damage are
+/// scaled when the game asks for them, so they follow the difficulty in effect at that moment.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::difficulty::Difficulty;
+///
+/// let mut world = World::new();
+/// world.set_difficulty(Difficulty::hard());
+///
+/// // The bomb goes off sooner and hurts more on hard.
+/// let fuse = world.timers.start("Bomb".into(), world.difficulty.deadline(10), true);
+/// assert_eq!(world.timers.get(fuse).unwrap().remaining, 7);
+/// assert_eq!(world.difficulty.damage(4), 6);
+///
+/// world.set_difficulty(Difficulty::easy());
+/// assert_eq!(world.difficulty.deadline(10), 15);
+/// assert_eq!(world.difficulty.knob("enemy speed", 1.0), 1.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Difficulty {
+    /// The name of the difficulty level, such as "normal".
+    pub name: String,
+    /// How eagerly tips are offered.
+    pub hints: HintLevel,
+    /// What deadlines are multiplied by. Below 1 gives the player less time.
+    pub deadline_multiplier: f64,
+    /// What damage to the player is multiplied by.
+    pub damage_multiplier: f64,
+    /// Other knobs defined by the game, by name.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub knobs: BTreeMap<String, f64>,
+}
+
+impl Difficulty {
+    /// Creates a new difficulty level with the given name and the knobs of [`normal`](Difficulty::normal).
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            hints: HintLevel::Eager,
+            deadline_multiplier: 1.0,
+            damage_multiplier: 1.0,
+            knobs: BTreeMap::new(),
+        }
+    }
+
+    /// Creates the "easy" difficulty level, with longer deadlines and half the damage.
+    pub fn easy() -> Self {
+        Self {
+            deadline_multiplier: 1.5,
+            damage_multiplier: 0.5,
+            ..Self::new("easy")
+        }
+    }
+
+    /// Creates the "normal" difficulty level, which shows tips until they are acknowledged and leaves deadlines and
+    /// damage as they are.
+    pub fn normal() -> Self {
+        Self::new("normal")
+    }
+
+    /// Creates the "hard" difficulty level, without hints, with shorter deadlines and more damage.
+    pub fn hard() -> Self {
+        Self {
+            hints: HintLevel::Off,
+            deadline_multiplier: 0.7,
+            damage_multiplier: 1.5,
+            ..Self::new("hard")
+        }
+    }
+
+    /// Finds a built-in difficulty level by name: "easy", "normal" or "hard".
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "easy" => Some(Self::easy()),
+            "normal" => Some(Self::normal()),
+            "hard" => Some(Self::hard()),
+            _ => None,
+        }
+    }
+
+    /// Scales a deadline in turns, never below one turn.
+    pub fn deadline(&self, turns: u32) -> u32 {
+        scale(turns, self.deadline_multiplier).max(1)
+    }
+
+    /// Scales damage to the player.
+    pub fn damage(&self, amount: u32) -> u32 {
+        scale(amount, self.damage_multiplier)
+    }
+
+    /// Gets a knob defined by the game, or `default` if this difficulty level doesn't set it.
+    pub fn knob(&self, name: &str, default: f64) -> f64 {
+        self.knobs.get(name).copied().unwrap_or(default)
+    }
+
+    /// Sets a knob defined by the game.
+    pub fn set_knob(&mut self, name: &str, value: f64) {
+        self.knobs.insert(name.into(), value);
+    }
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self::normal()
+    }
+}
+
+/// Multiplies a number, rounding to the nearest whole number.
+fn scale(value: u32, multiplier: f64) -> u32 {
+    (value as f64 * multiplier.max(0.0) + 0.5) as u32
+}
+
+impl World {
+    /// Switches the [`Difficulty`], applying its [`HintLevel`] to the [`Tutorial`](crate::tutorial::Tutorial).
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.tutorial.enabled = difficulty.hints != HintLevel::Off;
+        self.tutorial.max_times_shown = match difficulty.hints {
+            HintLevel::Gentle => Some(1),
+            _ => None,
+        };
+        self.difficulty = difficulty;
+    }
+}
+
+/// A command handler that switches between the built-in difficulty levels, such as "difficulty easy".
+///
+/// Without a level, it tells the player the current one.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::difficulty::difficulty_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["difficulty"], difficulty_command);
+///
+/// world.execute("difficulty").unwrap();
+/// world.execute("difficulty hard").unwrap();
+/// world.execute("difficulty nightmare").unwrap();
+/// assert_eq!(world.difficulty.name, "hard");
+/// assert!(!world.tutorial.enabled);
+/// assert_eq!(
+///     world.output.drain(),
+///     vec![
+///         "The difficulty is normal.",
+///         "Difficulty set to hard.",
+///         "There is no \"nightmare\" difficulty. Try easy, normal or hard.",
+///     ]
+/// );
+/// ```
+#[cfg(feature = "parser")]
+pub fn difficulty_command(world: &mut World, command: &Command) {
+    let Some(name) = command.noun() else {
+        let message = format!("The difficulty is {}.", world.difficulty.name);
+        world.output.say(message);
+        return;
+    };
+    match Difficulty::by_name(&name) {
+        Some(difficulty) => {
+            world.set_difficulty(difficulty);
+            world.output.say(format!("Difficulty set to {name}."));
+        }
+        None => world.output.say(format!(
+            "There is no \"{name}\" difficulty. Try easy, normal or hard."
+        )),
+    }
+}
@@ -0,0 +1,204 @@
+use crate::World;
+use crate::prelude::*;
+use alloc::rc::Rc;
+
+/// A condition over the state of the [`World`].
+pub type Condition = Rc<dyn Fn(&World) -> bool>;
+
+/// Identifies an [`Ending`] within [`Endings`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EndingId(usize);
+
+/// A weighted criterion that adds to the score of an [`Ending`] when its condition holds.
+#[derive(Clone)]
+pub struct Criterion {
+    /// A short description of the criterion, shown in the debug readout.
+    pub description: String,
+    /// How many points the criterion is worth. Can be negative.
+    pub weight: i32,
+    /// The condition that must hold for the criterion to be met.
+    pub condition: Condition,
+}
+
+impl core::fmt::Debug for Criterion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Criterion")
+            .field("description", &self.description)
+            .field("weight", &self.weight)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A possible ending of the game and the [`Criterion`]s that lead to it.
+#[derive(Clone, Debug)]
+pub struct Ending {
+    /// The name of the ending.
+    pub name: String,
+    /// The criteria scored for the ending.
+    pub criteria: Vec<Criterion>,
+}
+
+/// The set of possible [`Ending`]s of a game.
+///
+/// At the finale, every ending is scored by adding up the weights of its met [`Criterion`]s, and the ending with the highest score is picked.
+/// Ties are won by the ending that was added first.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::endings::Endings;
+///
+/// let mut endings = Endings::new();
+/// let hero = endings.add_ending("The Hero Returns".into());
+/// endings.add_criterion(hero, "explored the whole manor".into(), 10, |world| {
+///     world.map.graph.node_count() > 2
+/// });
+/// let villain = endings.add_ending("A Dark Legacy".into());
+/// endings.add_criterion(villain, "never left the start".into(), 5, |world| {
+///     world.map.graph.node_count() <= 2
+/// });
+///
+/// let world = World::new();
+/// let report = endings.evaluate(&world);
+/// assert_eq!(report.chosen(), Some("A Dark Legacy"));
+/// assert_eq!(
+///     report.to_string(),
+///     "The Hero Returns: 0\n  [ ] +10 explored the whole manor\nA Dark Legacy: 5\n  [x] +5 never left the start\nChosen: A Dark Legacy"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Endings {
+    endings: Vec<Ending>,
+}
+
+impl Endings {
+    /// Creates a new `Endings` without any endings.
+    pub fn new() -> Self {
+        Self {
+            endings: Vec::new(),
+        }
+    }
+
+    /// Adds an [`Ending`] without any criteria and returns its [`EndingId`].
+    pub fn add_ending(&mut self, name: String) -> EndingId {
+        self.endings.push(Ending {
+            name,
+            criteria: Vec::new(),
+        });
+        EndingId(self.endings.len() - 1)
+    }
+
+    /// Adds a [`Criterion`] to an [`Ending`].
+    pub fn add_criterion(
+        &mut self,
+        ending: EndingId,
+        description: String,
+        weight: i32,
+        condition: impl Fn(&World) -> bool + 'static,
+    ) {
+        self.endings[ending.0].criteria.push(Criterion {
+            description,
+            weight,
+            condition: Rc::new(condition),
+        });
+    }
+
+    /// Gets the [`Ending`] with the given [`EndingId`].
+    pub fn ending(&self, id: EndingId) -> Option<&Ending> {
+        self.endings.get(id.0)
+    }
+
+    /// Scores every [`Ending`] against the state of the [`World`] and picks the best one.
+    pub fn evaluate(&self, world: &World) -> EndingsReport {
+        let scores: Vec<EndingScore> = self
+            .endings
+            .iter()
+            .map(|ending| {
+                let criteria: Vec<CriterionResult> = ending
+                    .criteria
+                    .iter()
+                    .map(|criterion| CriterionResult {
+                        description: criterion.description.clone(),
+                        weight: criterion.weight,
+                        met: (criterion.condition)(world),
+                    })
+                    .collect();
+                EndingScore {
+                    name: ending.name.clone(),
+                    score: criteria.iter().filter(|c| c.met).map(|c| c.weight).sum(),
+                    criteria,
+                }
+            })
+            .collect();
+
+        let mut chosen: Option<usize> = None;
+        for (index, ending) in scores.iter().enumerate() {
+            if chosen.is_none_or(|best| ending.score > scores[best].score) {
+                chosen = Some(index);
+            }
+        }
+
+        EndingsReport {
+            scores,
+            chosen: chosen.map(EndingId),
+        }
+    }
+}
+
+/// Whether a [`Criterion`] was met during an evaluation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CriterionResult {
+    /// The description of the criterion.
+    pub description: String,
+    /// The weight of the criterion.
+    pub weight: i32,
+    /// Whether the criterion was met.
+    pub met: bool,
+}
+
+/// The score of an [`Ending`] during an evaluation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndingScore {
+    /// The name of the ending.
+    pub name: String,
+    /// The total weight of the met criteria.
+    pub score: i32,
+    /// The result of every criterion of the ending.
+    pub criteria: Vec<CriterionResult>,
+}
+
+/// The result of [`Endings::evaluate`].
+///
+/// When displayed, it prints a readout of every ending and criterion for debugging.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndingsReport {
+    /// The scores of every ending, in the order they were added.
+    pub scores: Vec<EndingScore>,
+    /// The [`EndingId`] of the picked ending, or `None` if there are no endings.
+    pub chosen: Option<EndingId>,
+}
+
+impl EndingsReport {
+    /// Returns the name of the picked ending.
+    pub fn chosen(&self) -> Option<&str> {
+        self.chosen.map(|id| self.scores[id.0].name.as_str())
+    }
+}
+
+impl core::fmt::Display for EndingsReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for ending in &self.scores {
+            writeln!(f, "{}: {}", ending.name, ending.score)?;
+            for criterion in &ending.criteria {
+                writeln!(
+                    f,
+                    "  [{}] {:+} {}",
+                    if criterion.met { "x" } else { " " },
+                    criterion.weight,
+                    criterion.description
+                )?;
+            }
+        }
+        write!(f, "Chosen: {}", self.chosen().unwrap_or("none"))
+    }
+}
@@ -0,0 +1,181 @@
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A value stored in [`Flags`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum FlagValue {
+    /// A flag that is set or not, such as "drawbridge_down".
+    Bool(bool),
+    /// A whole number, such as a score.
+    Int(i64),
+    /// A number with a fractional part.
+    Float(f64),
+    /// Text, such as the name the player gave their horse.
+    Text(String),
+}
+
+impl core::fmt::Display for FlagValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FlagValue::Bool(value) => write!(f, "{value}"),
+            FlagValue::Int(value) => write!(f, "{value}"),
+            FlagValue::Float(value) => write!(f, "{value}"),
+            FlagValue::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// A type that can be stored in [`Flags`] and read back, such as `bool`, `i32` or `String`.
+pub trait FlagType: Sized {
+    /// Converts the value into a [`FlagValue`].
+    fn into_flag(self) -> FlagValue;
+    /// Converts a [`FlagValue`] back, or returns `None` if it has another type.
+    fn from_flag(value: &FlagValue) -> Option<Self>;
+}
+
+impl FlagType for bool {
+    fn into_flag(self) -> FlagValue {
+        FlagValue::Bool(self)
+    }
+
+    fn from_flag(value: &FlagValue) -> Option<Self> {
+        match value {
+            FlagValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_flag_type_for_integers {
+    ($($integer:ty),*) => {
+        $(
+            impl FlagType for $integer {
+                fn into_flag(self) -> FlagValue {
+                    FlagValue::Int(i64::from(self))
+                }
+
+                fn from_flag(value: &FlagValue) -> Option<Self> {
+                    match value {
+                        FlagValue::Int(value) => (*value).try_into().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_flag_type_for_integers!(i32, i64, u32);
+
+impl FlagType for f64 {
+    fn into_flag(self) -> FlagValue {
+        FlagValue::Float(self)
+    }
+
+    fn from_flag(value: &FlagValue) -> Option<Self> {
+        match value {
+            FlagValue::Float(value) => Some(*value),
+            FlagValue::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+}
+
+impl FlagType for String {
+    fn into_flag(self) -> FlagValue {
+        FlagValue::Text(self)
+    }
+
+    fn from_flag(value: &FlagValue) -> Option<Self> {
+        match value {
+            FlagValue::Text(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// The global flags and variables of the game, such as whether the drawbridge is down or the score.
+///
+/// Unlike [`Properties`](crate::properties::Properties), values are limited to a few types so they can be serialized
+/// with the rest of the game. They can be used from conditions, dynamic descriptions, rules and templates alike.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::exit_types::ConditionalExit;
+/// use worldwright::map::ExitType;
+///
+/// let mut world = World::new();
+/// world.set_flag("drawbridge_down", true);
+/// world.set("score", 10i32);
+/// *world.flags.get_mut_int("score").unwrap() += 5;
+///
+/// assert!(world.flag("drawbridge_down"));
+/// assert!(!world.flag("gate_open"));
+/// assert_eq!(world.get::<i32>("score"), Some(15));
+/// assert_eq!(world.get::<String>("score"), None);
+/// assert_eq!(world.render_template("Score: {score}", &[]), "Score: 15");
+///
+/// let bridge = ConditionalExit::new("drawbridge".into(), |world| world.flag("drawbridge_down"), "The bridge is up.".into());
+/// assert_eq!(bridge.check_passage(&world), Ok(()));
+///
+/// # #[cfg(feature = "serde")] {
+/// let saved = serde_json::to_string(&world.flags).unwrap();
+/// assert_eq!(saved, r#"{"drawbridge_down":true,"score":15}"#);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Flags {
+    values: BTreeMap<String, FlagValue>,
+}
+
+impl Flags {
+    /// Creates a new, empty `Flags` store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a value, replacing any previous value regardless of its type.
+    pub fn set<T: FlagType>(&mut self, key: &str, value: T) {
+        self.values.insert(key.into(), value.into_flag());
+    }
+
+    /// Gets a value if it exists and can be read as `T`.
+    pub fn get<T: FlagType>(&self, key: &str) -> Option<T> {
+        T::from_flag(self.values.get(key)?)
+    }
+
+    /// Gets the raw [`FlagValue`] of a key.
+    pub fn value(&self, key: &str) -> Option<&FlagValue> {
+        self.values.get(key)
+    }
+
+    /// Gets a mutable reference to a whole number, such as to add to a score.
+    pub fn get_mut_int(&mut self, key: &str) -> Option<&mut i64> {
+        match self.values.get_mut(key)? {
+            FlagValue::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Checks whether a flag is set to `true`. Missing flags and values of other types are not set.
+    pub fn is_set(&self, flag: &str) -> bool {
+        self.get(flag) == Some(true)
+    }
+
+    /// Removes a value, returning whether it existed.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.values.remove(key).is_some()
+    }
+
+    /// Returns an iterator over every key and its value, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FlagValue)> {
+        self.values.iter().map(|(key, value)| (key.as_str(), value))
+    }
+}
@@ -0,0 +1,301 @@
+use crate::World;
+#[cfg(feature = "parser")]
+use crate::parser::ParseError;
+use crate::prelude::*;
+#[cfg(not(feature = "serde"))]
+use crate::properties::Properties;
+#[cfg(feature = "serde")]
+use alloc::collections::BTreeMap;
+use alloc::collections::VecDeque;
+#[cfg(feature = "serde")]
+use serde::{Serialize, de::DeserializeOwned};
+#[cfg(feature = "serde")]
+use serde_json::Value;
+
+/// The turn history of a game, kept by the game loop so the player can undo and redo turns.
+///
+/// A snapshot of the [`World`] is [recorded](History::record) before every turn. Only the last
+/// [`depth`](History::depth) turns can be undone, to bound the memory the snapshots take. The [`Outcomes`] of
+/// [undo-safe](World::undo_safe) sections are kept when turns are undone or redone.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "parser")] {
+/// use worldwright::World;
+/// use worldwright::history::History;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+///
+/// let mut world = World::new();
+/// let bridge = world.map.new_room("A rickety bridge.".into());
+/// let lair = world.map.new_room_in_direction(bridge, Direction::North, Box::new(RegularExit), "A troll's lair.".into());
+/// world.player.current_room = bridge;
+/// world.parser.add_verb(&["north"], |world, _| {
+///     world.move_player(Direction::North).unwrap();
+///     world.advance_turn();
+/// });
+/// world.parser.add_verb(&["look"], |world, _| world.output.say("You look around.".into()));
+/// let mut history = History::new(10);
+///
+/// history.execute(&mut world, "north").unwrap();
+/// assert_eq!(world.player.current_room, lair);
+/// // Looking around doesn't take a turn, so there is nothing to record.
+/// history.execute(&mut world, "look").unwrap();
+/// assert_eq!(history.undo_len(), 1);
+///
+/// world.output.drain();
+/// history.execute(&mut world, "undo").unwrap();
+/// assert_eq!(world.player.current_room, bridge);
+/// assert_eq!(world.turn, 0);
+/// assert_eq!(world.output.drain(), vec!["Previous turn undone."]);
+///
+/// history.execute(&mut world, "redo").unwrap();
+/// assert_eq!(world.player.current_room, lair);
+/// history.execute(&mut world, "redo").unwrap();
+/// assert_eq!(world.output.drain(), vec!["Turn redone.", "There is nothing to redo."]);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct History {
+    /// The most turns that can be undone. No history is kept if it is 0.
+    pub depth: usize,
+    undo: VecDeque<World>,
+    redo: Vec<World>,
+}
+
+impl core::fmt::Debug for History {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("History")
+            .field("depth", &self.depth)
+            .field("undo", &self.undo.len())
+            .field("redo", &self.redo.len())
+            .finish()
+    }
+}
+
+impl History {
+    /// Creates a new, empty `History` that can undo up to `depth` turns.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Records a snapshot of the world as it was before a turn, forgetting the oldest snapshot beyond the depth and
+    /// every turn that could be redone.
+    pub fn record(&mut self, snapshot: World) {
+        self.redo.clear();
+        if self.depth == 0 {
+            return;
+        }
+        if self.undo.len() == self.depth {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(snapshot);
+    }
+
+    /// Undoes the last recorded turn, putting the world back as it was before it. Returns whether there was a turn to
+    /// undo.
+    pub fn undo(&mut self, world: &mut World) -> bool {
+        let Some(snapshot) = self.undo.pop_back() else {
+            return false;
+        };
+        let outcomes = world.outcomes.clone();
+        self.redo.push(core::mem::replace(world, snapshot));
+        world.outcomes = outcomes;
+        true
+    }
+
+    /// Redoes the last undone turn. Returns whether there was a turn to redo.
+    pub fn redo(&mut self, world: &mut World) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+        let outcomes = world.outcomes.clone();
+        self.undo.push_back(core::mem::replace(world, snapshot));
+        world.outcomes = outcomes;
+        true
+    }
+
+    /// Gets the number of turns that can be undone.
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Gets the number of turns that can be redone.
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Forgets every recorded turn, such as after loading a save.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// [Executes](World::execute) the player's input, recording the turn if it took one, or undoes or redoes a turn
+    /// if the input is "undo" or "redo".
+    #[cfg(feature = "parser")]
+    pub fn execute(&mut self, world: &mut World, input: &str) -> Result<(), ParseError> {
+        let message = match input.trim().to_lowercase().as_str() {
+            "undo" if self.undo(world) => "Previous turn undone.",
+            "undo" => "There is nothing to undo.",
+            "redo" if self.redo(world) => "Turn redone.",
+            "redo" => "There is nothing to redo.",
+            _ => {
+                let snapshot = world.clone();
+                world.execute(input)?;
+                if world.turn != snapshot.turn {
+                    self.record(snapshot);
+                }
+                return Ok(());
+            }
+        };
+        world.output.say(message.into());
+        Ok(())
+    }
+}
+
+/// A value an [undo-safe](World::undo_safe) section can decide, such as the items of a randomized loot draw.
+///
+/// With the `serde` feature, outcomes must also be serializable, so they can be saved with the game in progress.
+#[cfg(feature = "serde")]
+pub trait Outcome: Clone + core::fmt::Debug + Serialize + DeserializeOwned + 'static {}
+
+#[cfg(feature = "serde")]
+impl<T: Clone + core::fmt::Debug + Serialize + DeserializeOwned + 'static> Outcome for T {}
+
+/// A value an [undo-safe](World::undo_safe) section can decide, such as the items of a randomized loot draw.
+///
+/// With the `serde` feature, outcomes must also be serializable, so they can be saved with the game in progress.
+#[cfg(not(feature = "serde"))]
+pub trait Outcome: Clone + core::fmt::Debug + 'static {}
+
+#[cfg(not(feature = "serde"))]
+impl<T: Clone + core::fmt::Debug + 'static> Outcome for T {}
+
+/// The outcomes of the [undo-safe](World::undo_safe) sections of the game, such as randomized loot draws.
+///
+/// They are kept when turns are undone, so that undoing a turn and trying again replays an outcome instead of drawing
+/// it again. With the `serde` feature, they are kept as JSON and saved with the game in progress.
+#[derive(Clone, Debug, Default)]
+pub struct Outcomes {
+    #[cfg(feature = "serde")]
+    values: BTreeMap<String, Value>,
+    #[cfg(not(feature = "serde"))]
+    values: Properties,
+}
+
+impl Outcomes {
+    /// Creates a new `Outcomes` without any outcomes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the outcome of a section, if it has been run and its outcome is a `T`.
+    pub fn get<T: Outcome>(&self, key: &str) -> Option<T> {
+        #[cfg(feature = "serde")]
+        let outcome = serde_json::from_value(self.values.get(key)?.clone()).ok();
+        #[cfg(not(feature = "serde"))]
+        let outcome = self.values.get(key).cloned();
+        outcome
+    }
+
+    /// Checks whether a section has been run.
+    pub fn contains(&self, key: &str) -> bool {
+        #[cfg(feature = "serde")]
+        let contains = self.values.contains_key(key);
+        #[cfg(not(feature = "serde"))]
+        let contains = self.values.contains(key);
+        contains
+    }
+
+    /// Forgets the outcome of a section, so it is run again the next time, such as when a chest is restocked. Returns
+    /// whether it had an outcome.
+    pub fn forget(&mut self, key: &str) -> bool {
+        #[cfg(feature = "serde")]
+        let removed = self.values.remove(key).is_some();
+        #[cfg(not(feature = "serde"))]
+        let removed = self.values.remove(key);
+        removed
+    }
+
+    /// Gets the outcomes of every section that has been run, as JSON by key, such as to save them.
+    #[cfg(feature = "serde")]
+    pub fn saved(&self) -> &BTreeMap<String, Value> {
+        &self.values
+    }
+
+    /// Replaces the outcomes with the given ones, as JSON by key, such as from a saved game.
+    #[cfg(feature = "serde")]
+    pub fn restore(&mut self, values: BTreeMap<String, Value>) {
+        self.values = values;
+    }
+
+    /// Records the outcome of a section, failing if it can't be turned into JSON, such as a map whose keys aren't
+    /// strings.
+    #[cfg(feature = "serde")]
+    fn record<T: Outcome>(&mut self, key: &str, outcome: &T) -> Result<(), serde_json::Error> {
+        let value = serde_json::to_value(outcome)?;
+        self.values.insert(key.into(), value);
+        Ok(())
+    }
+
+    /// Records the outcome of a section.
+    #[cfg(not(feature = "serde"))]
+    fn record<T: Outcome>(
+        &mut self,
+        key: &str,
+        outcome: &T,
+    ) -> Result<(), core::convert::Infallible> {
+        self.values.set(key, outcome.clone());
+        Ok(())
+    }
+}
+
+impl World {
+    /// Runs an undo-safe section, such as a randomized loot draw, whose outcome can't be changed by undoing turns.
+    ///
+    /// The first time a section with the given key is run, its outcome is recorded in the [`Outcomes`] of the world.
+    /// Later, even after the turn has been undone, the recorded outcome is replayed without running the section again.
+    /// The section should only decide the outcome, leaving its effects to the caller, as they are undone with the turn.
+    ///
+    /// With the `serde` feature, the outcome must be representable as JSON. An outcome that isn't, such as a map whose
+    /// keys aren't strings, isn't recorded, so the section is run again the next time. This is a bug in the game, and
+    /// panics in debug builds.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::history::History;
+    ///
+    /// let mut world = World::new();
+    /// world.seed(7);
+    /// let mut history = History::new(5);
+    /// let draw = |world: &mut World| world.undo_safe("chest loot", |world| world.rng.below(1000));
+    ///
+    /// history.record(world.clone());
+    /// let loot = draw(&mut world);
+    /// world.turn += 1;
+    /// history.undo(&mut world);
+    ///
+    /// // Doing something else first would change the draw, but its outcome is replayed.
+    /// world.rng.next_u64();
+    /// assert_eq!(draw(&mut world), loot);
+    /// assert_eq!(world.outcomes.get::<u32>("chest loot"), Some(loot));
+    /// ```
+    pub fn undo_safe<T: Outcome>(&mut self, key: &str, section: impl FnOnce(&mut World) -> T) -> T {
+        if let Some(outcome) = self.outcomes.get::<T>(key) {
+            return outcome;
+        }
+        let outcome = section(self);
+        let recorded = self.outcomes.record(key, &outcome);
+        debug_assert!(
+            recorded.is_ok(),
+            "the outcome of the undo-safe section {key:?} can't be recorded: {recorded:?}"
+        );
+        outcome
+    }
+}
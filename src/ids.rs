@@ -0,0 +1,90 @@
+use crate::actor::NpcId;
+use crate::item::ItemId;
+use crate::map::RoomId;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+
+/// Stable string IDs chosen by the author for rooms, items and NPCs, such as "manor.foyer".
+///
+/// Unlike [`RoomId`]s, [`ItemId`]s and [`NpcId`]s, which depend on the order things were added in, stable IDs stay the
+/// same when data files are reordered or merged. [`WorldData`](crate::data::WorldData) records the IDs it was
+/// written with when it is built.
+///
+/// # Examples
+/// ```
+/// use worldwright::ids::StableIds;
+/// use worldwright::map::Map;
+///
+/// let mut map = Map::new();
+/// let foyer = map.new_room_with_name("Foyer".into(), "A dusty foyer.".into());
+///
+/// let mut ids = StableIds::new();
+/// ids.add_room("manor.foyer", foyer);
+/// assert_eq!(ids.room("manor.foyer"), Some(foyer));
+/// assert_eq!(ids.room_key(foyer), Some("manor.foyer"));
+/// assert_eq!(ids.room("manor.attic"), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StableIds {
+    rooms: BTreeMap<String, RoomId>,
+    items: BTreeMap<String, ItemId>,
+    npcs: BTreeMap<String, NpcId>,
+}
+
+impl StableIds {
+    /// Creates a new, empty `StableIds` registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gives a room a stable ID.
+    pub fn add_room(&mut self, id: &str, room_id: RoomId) {
+        self.rooms.insert(id.into(), room_id);
+    }
+
+    /// Gets the room with the given stable ID.
+    pub fn room(&self, id: &str) -> Option<RoomId> {
+        self.rooms.get(id).copied()
+    }
+
+    /// Gets the stable ID of a room, if it has one.
+    pub fn room_key(&self, room_id: RoomId) -> Option<&str> {
+        key_of(&self.rooms, room_id)
+    }
+
+    /// Gives an item a stable ID.
+    pub fn add_item(&mut self, id: &str, item: ItemId) {
+        self.items.insert(id.into(), item);
+    }
+
+    /// Gets the item with the given stable ID.
+    pub fn item(&self, id: &str) -> Option<ItemId> {
+        self.items.get(id).copied()
+    }
+
+    /// Gets the stable ID of an item, if it has one.
+    pub fn item_key(&self, item: ItemId) -> Option<&str> {
+        key_of(&self.items, item)
+    }
+
+    /// Gives an NPC a stable ID.
+    pub fn add_npc(&mut self, id: &str, npc: NpcId) {
+        self.npcs.insert(id.into(), npc);
+    }
+
+    /// Gets the NPC with the given stable ID.
+    pub fn npc(&self, id: &str) -> Option<NpcId> {
+        self.npcs.get(id).copied()
+    }
+
+    /// Gets the stable ID of an NPC, if it has one.
+    pub fn npc_key(&self, npc: NpcId) -> Option<&str> {
+        key_of(&self.npcs, npc)
+    }
+}
+
+fn key_of<T: PartialEq>(ids: &BTreeMap<String, T>, value: T) -> Option<&str> {
+    ids.iter()
+        .find(|(_, id)| **id == value)
+        .map(|(key, _)| key.as_str())
+}
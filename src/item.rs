@@ -0,0 +1,539 @@
+use crate::actor::NpcId;
+use crate::map::{Description, Map, Region, UnlockError};
+use crate::prelude::*;
+use crate::reset::Persistence;
+use crate::{join_list, with_article};
+use petgraph::prelude::NodeIndex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Identifies an [`Item`] in the [`Items`] registry of the [`World`](crate::World).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemId(usize);
+
+/// A thing that can be located in a [`Room`](crate::map::Room), such as a brass key or a lamp.
+#[derive(Clone, Debug)]
+pub struct Item {
+    /// The name of the item, such as "brass key".
+    pub name: String,
+    /// A description of the item.
+    pub description: Description,
+    /// Whether the player can pick up the item. Items are takeable by default, unlike scenery such as a fountain.
+    pub takeable: bool,
+    /// How heavy the item is, counted against the [carrying capacity](crate::Player::capacity) of the player.
+    pub weight: u32,
+    /// Whether the player can get inside the item, such as a wardrobe or a cart.
+    pub enterable: bool,
+    /// Whether the item moves between rooms with the player inside it, such as a cart. Vehicles must also be enterable.
+    pub vehicle: bool,
+    /// Whether the player can push the item from room to room, such as a crate, through exits that
+    /// [allow bulky objects](crate::map::ExitType::allows_bulky_objects).
+    pub pushable: bool,
+    /// Makes the item a [`Container`] that other items can be put in, such as a box or a chest.
+    pub container: Option<Container>,
+    /// Makes the item a [`Supporter`] that other items can be put on, such as a table.
+    pub supporter: Option<Supporter>,
+    /// Whether the item respawns at its home when the world [resets](crate::World::reset_world).
+    pub persistence: Persistence,
+}
+
+impl Item {
+    /// Creates a new `Item` with the given `name` and `description`.
+    pub fn new(name: String, description: String) -> Self {
+        Self {
+            name,
+            description: description.into(),
+            takeable: true,
+            weight: 1,
+            enterable: false,
+            vehicle: false,
+            pushable: false,
+            container: None,
+            supporter: None,
+            persistence: Persistence::Resets,
+        }
+    }
+
+    /// Gets the items in or on the item, if it is a [`Container`] or a [`Supporter`].
+    pub fn contents(&self) -> &[ItemId] {
+        match (&self.container, &self.supporter) {
+            (Some(container), _) => &container.contents,
+            (None, Some(supporter)) => &supporter.contents,
+            (None, None) => &[],
+        }
+    }
+
+    fn contents_mut(&mut self) -> Option<&mut Vec<ItemId>> {
+        match (&mut self.container, &mut self.supporter) {
+            (Some(container), _) => Some(&mut container.contents),
+            (None, Some(supporter)) => Some(&mut supporter.contents),
+            (None, None) => None,
+        }
+    }
+
+    /// Checks whether the contents of the item can be seen from outside: it is a supporter, an open container or a transparent one.
+    pub fn shows_contents(&self) -> bool {
+        match &self.container {
+            Some(container) => container.open || container.transparent,
+            None => self.supporter.is_some(),
+        }
+    }
+
+    /// Checks whether the contents of the item can be reached from outside: it is a supporter or an open container.
+    pub fn exposes_contents(&self) -> bool {
+        match &self.container {
+            Some(container) => container.open,
+            None => self.supporter.is_some(),
+        }
+    }
+}
+
+/// An [`Item`] other items can be put in, such as a box or a chest.
+///
+/// A closed container hides its contents unless it is transparent, like a glass case, and they can only be taken out while it is open.
+///
+/// # Examples
+/// ```
+/// use worldwright::item::{Container, Item, Items};
+/// use worldwright::map::UnlockError;
+///
+/// let mut items = Items::new();
+/// let key = items.add(Item::new("iron key".into(), "A heavy iron key.".into()));
+/// let mut chest = Container::new_with_key(true, Some(20), Some(key));
+/// assert!(!chest.open());
+/// assert_eq!(chest.unlock_with(key), Ok(()));
+/// assert!(chest.open());
+/// assert_eq!(Container::new(false).unlock_with(key), Err(UnlockError::NoKeyhole));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Container {
+    /// Whether the container is open.
+    pub open: bool,
+    /// Whether the contents can be seen while the container is closed.
+    pub transparent: bool,
+    /// Whether the container is locked. A locked container is always closed.
+    pub locked: bool,
+    /// The [`ItemId`] of the key that unlocks the container, if it can be unlocked with a key.
+    pub key: Option<ItemId>,
+    /// The most total [weight](Item::weight) the container can hold, or `None` if it is unlimited.
+    pub capacity: Option<u32>,
+    /// The items in the container.
+    pub contents: Vec<ItemId>,
+}
+
+impl Container {
+    /// Creates a new, empty and unlocked container with unlimited capacity, open or closed.
+    pub fn new(open: bool) -> Self {
+        Self {
+            open,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new, empty container with the given capacity, locked if `locked` is true, and opened by `key` if there is one.
+    ///
+    /// Unlocked containers start open and locked ones start closed.
+    pub fn new_with_key(locked: bool, capacity: Option<u32>, key: Option<ItemId>) -> Self {
+        Self {
+            open: !locked,
+            locked,
+            key,
+            capacity,
+            ..Self::default()
+        }
+    }
+
+    /// Opens the container, returning whether it is now open. A locked container can't be opened.
+    pub fn open(&mut self) -> bool {
+        if !self.locked {
+            self.open = true;
+        }
+        self.open
+    }
+
+    /// Closes the container.
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Locks the container, closing it.
+    pub fn lock(&mut self) {
+        self.open = false;
+        self.locked = true;
+    }
+
+    /// Unlocks the container.
+    pub fn unlock(&mut self) {
+        self.locked = false;
+    }
+
+    /// Unlocks the container with a key.
+    pub fn unlock_with(&mut self, key: ItemId) -> Result<(), UnlockError> {
+        match self.key {
+            None => Err(UnlockError::NoKeyhole),
+            Some(expected) if expected != key => Err(UnlockError::WrongKey),
+            Some(_) => {
+                self.locked = false;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An [`Item`] other items can be put on, such as a table or a shelf. Its contents are always in plain view.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Supporter {
+    /// The most total [weight](Item::weight) the supporter can hold, or `None` if it is unlimited.
+    pub capacity: Option<u32>,
+    /// The items on the supporter.
+    pub contents: Vec<ItemId>,
+}
+
+impl Supporter {
+    /// Creates a new, empty supporter with unlimited capacity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The reason an item can't be put in or on another one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PutError {
+    /// The item to put isn't carried by the player.
+    NotCarried,
+    /// The target isn't a [`Container`] or a [`Supporter`], or it can't be reached.
+    NotAHolder,
+    /// The target is a closed [`Container`].
+    Closed,
+    /// The target would hold more than its capacity.
+    Full,
+    /// The target is the item itself, or inside of it.
+    IntoItself,
+}
+
+/// The registry of every [`Item`] in the [`World`](crate::World).
+///
+/// Items are referred to by their [`ItemId`] everywhere else, such as in the contents of a [`Room`](crate::map::Room).
+///
+/// # Examples
+/// ```
+/// use worldwright::item::{Item, Items};
+///
+/// let mut items = Items::new();
+/// let key = items.add(Item::new("brass key".into(), "A small brass key.".into()));
+/// assert_eq!(items[key].name, "brass key");
+/// assert_eq!(items.find("BRASS KEY"), Some(key));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Items {
+    items: Vec<Item>,
+    revision: u64,
+}
+
+impl Items {
+    /// Creates a new, empty `Items` registry.
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            revision: 0,
+        }
+    }
+
+    /// Returns the revision of the registry, which changes every time an item is added or mutably borrowed, such as to
+    /// open a [`Container`].
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Adds an [`Item`] to the registry and returns its [`ItemId`].
+    pub fn add(&mut self, item: Item) -> ItemId {
+        self.revision += 1;
+        self.items.push(item);
+        ItemId(self.items.len() - 1)
+    }
+
+    /// Gets the [`Item`] with the given [`ItemId`].
+    pub fn get(&self, id: ItemId) -> Option<&Item> {
+        self.items.get(id.0)
+    }
+
+    /// Gets a mutable reference to the [`Item`] with the given [`ItemId`].
+    pub fn get_mut(&mut self, id: ItemId) -> Option<&mut Item> {
+        self.revision += 1;
+        self.items.get_mut(id.0)
+    }
+
+    /// Finds the first [`Item`] with the given name, ignoring case.
+    pub fn find(&self, name: &str) -> Option<ItemId> {
+        self.items
+            .iter()
+            .position(|item| item.name.eq_ignore_ascii_case(name))
+            .map(ItemId)
+    }
+
+    /// Returns every [`Item`] along with its [`ItemId`].
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, &Item)> {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (ItemId(index), item))
+    }
+
+    /// Returns the number of items in the registry.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Checks whether the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Adds up the [weight](Item::weight) of an item and of everything in or on it.
+    pub fn total_weight(&self, item: ItemId) -> u32 {
+        self[item].weight
+            + self[item]
+                .contents()
+                .iter()
+                .map(|&content| self.total_weight(content))
+                .sum::<u32>()
+    }
+
+    /// Finds the [`Container`] or [`Supporter`] an item is in or on.
+    pub fn holder_of(&self, item: ItemId) -> Option<ItemId> {
+        self.iter()
+            .find(|(_, holder)| holder.contents().contains(&item))
+            .map(|(id, _)| id)
+    }
+
+    /// Checks whether an item is in or on `outer`, directly or nested in other items.
+    pub fn encloses(&self, outer: ItemId, item: ItemId) -> bool {
+        let mut current = item;
+        while let Some(holder) = self.holder_of(current) {
+            if holder == outer {
+                return true;
+            }
+            current = holder;
+        }
+        false
+    }
+
+    /// Lists the items that can be seen in or on an item, including nested ones, in order.
+    pub fn visible_contents(&self, item: ItemId) -> Vec<ItemId> {
+        let mut visible = Vec::new();
+        if self[item].shows_contents() {
+            for &content in self[item].contents() {
+                visible.push(content);
+                visible.extend(self.visible_contents(content));
+            }
+        }
+        visible
+    }
+
+    /// Takes an item out of the [`Container`] or [`Supporter`] it is in or on, returning whether it was in one.
+    pub fn remove_from_holder(&mut self, item: ItemId) -> bool {
+        let Some(holder) = self.holder_of(item) else {
+            return false;
+        };
+        if let Some(contents) = self[holder].contents_mut() {
+            contents.retain(|&content| content != item);
+        }
+        true
+    }
+
+    /// Puts an item in or on another one without any checks. Returns whether the target is a [`Container`] or a [`Supporter`].
+    pub fn insert_into(&mut self, item: ItemId, holder: ItemId) -> bool {
+        match self[holder].contents_mut() {
+            Some(contents) => {
+                contents.push(item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Describes an item with its article and what can be seen in or on it, such as "a box (in which is a coin)".
+    pub fn describe_with_contents(&self, item: ItemId) -> String {
+        let mut phrase = with_article(&self[item].name);
+        if let Some(listing) = self.list_contents(item) {
+            let preposition = if self[item].container.is_some() {
+                "in"
+            } else {
+                "on"
+            };
+            let verb = if self[item].contents().len() == 1 {
+                "is"
+            } else {
+                "are"
+            };
+            phrase.push_str(&format!(" ({preposition} which {verb} {listing})"));
+        }
+        phrase
+    }
+
+    /// Lists what can be seen in or on an item, recursively, such as "a coin and a box (in which is a ring)".
+    ///
+    /// Returns `None` if nothing can be seen.
+    pub fn list_contents(&self, item: ItemId) -> Option<String> {
+        if !self[item].shows_contents() || self[item].contents().is_empty() {
+            return None;
+        }
+        let phrases: Vec<String> = self[item]
+            .contents()
+            .iter()
+            .map(|&content| self.describe_with_contents(content))
+            .collect();
+        Some(join_list(&phrases))
+    }
+}
+
+impl core::ops::Index<ItemId> for Items {
+    type Output = Item;
+
+    fn index(&self, id: ItemId) -> &Item {
+        &self.items[id.0]
+    }
+}
+
+impl core::ops::IndexMut<ItemId> for Items {
+    fn index_mut(&mut self, id: ItemId) -> &mut Item {
+        self.revision += 1;
+        &mut self.items[id.0]
+    }
+}
+
+/// Identifies a [`Backdrop`] in the [`Backdrops`] registry of the [`World`](crate::World).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BackdropId(usize);
+
+/// Scenery present in many [`Room`](crate::map::Room)s at once, such as "the sky" or "the river".
+///
+/// Backdrops can be referred to in every room of their [`Region`] without being duplicated,
+/// but they are not listed in the contents of those rooms.
+#[derive(Clone, Debug)]
+pub struct Backdrop {
+    /// The name of the backdrop, such as "distant mountains".
+    pub name: String,
+    /// A description of the backdrop.
+    pub description: Description,
+    /// The rooms where the backdrop is present.
+    pub region: Region,
+}
+
+impl Backdrop {
+    /// Creates a new `Backdrop` present in the given [`Region`].
+    pub fn new(name: String, description: String, region: Region) -> Self {
+        Self {
+            name,
+            description: description.into(),
+            region,
+        }
+    }
+}
+
+/// The registry of every [`Backdrop`] in the [`World`](crate::World).
+///
+/// # Examples
+/// ```
+/// use worldwright::item::{Backdrop, Backdrops};
+/// use worldwright::map::{Map, Region};
+///
+/// let mut map = Map::new();
+/// let meadow = map.new_room("A wide meadow.".into());
+/// let cellar = map.new_room("A cellar.".into());
+/// map.graph[meadow].add_tag("outdoors");
+///
+/// let mut backdrops = Backdrops::new();
+/// let sky = backdrops.add(Backdrop::new(
+///     "sky".into(),
+///     "A cloudless blue sky.".into(),
+///     Region::Tagged("outdoors".into()),
+/// ));
+///
+/// assert_eq!(backdrops.in_room(&map, meadow), vec![sky]);
+/// assert!(backdrops.in_room(&map, cellar).is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Backdrops {
+    backdrops: Vec<Backdrop>,
+    revision: u64,
+}
+
+impl Backdrops {
+    /// Creates a new, empty `Backdrops` registry.
+    pub fn new() -> Self {
+        Self {
+            backdrops: Vec::new(),
+            revision: 0,
+        }
+    }
+
+    /// Returns the revision of the registry, which changes every time a backdrop is added or mutably borrowed, such as
+    /// to change its [`Region`].
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Adds a [`Backdrop`] to the registry and returns its [`BackdropId`].
+    pub fn add(&mut self, backdrop: Backdrop) -> BackdropId {
+        self.revision += 1;
+        self.backdrops.push(backdrop);
+        BackdropId(self.backdrops.len() - 1)
+    }
+
+    /// Gets the [`Backdrop`] with the given [`BackdropId`].
+    pub fn get(&self, id: BackdropId) -> Option<&Backdrop> {
+        self.backdrops.get(id.0)
+    }
+
+    /// Gets a mutable reference to the [`Backdrop`] with the given [`BackdropId`].
+    pub fn get_mut(&mut self, id: BackdropId) -> Option<&mut Backdrop> {
+        self.revision += 1;
+        self.backdrops.get_mut(id.0)
+    }
+
+    /// Returns every [`Backdrop`] along with its [`BackdropId`].
+    pub fn iter(&self) -> impl Iterator<Item = (BackdropId, &Backdrop)> {
+        self.backdrops
+            .iter()
+            .enumerate()
+            .map(|(index, backdrop)| (BackdropId(index), backdrop))
+    }
+
+    /// Returns the [`BackdropId`]s of every [`Backdrop`] present in a room.
+    pub fn in_room(&self, map: &Map, room_id: NodeIndex) -> Vec<BackdropId> {
+        self.iter()
+            .filter(|(_, backdrop)| backdrop.region.contains(map, room_id))
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
+
+impl core::ops::Index<BackdropId> for Backdrops {
+    type Output = Backdrop;
+
+    fn index(&self, id: BackdropId) -> &Backdrop {
+        &self.backdrops[id.0]
+    }
+}
+
+impl core::ops::IndexMut<BackdropId> for Backdrops {
+    fn index_mut(&mut self, id: BackdropId) -> &mut Backdrop {
+        self.revision += 1;
+        &mut self.backdrops[id.0]
+    }
+}
+
+/// Something the player can refer to in a [`Room`](crate::map::Room): an [`Item`], a [`Backdrop`] or an [`Npc`](crate::actor::Npc).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Thing {
+    /// An item in the room.
+    Item(ItemId),
+    /// A backdrop present in the room.
+    Backdrop(BackdropId),
+    /// An NPC in the room.
+    Npc(NpcId),
+}
@@ -0,0 +1,135 @@
+#[cfg(feature = "parser")]
+use crate::World;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kind of a [`JournalEntry`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EntryKind {
+    /// Progress on a quest.
+    Quest,
+    /// A clue the player found.
+    Clue,
+    /// Something said in a conversation.
+    Conversation,
+    /// Any other noteworthy event.
+    Event,
+    /// A free-form note written by the player.
+    Note,
+}
+
+/// A single entry in the [`Journal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JournalEntry {
+    /// What kind of entry this is.
+    pub kind: EntryKind,
+    /// The text of the entry.
+    pub text: String,
+}
+
+/// The player's journal.
+///
+/// The game logs key events such as quest updates, clues found and conversations, and the player can add their own notes.
+/// Entries can be searched by keyword to implement a "recall" command.
+///
+/// # Examples
+/// ```
+/// use worldwright::journal::{EntryKind, Journal};
+///
+/// let mut journal = Journal::new();
+/// journal.log(EntryKind::Clue, "Found a bloody glove in the garden.".into());
+/// journal.add_note("The butler seemed nervous.".into());
+///
+/// let recalled = journal.recall("BUTLER");
+/// assert_eq!(recalled.len(), 1);
+/// assert_eq!(recalled[0].kind, EntryKind::Note);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Journal {
+    entries: Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// Creates a new, empty `Journal`.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Logs an entry of the given [`EntryKind`].
+    pub fn log(&mut self, kind: EntryKind, text: String) {
+        self.entries.push(JournalEntry { kind, text });
+    }
+
+    /// Adds a free-form note written by the player.
+    pub fn add_note(&mut self, text: String) {
+        self.log(EntryKind::Note, text);
+    }
+
+    /// Returns all entries, oldest first.
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Returns all entries containing `keyword`, ignoring case, oldest first.
+    pub fn recall(&self, keyword: &str) -> Vec<&JournalEntry> {
+        let keyword = keyword.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|entry| entry.text.to_lowercase().contains(&keyword))
+            .collect()
+    }
+}
+
+/// A command handler that shows the journal entries mentioning a keyword, such as "recall butler".
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::journal::{EntryKind, recall_command};
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["recall", "remember"], recall_command);
+/// world.journal.log(EntryKind::Clue, "Found a bloody glove in the garden.".into());
+/// world.journal.add_note("The butler seemed nervous.".into());
+///
+/// world.execute("recall glove").unwrap();
+/// world.execute("remember the gardener").unwrap();
+/// world.execute("recall").unwrap();
+/// assert_eq!(
+///     world.output.drain(),
+///     vec![
+///         "Found a bloody glove in the garden.",
+///         "You don't recall anything about gardener.",
+///         "What do you want to recall?",
+///     ]
+/// );
+/// ```
+#[cfg(feature = "parser")]
+pub fn recall_command(world: &mut World, command: &Command) {
+    let Some(keyword) = command.noun() else {
+        world.output.say("What do you want to recall?".into());
+        return;
+    };
+    let recalled: Vec<String> = world
+        .journal
+        .recall(&keyword)
+        .into_iter()
+        .map(|entry| entry.text.clone())
+        .collect();
+    if recalled.is_empty() {
+        world
+            .output
+            .say(format!("You don't recall anything about {keyword}."));
+    }
+    for text in recalled {
+        world.output.say(text);
+    }
+}
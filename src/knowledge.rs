@@ -0,0 +1,136 @@
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+/// Identifies a [`Topic`] in the player's [`Knowledge`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TopicId(usize);
+
+/// Something abstract the player can know, such as a memory, an idea, or a password.
+#[derive(Clone, Debug)]
+pub struct Topic {
+    /// The name of the topic, such as "the password" or "mother's lullaby".
+    pub name: String,
+    /// What the player thinks when they think about the topic.
+    pub thought: String,
+    /// Whether the player currently knows the topic.
+    pub known: bool,
+    /// A message shown when the player acquires the topic.
+    pub on_acquire: Option<String>,
+    /// A message shown when the player forgets the topic.
+    pub on_forget: Option<String>,
+}
+
+impl Topic {
+    /// Creates a new, unknown `Topic` without acquire or forget messages.
+    pub fn new(name: String, thought: String) -> Self {
+        Self {
+            name,
+            thought,
+            known: false,
+            on_acquire: None,
+            on_forget: None,
+        }
+    }
+}
+
+/// The player's inventory of knowledge: non-physical [`Topic`]s they can think about or bring up in conversation.
+///
+/// # Examples
+/// ```
+/// use worldwright::knowledge::{Knowledge, Topic};
+///
+/// let mut knowledge = Knowledge::new();
+/// let password = knowledge.add(Topic::new(
+///     "password".into(),
+///     "The guard whispered \"swordfish\".".into(),
+/// ));
+///
+/// assert_eq!(knowledge.think_about(password), None);
+/// assert!(knowledge.acquire(password));
+/// assert_eq!(knowledge.think_about(password), Some("The guard whispered \"swordfish\"."));
+/// assert_eq!(knowledge.known(), vec![password]);
+///
+/// assert!(knowledge.forget(password));
+/// assert!(!knowledge.knows(password));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Knowledge {
+    topics: Vec<Topic>,
+}
+
+impl Knowledge {
+    /// Creates a new `Knowledge` without any topics.
+    pub fn new() -> Self {
+        Self { topics: Vec::new() }
+    }
+
+    /// Adds a [`Topic`] and returns its [`TopicId`].
+    pub fn add(&mut self, topic: Topic) -> TopicId {
+        self.topics.push(topic);
+        TopicId(self.topics.len() - 1)
+    }
+
+    /// Gets the [`Topic`] with the given [`TopicId`].
+    pub fn get(&self, id: TopicId) -> Option<&Topic> {
+        self.topics.get(id.0)
+    }
+
+    /// Finds a [`Topic`] by name, ignoring case.
+    pub fn find(&self, name: &str) -> Option<TopicId> {
+        self.topics
+            .iter()
+            .position(|topic| topic.name.eq_ignore_ascii_case(name))
+            .map(TopicId)
+    }
+
+    /// Checks whether the player knows a [`Topic`].
+    pub fn knows(&self, id: TopicId) -> bool {
+        self.get(id).is_some_and(|topic| topic.known)
+    }
+
+    /// Returns the [`TopicId`]s of every known [`Topic`].
+    pub fn known(&self) -> Vec<TopicId> {
+        (0..self.topics.len())
+            .map(TopicId)
+            .filter(|&id| self.knows(id))
+            .collect()
+    }
+
+    /// Makes the player know a [`Topic`], returning `true` if they didn't know it before.
+    pub fn acquire(&mut self, id: TopicId) -> bool {
+        self.set_known(id, true)
+    }
+
+    /// Makes the player forget a [`Topic`], returning `true` if they knew it before.
+    pub fn forget(&mut self, id: TopicId) -> bool {
+        self.set_known(id, false)
+    }
+
+    /// Returns what the player thinks about a [`Topic`], or `None` if they don't know it.
+    pub fn think_about(&self, id: TopicId) -> Option<&str> {
+        self.get(id)
+            .filter(|topic| topic.known)
+            .map(|topic| topic.thought.as_str())
+    }
+
+    /// Makes the player know exactly the given [`Topic`]s, such as from a saved game, without showing their messages.
+    pub fn restore(&mut self, known: impl IntoIterator<Item = TopicId>) {
+        for topic in &mut self.topics {
+            topic.known = false;
+        }
+        for id in known {
+            self.set_known(id, true);
+        }
+    }
+
+    fn set_known(&mut self, id: TopicId, known: bool) -> bool {
+        match self.topics.get_mut(id.0) {
+            Some(topic) if topic.known != known => {
+                topic.known = known;
+                true
+            }
+            _ => false,
+        }
+    }
+}
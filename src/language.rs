@@ -0,0 +1,166 @@
+use crate::World;
+use crate::map::Description;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+
+/// Text written in several languages side by side, such as a room description in English and French.
+///
+/// The text in the default language is used for every language without a translation.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::language::Localized;
+///
+/// let mut world = World::new();
+/// let description = Localized::new("A dusty foyer.".into())
+///     .with("fr", "Un vestibule poussiéreux.".into());
+/// let foyer = world.map.new_room("".into());
+/// world.map.room_mut(foyer).description = description.into();
+///
+/// assert_eq!(world.map.graph[foyer].description.render(&world), "A dusty foyer.");
+/// world.language = Some("fr".into());
+/// assert_eq!(world.map.graph[foyer].description.render(&world), "Un vestibule poussiéreux.");
+/// world.language = Some("de".into());
+/// assert_eq!(world.map.graph[foyer].description.render(&world), "A dusty foyer.");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Localized {
+    /// The text in the default language.
+    pub default: String,
+    /// The translations of the text, by language.
+    pub translations: BTreeMap<String, String>,
+}
+
+impl Localized {
+    /// Creates a new `Localized` text without translations.
+    pub fn new(default: String) -> Self {
+        Self {
+            default,
+            translations: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the translation of the text in a language.
+    pub fn with(mut self, language: &str, text: String) -> Self {
+        self.translations.insert(language.into(), text);
+        self
+    }
+
+    /// Gets the text in a language, falling back to the default language if it isn't translated.
+    ///
+    /// `None` is the default language.
+    pub fn get(&self, language: Option<&str>) -> &str {
+        language
+            .and_then(|language| self.translations.get(language))
+            .unwrap_or(&self.default)
+    }
+}
+
+/// Translations of story content kept as plain text, such as dialogue and barks, by language and by the text in the
+/// default language.
+///
+/// Text without a translation in the [language](crate::World::language) of the world is shown as it is.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::actor::Npc;
+///
+/// let mut world = World::new();
+/// let hall = world.map.new_room("A grand hall.".into());
+/// let mut butler = Npc::new("butler".into(), hall);
+/// butler.dialogue.greeting = Some("\"Good evening.\"".into());
+/// let butler = world.npcs.add(butler);
+/// world.translations.add("fr", "\"Good evening.\"", "« Bonsoir. »".into());
+///
+/// assert_eq!(world.talk_to(butler), "\"Good evening.\"");
+/// world.language = Some("fr".into());
+/// assert_eq!(world.talk_to(butler), "« Bonsoir. »");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Translations {
+    texts: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Translations {
+    /// Creates a new `Translations` without any translations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the translation of a text in a language.
+    pub fn add(&mut self, language: &str, text: &str, translation: String) {
+        self.texts
+            .entry(language.into())
+            .or_default()
+            .insert(text.into(), translation);
+    }
+
+    /// Gets the translation of a text in a language, or the text itself if it isn't translated.
+    ///
+    /// `None` is the default language.
+    pub fn get<'a>(&'a self, language: Option<&str>, text: &'a str) -> &'a str {
+        language
+            .and_then(|language| self.texts.get(language))
+            .and_then(|texts| texts.get(text))
+            .map_or(text, String::as_str)
+    }
+}
+
+impl World {
+    /// Translates a text of story content in the [language](World::language) of the world with its
+    /// [`translations`](World::translations).
+    pub fn translate(&self, text: &str) -> String {
+        self.translations.get(self.language.as_deref(), text).into()
+    }
+}
+
+/// Localized text becomes a [`Description`] in the [language](crate::World::language) of the world when rendered.
+///
+/// The text is rendered as a [template](crate::World::render_template), like fixed descriptions.
+impl From<Localized> for Description {
+    fn from(localized: Localized) -> Self {
+        if localized.translations.is_empty() {
+            return Description::Static(localized.default);
+        }
+        Description::dynamic(move |world| {
+            world.render_template(localized.get(world.language.as_deref()), &[])
+        })
+    }
+}
+
+/// A command handler that switches the [language](crate::World::language) of story content, such as "language fr".
+///
+/// "language default" switches back to the default language.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::language::language_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["language"], language_command);
+///
+/// world.execute("language fr").unwrap();
+/// assert_eq!(world.language.as_deref(), Some("fr"));
+/// world.execute("language default").unwrap();
+/// assert_eq!(world.language, None);
+/// assert_eq!(world.output.drain(), vec!["Language set to \"fr\".", "Language set to the default."]);
+/// ```
+#[cfg(feature = "parser")]
+pub fn language_command(world: &mut World, command: &Command) {
+    match command.noun().as_deref() {
+        None => world.output.say("Which language?".into()),
+        Some("default") => {
+            world.language = None;
+            world.output.say("Language set to the default.".into());
+        }
+        Some(language) => {
+            world.language = Some(language.into());
+            world.output.say(format!("Language set to \"{language}\"."));
+        }
+    }
+}
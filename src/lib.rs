@@ -4,19 +4,225 @@
     clippy::all
 )]
 #![allow(dead_code)]
+#![no_std]
 //! This crate aims to make it easier to create interactive fiction games in Rust.
 //! It is inspired by [Inform 7](https://ganelson.github.io/inform-website/).
+//!
+//! # Features
+//! - `std` (default): the standard library, needed by the [`Profiler`](crate::profiler::Profiler)'s clock and the [`build`] helpers.
+//!   Without it, the crate only needs `alloc`, so the core of the engine can run on constrained devices.
+//! - `serde` (default): loading [`WorldData`](crate::data::WorldData) and [`PatrolRoute`](crate::actor::PatrolRoute)s from data files,
+//!   and serializing boxed exits with an [`ExitRegistry`](crate::map::ExitRegistry).
+//! - `parser` (default): the command [`Parser`](crate::parser::Parser).
+//! - `derive` (default): the [`ExitType`](crate::map::ExitType) derive macro, for exits that don't need the trait written by hand.
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+/// The types that the standard prelude provides, but that `no_std` crates have to import from `alloc`.
+mod prelude {
+    pub use alloc::boxed::Box;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+    pub use alloc::{format, vec};
+}
+use prelude::*;
+
+/// Items used by the code generated by the derive macros. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::format;
+    pub use alloc::string::String;
+}
 
 /// The map module contains all the structures and functions related to the game map, including [`Room`](crate::map::Room)s and [`Exit`](crate::map::Exit)s.
 ///
 /// The map module contains everything related to managing the layout of the game map, including creating and connecting [`Room`](crate::map::Room)s, defining [`Exit`](crate::map::Exit)s between [`Room`](crate::map::Room)s, and navigating the [`Map`](crate::map::Map).
 pub mod map;
 
+/// The access module contains the [`AccessControl`](crate::access::AccessControl) of restricted rooms and exits,
+/// such as player homes and staff-only areas.
+pub mod access;
+
+/// The achievements module contains the [`Achievements`](crate::achievements::Achievements) the player can unlock.
+pub mod achievements;
+
+/// The actor module contains the [`Npc`](crate::actor::Npc)s of the world and the [`PatrolRoute`](crate::actor::PatrolRoute)s they follow.
+pub mod actor;
+
+/// The ambience module contains the [`Ambience`](crate::ambience::Ambience) of atmospheric messages shown in rooms and regions.
+pub mod ambience;
+
+/// The banter module contains scripted [`Banter`](crate::banter::Banter) between NPCs, said over several turns.
+pub mod banter;
+
+/// The build module contains helpers for build scripts, such as [`validate_data_files`](crate::build::validate_data_files).
+#[cfg(all(feature = "std", feature = "serde"))]
+pub mod build;
+
+/// The clock module contains the [`Clock`](crate::clock::Clock) and calendar of the world, with its day and night.
+pub mod clock;
+
+/// The clues module contains the [`ClueBoard`](crate::clues::ClueBoard) used to build mysteries out of discoverable evidence.
+pub mod clues;
+
+/// The currents module contains the [`Currents`](crate::currents::Currents) that sweep the player and floating items along
+/// exits, such as rivers, conveyor belts and slides.
+pub mod currents;
+
+/// The data module contains [`WorldData`](crate::data::WorldData), a declarative description of a world that can be loaded from data files.
+#[cfg(feature = "serde")]
+pub mod data;
+
+/// The dialogue module contains the [`Dialogue`](crate::dialogue::Dialogue) topic trees of NPCs, for "ask" and "tell" conversations.
+pub mod dialogue;
+
+/// The difficulty module contains the [`Difficulty`](crate::difficulty::Difficulty) knobs that game parameters are bound to.
+pub mod difficulty;
+
+/// The endings module contains an [`Endings`](crate::endings::Endings) calculator that picks the ending of the game from weighted criteria.
+pub mod endings;
+
+/// The flags module contains the serializable [`Flags`](crate::flags::Flags) store of global flags and variables.
+pub mod flags;
+
+/// The history module contains the turn [`History`](crate::history::History) that lets the player undo and redo turns.
+pub mod history;
+
+/// The ids module contains the [`StableIds`](crate::ids::StableIds) that authors give rooms, items and NPCs.
+pub mod ids;
+
+/// The item module contains the [`Item`](crate::item::Item)s of the world and their [`Items`](crate::item::Items) registry,
+/// as well as [`Backdrop`](crate::item::Backdrop)s present in many rooms at once.
+pub mod item;
+
+/// The journal module contains the player's [`Journal`](crate::journal::Journal) of events and notes.
+pub mod journal;
+
+/// The knowledge module contains the player's [`Knowledge`](crate::knowledge::Knowledge) of abstract [`Topic`](crate::knowledge::Topic)s, such as memories and passwords.
+pub mod knowledge;
+
+/// The language module contains [`Localized`](crate::language::Localized) text, for story content written in several languages.
+pub mod language;
+
+/// The light module contains the [`Lighting`](crate::light::Lighting) of the world, which decides whether dark rooms can be seen.
+pub mod light;
+
+/// The lint module contains a [`lint`](crate::lint::lint)er that finds mistakes in [`WorldData`](crate::data::WorldData),
+/// along with a [`spell_check`](crate::lint::spell_check) and a [`prose_lint`](crate::lint::prose_lint) of the prose of a world.
+#[cfg(feature = "serde")]
+pub mod lint;
+
+/// The mods module contains the [`Mods`](crate::mods::Mods) installed for a game: packages of community content laid
+/// over its world data, with dependency and version checks.
+#[cfg(feature = "serde")]
+pub mod mods;
+
+/// The movement module resolves movement phrases such as "go back", "exit" and "enter the boat" into
+/// [`MovementTarget`](crate::movement::MovementTarget)s.
+pub mod movement;
+
+/// The narration module contains the [`NarrationQueue`](crate::narration::NarrationQueue) for timed background narration.
+pub mod narration;
+
+/// The objects module contains command handlers for doing things with objects, such as
+/// [throwing](crate::objects::throw_command) and [pushing](crate::objects::push_command) them.
+#[cfg(feature = "parser")]
+pub mod objects;
+
+/// The parser module contains the [`Parser`](crate::parser::Parser) that turns the player's input into [`Command`](crate::parser::Command)s.
+#[cfg(feature = "parser")]
+pub mod parser;
+
+/// The profiler module contains a [`Profiler`](crate::profiler::Profiler) that measures the time spent in each part of the engine per turn.
+pub mod profiler;
+
+/// The properties module contains a typed key-value store, [`Properties`](crate::properties::Properties), for attaching custom data to game objects.
+pub mod properties;
+
+/// The provenance module tracks the [`Provenance`](crate::provenance::Provenance) of items: where they have been and who has held them.
+pub mod provenance;
+
+/// The puzzle module exports a [`PuzzleGraph`](crate::puzzle::PuzzleGraph) of what unlocks what, to find sequence breaks and bottlenecks.
+pub mod puzzle;
+
+/// The quest module contains the [`Quests`](crate::quest::Quests) the player can take on, made of steps completed by
+/// [`Trigger`](crate::quest::Trigger)s.
+pub mod quest;
+
+/// The report module contains a [`Report`](crate::report::Report) of statistics about a world, exportable to Markdown or HTML.
+pub mod report;
+
+/// The reset module contains the [`ResetPolicy`](crate::reset::ResetPolicy) that puts a server world back in its initial state,
+/// and the [`Persistence`](crate::reset::Persistence) of items and exits that opt out of it.
+pub mod reset;
+
+/// The rules module contains the [`Rulebooks`](crate::rules::Rulebooks) of before, instead, check, carry-out and after
+/// rules that intercept [`Action`](crate::rules::Action)s.
+pub mod rules;
+
+/// The screenplay module exports the dialogue of a world to a [`screenplay`](crate::screenplay::screenplay) for proofreading.
+pub mod screenplay;
+
+/// The scheduler module contains the [`Scheduler`](crate::scheduler::Scheduler) of fuses and daemons: events that fire
+/// after a number of turns or every few turns.
+pub mod scheduler;
+
+/// The save module contains the [`SaveState`](crate::save::SaveState) of a game in progress, written by
+/// [`World::save`](crate::World::save) and read by [`World::load`](crate::World::load).
+#[cfg(feature = "serde")]
+pub mod save;
+
+/// The scope module contains the [`ScopeCache`](crate::scope::ScopeCache) of what the player can perceive in each room.
+pub mod scope;
+
+/// The score module contains the player's [`Score`](crate::score::Score) and the ranks it earns.
+pub mod score;
+
+/// The stamina module contains the player's [`Stamina`](crate::stamina::Stamina), spent by strenuous actions and
+/// restored by rest and food.
+pub mod stamina;
+
+/// The template module contains the [`render`](crate::template::render) function that fills `{placeholders}` in prose,
+/// the [`Variables`](crate::template::Variables) store they can refer to, and helpers that describe narrative time, such as [`later`](crate::template::later).
+pub mod template;
+
+/// The timers module contains countdown [`Timers`](crate::timers::Timers), which front ends can show to the player.
+pub mod timers;
+
+/// The tutorial module contains an onboarding framework of contextual [`Tip`](crate::tutorial::Tip)s.
+///
+/// Tips are triggered by player behavior, such as the first failed command or the first locked door, and are suppressed once acknowledged.
+pub mod tutorial;
+
+/// The travel module lets the player [travel](crate::World::travel_to) to distant rooms, summing up the uneventful ones.
+pub mod travel;
+
+/// The turn module contains the [`TurnOutcome`](crate::turn::TurnOutcome) of [`World::advance_turn`], the game loop's heartbeat.
+pub mod turn;
+
+/// The wait module contains the [`wait_command`](crate::wait::wait_command) and [`sleep_command`](crate::wait::sleep_command)
+/// handlers, which skip time until something significant happens.
+pub mod wait;
+
+/// The weather module contains the [`Weathers`](crate::weather::Weathers) of the world, changing between clear
+/// skies, rain, storms and snow in each region.
+pub mod weather;
+
 mod world;
 pub use world::World;
 
+mod output;
+pub use output::Output;
+
 mod player;
-pub use player::Player;
+pub use player::{
+    EnterError, Movement, Player, PushError, RideError, Stats, TakeError, ThrowError,
+};
+
+mod rng;
+pub use rng::{Rng, RngStreams};
 
 const VOWELS: &str = "aeiou";
 
@@ -28,6 +234,29 @@ fn starts_with_vowel(s: &str) -> bool {
     }
 }
 
+/// Adds the indefinite article to a noun, such as "a key" or "an apple".
+fn with_article(noun: &str) -> String {
+    format!("a{} {noun}", if starts_with_vowel(noun) { "n" } else { "" })
+}
+
+/// Capitalizes the first letter of a phrase, such as "An hour passes".
+fn capitalize(phrase: &str) -> String {
+    let mut chars = phrase.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Joins a list of phrases into a sentence, such as "a lamp, a key and a rope".
+fn join_list(phrases: &[String]) -> String {
+    match phrases {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
 fn main() {
     let mut world = World::new();
     let foyer_id = world.map.new_room("You are in the dusty foyer of an old manor. A grand staircase leads up to a landing, but it's roped off.".into());
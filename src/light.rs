@@ -0,0 +1,156 @@
+use crate::World;
+use crate::prelude::*;
+use alloc::rc::Rc;
+use petgraph::prelude::NodeIndex;
+use petgraph::visit::EdgeRef;
+
+/// How much light there is in a [`Room`](crate::map::Room).
+///
+/// Light levels add up: two dim sources together light a room fully.
+///
+/// # Examples
+/// ```
+/// use worldwright::light::LightLevel;
+///
+/// assert_eq!(LightLevel::Dim + LightLevel::Dim, LightLevel::Lit);
+/// assert_eq!(LightLevel::PitchBlack + LightLevel::Dim, LightLevel::Dim);
+/// assert!(LightLevel::Lit > LightLevel::Dim);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LightLevel {
+    /// Nothing can be seen.
+    PitchBlack,
+    /// The room can be seen, but small details are hidden and nothing can be read.
+    Dim,
+    /// Everything can be seen.
+    #[default]
+    Lit,
+}
+
+impl LightLevel {
+    fn amount(self) -> u8 {
+        match self {
+            LightLevel::PitchBlack => 0,
+            LightLevel::Dim => 1,
+            LightLevel::Lit => 2,
+        }
+    }
+
+    fn from_amount(amount: u8) -> Self {
+        match amount {
+            0 => LightLevel::PitchBlack,
+            1 => LightLevel::Dim,
+            _ => LightLevel::Lit,
+        }
+    }
+}
+
+impl core::ops::Add for LightLevel {
+    type Output = LightLevel;
+
+    fn add(self, other: LightLevel) -> LightLevel {
+        LightLevel::from_amount(self.amount() + other.amount())
+    }
+}
+
+/// A function that tells how much light it gives to a [`Room`](crate::map::Room), such as a lamp carried by the player.
+pub type LightSource = Rc<dyn Fn(&World, NodeIndex) -> LightLevel>;
+
+/// The light sources of the [`World`], used to light up dark [`Room`](crate::map::Room)s.
+///
+/// The light level of a room is the sum of its own light, the light of every source, and the light spilling in from adjacent rooms.
+/// A fully lit adjacent room spills dim light through any exit the player can go through.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::light::LightLevel;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+///
+/// let mut world = World::new();
+/// let cellar = world.map.new_room("Damp stone walls surround you.".into());
+/// world.map.graph[cellar].light = LightLevel::PitchBlack;
+/// assert_eq!(world.light_level(cellar), LightLevel::PitchBlack);
+///
+/// let candle_room = cellar;
+/// world.lighting.add_source(move |_, room| {
+///     if room == candle_room { LightLevel::Dim } else { LightLevel::PitchBlack }
+/// });
+/// assert_eq!(world.light_level(cellar), LightLevel::Dim);
+/// assert!(world.is_lit(cellar));
+/// assert!(!world.can_read(cellar));
+///
+/// // Light from a lit room next door adds up with the candle.
+/// world.map.new_room_in_direction(cellar, Direction::North, Box::new(RegularExit), "A bright kitchen.".into());
+/// assert_eq!(world.light_level(cellar), LightLevel::Lit);
+/// ```
+#[derive(Clone)]
+pub struct Lighting {
+    /// Whether light spills into adjacent rooms.
+    pub spill: bool,
+    sources: Vec<LightSource>,
+}
+
+impl Lighting {
+    /// Creates a new `Lighting` without any light sources, where light spills into adjacent rooms.
+    pub fn new() -> Self {
+        Self {
+            spill: true,
+            sources: Vec::new(),
+        }
+    }
+
+    /// Adds a [`LightSource`].
+    pub fn add_source(&mut self, source: impl Fn(&World, NodeIndex) -> LightLevel + 'static) {
+        self.sources.push(Rc::new(source));
+    }
+
+    /// Computes the light level of a room in the given world.
+    pub fn level(&self, world: &World, room_id: NodeIndex) -> LightLevel {
+        let mut level = self.direct_level(world, room_id);
+        if self.spill && level < LightLevel::Lit {
+            let graph = &world.map.graph;
+            let neighbors = graph
+                .edges_directed(room_id, petgraph::Direction::Outgoing)
+                .map(|edge| (edge.weight(), edge.target()))
+                .chain(
+                    graph
+                        .edges_directed(room_id, petgraph::Direction::Incoming)
+                        .map(|edge| (edge.weight(), edge.source())),
+                );
+            for (exit, neighbor) in neighbors {
+                if exit.exit_type.can_go_through()
+                    && self.direct_level(world, neighbor) == LightLevel::Lit
+                {
+                    level = level + LightLevel::Dim;
+                }
+            }
+        }
+        level
+    }
+
+    /// Computes the light of a room from its own light and the light sources, without light from adjacent rooms.
+    fn direct_level(&self, world: &World, room_id: NodeIndex) -> LightLevel {
+        self.sources
+            .iter()
+            .fold(world.map.graph[room_id].light, |level, source| {
+                level + source(world, room_id)
+            })
+    }
+}
+
+impl Default for Lighting {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Lighting {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Lighting")
+            .field("spill", &self.spill)
+            .field("sources", &self.sources.len())
+            .finish()
+    }
+}
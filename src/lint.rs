@@ -0,0 +1,591 @@
+use crate::World;
+use crate::data::WorldData;
+use crate::dialogue::DialogueTopic;
+use crate::map::RoomId;
+use crate::prelude::*;
+use alloc::collections::{BTreeMap, BTreeSet};
+
+/// How serious a [`Diagnostic`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Something that is probably a mistake, but doesn't prevent the world from being built.
+    Warning,
+    /// Something that prevents the world from being built.
+    Error,
+}
+
+/// A problem found in [`WorldData`] by the [`lint`] function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Creates a new warning with the given message.
+    pub fn warning(message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message,
+        }
+    }
+
+    /// Creates a new error with the given message.
+    pub fn error(message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            message,
+        }
+    }
+}
+
+impl core::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{severity}: {}", self.message)
+    }
+}
+
+/// Checks [`WorldData`] for mistakes, such as exits leading to rooms that don't exist.
+///
+/// Errors prevent the world from being [built](WorldData::build), while warnings point out likely mistakes.
+///
+/// # Examples
+/// ```
+/// use worldwright::data::WorldData;
+/// use worldwright::lint::lint;
+///
+/// let data = WorldData::from_json(r#"{
+///     "rooms": [
+///         { "name": "Foyer", "description": "The dusty foyer of an old manor." },
+///         { "name": "Attic", "description": "" }
+///     ],
+///     "exits": [{ "from": "Foyer", "to": "Cellar", "direction": "south" }]
+/// }"#).unwrap();
+///
+/// let diagnostics: Vec<String> = lint(&data).iter().map(ToString::to_string).collect();
+/// assert_eq!(
+///     diagnostics,
+///     vec![
+///         "warning: room \"Attic\" has an empty description",
+///         "error: exit from \"Foyer\" leads to unknown room \"Cellar\"",
+///         "warning: room \"Attic\" can't be reached by any exit",
+///     ]
+/// );
+/// ```
+pub fn lint(data: &WorldData) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let mut names = BTreeSet::new();
+    for room in &data.rooms {
+        if !names.insert(room.name.to_lowercase()) {
+            diagnostics.push(Diagnostic::error(format!(
+                "room \"{}\" is defined more than once",
+                room.name
+            )));
+        }
+        if let Some(id) = &room.id
+            && data
+                .rooms
+                .iter()
+                .any(|other| !core::ptr::eq(other, room) && other.name.eq_ignore_ascii_case(id))
+        {
+            diagnostics.push(Diagnostic::error(format!(
+                "room ID \"{id}\" is also the name of another room"
+            )));
+        }
+        if room.description.trim().is_empty() {
+            diagnostics.push(Diagnostic::warning(format!(
+                "room \"{}\" has an empty description",
+                room.name
+            )));
+        }
+    }
+    for (kind, ids) in [
+        (
+            "room",
+            data.rooms.iter().map(|room| &room.id).collect::<Vec<_>>(),
+        ),
+        ("item", data.items.iter().map(|item| &item.id).collect()),
+        ("NPC", data.npcs.iter().map(|npc| &npc.id).collect()),
+    ] {
+        let mut seen = BTreeSet::new();
+        for id in ids.into_iter().flatten() {
+            if !seen.insert(id) {
+                diagnostics.push(Diagnostic::error(format!(
+                    "{kind} ID \"{id}\" is used more than once"
+                )));
+            }
+        }
+    }
+    // Rooms can be referred to by name or by ID.
+    let room_name = |reference: &str| data.room_name(reference).map(str::to_lowercase);
+    let known = |reference: &str| room_name(reference).is_some();
+
+    let mut connected = BTreeSet::new();
+    let mut directions = BTreeSet::new();
+    for exit in &data.exits {
+        if !known(&exit.from) {
+            diagnostics.push(Diagnostic::error(format!(
+                "exit to \"{}\" leads from unknown room \"{}\"",
+                exit.to, exit.from
+            )));
+        }
+        if !known(&exit.to) {
+            diagnostics.push(Diagnostic::error(format!(
+                "exit from \"{}\" leads to unknown room \"{}\"",
+                exit.from, exit.to
+            )));
+        }
+        let from = room_name(&exit.from).unwrap_or_else(|| exit.from.to_lowercase());
+        let to = room_name(&exit.to).unwrap_or_else(|| exit.to.to_lowercase());
+        connected.insert(from.clone());
+        connected.insert(to.clone());
+
+        let sides = [
+            (&exit.from, from, exit.direction),
+            (&exit.to, to, exit.direction.opposite()),
+        ];
+        for (room, name, direction) in sides {
+            if !directions.insert((name, direction)) {
+                diagnostics.push(Diagnostic::error(format!(
+                    "more than one exit {direction} from \"{room}\""
+                )));
+            }
+        }
+    }
+
+    for item in &data.items {
+        if let Some(room) = &item.room
+            && !known(room)
+        {
+            diagnostics.push(Diagnostic::error(format!(
+                "item \"{}\" is in unknown room \"{room}\"",
+                item.name
+            )));
+        }
+    }
+
+    for npc in &data.npcs {
+        if !known(&npc.room) {
+            diagnostics.push(Diagnostic::error(format!(
+                "NPC \"{}\" is in unknown room \"{}\"",
+                npc.name, npc.room
+            )));
+        }
+        for stop in npc.patrol.iter().flat_map(|patrol| &patrol.stops) {
+            if !known(&stop.room) {
+                diagnostics.push(Diagnostic::error(format!(
+                    "patrol of NPC \"{}\" visits unknown room \"{}\"",
+                    npc.name, stop.room
+                )));
+            }
+        }
+    }
+
+    for verb in &data.verbs {
+        if verb.words.is_empty() {
+            diagnostics.push(Diagnostic::error(format!(
+                "verb answering \"{}\" has no words",
+                verb.response
+            )));
+        }
+    }
+
+    for dialogue in &data.dialogue {
+        if data.npc_name(&dialogue.npc).is_none() {
+            diagnostics.push(Diagnostic::error(format!(
+                "dialogue about \"{}\" is for unknown NPC \"{}\"",
+                dialogue.topic, dialogue.npc
+            )));
+        }
+    }
+
+    if data.rooms.len() > 1 {
+        for room in &data.rooms {
+            if !connected.contains(&room.name.to_lowercase()) {
+                diagnostics.push(Diagnostic::warning(format!(
+                    "room \"{}\" can't be reached by any exit",
+                    room.name
+                )));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// A list of correctly spelled words for [`spell_check`], such as a base word list combined with a per-project
+/// dictionary of made-up names and jargon.
+///
+/// Words are compared case-insensitively.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    words: BTreeSet<String>,
+}
+
+impl Dictionary {
+    /// Creates a new, empty `Dictionary`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `Dictionary` from a word list, with words separated by whitespace such as one per line.
+    pub fn from_words(text: &str) -> Self {
+        let mut dictionary = Self::new();
+        dictionary.extend_from_words(text);
+        dictionary
+    }
+
+    /// Adds every word of a word list, with words separated by whitespace such as one per line.
+    pub fn extend_from_words(&mut self, text: &str) {
+        for word in text.split_whitespace() {
+            self.add(word);
+        }
+    }
+
+    /// Adds a word.
+    pub fn add(&mut self, word: &str) {
+        self.words.insert(word.to_lowercase());
+    }
+
+    /// Checks whether a word is spelled correctly. Possessives and hyphenated words are checked by their parts.
+    pub fn contains(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        if self.words.contains(&word) {
+            return true;
+        }
+        let word = word
+            .strip_suffix("'s")
+            .or_else(|| word.strip_suffix('\''))
+            .unwrap_or(&word);
+        if self.words.contains(word) {
+            return true;
+        }
+        word.contains('-')
+            && word
+                .split('-')
+                .all(|part| part.is_empty() || self.words.contains(part))
+    }
+}
+
+/// Checks the spelling of every piece of prose in a [`World`]: room and item descriptions, NPC lines and dialogue,
+/// banter, and messages such as the one shown when there is no exit.
+///
+/// Each unknown word is reported once per entity as a warning. Template placeholders such as `{name}` are skipped,
+/// and so are words with digits in them.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::actor::{BarkPool, Npc};
+/// use worldwright::lint::{Dictionary, spell_check};
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::NamedExit;
+///
+/// let mut world = World::new();
+/// let foyer = world.map.new_room_with_name("Foyer".into(), "A dusy foyer. The {name} is here.".into());
+/// let mut butler = Npc::new("butler".into(), foyer);
+/// butler.barks = Some(BarkPool::new(vec!["Welcome to Grimsby Hall.".into()]));
+/// world.npcs.add(butler);
+///
+/// let mut dictionary = Dictionary::from_words("a foyer the is here welcome to hall butler you can't go that way");
+/// // The project dictionary adds the made-up names of the game.
+/// dictionary.extend_from_words("Grimsby");
+///
+/// let diagnostics: Vec<String> = spell_check(&world, &dictionary).iter().map(ToString::to_string).collect();
+/// assert_eq!(diagnostics, vec!["warning: room \"Foyer\" has unknown word \"dusy\""]);
+///
+/// // Exit names and messages are checked too.
+/// world.map.connect_rooms(foyer, foyer, Direction::Up, Box::new(NamedExit::new("rikety stair".into())));
+/// dictionary.extend_from_words("stair");
+/// let diagnostics: Vec<String> = spell_check(&world, &dictionary).iter().map(ToString::to_string).collect();
+/// assert_eq!(diagnostics[1], "warning: exit 0 has unknown word \"rikety\"");
+/// ```
+pub fn spell_check(world: &World, dictionary: &Dictionary) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for (entity, text) in prose(world) {
+        let mut reported = BTreeSet::new();
+        for word in words(&text) {
+            if !dictionary.contains(word) && reported.insert(word.to_lowercase()) {
+                diagnostics.push(Diagnostic::warning(format!(
+                    "{entity} has unknown word \"{word}\""
+                )));
+            }
+        }
+    }
+    diagnostics
+}
+
+/// Refers to a room by name, such as `room "Foyer"`, or by index if it has none.
+fn room_entity(world: &World, room_id: RoomId) -> String {
+    match &world.map.graph[room_id].name {
+        Some(name) => format!("room \"{name}\""),
+        None => format!("room {}", room_id.index()),
+    }
+}
+
+/// Gathers every piece of prose in a world, with a reference to the entity it belongs to, such as `room "Foyer"`.
+fn prose(world: &World) -> Vec<(String, String)> {
+    let mut prose = Vec::new();
+    for room_id in world.map.graph.node_indices() {
+        let room = &world.map.graph[room_id];
+        let entity = room_entity(world, room_id);
+        if let Some(name) = &room.name {
+            prose.push((entity.clone(), name.clone()));
+        }
+        let descriptions = [
+            Some(&room.description),
+            room.short_description.as_ref(),
+            room.details.as_ref(),
+            room.sound.as_ref(),
+            room.distant_sound.as_ref(),
+            room.smell.as_ref(),
+        ];
+        for description in descriptions.into_iter().flatten() {
+            prose.push((entity.clone(), description.render(world)));
+        }
+        for message in room
+            .no_exit_message
+            .iter()
+            .chain(room.no_exit_messages.values())
+        {
+            prose.push((entity.clone(), message.clone()));
+        }
+    }
+    for exit_id in world.map.graph.edge_indices() {
+        let entity = format!("exit {}", exit_id.index());
+        for text in world.map.graph[exit_id].exit_type.prose() {
+            prose.push((entity.clone(), text));
+        }
+    }
+    for (_, item) in world.items.iter() {
+        let entity = format!("item \"{}\"", item.name);
+        prose.push((entity.clone(), item.name.clone()));
+        prose.push((entity, item.description.render(world)));
+    }
+    for (_, backdrop) in world.backdrops.iter() {
+        let entity = format!("backdrop \"{}\"", backdrop.name);
+        prose.push((entity.clone(), backdrop.name.clone()));
+        prose.push((entity, backdrop.description.render(world)));
+    }
+    for (_, npc) in world.npcs.iter() {
+        let entity = format!("NPC \"{}\"", npc.name);
+        prose.push((entity.clone(), npc.name.clone()));
+        let barks = npc
+            .barks
+            .iter()
+            .chain(npc.mood_barks.values())
+            .chain(npc.reactions.values());
+        let stops = npc.patrol.iter().flat_map(|patrol| &patrol.route.stops);
+        let lines = npc
+            .description
+            .iter()
+            .chain(barks.flat_map(|barks| &barks.lines))
+            .chain(stops.flat_map(|stop| &stop.barks))
+            .chain(&npc.dialogue.greeting)
+            .chain(&npc.dialogue.fallback);
+        prose.extend(lines.map(|line| (entity.clone(), line.clone())));
+        let mut topics: Vec<&DialogueTopic> = npc.dialogue.topics.iter().collect();
+        while let Some(topic) = topics.pop() {
+            for response in &topic.responses {
+                prose.push((entity.clone(), response.text.clone()));
+            }
+            topics.extend(&topic.subtopics);
+        }
+    }
+    for (index, banter) in world.banter.iter().enumerate() {
+        for (_, line) in &banter.lines {
+            prose.push((format!("banter {}", index + 1), line.clone()));
+        }
+    }
+    prose.push(("world".into(), world.no_exit_message.clone()));
+    prose
+}
+
+/// Splits prose into words, skipping template placeholders and words with digits.
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    text.split(move |c: char| {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        !(c.is_alphanumeric() || c == '\'' || c == '-' || c == '’') || depth > 0
+    })
+    .map(|word| word.trim_matches(['\'', '-', '’']))
+    .filter(|word| !word.is_empty() && !word.chars().any(|c| c.is_ascii_digit()))
+}
+
+/// The thresholds used by [`prose_lint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProseRules {
+    /// Room descriptions with fewer words than this are flagged.
+    pub min_words: usize,
+    /// Room descriptions with more words than this are flagged.
+    pub max_words: usize,
+    /// How many opening words adjacent rooms must share for their descriptions to be flagged as repetitive.
+    pub opening_words: usize,
+}
+
+impl Default for ProseRules {
+    fn default() -> Self {
+        Self {
+            min_words: 5,
+            max_words: 150,
+            opening_words: 2,
+        }
+    }
+}
+
+/// Checks the prose of a [`World`] for consistency: room descriptions that are too short or too long,
+/// adjacent rooms whose descriptions open with the same words, and status messages in the passive voice,
+/// such as "The door is opened."
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::lint::{ProseRules, prose_lint};
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+///
+/// let mut world = World::new();
+/// let hall = world.map.new_room_with_name("Hall".into(), "You are in a long hall lined with portraits.".into());
+/// let study = world.map.new_room_in_direction(hall, Direction::North, Box::new(RegularExit), "You are studying.".into());
+/// world.map.graph[study].name = Some("Study".into());
+/// world.no_exit_message = "Your way is blocked.".into();
+///
+/// let diagnostics: Vec<String> = prose_lint(&world, &ProseRules::default()).iter().map(ToString::to_string).collect();
+/// assert_eq!(
+///     diagnostics,
+///     vec![
+///         "warning: room \"Study\" has a description of 3 words, fewer than 5",
+///         "warning: rooms \"Hall\" and \"Study\" both open with \"you are\"",
+///         "warning: world message \"Your way is blocked.\" is in the passive voice (\"is blocked\")",
+///     ]
+/// );
+/// ```
+pub fn prose_lint(world: &World, rules: &ProseRules) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let map = &world.map;
+    let room_name = |room_id: RoomId| room_entity(world, room_id);
+    let openings: BTreeMap<RoomId, String> = map
+        .graph
+        .node_indices()
+        .map(|room_id| {
+            let description = map.graph[room_id].description.render(world);
+            let words: Vec<&str> = description.split_whitespace().collect();
+            if words.len() < rules.min_words {
+                diagnostics.push(Diagnostic::warning(format!(
+                    "{} has a description of {} words, fewer than {}",
+                    room_name(room_id),
+                    words.len(),
+                    rules.min_words
+                )));
+            } else if words.len() > rules.max_words {
+                diagnostics.push(Diagnostic::warning(format!(
+                    "{} has a description of {} words, more than {}",
+                    room_name(room_id),
+                    words.len(),
+                    rules.max_words
+                )));
+            }
+            let opening = words
+                .iter()
+                .take(rules.opening_words)
+                .map(|word| {
+                    word.trim_matches(|c: char| !c.is_alphanumeric())
+                        .to_lowercase()
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            (room_id, opening)
+        })
+        .collect();
+
+    let mut pairs = BTreeSet::new();
+    for exit_id in map.graph.edge_indices() {
+        let (a, b) = map.exit_endpoints(exit_id).unwrap();
+        let pair = (a.min(b), a.max(b));
+        if a != b
+            && rules.opening_words > 0
+            && !openings[&a].is_empty()
+            && openings[&a] == openings[&b]
+            && pairs.insert(pair)
+        {
+            diagnostics.push(Diagnostic::warning(format!(
+                "rooms {} and {} both open with \"{}\"",
+                room_name(pair.0).trim_start_matches("room "),
+                room_name(pair.1).trim_start_matches("room "),
+                openings[&a]
+            )));
+        }
+    }
+
+    for (entity, message) in status_messages(world) {
+        if let Some(phrase) = passive_phrase(&message) {
+            diagnostics.push(Diagnostic::warning(format!(
+                "{entity} message \"{message}\" is in the passive voice (\"{phrase}\")"
+            )));
+        }
+    }
+    diagnostics
+}
+
+/// Gathers the status messages of a world, with a reference to the entity they belong to.
+fn status_messages(world: &World) -> Vec<(String, String)> {
+    let mut messages = Vec::new();
+    for room_id in world.map.graph.node_indices() {
+        let room = &world.map.graph[room_id];
+        let entity = room_entity(world, room_id);
+        for message in room
+            .no_exit_message
+            .iter()
+            .chain(room.no_exit_messages.values())
+        {
+            messages.push((entity.clone(), message.clone()));
+        }
+    }
+    for exit_id in world.map.graph.edge_indices() {
+        if let Some(message) = world.map.graph[exit_id].exit_type.passage_message() {
+            messages.push((format!("exit {}", exit_id.index()), message));
+        }
+    }
+    messages.push(("world".into(), world.no_exit_message.clone()));
+    messages.push(("world".into(), world.access.denied_message.clone()));
+    messages.extend(
+        world
+            .resets
+            .message
+            .iter()
+            .map(|message| ("world".into(), message.clone())),
+    );
+    messages
+}
+
+/// Finds a passive construction in a sentence, such as "is opened" or "was taken".
+fn passive_phrase(text: &str) -> Option<String> {
+    const AUXILIARIES: [&str; 7] = ["is", "are", "was", "were", "be", "been", "being"];
+    const IRREGULAR: [&str; 12] = [
+        "taken", "broken", "given", "done", "made", "seen", "eaten", "hidden", "written", "stolen",
+        "thrown", "shut",
+    ];
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .collect();
+    words.windows(2).find_map(|pair| {
+        let participle = pair[1].ends_with("ed") || IRREGULAR.contains(&pair[1].as_str());
+        (AUXILIARIES.contains(&pair[0].as_str()) && participle)
+            .then(|| format!("{} {}", pair[0], pair[1]))
+    })
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Author-only metadata attached to a [`Room`](crate::map::Room) or an [`Exit`](crate::map::Exit).
+///
+/// Annotations are meant for editors and exports, and are never shown to the player.
+///
+/// # Examples
+/// ```
+/// use worldwright::map::Room;
+///
+/// let mut room = Room::new("The library.".into());
+/// room.annotations.comments.push("Inspired by the Bodleian.".into());
+/// room.annotations.todos.push("Add a secret passage.".into());
+/// room.annotations.color = Some("#aa8844".into());
+/// assert!(!room.annotations.is_empty());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Annotations {
+    /// Free-form comments by the author.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub comments: Vec<String>,
+    /// Work left to do.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub todos: Vec<String>,
+    /// A color label used by editors, such as "#ff0000" or "red".
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub color: Option<String>,
+}
+
+impl Annotations {
+    /// Creates new, empty `Annotations`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks whether there are no annotations.
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty() && self.todos.is_empty() && self.color.is_none()
+    }
+}
@@ -0,0 +1,108 @@
+use crate::World;
+use crate::prelude::*;
+use alloc::rc::Rc;
+
+/// A function that generates a description from the current state of the [`World`].
+pub type DescriptionProvider = Rc<dyn Fn(&World) -> String>;
+
+/// The description of a [`Room`](crate::map::Room), either a fixed string or generated from the state of the [`World`].
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::Description;
+///
+/// let world = World::new();
+///
+/// let fixed: Description = "A cold fireplace.".into();
+/// assert_eq!(fixed.render(&world), "A cold fireplace.");
+///
+/// let dynamic = Description::dynamic(|world| format!("There are {} rooms.", world.map.graph.node_count()));
+/// assert_eq!(dynamic.render(&world), "There are 0 rooms.");
+/// ```
+#[derive(Clone)]
+pub enum Description {
+    /// A description that never changes.
+    Static(String),
+    /// A description generated by a [`DescriptionProvider`] each time it is rendered.
+    Dynamic(DescriptionProvider),
+}
+
+impl Description {
+    /// Creates a [`Description::Dynamic`] from a function of the [`World`].
+    pub fn dynamic(provider: impl Fn(&World) -> String + 'static) -> Self {
+        Self::Dynamic(Rc::new(provider))
+    }
+
+    /// Renders the description for the current state of the [`World`].
+    pub fn render(&self, world: &World) -> String {
+        match self {
+            Description::Static(text) => text.clone(),
+            Description::Dynamic(provider) => provider(world),
+        }
+    }
+}
+
+impl core::fmt::Debug for Description {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Description::Static(text) => f.debug_tuple("Static").field(text).finish(),
+            Description::Dynamic(_) => f.debug_tuple("Dynamic").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl From<String> for Description {
+    fn from(text: String) -> Self {
+        Self::Static(text)
+    }
+}
+
+impl From<&str> for Description {
+    fn from(text: &str) -> Self {
+        Self::Static(text.into())
+    }
+}
+
+/// A rendered description of a [`Room`](crate::map::Room), with the title and body kept separate so front ends can style them differently.
+///
+/// When displayed, the title (if any) is printed on its own line before the body, followed by sentences listing the contents
+/// and the NPCs. The exits aren't displayed, so front ends can show them as they see fit, such as in a compass rose.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoomDescription {
+    /// The name of the room, if it has one.
+    pub title: Option<String>,
+    /// The prose describing the room.
+    pub body: String,
+    /// The visible things in the room, with their articles, such as "a brass key".
+    pub contents: Vec<String>,
+    /// The NPCs in the room, such as "the guard".
+    pub npcs: Vec<String>,
+    /// The descriptions of the exits, such as "a door north".
+    pub exits: Vec<String>,
+}
+
+impl core::fmt::Display for RoomDescription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(title) = &self.title {
+            writeln!(f, "{title}")?;
+        }
+        write!(f, "{}", self.body)?;
+        if !self.contents.is_empty() {
+            write!(
+                f,
+                "\nYou can see {} here.",
+                crate::join_list(&self.contents)
+            )?;
+        }
+        if !self.npcs.is_empty() {
+            let verb = if self.npcs.len() == 1 { "is" } else { "are" };
+            write!(
+                f,
+                "\n{} {verb} here.",
+                crate::capitalize(&crate::join_list(&self.npcs))
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,11 @@
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-/// Represents a cardinal direction.
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+/// Represents a cardinal direction, or up and down.
 ///
 /// It is used in the map to indicate the direction the player can go.
 pub enum Direction {
@@ -11,6 +17,10 @@ pub enum Direction {
     South,
     #[doc(hidden)]
     West,
+    #[doc(hidden)]
+    Up,
+    #[doc(hidden)]
+    Down,
 }
 
 impl Direction {
@@ -26,6 +36,9 @@ impl Direction {
     ///
     /// let east = Direction::East;
     /// assert_eq!(east.opposite(), Direction::West);
+    ///
+    /// let up = Direction::Up;
+    /// assert_eq!(up.opposite(), Direction::Down);
     /// ```
     pub fn opposite(&self) -> Direction {
         match self {
@@ -33,17 +46,61 @@ impl Direction {
             Direction::East => Direction::West,
             Direction::South => Direction::North,
             Direction::West => Direction::East,
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+
+    /// Checks whether the direction is up or down.
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// Returns a phrase locating something in this direction, such as "to the north" or "above".
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::Direction;
+    ///
+    /// assert_eq!(Direction::West.relative_phrase(), "to the west");
+    /// assert_eq!(Direction::Up.relative_phrase(), "above");
+    /// assert_eq!(Direction::Down.relative_phrase(), "below");
+    /// ```
+    pub fn relative_phrase(&self) -> String {
+        match self {
+            Direction::Up => "above".into(),
+            Direction::Down => "below".into(),
+            _ => format!("to the {self}"),
+        }
+    }
+}
+
+impl core::str::FromStr for Direction {
+    type Err = String;
+
+    /// Parses a direction from its name or its first letter, such as "north" or "n".
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.trim().to_lowercase().as_str() {
+            "north" | "n" => Ok(Direction::North),
+            "east" | "e" => Ok(Direction::East),
+            "south" | "s" => Ok(Direction::South),
+            "west" | "w" => Ok(Direction::West),
+            "up" | "u" => Ok(Direction::Up),
+            "down" | "d" => Ok(Direction::Down),
+            _ => Err(format!("\"{text}\" isn't a direction")),
         }
     }
 }
 
-impl std::fmt::Display for Direction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Direction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let s = match self {
             Direction::North => "north",
             Direction::East => "east",
             Direction::South => "south",
             Direction::West => "west",
+            Direction::Up => "up",
+            Direction::Down => "down",
         };
         write!(f, "{s}")
     }
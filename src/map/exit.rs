@@ -1,20 +1,40 @@
-use crate::map::{Direction, ExitType};
+use crate::map::{Annotations, Direction, ExitType};
+use crate::prelude::*;
+use crate::reset::Persistence;
 
 /// An `Exit` from a [`Room`](crate::map::Room) in a given [`Direction`] with a specific [`ExitType`].
-#[derive(Debug)]
+///
+/// Some exits, such as a [`Teleporter`](crate::map::exit_types::Teleporter), connect rooms without a compass direction.
+#[derive(Clone, Debug)]
 pub struct Exit {
-    /// The [`Direction`] of the `Exit`.
-    pub direction: Direction,
+    /// The [`Direction`] of the `Exit`, or `None` if it doesn't lead in a compass direction.
+    pub direction: Option<Direction>,
     /// The [`ExitType`] defines what type of exit and its behavior.
     pub exit_type: Box<dyn ExitType>,
+    /// Author-only [`Annotations`], never shown to the player.
+    pub annotations: Annotations,
+    /// Whether the exit goes back to its initial state when the world [resets](crate::World::reset_world).
+    pub persistence: Persistence,
 }
 
 impl Exit {
     /// Creates a new `Exit` with the specified [`Direction`] and [`ExitType`]
     pub fn new(direction: Direction, exit_type: Box<dyn ExitType>) -> Self {
         Self {
-            direction,
+            direction: Some(direction),
             exit_type,
+            annotations: Annotations::new(),
+            persistence: Persistence::Resets,
+        }
+    }
+
+    /// Creates a new `Exit` without a [`Direction`], such as a portal between two distant rooms.
+    pub fn new_without_direction(exit_type: Box<dyn ExitType>) -> Self {
+        Self {
+            direction: None,
+            exit_type,
+            annotations: Annotations::new(),
+            persistence: Persistence::Resets,
         }
     }
 }
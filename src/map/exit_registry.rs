@@ -0,0 +1,352 @@
+use crate::map::ExitType;
+use crate::map::exit_types::{
+    AutoClosingDoor, Chasm, CollapsingExit, ConditionalExit, Door, Ladder, NamedExit, RegularExit,
+    RestrictedExit, ScheduledExit, SecretDoor, SkillCheckExit, Staircase, Teleporter,
+};
+use crate::prelude::*;
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::any::TypeId;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map as JsonMap, Value};
+
+type Serializer =
+    fn(&ExitRegistry, &dyn ExitType) -> Result<JsonMap<String, Value>, ExitSerdeError>;
+type Deserializer = fn(
+    &ExitRegistry,
+    JsonMap<String, Value>,
+    Option<&dyn ExitType>,
+) -> Result<Box<dyn ExitType>, ExitSerdeError>;
+
+/// An [`ExitType`] that wraps another boxed exit, such as a [`ScheduledExit`], so that it can be registered with
+/// [`ExitRegistry::register_wrapper`].
+///
+/// The wrapper serializes its own fields, skipping the exit it wraps, which is serialized by the registry and rebuilt
+/// with [`set_exit`](ExitWrapper::set_exit). If the wrapped exit is [unsaved](ExitRegistry::register_unsaved), only the
+/// wrapper is saved, and the wrapped exit the game built is kept by [`restore`](ExitRegistry::restore).
+pub trait ExitWrapper: ExitType + Serialize + DeserializeOwned {
+    /// Returns the wrapped exit.
+    fn exit(&self) -> &dyn ExitType;
+
+    /// Replaces the wrapped exit.
+    fn set_exit(&mut self, exit: Box<dyn ExitType>);
+}
+
+impl ExitWrapper for ScheduledExit {
+    fn exit(&self) -> &dyn ExitType {
+        self.exit.as_ref()
+    }
+
+    fn set_exit(&mut self, exit: Box<dyn ExitType>) {
+        self.exit = exit;
+    }
+}
+
+impl ExitWrapper for CollapsingExit {
+    fn exit(&self) -> &dyn ExitType {
+        self.exit.as_ref()
+    }
+
+    fn set_exit(&mut self, exit: Box<dyn ExitType>) {
+        self.exit = exit;
+    }
+}
+
+impl ExitWrapper for RestrictedExit {
+    fn exit(&self) -> &dyn ExitType {
+        self.exit.as_ref()
+    }
+
+    fn set_exit(&mut self, exit: Box<dyn ExitType>) {
+        self.exit = exit;
+    }
+}
+
+/// The reason an [`ExitType`] couldn't be serialized or deserialized by an [`ExitRegistry`].
+#[derive(Debug)]
+pub enum ExitSerdeError {
+    /// The exit type isn't registered. Holds the exit in debug form when serializing, or its tag when deserializing.
+    Unregistered(String),
+    /// The serialized exit has no `"type"` tag.
+    MissingTag,
+    /// The serialized wrapper has no wrapped exit, as it was unsaved, and there is no built exit to keep it from.
+    MissingExit,
+    /// The exit couldn't be converted from or to JSON.
+    Json(serde_json::Error),
+}
+
+impl core::fmt::Display for ExitSerdeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ExitSerdeError::Unregistered(name) => {
+                write!(f, "exit type \"{name}\" isn't registered")
+            }
+            ExitSerdeError::MissingTag => write!(f, "serialized exit has no \"type\" tag"),
+            ExitSerdeError::MissingExit => {
+                write!(f, "serialized wrapper has no wrapped exit to restore")
+            }
+            ExitSerdeError::Json(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ExitSerdeError {
+    fn from(error: serde_json::Error) -> Self {
+        ExitSerdeError::Json(error)
+    }
+}
+
+/// A registry of [`ExitType`]s by string tag, so that boxed exits can be serialized and deserialized.
+///
+/// A `Box<dyn ExitType>` can't implement [`Serialize`] on its own, since its concrete type is only known at runtime.
+/// The registry looks up the concrete type of an exit and serializes it as a JSON object with a `"type"` tag,
+/// which is then used to pick the type to deserialize it as. Exits that don't serialize to an object
+/// are stored under an `"exit"` key next to the tag.
+///
+/// Exits that wrap another exit, such as a [`CollapsingExit`], are registered with
+/// [`register_wrapper`](ExitRegistry::register_wrapper), and store the exit they wrap, with its own tag, under an
+/// `"exit"` key. If the wrapped exit is unsaved, `"exit"` is `null`, and the wrapper can only be
+/// [restored](ExitRegistry::restore) over the exit the game built.
+///
+/// Exit types holding closures can't be serialized. Those that never change during play, such as a
+/// [`ConditionalExit`], can be registered with [`register_unsaved`](ExitRegistry::register_unsaved), so that saves
+/// leave them as the game built them instead of failing.
+///
+/// The built-in exit types are registered by [`new`](ExitRegistry::new) under snake case tags, such as `"door"`.
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use worldwright::map::exit_types::{Collapse, CollapsingExit, ConditionalExit, Door, RegularExit};
+/// use worldwright::map::{Direction, ExitRegistry, ExitType};
+///
+/// #[derive(Clone, Debug, Serialize, Deserialize)]
+/// struct Rope {
+///     length: u32,
+/// }
+///
+/// impl ExitType for Rope {
+///     fn can_go_through(&self) -> bool {
+///         self.length >= 10
+///     }
+///
+///     fn description(&self, direction: Direction) -> String {
+///         format!("a {}-foot rope {direction}", self.length)
+///     }
+/// }
+///
+/// let mut registry = ExitRegistry::new();
+/// registry.register::<Rope>("rope");
+///
+/// let value = registry.serialize(&Rope { length: 30 }).unwrap();
+/// assert_eq!(value, serde_json::json!({ "type": "rope", "length": 30 }));
+/// let rope = registry.deserialize(value).unwrap();
+/// assert_eq!(rope.description(Direction::Down), "a 30-foot rope down");
+///
+/// let door = registry.deserialize(serde_json::json!({
+///     "type": "door", "locked": true, "open": false, "name": null, "key": null, "breakable": null, "broken": false,
+/// })).unwrap();
+/// assert_eq!(door.description(Direction::North), "a locked door north");
+///
+/// // Exits that don't serialize to an object are stored next to the tag.
+/// let value = registry.serialize(&RegularExit).unwrap();
+/// assert_eq!(value, serde_json::json!({ "type": "regular", "exit": null }));
+/// assert!(registry.deserialize(value).unwrap().can_go_through());
+///
+/// // Wrapped exits are stored with their own tag.
+/// let bridge = CollapsingExit::new(Box::new(Rope { length: 30 }), 2, Collapse::Vanish);
+/// let value = registry.serialize(&bridge).unwrap();
+/// assert_eq!(value["exit"], serde_json::json!({ "type": "rope", "length": 30 }));
+/// let bridge = registry.deserialize(value).unwrap();
+/// assert_eq!(bridge.description(Direction::East), "a 30-foot rope east");
+///
+/// assert!(registry.serialize(&ConditionalExit::new("gap".into(), |_| true, "It is too wide.".into())).is_err());
+/// assert!(registry.is_unsaved(&ConditionalExit::new("gap".into(), |_| true, "It is too wide.".into())));
+///
+/// // Wrapped unsaved exits are kept from the exit the game built.
+/// let gap = ConditionalExit::new("gap".into(), |_| true, "It is too wide.".into());
+/// let built = CollapsingExit::new(Box::new(gap), 2, Collapse::Vanish);
+/// let mut crossed = built.clone();
+/// crossed.on_traverse();
+/// let value = registry.serialize(&crossed).unwrap();
+/// assert_eq!(value["exit"], serde_json::Value::Null);
+/// assert!(registry.deserialize(value.clone()).is_err());
+/// let restored = registry.restore(value, &built).unwrap();
+/// let restored = restored.downcast_ref::<CollapsingExit>().unwrap();
+/// assert_eq!(restored.traversals, 1);
+/// assert_eq!(restored.description(Direction::East), "a gap east");
+/// ```
+#[derive(Clone)]
+pub struct ExitRegistry {
+    tags: BTreeMap<TypeId, (String, Serializer)>,
+    deserializers: BTreeMap<String, Deserializer>,
+    unsaved: BTreeSet<TypeId>,
+}
+
+impl ExitRegistry {
+    /// Creates a new `ExitRegistry` with the built-in exit types that can be serialized.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register::<RegularExit>("regular");
+        registry.register::<NamedExit>("named");
+        registry.register::<Door>("door");
+        registry.register::<SecretDoor>("secret_door");
+        registry.register::<AutoClosingDoor>("auto_closing_door");
+        registry.register::<Chasm>("chasm");
+        registry.register::<Teleporter>("teleporter");
+        registry.register::<SkillCheckExit>("skill_check");
+        registry.register_wrapper::<ScheduledExit>("scheduled");
+        registry.register_wrapper::<CollapsingExit>("collapsing");
+        registry.register_wrapper::<RestrictedExit>("restricted");
+        registry.register_unsaved::<ConditionalExit>();
+        registry.register_unsaved::<Staircase>();
+        registry.register_unsaved::<Ladder>();
+        registry
+    }
+
+    /// Creates a new `ExitRegistry` without any exit types.
+    pub fn empty() -> Self {
+        Self {
+            tags: BTreeMap::new(),
+            deserializers: BTreeMap::new(),
+            unsaved: BTreeSet::new(),
+        }
+    }
+
+    /// Registers an exit type under a tag, replacing any type previously registered under the same tag.
+    pub fn register<T: ExitType + Serialize + DeserializeOwned>(&mut self, tag: &str) {
+        self.tags.insert(
+            TypeId::of::<T>(),
+            (tag.into(), |_, exit| {
+                // The serializer is only looked up by the `TypeId` of `T`.
+                Ok(
+                    match serde_json::to_value(exit.downcast_ref::<T>().unwrap())? {
+                        Value::Object(object) => object,
+                        value => JsonMap::from_iter([("exit".to_string(), value)]),
+                    },
+                )
+            }),
+        );
+        self.deserializers.insert(tag.into(), |_, mut object, _| {
+            let value = match object.remove("exit") {
+                Some(value) if object.is_empty() => value,
+                Some(value) => {
+                    object.insert("exit".into(), value);
+                    Value::Object(object)
+                }
+                None => Value::Object(object),
+            };
+            Ok(Box::new(serde_json::from_value::<T>(value)?))
+        });
+    }
+
+    /// Registers an exit type that wraps another exit under a tag, replacing any type previously registered under the
+    /// same tag. The exits it wraps must be registered too.
+    pub fn register_wrapper<T: ExitWrapper>(&mut self, tag: &str) {
+        self.tags.insert(
+            TypeId::of::<T>(),
+            (tag.into(), |registry, exit| {
+                // The serializer is only looked up by the `TypeId` of `T`.
+                let wrapper = exit.downcast_ref::<T>().unwrap();
+                let Value::Object(mut object) = serde_json::to_value(wrapper)? else {
+                    return Err(ExitSerdeError::MissingTag);
+                };
+                let exit = if registry.is_unsaved(wrapper.exit()) {
+                    Value::Null
+                } else {
+                    registry.serialize(wrapper.exit())?
+                };
+                object.insert("exit".into(), exit);
+                Ok(object)
+            }),
+        );
+        self.deserializers
+            .insert(tag.into(), |registry, mut object, built| {
+                let built = built
+                    .and_then(|built| built.downcast_ref::<T>())
+                    .map(|built| built.exit());
+                let exit = match (object.remove("exit").unwrap_or(Value::Null), built) {
+                    (Value::Null, Some(built)) => built.boxed_clone(),
+                    (Value::Null, None) => return Err(ExitSerdeError::MissingExit),
+                    (value, Some(built)) => registry.restore(value, built)?,
+                    (value, None) => registry.deserialize(value)?,
+                };
+                let mut wrapper = serde_json::from_value::<T>(Value::Object(object))?;
+                wrapper.set_exit(exit);
+                Ok(Box::new(wrapper))
+            });
+    }
+
+    /// Registers an exit type that can't be serialized and never changes during play, so that saves leave it as the
+    /// game built it.
+    pub fn register_unsaved<T: ExitType>(&mut self) {
+        self.unsaved.insert(TypeId::of::<T>());
+    }
+
+    /// Checks whether the type of an exit is registered as [unsaved](ExitRegistry::register_unsaved).
+    pub fn is_unsaved(&self, exit: &dyn ExitType) -> bool {
+        self.unsaved.contains(&exit.as_any().type_id())
+    }
+
+    /// Checks whether an exit type is registered under the given tag.
+    pub fn is_registered(&self, tag: &str) -> bool {
+        self.deserializers.contains_key(tag)
+    }
+
+    /// Serializes an exit to JSON with the tag of its type.
+    pub fn serialize(&self, exit: &dyn ExitType) -> Result<Value, ExitSerdeError> {
+        let Some((tag, serializer)) = self.tags.get(&exit.as_any().type_id()) else {
+            return Err(ExitSerdeError::Unregistered(format!("{exit:?}")));
+        };
+        let mut object = serializer(self, exit)?;
+        object.insert("type".into(), Value::String(tag.clone()));
+        Ok(Value::Object(object))
+    }
+
+    /// Deserializes an exit from JSON, using its tag to find its type.
+    ///
+    /// Fails with [`ExitSerdeError::MissingExit`] for wrappers whose wrapped exit was unsaved, which need
+    /// [`restore`](ExitRegistry::restore) instead.
+    pub fn deserialize(&self, value: Value) -> Result<Box<dyn ExitType>, ExitSerdeError> {
+        self.deserialize_with(value, None)
+    }
+
+    /// Deserializes an exit from JSON over the exit the game built, keeping the parts of it that are
+    /// [unsaved](ExitRegistry::register_unsaved), such as the exit a wrapper wraps.
+    pub fn restore(
+        &self,
+        value: Value,
+        built: &dyn ExitType,
+    ) -> Result<Box<dyn ExitType>, ExitSerdeError> {
+        self.deserialize_with(value, Some(built))
+    }
+
+    fn deserialize_with(
+        &self,
+        value: Value,
+        built: Option<&dyn ExitType>,
+    ) -> Result<Box<dyn ExitType>, ExitSerdeError> {
+        let Value::Object(mut object) = value else {
+            return Err(ExitSerdeError::MissingTag);
+        };
+        let Some(Value::String(tag)) = object.remove("type") else {
+            return Err(ExitSerdeError::MissingTag);
+        };
+        let Some(deserializer) = self.deserializers.get(&tag) else {
+            return Err(ExitSerdeError::Unregistered(tag));
+        };
+        deserializer(self, object, built)
+    }
+}
+
+impl Default for ExitRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for ExitRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.deserializers.keys()).finish()
+    }
+}
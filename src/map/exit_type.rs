@@ -1,9 +1,137 @@
-use crate::map::Direction;
+use crate::World;
+use crate::actor::NpcId;
+use crate::item::ItemId;
+use crate::light::LightLevel;
+use crate::map::{Direction, RoomId};
+use crate::prelude::*;
+use crate::puzzle::Requirement;
+use crate::weather::Weather;
+use core::any::Any;
+
+/// Gives access to a value as [`Any`], so that a `dyn` [`ExitType`] can be downcast to its concrete type.
+///
+/// This is implemented for every type, so it never needs to be implemented by hand.
+pub trait AsAny: Any {
+    /// Returns the value as [`Any`].
+    fn as_any(&self) -> &dyn Any;
+    /// Returns the value as mutable [`Any`].
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Clones a boxed [`ExitType`], so that [`Exit`](crate::map::Exit)s, the [`Map`](crate::map::Map) and the [`World`] can be cloned.
+///
+/// This is implemented for every `ExitType` that is `Clone`, so it never needs to be implemented by hand.
+///
+/// # Examples
+/// ```
+/// use worldwright::map::exit_types::Door;
+/// use worldwright::map::{Direction, Map};
+///
+/// let mut map = Map::new();
+/// let cell = map.new_room("A damp cell.".into());
+/// let corridor = map.new_room("A dark corridor.".into());
+/// let door = map.connect_rooms(cell, corridor, Direction::North, Box::new(Door::new(true)));
+///
+/// let mut snapshot = map.clone();
+/// snapshot.exit_as_mut::<Door>(door).unwrap().unlock();
+/// assert_eq!(snapshot.exit_as::<Door>(door).map(|door| door.locked), Some(false));
+/// assert_eq!(map.exit_as::<Door>(door).map(|door| door.locked), Some(true));
+/// ```
+pub trait BoxedClone {
+    /// Clones the exit type into a new box.
+    fn boxed_clone(&self) -> Box<dyn ExitType>;
+}
+
+impl<T: ExitType + Clone> BoxedClone for T {
+    fn boxed_clone(&self) -> Box<dyn ExitType> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn ExitType> {
+    fn clone(&self) -> Self {
+        (**self).boxed_clone()
+    }
+}
+
+/// The reason an exit can't be unlocked with a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnlockError {
+    /// There is no exit in that direction.
+    NoExit,
+    /// The exit can't be unlocked with a key.
+    NoKeyhole,
+    /// The key doesn't fit.
+    WrongKey,
+}
+
+/// The environment an exit is seen in, such as the light and weather of the room it leads from, which
+/// [`ExitType::description_in`] can reflect.
+///
+/// # Examples
+/// ```
+/// use worldwright::light::LightLevel;
+/// use worldwright::map::Surroundings;
+/// use worldwright::weather::Weather;
+///
+/// let mut surroundings = Surroundings { light: LightLevel::Lit, night: true, weather: None };
+/// assert_eq!(surroundings.adjective(), Some("moonlit"));
+/// surroundings.weather = Some(Weather::Rain);
+/// assert_eq!(surroundings.adjective(), Some("rain-lashed"));
+/// surroundings.night = false;
+/// surroundings.weather = Some(Weather::Clear);
+/// assert_eq!(surroundings.adjective(), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Surroundings {
+    /// The [`LightLevel`] of the room.
+    pub light: LightLevel,
+    /// Whether it is night on the [`Clock`](crate::clock::Clock).
+    pub night: bool,
+    /// The [`Weather`] in the room, if any.
+    pub weather: Option<Weather>,
+}
+
+impl Surroundings {
+    /// Gets a word describing how the surroundings change the look of an exit, such as "rain-lashed" or "moonlit".
+    ///
+    /// Weather other than clear skies comes first, then dim light, then night. Returns `None` in plain daylight.
+    pub fn adjective(&self) -> Option<&'static str> {
+        match self.weather {
+            Some(Weather::Rain) => return Some("rain-lashed"),
+            Some(Weather::Storm) => return Some("storm-battered"),
+            Some(Weather::Snow) => return Some("snow-covered"),
+            Some(Weather::Clear) | None => {}
+        }
+        if self.light == LightLevel::Dim {
+            Some("shadowy")
+        } else if self.night {
+            Some("moonlit")
+        } else {
+            None
+        }
+    }
+}
 
 /// An `ExitType` represents the type of passage between two [`Room`](crate::map::Room)s in the [`Map`](crate::map::Map).
 ///
 /// It represents a the type of passage the player can go through to move from one room to another and the conditions needed for the player to be able to go through an exit.
-pub trait ExitType: std::fmt::Debug {
+///
+/// Once boxed, an exit can be downcast back to its concrete type with [`downcast_ref`](ExitType#method.downcast_ref)
+/// and [`downcast_mut`](ExitType#method.downcast_mut), for example to unlock a [`Door`](crate::map::exit_types::Door).
+///
+/// Exit types must be `Clone`, so that the [`Map`](crate::map::Map) can be cloned for snapshots and undo.
+pub trait ExitType: AsAny + BoxedClone + core::fmt::Debug {
     /// Checks whether the player can go through this exit.
     fn can_go_through(&self) -> bool;
 
@@ -11,4 +139,223 @@ pub trait ExitType: std::fmt::Debug {
     ///
     /// The description should be a short phrase that describes the exit with a direction, such as "a wooden door north" or "an archway south".
     fn description(&self, direction: Direction) -> String;
+
+    /// Provides a description of the exit in a given direction that reflects its [`Surroundings`], such as
+    /// "a moonlit archway east" or "a rain-lashed path north".
+    ///
+    /// This is what [`World::describe_exits`] uses. By default, the surroundings are ignored and the
+    /// [description](ExitType::description) is used.
+    fn description_in(&self, direction: Direction, surroundings: &Surroundings) -> String {
+        let _ = surroundings;
+        self.description(direction)
+    }
+
+    /// Provides a description of the exit when it doesn't lead in a [`Direction`], such as "a shimmering portal".
+    ///
+    /// This is used for exits connected with [`connect_rooms_without_direction`](crate::map::Map::connect_rooms_without_direction).
+    /// By default, the exit is described as "a passage".
+    fn undirected_description(&self) -> String {
+        "a passage".into()
+    }
+
+    /// Explains why the player can't go through this exit, such as "The door is locked."
+    ///
+    /// Returns `None` if the player [can go through](ExitType::can_go_through). Exits that can only be gone through in
+    /// some states of the [`World`] give the message for when they are blocked, and decide in
+    /// [`check_passage`](ExitType::check_passage). By default, a generic refusal is given when the player can't.
+    fn blocked_message(&self) -> Option<String> {
+        if self.can_go_through() {
+            None
+        } else {
+            Some("You can't go that way.".into())
+        }
+    }
+
+    /// Checks whether the player can go through this exit in the current state of the [`World`],
+    /// returning the [blocked message](ExitType::blocked_message) if they can't.
+    ///
+    /// By default, the state of the world isn't taken into account. Exits whose passability depends on game state,
+    /// such as a [`ConditionalExit`](crate::map::exit_types::ConditionalExit), override this.
+    fn check_passage(&self, world: &World) -> Result<(), String> {
+        let _ = world;
+        match self.blocked_message() {
+            None => Ok(()),
+            Some(message) => Err(message),
+        }
+    }
+
+    /// Checks whether the exit can be gone through from a room in the current state of the [`World`], like
+    /// [`check_passage`](ExitType::check_passage).
+    ///
+    /// By default, the room isn't taken into account. Exits that wrap another exit, such as a
+    /// [`RestrictedExit`](crate::map::exit_types::RestrictedExit), override this to pass the room on.
+    fn check_passage_from(&self, world: &World, room: RoomId) -> Result<(), String> {
+        let _ = room;
+        self.check_passage(world)
+    }
+
+    /// Checks whether an NPC can go through the exit from a room in the current state of the [`World`].
+    ///
+    /// By default, NPCs are held to the same checks as the player, through
+    /// [`check_passage_from`](ExitType::check_passage_from). Exits that judge the player themselves, such as a
+    /// [`SkillCheckExit`](crate::map::exit_types::SkillCheckExit) testing their stats, override this.
+    fn check_npc_passage(&self, world: &World, npc: NpcId, room: RoomId) -> Result<(), String> {
+        let _ = npc;
+        self.check_passage_from(world, room)
+    }
+
+    /// Provides a message shown when the player goes through this exit, such as "You force the gate open."
+    ///
+    /// By default, going through an exit isn't remarked upon.
+    fn passage_message(&self) -> Option<String> {
+        None
+    }
+
+    /// Called when the player actually tries to go through the exit, after [`check_passage`](ExitType::check_passage),
+    /// whether or not they can.
+    ///
+    /// As `check_passage` is also used to query passability, exits that draw from the [`World`]'s random streams
+    /// use up their roll here instead. By default, nothing happens.
+    fn on_attempt(&self, world: &World) {
+        let _ = world;
+    }
+
+    /// Checks whether objects can be thrown through this exit into the next room.
+    ///
+    /// By default, objects can be thrown through an exit if the player can go through it.
+    fn can_throw_through(&self) -> bool {
+        self.can_go_through()
+    }
+
+    /// Checks whether bulky objects, such as a wheelbarrow or a statue, can be pushed through this exit.
+    ///
+    /// By default, bulky objects can be pushed through an exit if the player can go through it.
+    fn allows_bulky_objects(&self) -> bool {
+        self.can_go_through()
+    }
+
+    /// Checks whether sound from the next room can be heard through this exit.
+    ///
+    /// By default, sound carries through an exit if objects can be thrown through it.
+    fn transmits_sound(&self) -> bool {
+        self.can_throw_through()
+    }
+
+    /// Checks whether the exit can be unlocked with a key, such as a [`Door`](crate::map::exit_types::Door) that has one.
+    ///
+    /// By default, exits have no keyhole.
+    fn has_keyhole(&self) -> bool {
+        false
+    }
+
+    /// Checks whether the exit is locked, such as a locked [`Door`](crate::map::exit_types::Door), including doors
+    /// wrapped in other exits.
+    ///
+    /// By default, exits aren't locked.
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    /// Tries to unlock the exit with the given key.
+    ///
+    /// By default, exits can't be unlocked with a key.
+    fn unlock_with(&mut self, key: ItemId) -> Result<(), UnlockError> {
+        let _ = key;
+        Err(UnlockError::NoKeyhole)
+    }
+
+    /// Called after the player goes through the exit, through [`Map::traverse_exit`](crate::map::Map::traverse_exit).
+    ///
+    /// Exits that change as they are used, such as a [`CollapsingExit`](crate::map::exit_types::CollapsingExit),
+    /// keep track of it here. By default, nothing happens.
+    fn on_traverse(&mut self) {}
+
+    /// Called every turn through [`Map::tick_exits`](crate::map::Map::tick_exits), returning a message if the exit changed,
+    /// such as "The door swings shut."
+    ///
+    /// Exits that change over time, such as an [`AutoClosingDoor`](crate::map::exit_types::AutoClosingDoor), update themselves here.
+    /// By default, nothing happens.
+    fn on_turn(&mut self) -> Option<String> {
+        None
+    }
+
+    /// Lists the nouns the player can refer to the exit by, such as "archway", so they can type "enter archway".
+    ///
+    /// The last word of a noun also refers to the exit, so "heavy wooden door" can be called "door".
+    /// By default, exits have no nouns and can only be taken by [`Direction`].
+    fn nouns(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Lists what the player needs to get through the exit, such as a key, for the [`PuzzleGraph`](crate::puzzle::PuzzleGraph).
+    ///
+    /// Exits that need nothing are free passages. By default, exits need nothing.
+    fn requirements(&self) -> Vec<Requirement> {
+        Vec::new()
+    }
+
+    /// Checks whether the exit is hidden, such as an undiscovered [`SecretDoor`](crate::map::exit_types::SecretDoor).
+    ///
+    /// Hidden exits behave like walls: they aren't listed or described, and they can't be found by direction.
+    /// By default, exits aren't hidden.
+    fn is_hidden(&self) -> bool {
+        false
+    }
+
+    /// Reveals the exit if it is hidden, returning whether it was.
+    ///
+    /// By default, exits can't be hidden, so there is nothing to reveal.
+    fn reveal(&mut self) -> bool {
+        false
+    }
+
+    /// Checks whether another exit is of the same type and in the same state, such as a door locked in both, so that
+    /// [resets](crate::World::reset_world) only restore the exits that changed.
+    ///
+    /// By default, exits are never in the same state, so they are always restored.
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        let _ = other;
+        false
+    }
+
+    /// Lists the prose of the exit, such as its name and the messages it shows, for the
+    /// [spell check](crate::lint::spell_check).
+    ///
+    /// By default, the [blocked message](ExitType::blocked_message) and the
+    /// [passage message](ExitType::passage_message) are listed.
+    fn prose(&self) -> Vec<String> {
+        self.blocked_message()
+            .into_iter()
+            .chain(self.passage_message())
+            .collect()
+    }
+
+    /// Provides values describing the state of the exit, which templates in descriptions can refer to.
+    ///
+    /// For example, a door provides `door_state`, so a description can say "The door is {door_state}."
+    fn template_variables(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+}
+
+impl dyn ExitType {
+    /// Returns the exit as its concrete type `T`, or `None` if it is of another type.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::ExitType;
+    /// use worldwright::map::exit_types::{Door, RegularExit};
+    ///
+    /// let exit: Box<dyn ExitType> = Box::new(Door::new(true));
+    /// assert!(exit.downcast_ref::<Door>().is_some_and(|door| door.locked));
+    /// assert!(exit.downcast_ref::<RegularExit>().is_none());
+    /// ```
+    pub fn downcast_ref<T: ExitType>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns the exit as a mutable reference to its concrete type `T`, or `None` if it is of another type.
+    pub fn downcast_mut<T: ExitType>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
 }
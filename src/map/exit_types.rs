@@ -1,5 +1,14 @@
-use crate::map::{Direction, ExitType};
-use crate::starts_with_vowel;
+use crate::World;
+use crate::actor::NpcId;
+use crate::item::ItemId;
+use crate::map::{Direction, ExitType, RoomId, Surroundings, UnlockError};
+use crate::prelude::*;
+use crate::puzzle::Requirement;
+use crate::weather::Weather;
+use crate::{Rng, starts_with_vowel, with_article};
+use alloc::rc::Rc;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A regular exit that the player can always go through.
 ///
@@ -14,7 +23,8 @@ use crate::starts_with_vowel;
 /// assert!(exit.can_go_through());
 /// assert_eq!(exit.description(Direction::North), "an exit north");
 /// ```
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RegularExit;
 
 impl ExitType for RegularExit {
@@ -47,6 +57,10 @@ impl ExitType for RegularExit {
     fn description(&self, direction: Direction) -> String {
         format!("an exit {direction}")
     }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        same_as(self, other)
+    }
 }
 
 /// A named exit that the player can always go through.
@@ -61,16 +75,50 @@ impl ExitType for RegularExit {
 /// let exit = NamedExit::new("archway".into());
 /// assert!(exit.can_go_through());
 /// assert_eq!(exit.description(Direction::East), "an archway east");
-#[derive(Clone, Debug)]
+/// ```
+///
+/// An exit open to the elements can reflect the weather and light it is seen in:
+/// ```
+/// use worldwright::World;
+/// use worldwright::clock::GameTime;
+/// use worldwright::map::exit_types::NamedExit;
+/// use worldwright::map::{Direction, Region};
+/// use worldwright::weather::{Weather, WeatherZone};
+///
+/// let mut world = World::new();
+/// let courtyard = world.map.new_room("A cobbled courtyard.".into());
+/// world.map.new_room_in_direction(courtyard, Direction::East, Box::new(NamedExit::new("archway".into()).exposed()), "A cloister.".into());
+/// world.map.new_room_in_direction(courtyard, Direction::North, Box::new(NamedExit::new("path".into()).exposed()), "A garden.".into());
+/// world.clock.start = GameTime::new(0, 23, 0);
+/// assert_eq!(world.describe_exits(courtyard), vec!["a moonlit path north", "a moonlit archway east"]);
+///
+/// world.weather.add(WeatherZone::new(Region::Everywhere, Weather::Rain));
+/// assert_eq!(world.describe_exits(courtyard), vec!["a rain-lashed path north", "a rain-lashed archway east"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NamedExit {
     /// The name of the exit, such as "archway" or "tunnel".
     pub name: String,
+    /// Whether the exit is open to the elements, so that its description reflects the weather and light, such as
+    /// "a moonlit archway east".
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub exposed: bool,
 }
 
 impl NamedExit {
     /// Creates a new named exit with the given name.
     pub fn new(name: String) -> Self {
-        Self { name }
+        Self {
+            name,
+            exposed: false,
+        }
+    }
+
+    /// Makes the exit open to the elements, so that its description reflects the weather and light.
+    pub fn exposed(mut self) -> Self {
+        self.exposed = true;
+        self
     }
 }
 
@@ -113,11 +161,38 @@ impl ExitType for NamedExit {
             direction
         )
     }
+
+    /// Provides a description of the exit that reflects the weather and light if it is [exposed](NamedExit::exposed),
+    /// such as "a rain-lashed path north".
+    fn description_in(&self, direction: Direction, surroundings: &Surroundings) -> String {
+        match surroundings.adjective() {
+            Some(adjective) if self.exposed => {
+                format!(
+                    "{} {direction}",
+                    with_article(&format!("{adjective} {}", self.name))
+                )
+            }
+            _ => self.description(direction),
+        }
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        same_as(self, other)
+    }
+
+    fn prose(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
 }
 
-/// A door that can be locked or unlocked.
+/// A door that can be opened, closed, locked or unlocked.
 ///
-/// This exit type represents a door that can be locked or unlocked, preventing or allowing passage between two rooms.
+/// This exit type represents a door between two rooms. The player can only go through it while it is open,
+/// and it can only be opened while it is unlocked.
 /// Additionally, the door can have an optional name to provide more description.
 ///
 /// # Examples
@@ -130,33 +205,122 @@ impl ExitType for NamedExit {
 /// assert_eq!(door.description(Direction::North), "a locked heavy wooden door north");
 ///
 /// door.unlock();
+/// assert!(!door.can_go_through());
+/// assert_eq!(door.description(Direction::North), "a closed heavy wooden door north");
 ///
+/// door.open();
 /// assert!(door.can_go_through());
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Door {
     /// Indicates whether the door is locked.
     pub locked: bool,
+    /// Indicates whether the door is open. A locked door is always closed.
+    pub open: bool,
     /// An optional name for the door, such as "heavy wooden door".
     pub name: Option<String>,
+    /// The [`ItemId`] of the key that unlocks the door, if it can be unlocked with a key.
+    pub key: Option<ItemId>,
+    /// How the door can be broken down, if it can.
+    pub breakable: Option<Breakable>,
+    /// Whether the door has been broken down. A broken door is always open.
+    pub broken: bool,
+}
+
+/// How sturdy a breakable [`Door`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Breakable {
+    /// The least force that damages the door.
+    pub threshold: u32,
+    /// How much more damage the door can take before it breaks.
+    pub hit_points: u32,
+}
+
+impl Breakable {
+    /// Creates a new `Breakable` with the given force threshold and hit points.
+    pub fn new(threshold: u32, hit_points: u32) -> Self {
+        Self {
+            threshold,
+            hit_points,
+        }
+    }
+}
+
+/// What happened when a [`Door`] was [forced](Door::force).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ForceOutcome {
+    /// The door can't be broken.
+    Unbreakable,
+    /// The force was below the threshold of the door, so it held.
+    Held,
+    /// The door was damaged, but still stands.
+    Damaged,
+    /// The door broke.
+    Broken,
 }
 
 impl Door {
     /// Creates a new door with the specified locked state and no name.
+    ///
+    /// Unlocked doors start open and locked doors start closed.
     pub fn new(locked: bool) -> Self {
-        Self { locked, name: None }
+        Self {
+            locked,
+            open: !locked,
+            name: None,
+            key: None,
+            breakable: None,
+            broken: false,
+        }
     }
 
     /// Creates a new door with the specified lock state and a name/
+    ///
+    /// Unlocked doors start open and locked doors start closed.
     pub fn new_with_name(locked: bool, name: String) -> Self {
         Self {
             locked,
+            open: !locked,
             name: Some(name),
+            key: None,
+            breakable: None,
+            broken: false,
+        }
+    }
+
+    /// Creates a new locked door that can be unlocked with the given key.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::item::{Item, Items};
+    /// use worldwright::map::exit_types::Door;
+    /// use worldwright::map::{ExitType, UnlockError};
+    ///
+    /// let mut items = Items::new();
+    /// let brass_key = items.add(Item::new("brass key".into(), "A small brass key.".into()));
+    /// let iron_key = items.add(Item::new("iron key".into(), "A heavy iron key.".into()));
+    ///
+    /// let mut door = Door::new_with_key(brass_key);
+    /// assert!(door.locked);
+    /// assert_eq!(door.unlock_with(iron_key), Err(UnlockError::WrongKey));
+    /// assert_eq!(door.unlock_with(brass_key), Ok(()));
+    /// assert!(!door.locked);
+    /// ```
+    pub fn new_with_key(key: ItemId) -> Self {
+        Self {
+            locked: true,
+            open: false,
+            name: None,
+            key: Some(key),
+            breakable: None,
+            broken: false,
         }
     }
 
     /// Locks the door, preventing passage.
     ///
-    /// Sets the door's locked property to true.
+    /// Sets the door's locked property to true, closing the door first if it is open.
     ///
     /// # Examples
     /// ```
@@ -168,6 +332,10 @@ impl Door {
     /// door.lock();
     /// assert!(door.locked);
     pub fn lock(&mut self) {
+        if self.broken {
+            return;
+        }
+        self.open = false;
         self.locked = true;
     }
 
@@ -187,24 +355,98 @@ impl Door {
     pub fn unlock(&mut self) {
         self.locked = false;
     }
+
+    /// Opens the door, returning whether it is now open. A locked door can't be opened.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::Door;
+    ///
+    /// let mut door = Door::new(true);
+    /// assert!(!door.open());
+    /// door.unlock();
+    /// assert!(door.open());
+    /// door.close();
+    /// assert!(!door.open);
+    /// ```
+    pub fn open(&mut self) -> bool {
+        if !self.locked {
+            self.open = true;
+        }
+        self.open
+    }
+
+    /// Closes the door, preventing passage. A broken door can't be closed.
+    pub fn close(&mut self) {
+        if !self.broken {
+            self.open = false;
+        }
+    }
+
+    /// Applies `force` to the door, such as from a kick or a battering ram, damaging it if the door is [`Breakable`].
+    ///
+    /// Force below the threshold of the door has no effect. Otherwise, the force is taken off its hit points,
+    /// and the door breaks when none are left.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Breakable, Door, ForceOutcome};
+    /// use worldwright::map::{Direction, ExitType};
+    ///
+    /// let mut door = Door::new(true);
+    /// assert_eq!(door.force(10), ForceOutcome::Unbreakable);
+    ///
+    /// door.breakable = Some(Breakable::new(5, 8));
+    /// assert_eq!(door.force(3), ForceOutcome::Held);
+    /// assert_eq!(door.force(5), ForceOutcome::Damaged);
+    /// assert_eq!(door.force(5), ForceOutcome::Broken);
+    /// assert!(door.can_go_through());
+    /// assert_eq!(door.description(Direction::North), "the splintered remains of a door north");
+    /// ```
+    pub fn force(&mut self, force: u32) -> ForceOutcome {
+        if self.broken {
+            return ForceOutcome::Broken;
+        }
+        let Some(breakable) = &mut self.breakable else {
+            return ForceOutcome::Unbreakable;
+        };
+        if force < breakable.threshold {
+            return ForceOutcome::Held;
+        }
+        breakable.hit_points = breakable.hit_points.saturating_sub(force);
+        if breakable.hit_points > 0 {
+            return ForceOutcome::Damaged;
+        }
+        self.break_down();
+        ForceOutcome::Broken
+    }
+
+    /// Breaks the door down at once, leaving it open for good, whether it is [`Breakable`] or not.
+    pub fn break_down(&mut self) {
+        self.broken = true;
+        self.locked = false;
+        self.open = true;
+    }
 }
 
 impl ExitType for Door {
-    /// Always returns true, as the player can always go through a regular exit.
+    /// Returns true if the door is open.
     ///
     /// # Examples
     /// ```
-    /// use worldwright::map::exit_types::NamedExit;
+    /// use worldwright::map::exit_types::Door;
     /// use worldwright::map::ExitType;
     ///
-    /// let exit = NamedExit::new("archway".into());
-    /// assert!(exit.can_go_through());
+    /// let mut door = Door::new(false);
+    /// assert!(door.can_go_through());
+    /// door.close();
+    /// assert!(!door.can_go_through());
     /// ```
     fn can_go_through(&self) -> bool {
-        !self.locked
+        self.open
     }
 
-    /// Provides a description of the door with its name (if any), if it is locked, and a direction.
+    /// Provides a description of the door with its name (if any), if it is locked, closed or broken, and a direction.
     ///
     /// # Examples
     /// ```
@@ -218,25 +460,1892 @@ impl ExitType for Door {
     ///
     /// let mut unnamed_door = Door::new(false);
     /// assert_eq!(unnamed_door.description(Direction::South), "a door south");
+    /// unnamed_door.close();
+    /// assert_eq!(unnamed_door.description(Direction::South), "a closed door south");
     /// unnamed_door.lock();
     /// assert_eq!(unnamed_door.description(Direction::South), "a locked door south");
     /// ```
     fn description(&self, direction: Direction) -> String {
-        if let Some(name) = &self.name {
-            format!(
-                "a{}{}{name} {direction}",
-                if starts_with_vowel(name) && !self.locked {
-                    "n"
-                } else {
-                    ""
-                },
-                if self.locked { " locked " } else { " " }
-            )
+        let name = self.name.as_deref().unwrap_or("door");
+        if self.broken {
+            return format!(
+                "the splintered remains of {} {direction}",
+                with_article(name)
+            );
+        }
+        let state = if self.locked {
+            "locked "
+        } else if !self.open {
+            "closed "
+        } else {
+            ""
+        };
+        format!("{} {direction}", with_article(&format!("{state}{name}")))
+    }
+
+    /// Explains that the door is locked or closed.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::Door;
+    /// use worldwright::map::ExitType;
+    ///
+    /// let mut door = Door::new_with_name(true, "heavy wooden door".into());
+    /// assert_eq!(door.blocked_message().as_deref(), Some("The heavy wooden door is locked."));
+    /// door.unlock();
+    /// assert_eq!(door.blocked_message().as_deref(), Some("The heavy wooden door is closed."));
+    /// door.open();
+    /// assert_eq!(door.blocked_message(), None);
+    /// ```
+    fn blocked_message(&self) -> Option<String> {
+        let name = self.name.as_deref().unwrap_or("door");
+        if self.locked {
+            Some(format!("The {name} is locked."))
+        } else if !self.open {
+            Some(format!("The {name} is closed."))
+        } else {
+            None
+        }
+    }
+
+    /// Provides `door_state`, which is either "locked", "unlocked" or "broken", and `door_position`, which is either "open" or "closed".
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::Door;
+    /// use worldwright::map::ExitType;
+    ///
+    /// let door = Door::new(true);
+    /// assert_eq!(
+    ///     door.template_variables(),
+    ///     vec![
+    ///         ("door_state".into(), "locked".into()),
+    ///         ("door_position".into(), "closed".into()),
+    ///     ]
+    /// );
+    /// ```
+    fn template_variables(&self) -> Vec<(String, String)> {
+        let state = if self.broken {
+            "broken"
+        } else if self.locked {
+            "locked"
+        } else {
+            "unlocked"
+        };
+        let position = if self.open { "open" } else { "closed" };
+        vec![
+            ("door_state".into(), state.into()),
+            ("door_position".into(), position.into()),
+        ]
+    }
+
+    /// Has a keyhole if the door has a key.
+    fn has_keyhole(&self) -> bool {
+        self.key.is_some()
+    }
+
+    fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Unlocks the door if `key` is its key.
+    fn unlock_with(&mut self, key: ItemId) -> Result<(), UnlockError> {
+        match self.key {
+            None => Err(UnlockError::NoKeyhole),
+            Some(door_key) if door_key != key => Err(UnlockError::WrongKey),
+            Some(_) => {
+                self.unlock();
+                Ok(())
+            }
+        }
+    }
+
+    /// Needs the key of the door, or some other way to unlock it, while it is locked.
+    fn requirements(&self) -> Vec<Requirement> {
+        match (self.locked, self.key) {
+            (false, _) => Vec::new(),
+            (true, Some(key)) => vec![Requirement::Key(key)],
+            (true, None) => vec![Requirement::Condition("unlocking".into())],
+        }
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        vec![self.name.clone().unwrap_or_else(|| "door".into())]
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        same_as(self, other)
+    }
+
+    fn prose(&self) -> Vec<String> {
+        self.name
+            .iter()
+            .cloned()
+            .chain(self.blocked_message())
+            .collect()
+    }
+}
+
+/// A [`Door`] that behaves like a wall until it is revealed, such as a bookcase that swings open.
+///
+/// While hidden, it isn't listed or described and can't be gone through. Once [revealed](SecretDoor::reveal),
+/// it acts like the door it wraps. Use [`Map::reveal_exit`](crate::map::Map::reveal_exit) to reveal it in a map.
+///
+/// # Examples
+/// ```
+/// use worldwright::map::exit_types::{Door, SecretDoor};
+/// use worldwright::map::{Direction, ExitType};
+///
+/// let mut secret = SecretDoor::new(Door::new_with_name(false, "bookcase".into()));
+/// assert!(secret.is_hidden());
+/// assert!(!secret.can_go_through());
+///
+/// secret.reveal();
+/// assert!(secret.can_go_through());
+/// assert_eq!(secret.description(Direction::West), "a bookcase west");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SecretDoor {
+    /// The door used once the secret door is revealed.
+    pub door: Door,
+    hidden: bool,
+}
+
+impl SecretDoor {
+    /// Creates a new, hidden secret door that acts like `door` once revealed.
+    pub fn new(door: Door) -> Self {
+        Self { door, hidden: true }
+    }
+}
+
+impl ExitType for SecretDoor {
+    /// Returns true if the secret door is revealed and its door is open.
+    fn can_go_through(&self) -> bool {
+        !self.hidden && self.door.can_go_through()
+    }
+
+    /// Provides the description of the door.
+    fn description(&self, direction: Direction) -> String {
+        self.door.description(direction)
+    }
+
+    /// Gives a generic refusal while hidden, then the blocked message of the door.
+    fn blocked_message(&self) -> Option<String> {
+        if self.hidden {
+            Some("You can't go that way.".into())
+        } else {
+            self.door.blocked_message()
+        }
+    }
+
+    /// Provides the template variables of the door.
+    fn template_variables(&self) -> Vec<(String, String)> {
+        self.door.template_variables()
+    }
+
+    /// Has a keyhole once revealed, if the door has a key.
+    fn has_keyhole(&self) -> bool {
+        !self.hidden && self.door.has_keyhole()
+    }
+
+    fn is_locked(&self) -> bool {
+        self.door.is_locked()
+    }
+
+    /// Unlocks the door if it is revealed and `key` is its key.
+    fn unlock_with(&mut self, key: ItemId) -> Result<(), UnlockError> {
+        if self.hidden {
+            return Err(UnlockError::NoExit);
+        }
+        self.door.unlock_with(key)
+    }
+
+    fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn reveal(&mut self) -> bool {
+        core::mem::replace(&mut self.hidden, false)
+    }
+
+    /// Needs to be discovered while hidden, then whatever the door needs.
+    fn requirements(&self) -> Vec<Requirement> {
+        let mut requirements = self.door.requirements();
+        if self.hidden {
+            requirements.insert(0, Requirement::Condition("discovery".into()));
+        }
+        requirements
+    }
+
+    /// Can't be referred to until it is discovered.
+    fn nouns(&self) -> Vec<String> {
+        if self.hidden {
+            Vec::new()
+        } else {
+            self.door.nouns()
+        }
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        same_as(self, other)
+    }
+
+    fn prose(&self) -> Vec<String> {
+        self.door.prose()
+    }
+}
+
+/// A chasm that the player can't cross, but that objects can be thrown across.
+///
+/// # Examples
+/// ```
+/// use worldwright::map::exit_types::Chasm;
+/// use worldwright::map::{ Direction, ExitType };
+///
+/// let chasm = Chasm;
+/// assert!(!chasm.can_go_through());
+/// assert!(chasm.can_throw_through());
+/// assert_eq!(chasm.description(Direction::East), "a chasm east");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chasm;
+
+impl ExitType for Chasm {
+    /// Always returns false, as the player can't cross a chasm.
+    fn can_go_through(&self) -> bool {
+        false
+    }
+
+    /// Provides a description of the chasm with a direction.
+    fn description(&self, direction: Direction) -> String {
+        format!("a chasm {direction}")
+    }
+
+    /// Always returns true, as objects can be thrown across a chasm.
+    fn can_throw_through(&self) -> bool {
+        true
+    }
+
+    /// Explains that the chasm is too wide to cross.
+    fn blocked_message(&self) -> Option<String> {
+        Some("The chasm is too wide to cross.".into())
+    }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        vec![Requirement::Condition("a way across".into())]
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        vec!["chasm".into()]
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        same_as(self, other)
+    }
+}
+
+/// An exit whose passability is decided by a condition over the state of the [`World`], such as a flag being set,
+/// an item being carried or a score being reached.
+///
+/// Without access to the world, the exit is considered impassable, so [`can_go_through`](ExitType::can_go_through) always
+/// returns false and [`blocked_message`](ExitType::blocked_message) always gives the message for when the condition
+/// doesn't hold. Movement code should use [`check_passage`](ExitType::check_passage) instead, and pathfinding
+/// [`World::find_path`], as [`Map::find_path`](crate::map::Map::find_path) treats the exit as a wall.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::exit_types::ConditionalExit;
+/// use worldwright::map::{Direction, ExitType};
+///
+/// let portal = ConditionalExit::new(
+///     "shimmering portal".into(),
+///     |world| world.variables.get("portal_open") == Some("yes"),
+///     "The portal's surface is as hard as glass.".into(),
+/// );
+/// assert_eq!(portal.description(Direction::North), "a shimmering portal north");
+///
+/// let mut world = World::new();
+/// assert_eq!(
+///     portal.check_passage(&world),
+///     Err("The portal's surface is as hard as glass.".into())
+/// );
+/// world.variables.set("portal_open", "yes");
+/// assert_eq!(portal.check_passage(&world), Ok(()));
+/// ```
+#[derive(Clone)]
+pub struct ConditionalExit {
+    /// The name of the exit, such as "shimmering portal".
+    pub name: String,
+    /// The condition that must hold for the player to go through.
+    pub condition: Rc<dyn Fn(&World) -> bool>,
+    /// The message shown when the player can't go through.
+    pub blocked_message: String,
+}
+
+impl ConditionalExit {
+    /// Creates a new conditional exit with the given name, condition and blocked message.
+    pub fn new(
+        name: String,
+        condition: impl Fn(&World) -> bool + 'static,
+        blocked_message: String,
+    ) -> Self {
+        Self {
+            name,
+            condition: Rc::new(condition),
+            blocked_message,
+        }
+    }
+}
+
+impl core::fmt::Debug for ConditionalExit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConditionalExit")
+            .field("name", &self.name)
+            .field("blocked_message", &self.blocked_message)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ExitType for ConditionalExit {
+    /// Always returns false, as the condition can't be checked without the [`World`].
+    fn can_go_through(&self) -> bool {
+        false
+    }
+
+    /// Provides a description of the exit with its name and a direction.
+    fn description(&self, direction: Direction) -> String {
+        format!("{} {direction}", with_article(&self.name))
+    }
+
+    /// Returns the blocked message of the exit, as it is considered impassable without the [`World`].
+    fn blocked_message(&self) -> Option<String> {
+        Some(self.blocked_message.clone())
+    }
+
+    /// Checks the condition of the exit against the [`World`].
+    fn check_passage(&self, world: &World) -> Result<(), String> {
+        if (self.condition)(world) {
+            Ok(())
         } else {
-            format!(
-                "a{}door {direction}",
-                if self.locked { " locked " } else { " " }
-            )
+            Err(self.blocked_message.clone())
         }
     }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        vec![Requirement::Condition("a condition".into())]
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+
+    /// Checks whether the other exit is also a `ConditionalExit`, as it never changes during play.
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+
+    fn prose(&self) -> Vec<String> {
+        vec![self.name.clone(), self.blocked_message.clone()]
+    }
+}
+
+/// Checks whether `other` is of the same type as `exit` and equal to it, for [`ExitType::same_state`].
+fn same_as<T: ExitType + PartialEq>(exit: &T, other: &dyn ExitType) -> bool {
+    other.downcast_ref::<T>() == Some(exit)
+}
+
+/// The exit of a wrapper being deserialized, until the [`ExitRegistry`](crate::map::ExitRegistry) sets the exit it wraps.
+#[cfg(feature = "serde")]
+fn unwrapped_exit() -> Box<dyn ExitType> {
+    Box::new(RegularExit)
+}
+
+/// When a [`ScheduledExit`] is open, in turns of the [`World`] or on its [`Clock`](crate::clock::Clock).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Schedule {
+    /// Open from turn `from` until just before turn `until`.
+    Turns {
+        /// The first turn the exit is open.
+        from: u32,
+        /// The first turn the exit is closed again.
+        until: u32,
+    },
+    /// Open every day from turn `from` of the day until just before turn `until` of the day.
+    ///
+    /// If `from` is after `until`, the exit is open overnight.
+    Daily {
+        /// The number of turns in a day.
+        day_length: u32,
+        /// The first turn of the day the exit is open.
+        from: u32,
+        /// The first turn of the day the exit is closed again.
+        until: u32,
+    },
+    /// Open every day from minute `from` of the day until just before minute `until` on the
+    /// [`Clock`](crate::clock::Clock) of the world.
+    ///
+    /// If `from` is after `until`, the exit is open overnight.
+    Hours {
+        /// The first minute of the day the exit is open.
+        from: u32,
+        /// The first minute of the day the exit is closed again.
+        until: u32,
+    },
+    /// Open while it isn't [night](crate::World::is_night) on the [`Clock`](crate::clock::Clock) of the world.
+    Daylight,
+}
+
+impl Schedule {
+    /// Checks whether the schedule is open on the given turn.
+    ///
+    /// Schedules that follow the [`Clock`](crate::clock::Clock) can't be checked without the [`World`], and are always
+    /// open here. Use [`is_open_in`](Schedule::is_open_in) to check them.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::Schedule;
+    ///
+    /// let drawbridge = Schedule::Turns { from: 10, until: 20 };
+    /// assert!(!drawbridge.is_open(9));
+    /// assert!(drawbridge.is_open(10));
+    /// assert!(!drawbridge.is_open(20));
+    ///
+    /// let shop = Schedule::Daily { day_length: 24, from: 8, until: 18 };
+    /// assert!(shop.is_open(24 + 12));
+    /// assert!(!shop.is_open(24 + 20));
+    ///
+    /// let tavern = Schedule::Daily { day_length: 24, from: 18, until: 2 };
+    /// assert!(tavern.is_open(23));
+    /// assert!(tavern.is_open(24 + 1));
+    /// assert!(!tavern.is_open(24 + 12));
+    /// ```
+    pub fn is_open(&self, turn: u32) -> bool {
+        match *self {
+            Schedule::Turns { from, until } => (from..until).contains(&turn),
+            Schedule::Daily {
+                day_length,
+                from,
+                until,
+            } => {
+                let time = turn % day_length.max(1);
+                within(time, from, until)
+            }
+            Schedule::Hours { .. } | Schedule::Daylight => true,
+        }
+    }
+
+    /// Checks whether the schedule is open in the current state of the [`World`], on its turn and its
+    /// [`Clock`](crate::clock::Clock).
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::clock::GameTime;
+    /// use worldwright::map::exit_types::Schedule;
+    ///
+    /// let mut world = World::new();
+    /// world.clock.start = GameTime::new(0, 21, 30);
+    /// assert!(!Schedule::Daylight.is_open_in(&world));
+    /// assert!(Schedule::Hours { from: 18 * 60, until: 2 * 60 }.is_open_in(&world));
+    /// assert!(!Schedule::Hours { from: 8 * 60, until: 18 * 60 }.is_open_in(&world));
+    /// ```
+    pub fn is_open_in(&self, world: &World) -> bool {
+        match *self {
+            Schedule::Hours { from, until } => within(world.now().minute_of_day(), from, until),
+            Schedule::Daylight => !world.is_night(),
+            _ => self.is_open(world.turn),
+        }
+    }
+}
+
+/// Checks whether a time of a day is between `from` and just before `until`, overnight if `from` is after `until`.
+fn within(time: u32, from: u32, until: u32) -> bool {
+    if from <= until {
+        (from..until).contains(&time)
+    } else {
+        time >= from || time < until
+    }
+}
+
+/// An exit that can only be gone through at certain times, such as a drawbridge that is only down for a while
+/// or a shop door that closes at night.
+///
+/// The exit wraps another [`ExitType`], which decides everything else about it while the schedule is open.
+/// Its passability is driven by the [turn](crate::World::turn) and the [clock](crate::World::clock) of the world, so
+/// movement code should use
+/// [`check_passage`](ExitType::check_passage). Without access to the world, it behaves like the exit it wraps.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::exit_types::{Door, Schedule, ScheduledExit};
+/// use worldwright::map::{Direction, ExitType};
+///
+/// let shop_door = ScheduledExit::new(
+///     Box::new(Door::new_with_name(false, "shop door".into())),
+///     Schedule::Daily { day_length: 24, from: 8, until: 18 },
+///     "The shop is closed for the night.".into(),
+/// );
+/// assert_eq!(shop_door.description(Direction::East), "a shop door east");
+///
+/// let mut world = World::new();
+/// world.turn = 20;
+/// assert_eq!(shop_door.check_passage(&world), Err("The shop is closed for the night.".into()));
+/// world.turn = 24 + 9;
+/// assert_eq!(shop_door.check_passage(&world), Ok(()));
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScheduledExit {
+    /// The exit used while the schedule is open.
+    #[cfg_attr(feature = "serde", serde(skip, default = "unwrapped_exit"))]
+    pub exit: Box<dyn ExitType>,
+    /// When the exit is open.
+    pub schedule: Schedule,
+    /// The message shown when the player tries to go through while the schedule is closed.
+    pub closed_message: String,
+}
+
+impl ScheduledExit {
+    /// Creates a new scheduled exit wrapping `exit`, with the given schedule and closed message.
+    pub fn new(exit: Box<dyn ExitType>, schedule: Schedule, closed_message: String) -> Self {
+        Self {
+            exit,
+            schedule,
+            closed_message,
+        }
+    }
+}
+
+impl ExitType for ScheduledExit {
+    /// Returns whether the wrapped exit can be gone through, as the schedule can't be checked without the [`World`].
+    fn can_go_through(&self) -> bool {
+        self.exit.can_go_through()
+    }
+
+    /// Provides the description of the wrapped exit.
+    fn description(&self, direction: Direction) -> String {
+        self.exit.description(direction)
+    }
+
+    fn description_in(&self, direction: Direction, surroundings: &Surroundings) -> String {
+        self.exit.description_in(direction, surroundings)
+    }
+
+    fn undirected_description(&self) -> String {
+        self.exit.undirected_description()
+    }
+
+    /// Provides the blocked message of the wrapped exit.
+    fn blocked_message(&self) -> Option<String> {
+        self.exit.blocked_message()
+    }
+
+    /// Checks the schedule against the turn of the [`World`], then the wrapped exit from the player's room.
+    fn check_passage(&self, world: &World) -> Result<(), String> {
+        self.check_passage_from(world, world.player.current_room)
+    }
+
+    fn check_passage_from(&self, world: &World, room: RoomId) -> Result<(), String> {
+        if !self.schedule.is_open_in(world) {
+            return Err(self.closed_message.clone());
+        }
+        self.exit.check_passage_from(world, room)
+    }
+
+    fn check_npc_passage(&self, world: &World, npc: NpcId, room: RoomId) -> Result<(), String> {
+        if !self.schedule.is_open_in(world) {
+            return Err(self.closed_message.clone());
+        }
+        self.exit.check_npc_passage(world, npc, room)
+    }
+
+    fn can_throw_through(&self) -> bool {
+        self.exit.can_throw_through()
+    }
+
+    fn allows_bulky_objects(&self) -> bool {
+        self.exit.allows_bulky_objects()
+    }
+
+    fn transmits_sound(&self) -> bool {
+        self.exit.transmits_sound()
+    }
+
+    fn has_keyhole(&self) -> bool {
+        self.exit.has_keyhole()
+    }
+
+    fn is_locked(&self) -> bool {
+        self.exit.is_locked()
+    }
+
+    fn unlock_with(&mut self, key: ItemId) -> Result<(), UnlockError> {
+        self.exit.unlock_with(key)
+    }
+
+    fn template_variables(&self) -> Vec<(String, String)> {
+        self.exit.template_variables()
+    }
+
+    fn passage_message(&self) -> Option<String> {
+        self.exit.passage_message()
+    }
+
+    fn on_attempt(&self, world: &World) {
+        self.exit.on_attempt(world);
+    }
+
+    fn on_traverse(&mut self) {
+        self.exit.on_traverse();
+    }
+
+    fn on_turn(&mut self) -> Option<String> {
+        self.exit.on_turn()
+    }
+
+    fn is_hidden(&self) -> bool {
+        self.exit.is_hidden()
+    }
+
+    fn reveal(&mut self) -> bool {
+        self.exit.reveal()
+    }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        let mut requirements = vec![Requirement::Condition("the right time".into())];
+        requirements.extend(self.exit.requirements());
+        requirements
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        self.exit.nouns()
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|other| {
+            self.schedule == other.schedule
+                && self.closed_message == other.closed_message
+                && self.exit.same_state(other.exit.as_ref())
+        })
+    }
+
+    fn prose(&self) -> Vec<String> {
+        let mut prose = vec![self.closed_message.clone()];
+        prose.extend(self.exit.prose());
+        prose
+    }
+}
+
+/// An exit that connects two rooms without a meaningful compass direction, such as a magic portal.
+///
+/// Teleporters should be connected with [`Map::connect_rooms_without_direction`](crate::map::Map::connect_rooms_without_direction),
+/// so that they are described as "a shimmering portal" rather than by direction.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::exit_types::Teleporter;
+///
+/// let mut world = World::new();
+/// let lab = world.map.new_room("A wizard's laboratory.".into());
+/// let tower = world.map.new_room("The top of a lonely tower.".into());
+/// world.map.connect_rooms_without_direction(lab, tower, Box::new(Teleporter::new("shimmering portal".into())));
+///
+/// assert_eq!(world.describe_exits(lab), vec!["a shimmering portal"]);
+/// assert_eq!(world.describe_exits(tower), vec!["a shimmering portal"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Teleporter {
+    /// The name of the teleporter, such as "shimmering portal" or "magic circle".
+    pub name: String,
+}
+
+impl Teleporter {
+    /// Creates a new teleporter with the given name.
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl ExitType for Teleporter {
+    /// Lets the player go through the teleporter.
+    fn can_go_through(&self) -> bool {
+        true
+    }
+
+    /// Describes the teleporter by name, as the direction it was placed in has no meaning.
+    fn description(&self, _direction: Direction) -> String {
+        self.undirected_description()
+    }
+
+    /// Describes the teleporter by name, such as "a shimmering portal".
+    fn undirected_description(&self) -> String {
+        with_article(&self.name)
+    }
+
+    /// Objects can't be thrown through a teleporter.
+    fn can_throw_through(&self) -> bool {
+        false
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        same_as(self, other)
+    }
+
+    fn prose(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+}
+
+/// A requirement the player must meet to climb a [`Staircase`] or a [`Ladder`], such as having both hands free.
+#[derive(Clone)]
+pub struct ClimbRequirement {
+    /// The condition that must hold for the player to climb.
+    pub condition: Rc<dyn Fn(&World) -> bool>,
+    /// The message shown when the player can't climb.
+    pub message: String,
+}
+
+impl ClimbRequirement {
+    /// Creates a new climb requirement with the given condition and message.
+    pub fn new(condition: impl Fn(&World) -> bool + 'static, message: String) -> Self {
+        Self {
+            condition: Rc::new(condition),
+            message,
+        }
+    }
+
+    fn check(&self, world: &World) -> Result<(), String> {
+        if (self.condition)(world) {
+            Ok(())
+        } else {
+            Err(self.message.clone())
+        }
+    }
+}
+
+impl core::fmt::Debug for ClimbRequirement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ClimbRequirement")
+            .field("message", &self.message)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A staircase, usually leading [up](Direction::Up) or [down](Direction::Down).
+///
+/// Rooms above and below can be created with [`Map::new_room_above`](crate::map::Map::new_room_above)
+/// and [`Map::new_room_below`](crate::map::Map::new_room_below).
+///
+/// # Examples
+/// ```
+/// use worldwright::map::exit_types::Staircase;
+/// use worldwright::map::{Direction, ExitType};
+///
+/// let stairs = Staircase::new();
+/// assert_eq!(stairs.description(Direction::Up), "a staircase leading up");
+/// assert_eq!(stairs.description(Direction::Down), "a staircase leading down");
+///
+/// let spiral = Staircase::new_with_name("spiral staircase".into());
+/// assert_eq!(spiral.description(Direction::Up), "a spiral staircase leading up");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Staircase {
+    /// The name of the staircase, "staircase" by default.
+    pub name: String,
+    /// What the player needs to climb the staircase, if anything.
+    pub requirement: Option<ClimbRequirement>,
+}
+
+impl Staircase {
+    /// Creates a new staircase anyone can climb.
+    pub fn new() -> Self {
+        Self::new_with_name("staircase".into())
+    }
+
+    /// Creates a new staircase with the given name, such as "spiral staircase".
+    pub fn new_with_name(name: String) -> Self {
+        Self {
+            name,
+            requirement: None,
+        }
+    }
+}
+
+impl Default for Staircase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExitType for Staircase {
+    /// Returns whether the staircase has no climb requirement, as it can't be checked without the [`World`].
+    fn can_go_through(&self) -> bool {
+        self.requirement.is_none()
+    }
+
+    /// Describes the staircase and where it leads, such as "a staircase leading up".
+    fn description(&self, direction: Direction) -> String {
+        format!("{} leading {direction}", with_article(&self.name))
+    }
+
+    /// Returns the message of the climb requirement, if any.
+    fn blocked_message(&self) -> Option<String> {
+        self.requirement
+            .as_ref()
+            .map(|requirement| requirement.message.clone())
+    }
+
+    /// Checks the climb requirement against the [`World`].
+    fn check_passage(&self, world: &World) -> Result<(), String> {
+        match &self.requirement {
+            Some(requirement) => requirement.check(world),
+            None => Ok(()),
+        }
+    }
+
+    /// Lets NPCs climb, as the climb requirement is about the player.
+    fn check_npc_passage(&self, _world: &World, _npc: NpcId, _room: RoomId) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        self.requirement
+            .iter()
+            .map(|_| Requirement::Condition("a climb requirement".into()))
+            .collect()
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        vec![self.name.clone(), "stairs".into()]
+    }
+
+    /// Checks whether the other exit is also a `Staircase`, as it never changes during play.
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+
+    fn prose(&self) -> Vec<String> {
+        let requirement = self
+            .requirement
+            .iter()
+            .map(|requirement| requirement.message.clone());
+        core::iter::once(self.name.clone())
+            .chain(requirement)
+            .collect()
+    }
+}
+
+/// A ladder, usually leading [up](Direction::Up) or [down](Direction::Down).
+///
+/// Unlike a [`Staircase`], bulky objects can't be carried up or down a ladder.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::exit_types::{ClimbRequirement, Ladder};
+/// use worldwright::map::{Direction, ExitType};
+///
+/// let mut ladder = Ladder::new_with_name("rope ladder".into());
+/// ladder.requirement = Some(ClimbRequirement::new(
+///     |world| world.player.inventory.is_empty(),
+///     "You need both hands free to climb the rope ladder.".into(),
+/// ));
+/// assert_eq!(ladder.description(Direction::Down), "a rope ladder leading down");
+/// assert!(!ladder.allows_bulky_objects());
+/// assert_eq!(ladder.check_passage(&World::new()), Ok(()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ladder {
+    /// The name of the ladder, "ladder" by default.
+    pub name: String,
+    /// What the player needs to climb the ladder, if anything.
+    pub requirement: Option<ClimbRequirement>,
+}
+
+impl Ladder {
+    /// Creates a new ladder anyone can climb.
+    pub fn new() -> Self {
+        Self::new_with_name("ladder".into())
+    }
+
+    /// Creates a new ladder with the given name, such as "rope ladder".
+    pub fn new_with_name(name: String) -> Self {
+        Self {
+            name,
+            requirement: None,
+        }
+    }
+}
+
+impl Default for Ladder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExitType for Ladder {
+    /// Returns whether the ladder has no climb requirement, as it can't be checked without the [`World`].
+    fn can_go_through(&self) -> bool {
+        self.requirement.is_none()
+    }
+
+    /// Describes the ladder and where it leads, such as "a ladder leading up".
+    fn description(&self, direction: Direction) -> String {
+        format!("{} leading {direction}", with_article(&self.name))
+    }
+
+    /// Returns the message of the climb requirement, if any.
+    fn blocked_message(&self) -> Option<String> {
+        self.requirement
+            .as_ref()
+            .map(|requirement| requirement.message.clone())
+    }
+
+    /// Checks the climb requirement against the [`World`].
+    fn check_passage(&self, world: &World) -> Result<(), String> {
+        match &self.requirement {
+            Some(requirement) => requirement.check(world),
+            None => Ok(()),
+        }
+    }
+
+    /// Lets NPCs climb, as the climb requirement is about the player.
+    fn check_npc_passage(&self, _world: &World, _npc: NpcId, _room: RoomId) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Bulky objects can't be carried up or down a ladder.
+    fn allows_bulky_objects(&self) -> bool {
+        false
+    }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        self.requirement
+            .iter()
+            .map(|_| Requirement::Condition("a climb requirement".into()))
+            .collect()
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+
+    /// Checks whether the other exit is also a `Ladder`, as it never changes during play.
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+
+    fn prose(&self) -> Vec<String> {
+        let requirement = self
+            .requirement
+            .iter()
+            .map(|requirement| requirement.message.clone());
+        core::iter::once(self.name.clone())
+            .chain(requirement)
+            .collect()
+    }
+}
+
+/// The test a [`SkillCheckExit`] makes against a [stat](crate::Stats) of the player.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SkillCheck {
+    /// The stat must be at least `value`, such as "STR 12 to force the gate".
+    Threshold {
+        /// The name of the stat, such as "strength".
+        stat: String,
+        /// The lowest value that passes.
+        value: i32,
+    },
+    /// A roll of a die with `sides` sides plus the stat must be at least `difficulty`.
+    Roll {
+        /// The name of the stat added to the roll.
+        stat: String,
+        /// The number of sides of the die.
+        sides: u32,
+        /// The lowest total that passes.
+        difficulty: i32,
+    },
+}
+
+impl SkillCheck {
+    /// Makes the check against the player's stats.
+    ///
+    /// Rolls look at the next roll of the [combat stream](crate::RngStreams::combat) of the [`World`] without using it up,
+    /// so the check can be made any number of times with the same result. [`roll`](SkillCheck::roll) uses it up.
+    pub fn passes(&self, world: &World) -> bool {
+        match self {
+            SkillCheck::Threshold { stat, value } => world.player.stats.get(stat) >= *value,
+            SkillCheck::Roll {
+                stat,
+                sides,
+                difficulty,
+            } => {
+                let upcoming = Rng::new(world.rng_streams.combat().state());
+                let roll = upcoming.below((*sides).max(1)) as i32 + 1;
+                roll + world.player.stats.get(stat) >= *difficulty
+            }
+        }
+    }
+
+    /// Uses up the roll of a [`Roll`](SkillCheck::Roll) check, so that the next check rolls again.
+    pub fn roll(&self, world: &World) {
+        if let SkillCheck::Roll { sides, .. } = self {
+            world.rng_streams.combat().below((*sides).max(1));
+        }
+    }
+}
+
+/// An exit that the player can only go through by passing a [`SkillCheck`], such as a gate that takes strength to force.
+///
+/// Checking passage doesn't change the exit. If [`remember_success`](SkillCheckExit::remember_success) is set, the
+/// exit stays passable once the player has passed the check and gone through.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::actor::Npc;
+/// use worldwright::map::exit_types::{SkillCheck, SkillCheckExit};
+/// use worldwright::map::{Direction, ExitType};
+///
+/// let mut gate = SkillCheckExit::new(
+///     "rusted gate".into(),
+///     SkillCheck::Threshold { stat: "strength".into(), value: 12 },
+///     "You force the rusted gate open.".into(),
+///     "The rusted gate won't budge.".into(),
+/// );
+/// gate.remember_success = true;
+/// assert_eq!(gate.description(Direction::North), "a rusted gate north");
+///
+/// let mut world = World::new();
+/// world.player.stats.set("strength", 10);
+/// assert_eq!(gate.check_passage(&world), Err("The rusted gate won't budge.".into()));
+///
+/// world.player.stats.set("strength", 12);
+/// assert_eq!(gate.check_passage(&world), Ok(()));
+/// assert_eq!(gate.passage_message().as_deref(), Some("You force the rusted gate open."));
+///
+/// world.player.stats.set("strength", 3);
+/// assert_eq!(gate.check_passage(&world), Err("The rusted gate won't budge.".into()));
+///
+/// // NPCs aren't tested against the player's stats, so they only get through once the gate is open for good.
+/// let yard = world.map.new_room("A muddy yard.".into());
+/// let farmer = world.npcs.add(Npc::new("farmer".into(), yard));
+/// world.player.stats.set("strength", 12);
+/// assert!(gate.check_npc_passage(&world, farmer, yard).is_err());
+///
+/// gate.on_traverse();
+/// world.player.stats.set("strength", 3);
+/// assert_eq!(gate.check_passage(&world), Ok(()));
+/// assert_eq!(gate.check_npc_passage(&world, farmer, yard), Ok(()));
+/// assert_eq!(gate.passage_message(), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SkillCheckExit {
+    /// The name of the exit, such as "rusted gate".
+    pub name: String,
+    /// The check the player must pass.
+    pub check: SkillCheck,
+    /// The message shown when the player passes the check and goes through.
+    pub success_message: String,
+    /// The message shown when the player fails the check.
+    pub failure_message: String,
+    /// Whether the exit stays passable once the check has passed.
+    pub remember_success: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    succeeded: bool,
+}
+
+impl SkillCheckExit {
+    /// Creates a new exit gated by `check`, with the given success and failure messages.
+    pub fn new(
+        name: String,
+        check: SkillCheck,
+        success_message: String,
+        failure_message: String,
+    ) -> Self {
+        Self {
+            name,
+            check,
+            success_message,
+            failure_message,
+            remember_success: false,
+            succeeded: false,
+        }
+    }
+
+    /// Checks whether the check has passed before and the success is remembered.
+    pub fn is_passed(&self) -> bool {
+        self.remember_success && self.succeeded
+    }
+}
+
+impl ExitType for SkillCheckExit {
+    /// Returns whether the success of the check is remembered, as the check can't be made without the [`World`].
+    fn can_go_through(&self) -> bool {
+        self.is_passed()
+    }
+
+    /// Provides a description of the exit with its name and a direction.
+    fn description(&self, direction: Direction) -> String {
+        format!("{} {direction}", with_article(&self.name))
+    }
+
+    /// Returns the failure message, unless the success is remembered.
+    fn blocked_message(&self) -> Option<String> {
+        (!self.is_passed()).then(|| self.failure_message.clone())
+    }
+
+    /// Makes the check against the [`World`], unless the success is remembered.
+    fn check_passage(&self, world: &World) -> Result<(), String> {
+        if self.is_passed() || self.check.passes(world) {
+            Ok(())
+        } else {
+            Err(self.failure_message.clone())
+        }
+    }
+
+    /// Returns the success message, unless the success is remembered.
+    fn passage_message(&self) -> Option<String> {
+        (!self.is_passed()).then(|| self.success_message.clone())
+    }
+
+    /// Only lets NPCs through once the success is remembered, as the check tests the player.
+    fn check_npc_passage(&self, _world: &World, _npc: NpcId, _room: RoomId) -> Result<(), String> {
+        if self.is_passed() {
+            Ok(())
+        } else {
+            Err(self.failure_message.clone())
+        }
+    }
+
+    /// Uses up the roll of the check, unless the success is remembered.
+    fn on_attempt(&self, world: &World) {
+        if !self.is_passed() {
+            self.check.roll(world);
+        }
+    }
+
+    /// Remembers that the check has passed.
+    fn on_traverse(&mut self) {
+        self.succeeded = true;
+    }
+
+    /// Needs the stat of the check, unless the success is remembered.
+    fn requirements(&self) -> Vec<Requirement> {
+        if self.is_passed() {
+            return Vec::new();
+        }
+        let condition = match &self.check {
+            SkillCheck::Threshold { stat, value } => format!("{stat} {value}"),
+            SkillCheck::Roll {
+                stat,
+                sides,
+                difficulty,
+            } => format!("{stat} + d{sides} of {difficulty}"),
+        };
+        vec![Requirement::Condition(condition)]
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        vec![self.name.clone()]
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        same_as(self, other)
+    }
+
+    fn prose(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.success_message.clone(),
+            self.failure_message.clone(),
+        ]
+    }
+}
+
+/// What happens to a [`CollapsingExit`] once it has been used up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Collapse {
+    /// The exit stays, but can't be gone through anymore, such as the frayed ropes of a broken bridge.
+    Impassable {
+        /// The description of the collapsed exit, rendered as a template where `{direction}` can be used.
+        description: String,
+        /// The message shown when the player tries to go through.
+        message: String,
+    },
+    /// The exit disappears entirely, as if it were a wall, such as a tunnel that caved in.
+    Vanish,
+}
+
+/// An exit that collapses after being gone through a number of times, such as a rope bridge, a cave-in or melting ice.
+///
+/// It wraps another [`ExitType`], which decides everything else about it until it collapses.
+/// Traversals are counted by [`Map::traverse_exit`](crate::map::Map::traverse_exit) in the public
+/// [`traversals`](CollapsingExit::traversals) field, and are saved with the rest of the exit by the
+/// [`ExitRegistry`](crate::map::ExitRegistry), as long as the exit it wraps can be saved too.
+///
+/// # Examples
+/// ```
+/// use worldwright::map::exit_types::{Collapse, CollapsingExit, RegularExit};
+/// use worldwright::map::{Direction, Map};
+///
+/// let mut map = Map::new();
+/// let cliff = map.new_room("A windy cliff.".into());
+/// let bridge = CollapsingExit::new(
+///     Box::new(RegularExit),
+///     1,
+///     Collapse::Impassable {
+///         description: "the frayed ropes of a bridge {direction}".into(),
+///         message: "The bridge is gone.".into(),
+///     },
+/// );
+/// let ledge = map.new_room_in_direction(cliff, Direction::East, Box::new(bridge), "A narrow ledge.".into());
+/// let exit_id = map.exit_id_in_direction(cliff, Direction::East).unwrap();
+///
+/// assert_eq!(map.push_target(cliff, Direction::East), Ok(ledge));
+/// map.traverse_exit(exit_id);
+/// assert!(map.exit_as::<CollapsingExit>(exit_id).unwrap().is_collapsed());
+/// assert!(map.push_target(cliff, Direction::East).is_err());
+/// ```
+///
+/// A collapsed exit stays collapsed in a saved game:
+/// ```
+/// # #[cfg(all(feature = "serde", feature = "std"))] {
+/// use worldwright::World;
+/// use worldwright::map::exit_types::{Collapse, CollapsingExit, RegularExit};
+/// use worldwright::map::Direction;
+///
+/// fn build() -> World {
+///     let mut world = World::new();
+///     let cliff = world.map.new_room("A windy cliff.".into());
+///     let bridge = CollapsingExit::new(Box::new(RegularExit), 1, Collapse::Vanish);
+///     world.map.new_room_in_direction(cliff, Direction::East, Box::new(bridge), "A narrow ledge.".into());
+///     world.player.current_room = cliff;
+///     world
+/// }
+///
+/// let mut world = build();
+/// world.move_player(Direction::East).unwrap();
+/// let mut save = Vec::new();
+/// world.save(&mut save).unwrap();
+///
+/// let mut restored = build();
+/// restored.load(save.as_slice()).unwrap();
+/// assert!(restored.move_player(Direction::West).is_err());
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CollapsingExit {
+    /// The exit used until it collapses.
+    #[cfg_attr(feature = "serde", serde(skip, default = "unwrapped_exit"))]
+    pub exit: Box<dyn ExitType>,
+    /// How many times the exit can be gone through before it collapses.
+    pub limit: u32,
+    /// How many times the exit has been gone through.
+    pub traversals: u32,
+    /// What happens once the exit collapses.
+    pub collapse: Collapse,
+}
+
+impl CollapsingExit {
+    /// Creates a new exit wrapping `exit` that collapses after `limit` traversals.
+    pub fn new(exit: Box<dyn ExitType>, limit: u32, collapse: Collapse) -> Self {
+        Self {
+            exit,
+            limit,
+            traversals: 0,
+            collapse,
+        }
+    }
+
+    /// Checks whether the exit has been used up.
+    pub fn is_collapsed(&self) -> bool {
+        self.traversals >= self.limit
+    }
+}
+
+impl ExitType for CollapsingExit {
+    /// Returns whether the exit hasn't collapsed and the wrapped exit can be gone through.
+    fn can_go_through(&self) -> bool {
+        !self.is_collapsed() && self.exit.can_go_through()
+    }
+
+    /// Provides the description of the wrapped exit, or of the collapsed exit.
+    fn description(&self, direction: Direction) -> String {
+        match &self.collapse {
+            Collapse::Impassable { description, .. } if self.is_collapsed() => description.clone(),
+            _ => self.exit.description(direction),
+        }
+    }
+
+    fn description_in(&self, direction: Direction, surroundings: &Surroundings) -> String {
+        match &self.collapse {
+            Collapse::Impassable { description, .. } if self.is_collapsed() => description.clone(),
+            _ => self.exit.description_in(direction, surroundings),
+        }
+    }
+
+    fn undirected_description(&self) -> String {
+        match &self.collapse {
+            Collapse::Impassable { description, .. } if self.is_collapsed() => description.clone(),
+            _ => self.exit.undirected_description(),
+        }
+    }
+
+    /// Provides the message of the collapsed exit, or the blocked message of the wrapped exit.
+    fn blocked_message(&self) -> Option<String> {
+        match &self.collapse {
+            Collapse::Impassable { message, .. } if self.is_collapsed() => Some(message.clone()),
+            Collapse::Vanish if self.is_collapsed() => Some("You can't go that way.".into()),
+            _ => self.exit.blocked_message(),
+        }
+    }
+
+    /// Checks whether the exit has collapsed, then the wrapped exit from the player's room.
+    fn check_passage(&self, world: &World) -> Result<(), String> {
+        self.check_passage_from(world, world.player.current_room)
+    }
+
+    fn check_passage_from(&self, world: &World, room: RoomId) -> Result<(), String> {
+        if self.is_collapsed() {
+            return Err(self.blocked_message().unwrap_or_default());
+        }
+        self.exit.check_passage_from(world, room)
+    }
+
+    fn check_npc_passage(&self, world: &World, npc: NpcId, room: RoomId) -> Result<(), String> {
+        if self.is_collapsed() {
+            return Err(self.blocked_message().unwrap_or_default());
+        }
+        self.exit.check_npc_passage(world, npc, room)
+    }
+
+    fn can_throw_through(&self) -> bool {
+        !self.is_collapsed() && self.exit.can_throw_through()
+    }
+
+    fn allows_bulky_objects(&self) -> bool {
+        !self.is_collapsed() && self.exit.allows_bulky_objects()
+    }
+
+    fn transmits_sound(&self) -> bool {
+        !self.is_collapsed() && self.exit.transmits_sound()
+    }
+
+    fn has_keyhole(&self) -> bool {
+        self.exit.has_keyhole()
+    }
+
+    fn is_locked(&self) -> bool {
+        self.exit.is_locked()
+    }
+
+    fn unlock_with(&mut self, key: ItemId) -> Result<(), UnlockError> {
+        self.exit.unlock_with(key)
+    }
+
+    fn template_variables(&self) -> Vec<(String, String)> {
+        self.exit.template_variables()
+    }
+
+    fn passage_message(&self) -> Option<String> {
+        self.exit.passage_message()
+    }
+
+    fn on_attempt(&self, world: &World) {
+        self.exit.on_attempt(world);
+    }
+
+    /// Counts the traversal, and passes it on to the wrapped exit.
+    fn on_traverse(&mut self) {
+        self.traversals = self.traversals.saturating_add(1);
+        self.exit.on_traverse();
+    }
+
+    fn on_turn(&mut self) -> Option<String> {
+        self.exit.on_turn()
+    }
+
+    /// Hides the exit once it has collapsed, if it [vanishes](Collapse::Vanish).
+    fn is_hidden(&self) -> bool {
+        (self.is_collapsed() && self.collapse == Collapse::Vanish) || self.exit.is_hidden()
+    }
+
+    fn reveal(&mut self) -> bool {
+        self.exit.reveal()
+    }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        if self.is_collapsed() {
+            return vec![Requirement::Condition("a way past the collapse".into())];
+        }
+        self.exit.requirements()
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        self.exit.nouns()
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|other| {
+            self.limit == other.limit
+                && self.traversals == other.traversals
+                && self.collapse == other.collapse
+                && self.exit.same_state(other.exit.as_ref())
+        })
+    }
+
+    fn prose(&self) -> Vec<String> {
+        let mut prose = self.exit.prose();
+        if let Collapse::Impassable {
+            description,
+            message,
+        } = &self.collapse
+        {
+            prose.extend([description.clone(), message.clone()]);
+        }
+        prose
+    }
+}
+
+/// A [`Door`] that closes by itself a number of turns after being opened, such as an automatic door or a drawbridge that rises.
+///
+/// The countdown starts when the door is opened, whether [through the wrapper](AutoClosingDoor::open) or on the
+/// [`door`](AutoClosingDoor::door) itself, and runs in [`Map::tick_exits`](crate::map::Map::tick_exits). Rules can
+/// [hold the door open](AutoClosingDoor::hold_open) to cancel it. It is kept by the door rather than the
+/// [`Scheduler`](crate::scheduler::Scheduler), so it works in a [`Map`](crate::map::Map) alone and is saved with the
+/// exit. The close is only announced if the door was open and shuts: a door closed in the meantime or
+/// [broken](Door::broken) drops its countdown without a message.
+///
+/// # Examples
+/// ```
+/// use worldwright::map::exit_types::{AutoClosingDoor, Door};
+/// use worldwright::map::{Direction, ExitType, Map};
+///
+/// let mut map = Map::new();
+/// let lobby = map.new_room("A sterile lobby.".into());
+/// let door = AutoClosingDoor::new(Door::new(false), 2, "The sliding door hisses shut.".into());
+/// let lab = map.new_room_in_direction(lobby, Direction::North, Box::new(door), "A laboratory.".into());
+/// let exit_id = map.exit_id_in_direction(lobby, Direction::North).unwrap();
+///
+/// assert!(map.tick_exits().is_empty());
+/// assert_eq!(map.tick_exits(), vec![(exit_id, "The sliding door hisses shut.".to_string())]);
+/// assert!(map.push_target(lobby, Direction::North).is_err());
+///
+/// let door = map.exit_as_mut::<AutoClosingDoor>(exit_id).unwrap();
+/// door.open();
+/// door.hold_open();
+/// assert!(map.tick_exits().is_empty());
+/// assert!(map.tick_exits().is_empty());
+/// assert_eq!(map.push_target(lobby, Direction::North), Ok(lab));
+///
+/// // Closing the door itself drops the countdown, and opening it starts the countdown again.
+/// let door = map.exit_as_mut::<AutoClosingDoor>(exit_id).unwrap();
+/// door.door.close();
+/// assert!(map.tick_exits().is_empty());
+/// map.exit_as_mut::<AutoClosingDoor>(exit_id).unwrap().door.open();
+/// assert!(map.tick_exits().is_empty());
+/// assert_eq!(map.tick_exits().len(), 1);
+///
+/// let door = map.exit_as_mut::<AutoClosingDoor>(exit_id).unwrap();
+/// door.open();
+/// map.exit_as_mut::<AutoClosingDoor>(exit_id).unwrap().door.close();
+/// assert!(map.tick_exits().is_empty());
+/// assert!(map.tick_exits().is_empty());
+///
+/// let door = map.exit_as_mut::<AutoClosingDoor>(exit_id).unwrap();
+/// door.open();
+/// door.door.broken = true;
+/// assert!(map.tick_exits().is_empty());
+/// assert!(map.tick_exits().is_empty());
+/// assert_eq!(map.push_target(lobby, Direction::North), Ok(lab));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AutoClosingDoor {
+    /// The door that closes by itself.
+    pub door: Door,
+    /// How many turns the door stays open.
+    pub delay: u32,
+    /// How many turns are left before the door closes, if it is counting down.
+    pub remaining: Option<u32>,
+    /// Whether the door is [held open](AutoClosingDoor::hold_open) until it is closed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub held: bool,
+    /// The message shown when the door closes.
+    pub close_message: String,
+}
+
+impl AutoClosingDoor {
+    /// Creates a new door that closes `delay` turns after being opened, showing `close_message`.
+    ///
+    /// If `door` starts open, the countdown starts right away.
+    pub fn new(door: Door, delay: u32, close_message: String) -> Self {
+        let remaining = door.open.then_some(delay);
+        Self {
+            door,
+            delay,
+            remaining,
+            held: false,
+            close_message,
+        }
+    }
+
+    /// Opens the door and starts the countdown, returning whether it is now open. A locked door can't be opened.
+    pub fn open(&mut self) -> bool {
+        let open = self.door.open();
+        if open {
+            self.remaining = Some(self.delay);
+            self.held = false;
+        }
+        open
+    }
+
+    /// Closes the door right away, cancelling the countdown.
+    pub fn close(&mut self) {
+        self.door.close();
+        self.remaining = None;
+        self.held = false;
+    }
+
+    /// Cancels the countdown, so the door stays open until it is closed.
+    pub fn hold_open(&mut self) {
+        self.remaining = None;
+        self.held = true;
+    }
+}
+
+impl ExitType for AutoClosingDoor {
+    /// Returns true if the door is open.
+    fn can_go_through(&self) -> bool {
+        self.door.can_go_through()
+    }
+
+    /// Provides the description of the door.
+    fn description(&self, direction: Direction) -> String {
+        self.door.description(direction)
+    }
+
+    /// Provides the blocked message of the door.
+    fn blocked_message(&self) -> Option<String> {
+        self.door.blocked_message()
+    }
+
+    /// Provides the template variables of the door.
+    fn template_variables(&self) -> Vec<(String, String)> {
+        self.door.template_variables()
+    }
+
+    fn has_keyhole(&self) -> bool {
+        self.door.has_keyhole()
+    }
+
+    fn is_locked(&self) -> bool {
+        self.door.is_locked()
+    }
+
+    /// Unlocks the door if `key` is its key.
+    fn unlock_with(&mut self, key: ItemId) -> Result<(), UnlockError> {
+        self.door.unlock_with(key)
+    }
+
+    /// Counts down while the door is open, closing it when the time is up.
+    fn on_turn(&mut self) -> Option<String> {
+        if self.door.broken || !self.door.open {
+            self.remaining = None;
+            self.held = false;
+            return None;
+        }
+        if self.held {
+            return None;
+        }
+        let remaining = self.remaining.unwrap_or(self.delay).saturating_sub(1);
+        if remaining > 0 {
+            self.remaining = Some(remaining);
+            return None;
+        }
+        self.close();
+        (!self.door.open).then(|| self.close_message.clone())
+    }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        self.door.requirements()
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        self.door.nouns()
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        same_as(self, other)
+    }
+
+    fn prose(&self) -> Vec<String> {
+        let mut prose = self.door.prose();
+        prose.push(self.close_message.clone());
+        prose
+    }
+}
+
+/// A reason a [`RestrictedExit`] is unavailable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Restriction {
+    /// The exit is only available while the [`Schedule`] is open, such as a ferry that runs in daylight.
+    Schedule(Schedule),
+    /// The exit is unavailable while the [weather](crate::World::weather_at) in `room` is `weather`, such as a pass
+    /// closed during a [storm](Weather::Storm) on the summit. The weather of the same room is checked whichever way the
+    /// exit is gone through, so a traveller caught by the weather can't get back out either.
+    Weather {
+        /// The weather that closes the exit.
+        weather: Weather,
+        /// The room whose weather is checked, usually one of the ends of the exit.
+        #[cfg_attr(feature = "serde", serde(with = "room_index"))]
+        room: RoomId,
+    },
+}
+
+impl Restriction {
+    /// Checks whether the restriction lets anyone through in the current state of the [`World`].
+    pub fn allows(&self, world: &World) -> bool {
+        match self {
+            Restriction::Schedule(schedule) => schedule.is_open_in(world),
+            Restriction::Weather { weather, room } => world.weather_at(*room) != Some(*weather),
+        }
+    }
+}
+
+/// Serializes a [`RoomId`] as its index, the way saves refer to rooms.
+#[cfg(feature = "serde")]
+mod room_index {
+    use crate::map::RoomId;
+    use petgraph::prelude::NodeIndex;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(room: &RoomId, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(room.index() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RoomId, D::Error> {
+        usize::deserialize(deserializer).map(NodeIndex::new)
+    }
+}
+
+/// An exit whose availability depends on the time and the weather, with a distinct blocked message for each cause.
+///
+/// It wraps another [`ExitType`], which decides everything else about it while every restriction allows it.
+/// Restrictions are checked in order, and the message of the first one that blocks the way is shown.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::{ExitType, Region};
+/// use worldwright::map::exit_types::{NamedExit, Restriction, RestrictedExit, Schedule};
+/// use worldwright::weather::{Weather, WeatherZone};
+///
+/// let mut pass = RestrictedExit::new(Box::new(NamedExit::new("mountain pass".into())));
+/// pass.restrict(
+///     Restriction::Schedule(Schedule::Daily { day_length: 24, from: 6, until: 20 }),
+///     "It is too dark to cross the pass.".into(),
+/// );
+///
+/// let mut world = World::new();
+/// let valley = world.map.new_room("A green valley.".into());
+/// let summit = world.map.new_room("A windswept summit.".into());
+/// pass.restrict(
+///     Restriction::Weather { weather: Weather::Storm, room: summit },
+///     "The pass is closed during the storm.".into(),
+/// );
+/// world.turn = 12;
+/// assert_eq!(pass.check_passage_from(&world, summit), Ok(()));
+///
+/// // The storm on the summit closes the pass both ways.
+/// world.weather.add(WeatherZone::new(Region::Rooms(vec![summit]), Weather::Storm));
+/// assert_eq!(pass.check_passage_from(&world, summit), Err("The pass is closed during the storm.".into()));
+/// assert_eq!(pass.check_passage_from(&world, valley), Err("The pass is closed during the storm.".into()));
+///
+/// world.turn = 22;
+/// assert_eq!(pass.check_passage_from(&world, valley), Err("It is too dark to cross the pass.".into()));
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RestrictedExit {
+    /// The exit used while every restriction allows it.
+    #[cfg_attr(feature = "serde", serde(skip, default = "unwrapped_exit"))]
+    pub exit: Box<dyn ExitType>,
+    /// The restrictions of the exit, each with the message shown when it blocks the way.
+    pub restrictions: Vec<(Restriction, String)>,
+}
+
+impl RestrictedExit {
+    /// Creates a new exit wrapping `exit`, without any restrictions yet.
+    pub fn new(exit: Box<dyn ExitType>) -> Self {
+        Self {
+            exit,
+            restrictions: Vec::new(),
+        }
+    }
+
+    /// Adds a restriction, with the message shown when it blocks the way.
+    pub fn restrict(&mut self, restriction: Restriction, message: String) {
+        self.restrictions.push((restriction, message));
+    }
+
+    fn check_restrictions(&self, world: &World) -> Result<(), String> {
+        match self
+            .restrictions
+            .iter()
+            .find(|(restriction, _)| !restriction.allows(world))
+        {
+            Some((_, message)) => Err(message.clone()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ExitType for RestrictedExit {
+    /// Returns whether the wrapped exit can be gone through, as the restrictions can't be checked without the [`World`].
+    fn can_go_through(&self) -> bool {
+        self.exit.can_go_through()
+    }
+
+    /// Provides the description of the wrapped exit.
+    fn description(&self, direction: Direction) -> String {
+        self.exit.description(direction)
+    }
+
+    fn description_in(&self, direction: Direction, surroundings: &Surroundings) -> String {
+        self.exit.description_in(direction, surroundings)
+    }
+
+    fn undirected_description(&self) -> String {
+        self.exit.undirected_description()
+    }
+
+    /// Provides the blocked message of the wrapped exit.
+    fn blocked_message(&self) -> Option<String> {
+        self.exit.blocked_message()
+    }
+
+    /// Checks every restriction against the [`World`] from the player's room, then the wrapped exit.
+    fn check_passage(&self, world: &World) -> Result<(), String> {
+        self.check_passage_from(world, world.player.current_room)
+    }
+
+    /// Checks every restriction against the [`World`] from the given room, then the wrapped exit.
+    fn check_passage_from(&self, world: &World, room: RoomId) -> Result<(), String> {
+        self.check_restrictions(world)?;
+        self.exit.check_passage_from(world, room)
+    }
+
+    fn check_npc_passage(&self, world: &World, npc: NpcId, room: RoomId) -> Result<(), String> {
+        self.check_restrictions(world)?;
+        self.exit.check_npc_passage(world, npc, room)
+    }
+
+    fn can_throw_through(&self) -> bool {
+        self.exit.can_throw_through()
+    }
+
+    fn allows_bulky_objects(&self) -> bool {
+        self.exit.allows_bulky_objects()
+    }
+
+    fn transmits_sound(&self) -> bool {
+        self.exit.transmits_sound()
+    }
+
+    fn has_keyhole(&self) -> bool {
+        self.exit.has_keyhole()
+    }
+
+    fn is_locked(&self) -> bool {
+        self.exit.is_locked()
+    }
+
+    fn unlock_with(&mut self, key: ItemId) -> Result<(), UnlockError> {
+        self.exit.unlock_with(key)
+    }
+
+    fn template_variables(&self) -> Vec<(String, String)> {
+        self.exit.template_variables()
+    }
+
+    fn passage_message(&self) -> Option<String> {
+        self.exit.passage_message()
+    }
+
+    fn on_attempt(&self, world: &World) {
+        self.exit.on_attempt(world);
+    }
+
+    fn on_traverse(&mut self) {
+        self.exit.on_traverse();
+    }
+
+    fn on_turn(&mut self) -> Option<String> {
+        self.exit.on_turn()
+    }
+
+    fn is_hidden(&self) -> bool {
+        self.exit.is_hidden()
+    }
+
+    fn reveal(&mut self) -> bool {
+        self.exit.reveal()
+    }
+
+    fn requirements(&self) -> Vec<Requirement> {
+        let mut requirements: Vec<Requirement> = self
+            .restrictions
+            .iter()
+            .map(|(restriction, _)| {
+                Requirement::Condition(match restriction {
+                    Restriction::Schedule(_) => "the right time".into(),
+                    Restriction::Weather { weather, .. } => format!("weather other than {weather}"),
+                })
+            })
+            .collect();
+        requirements.extend(self.exit.requirements());
+        requirements
+    }
+
+    fn nouns(&self) -> Vec<String> {
+        self.exit.nouns()
+    }
+
+    fn same_state(&self, other: &dyn ExitType) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|other| {
+            self.restrictions == other.restrictions && self.exit.same_state(other.exit.as_ref())
+        })
+    }
+
+    fn prose(&self) -> Vec<String> {
+        let mut prose: Vec<String> = self
+            .restrictions
+            .iter()
+            .map(|(_, message)| message.clone())
+            .collect();
+        prose.extend(self.exit.prose());
+        prose
+    }
 }
@@ -0,0 +1,38 @@
+use crate::World;
+use crate::prelude::*;
+use alloc::rc::Rc;
+use petgraph::prelude::NodeIndex;
+
+/// A callback run for a [`Room`](crate::map::Room) with mutable access to the [`World`] and the `NodeIndex` of the room.
+pub type RoomHook = Rc<dyn Fn(&mut World, NodeIndex)>;
+
+/// The callbacks registered on a [`Room`](crate::map::Room).
+///
+/// The movement code runs the enter and exit hooks when the player enters or leaves the room,
+/// and the game loop runs the turn hooks every turn the player spends in it.
+#[derive(Clone, Default)]
+pub struct RoomHooks {
+    /// Hooks run when the player enters the room.
+    pub on_enter: Vec<RoomHook>,
+    /// Hooks run when the player leaves the room.
+    pub on_exit: Vec<RoomHook>,
+    /// Hooks run every turn while the player is in the room.
+    pub each_turn: Vec<RoomHook>,
+}
+
+impl RoomHooks {
+    /// Creates new, empty `RoomHooks`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl core::fmt::Debug for RoomHooks {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RoomHooks")
+            .field("on_enter", &self.on_enter.len())
+            .field("on_exit", &self.on_exit.len())
+            .field("each_turn", &self.each_turn.len())
+            .finish()
+    }
+}
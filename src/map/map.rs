@@ -1,5 +1,15 @@
-use crate::map::{Direction, Exit, ExitType, Room};
-use petgraph::prelude::{Graph, NodeIndex};
+use crate::item::ItemId;
+use crate::map::{Direction, Exit, ExitType, Room, UnlockError};
+use crate::prelude::*;
+use alloc::collections::{BTreeMap, VecDeque};
+use petgraph::prelude::{EdgeIndex, Graph, NodeIndex};
+use petgraph::visit::EdgeRef;
+
+/// Identifies a [`Room`] in a [`Map`].
+pub type RoomId = NodeIndex;
+
+/// Identifies an [`Exit`] in a [`Map`].
+pub type ExitId = EdgeIndex;
 
 /// Indicates whether an exit is leading away from or towards a node.
 #[derive(Clone, Copy, Debug)]
@@ -10,6 +20,15 @@ pub enum ExitWay {
     To,
 }
 
+/// The reason an object can't be moved through the [`Map`] in a [`Direction`], for example by throwing or pushing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PassageError {
+    /// There is no exit in that direction.
+    NoExit,
+    /// The exit in that direction doesn't let the object through.
+    Blocked,
+}
+
 /// A map of [`Room`]s connected by [`ExitType`]s and [`Direction`]s.
 ///
 /// A `Map` is a graph where nodes are [`Room`]s and edges are tuples of [`Direction`]s and [`ExitType`]s.
@@ -29,17 +48,35 @@ pub enum ExitWay {
 ///    "You step into the magnificent Grand Hall.".into(),
 /// );
 /// ```
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Map {
     /// The underlying graph structure of the map.
     pub graph: Graph<Room, Exit>,
+    revision: u64,
 }
 
 impl Map {
     /// Creates a new, empty `Map`.
     pub fn new() -> Self {
         let map = Graph::new();
-        Self { graph: map }
+        Self {
+            graph: map,
+            revision: 0,
+        }
+    }
+
+    /// Returns the revision of the `Map`, which changes every time rooms, exits or contents are changed through its methods.
+    ///
+    /// Changes made directly through [`graph`](Map::graph) don't change the revision. Use [`room_mut`](Map::room_mut) instead
+    /// to keep caches that depend on the revision, such as the [`ScopeCache`](crate::scope::ScopeCache), up to date.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Gets a mutable reference to a [`Room`], changing the [revision](Map::revision) of the `Map`.
+    pub fn room_mut(&mut self, room_id: RoomId) -> &mut Room {
+        self.revision += 1;
+        &mut self.graph[room_id]
     }
 
     /// Creates a new [`Room`] in the `Map`.
@@ -57,7 +94,24 @@ impl Map {
     /// ```
     pub fn new_room(&mut self, room_description: String) -> NodeIndex {
         let room = Room::new(room_description);
-        self.graph.add_node(room)
+        self.add_room(room)
+    }
+
+    /// Creates a new named [`Room`] in the `Map`.
+    ///
+    /// Creates a new [`Room`] with the given `name` and `description`, adds it to the map, and returns the `NodeIndex` of the new room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::Map;
+    ///
+    /// let mut map = Map::new();
+    /// let room_id = map.new_room_with_name("Study".into(), "You are in a small, cozy room.".into());
+    /// assert_eq!(map.graph[room_id].name.as_deref(), Some("Study"));
+    /// ```
+    pub fn new_room_with_name(&mut self, name: String, room_description: String) -> NodeIndex {
+        let room = Room::new_with_name(name, room_description);
+        self.add_room(room)
     }
 
     /// Adds an existing [`Room`] to the `Map`.
@@ -74,9 +128,67 @@ impl Map {
     /// let room_id = map.add_room(room);
     /// assert!(map.graph.node_count() == 1);
     pub fn add_room(&mut self, room: Room) -> NodeIndex {
+        self.revision += 1;
         self.graph.add_node(room)
     }
 
+    /// Adds many [`Room`]s to the `Map` at once, returning their `RoomId`s in order.
+    ///
+    /// Graph capacity is reserved up front, which makes building large, procedurally generated maps much faster than adding rooms one by one.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::{Map, Room};
+    ///
+    /// let mut map = Map::new();
+    /// let rooms = map.extend_rooms((0..1000).map(|i| Room::new(format!("Cell number {i}."))));
+    /// assert_eq!(rooms.len(), 1000);
+    /// assert_eq!(map.graph.node_count(), 1000);
+    /// ```
+    pub fn extend_rooms(&mut self, rooms: impl IntoIterator<Item = Room>) -> Vec<RoomId> {
+        let rooms = rooms.into_iter();
+        let (lower, _) = rooms.size_hint();
+        self.graph.reserve_nodes(lower);
+        self.revision += 1;
+        let mut ids = Vec::with_capacity(lower);
+        ids.extend(rooms.map(|room| self.graph.add_node(room)));
+        ids
+    }
+
+    /// Connects many pairs of [`Room`]s at once, returning the `ExitId`s of the new exits in order.
+    ///
+    /// Each exit is given as the room it leads from, the room it leads to, its [`Direction`] and its [`ExitType`],
+    /// like the arguments of [`connect_rooms`](Map::connect_rooms). Graph capacity is reserved up front.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::RegularExit;
+    /// use worldwright::map::{Direction, ExitType, Map, Room};
+    ///
+    /// let mut map = Map::new();
+    /// let rooms = map.extend_rooms((0..100).map(|i| Room::new(format!("Tunnel section {i}."))));
+    /// let exits = map.extend_exits(rooms.windows(2).map(|pair| {
+    ///     (pair[0], pair[1], Direction::East, Box::new(RegularExit) as Box<dyn ExitType>)
+    /// }));
+    /// assert_eq!(exits.len(), 99);
+    /// assert_eq!(map.get_exits(rooms[50]).len(), 2);
+    /// ```
+    pub fn extend_exits(
+        &mut self,
+        exits: impl IntoIterator<Item = (RoomId, RoomId, Direction, Box<dyn ExitType>)>,
+    ) -> Vec<ExitId> {
+        let exits = exits.into_iter();
+        let (lower, _) = exits.size_hint();
+        self.graph.reserve_edges(lower);
+        self.revision += 1;
+        let mut ids = Vec::with_capacity(lower);
+        ids.extend(exits.map(|(from, to, direction, exit_type)| {
+            self.graph
+                .add_edge(from, to, Exit::new(direction, exit_type))
+        }));
+        ids
+    }
+
     /// Creates and connects a new [`Room`] in a specified [`Direction`] from an existing [`Room`].
     ///
     /// Creates a new room with the given `description`, adds it to the `Map`, and connects
@@ -108,26 +220,373 @@ impl Map {
         room_description: String,
     ) -> NodeIndex {
         let to = self.new_room(room_description);
-        self.graph.add_edge(from, to, Exit::new(direction, exit));
+        self.connect_rooms(from, to, direction, exit);
         to
     }
 
+    /// Creates a new [`Room`] above an existing one, connected by an exit leading [up](Direction::Up) such as a
+    /// [`Staircase`](crate::map::exit_types::Staircase), and returns its `NodeIndex`.
+    ///
+    /// Like every exit, it can be gone through both ways, so the new room leads back down.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Ladder, Staircase};
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let landing = map.new_room_above(hall, Box::new(Staircase::new()), "A creaky landing.".into());
+    /// let cellar = map.new_room_below(hall, Box::new(Ladder::new()), "A damp cellar.".into());
+    ///
+    /// assert_eq!(map.neighbor_in_direction(hall, Direction::Up), Some(landing));
+    /// assert_eq!(map.neighbor_in_direction(landing, Direction::Down), Some(hall));
+    /// assert_eq!(map.neighbor_in_direction(cellar, Direction::Up), Some(hall));
+    /// ```
+    pub fn new_room_above(
+        &mut self,
+        from: RoomId,
+        exit: Box<dyn ExitType>,
+        room_description: String,
+    ) -> RoomId {
+        self.new_room_in_direction(from, Direction::Up, exit, room_description)
+    }
+
+    /// Creates a new [`Room`] below an existing one, connected by an exit leading [down](Direction::Down) such as a
+    /// [`Ladder`](crate::map::exit_types::Ladder), and returns its `NodeIndex`.
+    pub fn new_room_below(
+        &mut self,
+        from: RoomId,
+        exit: Box<dyn ExitType>,
+        room_description: String,
+    ) -> RoomId {
+        self.new_room_in_direction(from, Direction::Down, exit, room_description)
+    }
+
     /// Connects two existing [`Room`]s in the `Map`.
     ///
-    /// Connects the [`Room`] identified by `from` to the [`Room`] identified by `to` in the specified `Direction`
+    /// Connects the [`Room`] identified by `from` to the [`Room`] identified by `to` in the specified `Direction`,
+    /// and returns the [`ExitId`] of the new exit.
     pub fn connect_rooms(
         &mut self,
-        from: NodeIndex,
-        to: NodeIndex,
+        from: RoomId,
+        to: RoomId,
         direction: Direction,
         exit: Box<dyn ExitType>,
-    ) {
-        self.graph.add_edge(from, to, Exit::new(direction, exit));
+    ) -> ExitId {
+        self.revision += 1;
+        self.graph.add_edge(from, to, Exit::new(direction, exit))
+    }
+
+    /// Connects two existing [`Room`]s in the `Map` without a [`Direction`], such as with a
+    /// [`Teleporter`](crate::map::exit_types::Teleporter), and returns the [`ExitId`] of the new exit.
+    ///
+    /// The exit can't be found by direction, but it is listed by [`undirected_exits`](Map::undirected_exits)
+    /// and used by [`find_path`](Map::find_path).
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::Teleporter;
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let lab = map.new_room("A wizard's laboratory.".into());
+    /// let tower = map.new_room("The top of a lonely tower.".into());
+    /// let exit_id = map.connect_rooms_without_direction(lab, tower, Box::new(Teleporter::new("shimmering portal".into())));
+    ///
+    /// assert_eq!(map.undirected_exits(tower), vec![(exit_id, lab)]);
+    /// assert_eq!(map.neighbor_in_direction(lab, Direction::North), None);
+    /// ```
+    pub fn connect_rooms_without_direction(
+        &mut self,
+        from: RoomId,
+        to: RoomId,
+        exit: Box<dyn ExitType>,
+    ) -> ExitId {
+        self.revision += 1;
+        self.graph
+            .add_edge(from, to, Exit::new_without_direction(exit))
+    }
+
+    /// Lists the exits of a [`Room`] that don't lead in a [`Direction`], along with the room each one leads to.
+    pub fn undirected_exits(&self, room_id: RoomId) -> Vec<(ExitId, RoomId)> {
+        let edges_from = self
+            .graph
+            .edges_directed(room_id, petgraph::Direction::Outgoing)
+            .filter(|edge| {
+                edge.weight().direction.is_none() && !edge.weight().exit_type.is_hidden()
+            })
+            .map(|edge| (edge.id(), edge.target()));
+        let edges_to = self
+            .graph
+            .edges_directed(room_id, petgraph::Direction::Incoming)
+            .filter(|edge| {
+                edge.weight().direction.is_none() && !edge.weight().exit_type.is_hidden()
+            })
+            .map(|edge| (edge.id(), edge.source()));
+        edges_from.chain(edges_to).collect()
+    }
+
+    /// Finds the exit of a [`Room`] the player refers to by a [noun](ExitType::nouns), such as "archway",
+    /// along with the room it leads to.
+    ///
+    /// The noun matches, ignoring case, any noun of the exit or the last word of one. [Hidden](ExitType::is_hidden) exits are never found.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Door, NamedExit, RegularExit};
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let courtyard = map.new_room("A sunny courtyard.".into());
+    /// let garden = map.new_room_in_direction(courtyard, Direction::East, Box::new(NamedExit::new("archway".into())), "A garden.".into());
+    /// let hall = map.new_room_in_direction(
+    ///     courtyard,
+    ///     Direction::North,
+    ///     Box::new(Door::new_with_name(false, "heavy wooden door".into())),
+    ///     "A hall.".into(),
+    /// );
+    ///
+    /// assert_eq!(map.exit_by_noun(courtyard, "Archway").map(|(_, room)| room), Some(garden));
+    /// assert_eq!(map.exit_by_noun(courtyard, "door").map(|(_, room)| room), Some(hall));
+    /// assert_eq!(map.exit_by_noun(garden, "archway").map(|(_, room)| room), Some(courtyard));
+    /// assert_eq!(map.exit_by_noun(courtyard, "window"), None);
+    /// ```
+    pub fn exit_by_noun(&self, room_id: RoomId, noun: &str) -> Option<(ExitId, RoomId)> {
+        let noun = noun.trim().to_lowercase();
+        let matches = |exit: &Exit| {
+            !exit.exit_type.is_hidden()
+                && exit.exit_type.nouns().iter().any(|name| {
+                    let name = name.to_lowercase();
+                    name == noun || name.split_whitespace().last() == Some(noun.as_str())
+                })
+        };
+        let outgoing = self
+            .graph
+            .edges_directed(room_id, petgraph::Direction::Outgoing)
+            .find(|edge| matches(edge.weight()))
+            .map(|edge| (edge.id(), edge.target()));
+        outgoing.or_else(|| {
+            self.graph
+                .edges_directed(room_id, petgraph::Direction::Incoming)
+                .find(|edge| matches(edge.weight()))
+                .map(|edge| (edge.id(), edge.source()))
+        })
+    }
+
+    /// Finds the shortest path between two [`Room`]s through exits the player [can go through](ExitType::can_go_through),
+    /// including exits without a [`Direction`].
+    ///
+    /// Returns every room on the path, starting with `from` and ending with `to`, or `None` if `to` can't be reached.
+    ///
+    /// Exits whose passability depends on the state of the world, such as a
+    /// [`ConditionalExit`](crate::map::exit_types::ConditionalExit), can't be gone through without it, so they are
+    /// treated as walls. Use [`World::find_path`](crate::World::find_path) to take them into account.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Door, RegularExit, Teleporter};
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let lab = map.new_room_in_direction(hall, Direction::East, Box::new(RegularExit), "A laboratory.".into());
+    /// let vault = map.new_room_in_direction(hall, Direction::West, Box::new(Door::new(true)), "A vault.".into());
+    /// let tower = map.new_room("A lonely tower.".into());
+    /// map.connect_rooms_without_direction(lab, tower, Box::new(Teleporter::new("portal".into())));
+    ///
+    /// assert_eq!(map.find_path(hall, tower), Some(vec![hall, lab, tower]));
+    /// assert_eq!(map.find_path(hall, hall), Some(vec![hall]));
+    /// assert_eq!(map.find_path(hall, vault), None);
+    /// ```
+    pub fn find_path(&self, from: RoomId, to: RoomId) -> Option<Vec<RoomId>> {
+        let route = self.find_route(from, to, |_, exit, _| exit.exit_type.can_go_through())?;
+        Some(
+            core::iter::once(from)
+                .chain(route.into_iter().map(|(_, room_id)| room_id))
+                .collect(),
+        )
+    }
+
+    /// Finds the shortest route between two [`Room`]s through the exits `passable` accepts, given the
+    /// [`ExitId`], the [`Exit`] and the room it leads to, including exits without a [`Direction`].
+    ///
+    /// Returns every step of the route, as the exit gone through and the room it leads to, or `None` if `to` can't be
+    /// reached.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{RegularExit, Teleporter};
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let lab = map.new_room_in_direction(hall, Direction::East, Box::new(RegularExit), "A laboratory.".into());
+    /// let tower = map.new_room("A lonely tower.".into());
+    /// let portal = map.connect_rooms_without_direction(lab, tower, Box::new(Teleporter::new("portal".into())));
+    /// let corridor = map.exit_id_in_direction(hall, Direction::East).unwrap();
+    ///
+    /// assert_eq!(map.find_route(hall, tower, |_, _, _| true), Some(vec![(corridor, lab), (portal, tower)]));
+    /// assert_eq!(map.find_route(hall, tower, |_, _, room_id| room_id != tower), None);
+    /// assert_eq!(map.find_route(hall, hall, |_, _, _| true), Some(vec![]));
+    /// ```
+    pub fn find_route(
+        &self,
+        from: RoomId,
+        to: RoomId,
+        passable: impl Fn(ExitId, &Exit, RoomId) -> bool,
+    ) -> Option<Vec<(ExitId, RoomId)>> {
+        let mut previous: BTreeMap<RoomId, Option<(ExitId, RoomId)>> = BTreeMap::new();
+        let mut queue = VecDeque::from([from]);
+        previous.insert(from, None);
+        while let Some(room_id) = queue.pop_front() {
+            if room_id == to {
+                let mut route = Vec::new();
+                let mut current = to;
+                while let Some((exit_id, before)) = previous[&current] {
+                    route.push((exit_id, current));
+                    current = before;
+                }
+                route.reverse();
+                return Some(route);
+            }
+            let edges_from = self
+                .graph
+                .edges_directed(room_id, petgraph::Direction::Outgoing)
+                .map(|edge| (edge.id(), edge.weight(), edge.target()));
+            let edges_to = self
+                .graph
+                .edges_directed(room_id, petgraph::Direction::Incoming)
+                .map(|edge| (edge.id(), edge.weight(), edge.source()));
+            for (exit_id, exit, next) in edges_from.chain(edges_to) {
+                if !previous.contains_key(&next) && passable(exit_id, exit, next) {
+                    previous.insert(next, Some((exit_id, room_id)));
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Gets the [`ExitId`] of the exit leading away from a [`Room`] in a relative [`Direction`].
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::RegularExit;
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let kitchen = map.new_room("A kitchen.".into());
+    /// let exit_id = map.connect_rooms(hall, kitchen, Direction::North, Box::new(RegularExit));
+    ///
+    /// assert_eq!(map.exit_id_in_direction(hall, Direction::North), Some(exit_id));
+    /// assert_eq!(map.exit_id_in_direction(kitchen, Direction::South), Some(exit_id));
+    /// assert_eq!(map.exit_id_in_direction(hall, Direction::West), None);
+    /// ```
+    pub fn exit_id_in_direction(&self, room_id: RoomId, direction: Direction) -> Option<ExitId> {
+        self.find_exit(room_id, direction, |exit| !exit.exit_type.is_hidden())
+            .map(|(exit_id, _)| exit_id)
+    }
+
+    /// Gets the rooms an exit connects, as the room it leads from and the room it leads to.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::RegularExit;
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let kitchen = map.new_room("A kitchen.".into());
+    /// let exit_id = map.connect_rooms(hall, kitchen, Direction::North, Box::new(RegularExit));
+    ///
+    /// assert_eq!(map.exit_endpoints(exit_id), Some((hall, kitchen)));
+    /// ```
+    pub fn exit_endpoints(&self, exit_id: ExitId) -> Option<(RoomId, RoomId)> {
+        self.graph.edge_endpoints(exit_id)
+    }
+
+    /// Gets the room adjacent to a [`Room`] in a relative [`Direction`], whether or not its exit can be gone through.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::Door;
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let vault = map.new_room_in_direction(hall, Direction::South, Box::new(Door::new(true)), "A vault.".into());
+    ///
+    /// assert_eq!(map.neighbor_in_direction(hall, Direction::South), Some(vault));
+    /// assert_eq!(map.neighbor_in_direction(vault, Direction::North), Some(hall));
+    /// assert_eq!(map.neighbor_in_direction(hall, Direction::East), None);
+    /// ```
+    pub fn neighbor_in_direction(&self, room_id: RoomId, direction: Direction) -> Option<RoomId> {
+        self.exit_in_direction(room_id, direction)
+            .map(|(_, neighbor)| neighbor)
+    }
+
+    /// Gets a mutable reference to the [`Exit`] leading away from a [`Room`] in a relative [`Direction`].
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Door, RegularExit};
+    /// use worldwright::map::{Direction, ExitType, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// map.new_room_in_direction(hall, Direction::North, Box::new(Door::new(false)), "A study.".into());
+    ///
+    /// let exit = map.exit_mut(hall, Direction::North).unwrap();
+    /// exit.exit_type.downcast_mut::<Door>().unwrap().lock();
+    /// assert!(!map.exit_mut(hall, Direction::North).unwrap().exit_type.can_go_through());
+    ///
+    /// // Exits can also be replaced entirely.
+    /// map.exit_mut(hall, Direction::North).unwrap().exit_type = Box::new(RegularExit);
+    /// assert!(map.exit_mut(hall, Direction::North).unwrap().exit_type.can_go_through());
+    /// ```
+    pub fn exit_mut(&mut self, from: RoomId, direction: Direction) -> Option<&mut Exit> {
+        let exit_id = self.exit_id_in_direction(from, direction)?;
+        self.exit_weight_mut(exit_id)
+    }
+
+    /// Gets a mutable reference to the [`Exit`] with the given `ExitId`.
+    pub fn exit_weight_mut(&mut self, exit_id: ExitId) -> Option<&mut Exit> {
+        self.revision += 1;
+        self.graph.edge_weight_mut(exit_id)
+    }
+
+    /// Gets the [`ExitType`] of an exit as its concrete type `T`, or `None` if the exit doesn't exist or is of another type.
+    pub fn exit_as<T: ExitType>(&self, exit_id: ExitId) -> Option<&T> {
+        self.graph.edge_weight(exit_id)?.exit_type.downcast_ref()
+    }
+
+    /// Gets the [`ExitType`] of an exit as a mutable reference to its concrete type `T`,
+    /// or `None` if the exit doesn't exist or is of another type.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Door, RegularExit};
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let study = map.new_room("A quiet study.".into());
+    /// let door = map.connect_rooms(hall, study, Direction::East, Box::new(Door::new(true)));
+    ///
+    /// map.exit_as_mut::<Door>(door).unwrap().unlock();
+    /// assert_eq!(map.exit_as::<Door>(door).map(|door| door.locked), Some(false));
+    /// assert!(map.exit_as::<RegularExit>(door).is_none());
+    /// ```
+    pub fn exit_as_mut<T: ExitType>(&mut self, exit_id: ExitId) -> Option<&mut T> {
+        self.exit_weight_mut(exit_id)?.exit_type.downcast_mut()
     }
 
     /// Retrieves all [`Exit`]s connected to a given [`Room`], along with their [`Direction`].
     ///
     /// Returns a vector of tuples containing references to the [`Exit`] and an [`ExitWay`] indicating whether the exit is going away from or to the node.
+    /// [Hidden](ExitType::is_hidden) exits are left out.
     ///
     /// **Important**: The direction of the exit is determined by the direction it was added to the graph, NOT relative to this node.
     /// To get the relative direction, you can use the [`get_relative_direction`](Map::get_relative_direction) method.
@@ -148,7 +607,7 @@ impl Map {
     /// );
     /// assert_eq!(map.get_exits(central_room).len(), 1);
     /// let (central_room_first_exit, _) = map.get_exits(central_room)[0];
-    /// assert_eq!(central_room_first_exit.direction, Direction::North);
+    /// assert_eq!(central_room_first_exit.direction, Some(Direction::North));
     ///
     /// let lower_room = map.new_room("You are in the lower room.".into());
     /// map.connect_rooms(
@@ -160,12 +619,12 @@ impl Map {
     /// assert_eq!(map.get_exits(central_room).len(), 2);
     /// let (central_room_second_exit, central_room_second_exit_way) = map.get_exits(central_room)[1];
     /// // It should still be North, because the direction is determined by how it was added to the graph, not relative to this node.
-    /// assert_eq!(central_room_second_exit.direction, Direction::North);
+    /// assert_eq!(central_room_second_exit.direction, Some(Direction::North));
     ///
     /// // To get the relative direction, you can use the get_relative_direction method.
     /// let relative_direction =
     ///     map.get_relative_direction(central_room_second_exit, central_room_second_exit_way);
-    /// assert_eq!(relative_direction, Direction::South);
+    /// assert_eq!(relative_direction, Some(Direction::South));
     pub fn get_exits(&self, room_id: NodeIndex) -> Vec<(&Exit, ExitWay)> {
         let edges_from = self
             .graph
@@ -176,24 +635,418 @@ impl Map {
 
         let mut exits = Vec::new();
 
-        for edge in edges_from {
+        for edge in edges_from.filter(|edge| !edge.weight().exit_type.is_hidden()) {
             exits.push((edge.weight(), ExitWay::From));
         }
 
-        for edge in edges_to {
+        for edge in edges_to.filter(|edge| !edge.weight().exit_type.is_hidden()) {
             exits.push((edge.weight(), ExitWay::To));
         }
 
         exits
     }
 
-    /// Gets the relative [`Direction`] of an [`Exit`] based on the specified [`ExitWay`].
-    pub fn get_relative_direction(&self, exit: &Exit, exit_way: ExitWay) -> Direction {
-        match exit_way {
-            ExitWay::From => exit.direction,
-            ExitWay::To => exit.direction.opposite(),
+    /// Gets every [`Room`] adjacent to the given room, along with the [`Exit`] leading to it and its [`Direction`] relative to the given room.
+    ///
+    /// Rooms connected without a direction, such as by a [`Teleporter`](crate::map::exit_types::Teleporter), or by a
+    /// [hidden](ExitType::is_hidden) exit aren't adjacent.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::RegularExit;
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let kitchen = map.new_room_in_direction(hall, Direction::North, Box::new(RegularExit), "A kitchen.".into());
+    ///
+    /// let adjacent = map.adjacent_rooms(kitchen);
+    /// assert_eq!(adjacent.len(), 1);
+    /// let (_, direction, room_id) = adjacent[0];
+    /// assert_eq!((direction, room_id), (Direction::South, hall));
+    /// ```
+    pub fn adjacent_rooms(&self, room_id: NodeIndex) -> Vec<(&Exit, Direction, NodeIndex)> {
+        let edges_from = self
+            .graph
+            .edges_directed(room_id, petgraph::Direction::Outgoing)
+            .filter(|edge| !edge.weight().exit_type.is_hidden())
+            .filter_map(|edge| Some((edge.weight(), edge.weight().direction?, edge.target())));
+        let edges_to = self
+            .graph
+            .edges_directed(room_id, petgraph::Direction::Incoming)
+            .filter(|edge| !edge.weight().exit_type.is_hidden())
+            .filter_map(|edge| {
+                Some((
+                    edge.weight(),
+                    edge.weight().direction?.opposite(),
+                    edge.source(),
+                ))
+            });
+        edges_from.chain(edges_to).collect()
+    }
+
+    /// Finds how a sound made in one [`Room`] can be heard from another.
+    ///
+    /// Returns `Some(None)` if both are the same room, `Some(Some(direction))` if the sound comes from an adjacent room
+    /// in `direction` through an exit that [transmits sound](ExitType::transmits_sound), and `None` if it can't be heard.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Door, RegularExit};
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// let kitchen = map.new_room_in_direction(hall, Direction::North, Box::new(RegularExit), "A kitchen.".into());
+    /// let vault = map.new_room_in_direction(hall, Direction::South, Box::new(Door::new(true)), "A vault.".into());
+    ///
+    /// assert_eq!(map.hearing_direction(hall, hall), Some(None));
+    /// assert_eq!(map.hearing_direction(hall, kitchen), Some(Some(Direction::North)));
+    /// assert_eq!(map.hearing_direction(hall, vault), None);
+    /// ```
+    pub fn hearing_direction(
+        &self,
+        listener_id: NodeIndex,
+        source_id: NodeIndex,
+    ) -> Option<Option<Direction>> {
+        if listener_id == source_id {
+            return Some(None);
+        }
+        self.adjacent_rooms(listener_id)
+            .into_iter()
+            .find(|(exit, _, room_id)| *room_id == source_id && exit.exit_type.transmits_sound())
+            .map(|(_, direction, _)| Some(direction))
+    }
+
+    /// Marks the [`Room`] identified by `room_id` as visited.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::Map;
+    ///
+    /// let mut map = Map::new();
+    /// let room_id = map.new_room("You are in a small, cozy room.".into());
+    /// assert!(!map.graph[room_id].is_visited());
+    /// map.mark_visited(room_id);
+    /// assert!(map.graph[room_id].is_visited());
+    /// ```
+    pub fn mark_visited(&mut self, room_id: NodeIndex) {
+        self.graph[room_id].visited = true;
+    }
+
+    /// Places an [`Item`](crate::item::Item) in a [`Room`].
+    ///
+    /// The item is added to the contents of the room. It is not removed from anywhere else.
+    pub fn place_item(&mut self, room_id: NodeIndex, item: ItemId) {
+        self.revision += 1;
+        self.graph[room_id].contents.push(item);
+    }
+
+    /// Removes an [`Item`](crate::item::Item) from a [`Room`], returning whether it was there.
+    pub fn remove_item(&mut self, room_id: NodeIndex, item: ItemId) -> bool {
+        let contents = &mut self.graph[room_id].contents;
+        match contents.iter().position(|&content| content == item) {
+            Some(index) => {
+                contents.remove(index);
+                self.revision += 1;
+                true
+            }
+            None => false,
         }
     }
+
+    /// Moves an [`Item`](crate::item::Item) from one [`Room`] to another.
+    ///
+    /// Returns `false` and leaves the item where it is if it wasn't in the `from` room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::item::{Item, Items};
+    /// use worldwright::map::Map;
+    ///
+    /// let mut items = Items::new();
+    /// let lamp = items.add(Item::new("lamp".into(), "A brass oil lamp.".into()));
+    ///
+    /// let mut map = Map::new();
+    /// let kitchen = map.new_room("The kitchen.".into());
+    /// let pantry = map.new_room("The pantry.".into());
+    /// map.place_item(kitchen, lamp);
+    ///
+    /// assert!(map.move_item(lamp, kitchen, pantry));
+    /// assert!(map.graph[kitchen].contents.is_empty());
+    /// assert_eq!(map.graph[pantry].contents, vec![lamp]);
+    /// assert_eq!(map.find_item(lamp), Some(pantry));
+    /// ```
+    pub fn move_item(&mut self, item: ItemId, from: NodeIndex, to: NodeIndex) -> bool {
+        if !self.remove_item(from, item) {
+            return false;
+        }
+        self.place_item(to, item);
+        true
+    }
+
+    /// Finds the [`Room`] an [`Item`](crate::item::Item) is in.
+    pub fn find_item(&self, item: ItemId) -> Option<NodeIndex> {
+        self.graph
+            .node_indices()
+            .find(|&room_id| self.graph[room_id].contents.contains(&item))
+    }
+
+    /// Returns the `NodeIndex` of every [`Room`] with the given tag.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::Map;
+    ///
+    /// let mut map = Map::new();
+    /// let cellar = map.new_room("A cold cellar.".into());
+    /// let attic = map.new_room("A dusty attic.".into());
+    /// map.graph[cellar].add_tag("underground");
+    ///
+    /// assert_eq!(map.rooms_with_tag("underground"), vec![cellar]);
+    /// assert!(map.rooms_with_tag("safe_zone").is_empty());
+    /// ```
+    pub fn rooms_with_tag(&self, tag: &str) -> Vec<NodeIndex> {
+        self.graph
+            .node_indices()
+            .filter(|&room_id| self.graph[room_id].has_tag(tag))
+            .collect()
+    }
+
+    /// Finds the [`Room`] with the given name, ignoring case.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::Map;
+    ///
+    /// let mut map = Map::new();
+    /// let study = map.new_room_with_name("Study".into(), "You are in a small, cozy room.".into());
+    ///
+    /// assert_eq!(map.room_by_name("study"), Some(study));
+    /// assert_eq!(map.room_by_name("Kitchen"), None);
+    /// ```
+    pub fn room_by_name(&self, name: &str) -> Option<NodeIndex> {
+        self.graph.node_indices().find(|&room_id| {
+            self.graph[room_id]
+                .name
+                .as_ref()
+                .is_some_and(|room_name| room_name.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Finds where an object thrown from a [`Room`] in a [`Direction`] lands.
+    ///
+    /// Returns the `NodeIndex` of the adjacent room if there is an exit in that direction that
+    /// [lets objects through](ExitType::can_throw_through).
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::Chasm;
+    /// use worldwright::map::{Direction, Map, PassageError};
+    ///
+    /// let mut map = Map::new();
+    /// let ledge = map.new_room("A narrow ledge.".into());
+    /// let far_side = map.new_room_in_direction(
+    ///     ledge,
+    ///     Direction::East,
+    ///     Box::new(Chasm),
+    ///     "The far side of the chasm.".into(),
+    /// );
+    ///
+    /// assert_eq!(map.throw_target(ledge, Direction::East), Ok(far_side));
+    /// assert_eq!(map.throw_target(far_side, Direction::West), Ok(ledge));
+    /// assert_eq!(map.throw_target(ledge, Direction::North), Err(PassageError::NoExit));
+    /// ```
+    pub fn throw_target(
+        &self,
+        from: NodeIndex,
+        direction: Direction,
+    ) -> Result<NodeIndex, PassageError> {
+        let (exit, to) = self
+            .exit_in_direction(from, direction)
+            .ok_or(PassageError::NoExit)?;
+        if exit.exit_type.can_throw_through() {
+            Ok(to)
+        } else {
+            Err(PassageError::Blocked)
+        }
+    }
+
+    /// Finds where a bulky object pushed from a [`Room`] in a [`Direction`] ends up.
+    ///
+    /// Returns the `NodeIndex` of the adjacent room if there is an exit in that direction that
+    /// [allows bulky objects](ExitType::allows_bulky_objects).
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Door, RegularExit};
+    /// use worldwright::map::{Direction, Map, PassageError};
+    ///
+    /// let mut map = Map::new();
+    /// let barn = map.new_room("A creaky barn.".into());
+    /// let yard = map.new_room_in_direction(barn, Direction::South, Box::new(RegularExit), "The yard.".into());
+    /// let shed = map.new_room_in_direction(barn, Direction::East, Box::new(Door::new(true)), "A shed.".into());
+    ///
+    /// assert_eq!(map.push_target(barn, Direction::South), Ok(yard));
+    /// assert_eq!(map.push_target(barn, Direction::East), Err(PassageError::Blocked));
+    /// ```
+    pub fn push_target(
+        &self,
+        from: NodeIndex,
+        direction: Direction,
+    ) -> Result<NodeIndex, PassageError> {
+        let (exit, to) = self
+            .exit_in_direction(from, direction)
+            .ok_or(PassageError::NoExit)?;
+        if exit.exit_type.allows_bulky_objects() {
+            Ok(to)
+        } else {
+            Err(PassageError::Blocked)
+        }
+    }
+
+    /// Tries to unlock the exit leading away from a [`Room`] in a [`Direction`] with the given key.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::item::{Item, Items};
+    /// use worldwright::map::exit_types::Door;
+    /// use worldwright::map::{Direction, Map, UnlockError};
+    ///
+    /// let mut items = Items::new();
+    /// let key = items.add(Item::new("brass key".into(), "A small brass key.".into()));
+    ///
+    /// let mut map = Map::new();
+    /// let hall = map.new_room("A long hall.".into());
+    /// map.new_room_in_direction(hall, Direction::North, Box::new(Door::new_with_key(key)), "A study.".into());
+    ///
+    /// assert_eq!(map.unlock_exit(hall, Direction::East, key), Err(UnlockError::NoExit));
+    /// assert_eq!(map.unlock_exit(hall, Direction::North, key), Ok(()));
+    /// ```
+    pub fn unlock_exit(
+        &mut self,
+        room_id: NodeIndex,
+        direction: Direction,
+        key: ItemId,
+    ) -> Result<(), UnlockError> {
+        let edge = self
+            .exit_id_in_direction(room_id, direction)
+            .ok_or(UnlockError::NoExit)?;
+        self.revision += 1;
+        self.graph[edge].exit_type.unlock_with(key)
+    }
+
+    /// Finds the [`Exit`] leading away from a [`Room`] in a relative [`Direction`], along with the room it leads to.
+    fn exit_in_direction(
+        &self,
+        room_id: NodeIndex,
+        direction: Direction,
+    ) -> Option<(&Exit, NodeIndex)> {
+        self.find_exit(room_id, direction, |exit| !exit.exit_type.is_hidden())
+            .map(|(exit_id, to)| (&self.graph[exit_id], to))
+    }
+
+    /// Finds the first exit `matches` accepts leading away from a [`Room`] in a relative [`Direction`], along with the
+    /// room it leads to.
+    fn find_exit(
+        &self,
+        room_id: RoomId,
+        direction: Direction,
+        matches: impl Fn(&Exit) -> bool,
+    ) -> Option<(ExitId, RoomId)> {
+        let outgoing = self
+            .graph
+            .edges_directed(room_id, petgraph::Direction::Outgoing)
+            .find(|edge| edge.weight().direction == Some(direction) && matches(edge.weight()))
+            .map(|edge| (edge.id(), edge.target()));
+        outgoing.or_else(|| {
+            self.graph
+                .edges_directed(room_id, petgraph::Direction::Incoming)
+                .find(|edge| {
+                    edge.weight().direction.map(|way| way.opposite()) == Some(direction)
+                        && matches(edge.weight())
+                })
+                .map(|edge| (edge.id(), edge.source()))
+        })
+    }
+
+    /// Tells an exit that the player went through it, so that exits that change as they are used can keep track of it.
+    ///
+    /// Movement code should call this after moving the player. Returns whether the exit exists.
+    pub fn traverse_exit(&mut self, exit_id: ExitId) -> bool {
+        let Some(exit) = self.graph.edge_weight_mut(exit_id) else {
+            return false;
+        };
+        exit.exit_type.on_traverse();
+        self.revision += 1;
+        true
+    }
+
+    /// Advances every exit by one turn, and returns the messages of the exits that changed, such as "The door swings shut.",
+    /// along with their [`ExitId`]s.
+    pub fn tick_exits(&mut self) -> Vec<(ExitId, String)> {
+        let ids: Vec<ExitId> = self.graph.edge_indices().collect();
+        let messages: Vec<(ExitId, String)> = ids
+            .into_iter()
+            .filter_map(|exit_id| Some((exit_id, self.graph[exit_id].exit_type.on_turn()?)))
+            .collect();
+        if !messages.is_empty() {
+            self.revision += 1;
+        }
+        messages
+    }
+
+    /// Reveals the [hidden](ExitType::is_hidden) exit leading away from a [`Room`] in a relative [`Direction`],
+    /// such as a [`SecretDoor`](crate::map::exit_types::SecretDoor), returning whether an exit was revealed.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::exit_types::{Door, SecretDoor};
+    /// use worldwright::map::{Direction, Map};
+    ///
+    /// let mut map = Map::new();
+    /// let study = map.new_room("A cluttered study.".into());
+    /// let passage = map.new_room_in_direction(
+    ///     study,
+    ///     Direction::West,
+    ///     Box::new(SecretDoor::new(Door::new_with_name(false, "bookcase".into()))),
+    ///     "A narrow passage.".into(),
+    /// );
+    ///
+    /// assert!(map.get_exits(study).is_empty());
+    /// assert_eq!(map.neighbor_in_direction(study, Direction::West), None);
+    ///
+    /// assert!(map.reveal_exit(study, Direction::West));
+    /// assert!(!map.reveal_exit(study, Direction::West));
+    /// assert_eq!(map.get_exits(study).len(), 1);
+    /// assert_eq!(map.neighbor_in_direction(study, Direction::West), Some(passage));
+    ///
+    /// // A visible exit in the same direction doesn't hide the secret one.
+    /// let cellar = map.new_room("A damp cellar.".into());
+    /// map.new_room_in_direction(cellar, Direction::Down, Box::new(Door::new(false)), "A crypt.".into());
+    /// map.new_room_in_direction(cellar, Direction::Down, Box::new(SecretDoor::new(Door::new(false))), "A tunnel.".into());
+    /// assert!(map.reveal_exit(cellar, Direction::Down));
+    /// ```
+    pub fn reveal_exit(&mut self, room_id: RoomId, direction: Direction) -> bool {
+        let Some((exit_id, _)) =
+            self.find_exit(room_id, direction, |exit| exit.exit_type.is_hidden())
+        else {
+            return false;
+        };
+        self.revision += 1;
+        self.graph[exit_id].exit_type.reveal()
+    }
+
+    /// Gets the relative [`Direction`] of an [`Exit`] based on the specified [`ExitWay`].
+    ///
+    /// Returns `None` if the exit doesn't lead in a direction.
+    pub fn get_relative_direction(&self, exit: &Exit, exit_way: ExitWay) -> Option<Direction> {
+        let direction = exit.direction?;
+        Some(match exit_way {
+            ExitWay::From => direction,
+            ExitWay::To => direction.opposite(),
+        })
+    }
 }
 
 impl Default for Map {
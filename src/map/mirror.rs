@@ -0,0 +1,60 @@
+use crate::map::Description;
+use crate::prelude::*;
+use petgraph::prelude::NodeIndex;
+
+/// A piece of scenery that shows another [`Room`](crate::map::Room) when looked into, such as a mirror, a crystal ball, or a window.
+///
+/// Looking into a mirror redirects what the player sees to its target room, which can be the room the mirror is in.
+/// A mirror can also reveal something that is only visible in the reflection.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::light::LightLevel;
+/// use worldwright::map::{Mirror, MirrorError};
+///
+/// let mut world = World::new();
+/// let hall = world.map.new_room_with_name("Hall".into(), "A long hall.".into());
+/// let crypt = world.map.new_room_with_name("Crypt".into(), "Rows of stone coffins.".into());
+///
+/// let mut mirror = Mirror::new("mirror".into(), crypt);
+/// mirror.reveals = Some("A pale figure stands among the coffins.".into());
+/// world.map.graph[hall].mirrors.push(mirror);
+///
+/// let reflection = world.look_in_mirror(hall, "mirror").unwrap();
+/// assert_eq!(reflection.title.as_deref(), Some("Crypt"));
+/// assert_eq!(reflection.body, "Rows of stone coffins. A pale figure stands among the coffins.");
+///
+/// world.map.graph[hall].light = LightLevel::PitchBlack;
+/// assert_eq!(world.look_in_mirror(hall, "mirror"), Err(MirrorError::TooDark));
+/// assert_eq!(world.look_in_mirror(hall, "window"), Err(MirrorError::NoMirror));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Mirror {
+    /// The name the player uses to refer to the mirror, such as "mirror" or "crystal ball".
+    pub name: String,
+    /// The room shown in the mirror.
+    pub target: NodeIndex,
+    /// Something only visible in the reflection.
+    pub reveals: Option<Description>,
+}
+
+impl Mirror {
+    /// Creates a new `Mirror` showing the `target` room.
+    pub fn new(name: String, target: NodeIndex) -> Self {
+        Self {
+            name,
+            target,
+            reveals: None,
+        }
+    }
+}
+
+/// The reason looking into a [`Mirror`] shows nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MirrorError {
+    /// There is no mirror with that name in the room.
+    NoMirror,
+    /// The room with the mirror is too dark to see a reflection.
+    TooDark,
+}
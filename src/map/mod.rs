@@ -1,14 +1,95 @@
+mod annotations;
+pub use annotations::Annotations;
+
+mod description;
+pub use description::{Description, DescriptionProvider, RoomDescription};
+
 mod direction;
 pub use direction::Direction;
 
 mod exit;
 pub use exit::Exit;
 
+#[cfg(feature = "serde")]
+mod exit_registry;
+#[cfg(feature = "serde")]
+pub use exit_registry::{ExitRegistry, ExitSerdeError, ExitWrapper};
+
 mod exit_type;
-pub use exit_type::ExitType;
+pub use exit_type::{AsAny, BoxedClone, ExitType, Surroundings, UnlockError};
+/// Derives [`ExitType`] for exits whose description is a format string and whose passability is an expression.
+///
+/// The `#[exit(...)]` attribute takes a `description` format string, where `{direction}` and the named fields of the
+/// struct can be used, and an optional `passable` expression of `self`. Exits are passable by default, and every other
+/// method keeps its default. `{direction}` is always the direction of the exit, even if the struct has a field with
+/// that name.
+///
+/// # Examples
+/// ```
+/// use worldwright::map::{Direction, ExitType};
+///
+/// #[derive(Clone, Debug, ExitType)]
+/// #[exit(description = "a {material} bridge {direction}", passable = !self.collapsed)]
+/// struct Bridge {
+///     material: String,
+///     collapsed: bool,
+/// }
+///
+/// let mut bridge = Bridge { material: "rope".into(), collapsed: false };
+/// assert_eq!(bridge.description(Direction::East), "a rope bridge east");
+/// assert!(bridge.can_go_through());
+/// bridge.collapsed = true;
+/// assert!(!bridge.can_go_through());
+///
+/// #[derive(Clone, Debug, ExitType)]
+/// #[exit(description = "a crawlspace {direction}")]
+/// struct Crawlspace;
+///
+/// assert_eq!(Crawlspace.description(Direction::Down), "a crawlspace down");
+/// assert!(Crawlspace.can_go_through());
+///
+/// #[derive(Clone, Debug, ExitType)]
+/// #[exit(description = "a sign pointing {direction}")]
+/// struct Signpost {
+///     direction: Direction,
+/// }
+///
+/// let signpost = Signpost { direction: Direction::South };
+/// assert_eq!(signpost.description(Direction::West), "a sign pointing west");
+/// ```
+///
+/// The description is required:
+/// ```compile_fail
+/// use worldwright::map::ExitType;
+///
+/// #[derive(Clone, Debug, ExitType)]
+/// #[exit(passable = true)]
+/// struct Crawlspace;
+/// ```
+///
+/// And other keys aren't allowed:
+/// ```compile_fail
+/// use worldwright::map::ExitType;
+///
+/// #[derive(Clone, Debug, ExitType)]
+/// #[exit(description = "a crawlspace {direction}", hidden = true)]
+/// struct Crawlspace;
+/// ```
+#[cfg(feature = "derive")]
+pub use worldwright_derive::ExitType;
 
+mod hooks;
+pub use hooks::{RoomHook, RoomHooks};
+
+#[allow(clippy::module_inception)]
 mod map;
-pub use map::{ExitWay, Map};
+pub use map::{ExitId, ExitWay, Map, PassageError, RoomId};
+
+mod mirror;
+pub use mirror::{Mirror, MirrorError};
+
+mod region;
+pub use region::Region;
 
 mod room;
 pub use room::Room;
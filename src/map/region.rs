@@ -0,0 +1,47 @@
+use crate::map::Map;
+use crate::prelude::*;
+use petgraph::prelude::NodeIndex;
+
+/// A set of [`Room`](crate::map::Room)s in the [`Map`](crate::map::Map), such as "the manor" or "the forest".
+///
+/// # Examples
+/// ```
+/// use worldwright::map::{Map, Region};
+///
+/// let mut map = Map::new();
+/// let foyer = map.new_room("The foyer.".into());
+/// let garden = map.new_room("The garden.".into());
+/// map.graph[garden].add_tag("outdoors");
+///
+/// let manor = Region::Rooms(vec![foyer]);
+/// assert!(manor.contains(&map, foyer));
+/// assert!(!manor.contains(&map, garden));
+/// assert!(Region::Tagged("outdoors".into()).contains(&map, garden));
+/// assert!(Region::Everywhere.contains(&map, garden));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Region {
+    /// A specific list of rooms.
+    Rooms(Vec<NodeIndex>),
+    /// Every room with the given tag.
+    Tagged(String),
+    /// Every room in the map.
+    Everywhere,
+}
+
+impl Region {
+    /// Checks whether the region contains the given room.
+    pub fn contains(&self, map: &Map, room_id: NodeIndex) -> bool {
+        match self {
+            Region::Rooms(rooms) => rooms.contains(&room_id),
+            Region::Tagged(tag) => map.graph[room_id].has_tag(tag),
+            Region::Everywhere => true,
+        }
+    }
+}
+
+impl From<NodeIndex> for Region {
+    fn from(room_id: NodeIndex) -> Self {
+        Region::Rooms(vec![room_id])
+    }
+}
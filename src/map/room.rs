@@ -1,15 +1,285 @@
+use crate::World;
+use crate::item::ItemId;
+use crate::light::LightLevel;
+use crate::map::{Annotations, Description, Direction, Mirror, RoomHooks};
+use crate::prelude::*;
+use crate::properties::{Properties, PropertyValue};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::rc::Rc;
+use petgraph::prelude::NodeIndex;
+
 #[derive(Clone, Debug)]
 /// A struct representing a room in the [`Map`](crate::map::Map).
 ///
-/// Each `Room` has a description.
+/// Each `Room` has a [`Description`] and an optional name, which is shown as a short title before the description.
+/// The description can be a fixed string or generated from the state of the [`World`](crate::World).
+///
+/// A room can also have a short description, shown instead of the full one once the room has been visited,
+/// any number of custom [`Properties`], and an extension of any type for game-specific data.
+///
+/// Rooms can be tagged, such as "underground" or "safe_zone", to group them into [`Region`](crate::map::Region)s.
+///
+/// A room has its own [`LightLevel`], which [light sources](crate::light::LightSource) and adjacent rooms can add to.
+/// Its details are only shown when it is fully lit.
+///
+/// The [`Item`](crate::item::Item)s located in a room are listed in its contents.
+///
+/// Rooms can also have a sound and a smell, which the player notices when they listen or sniff.
+/// A room's distant sound can be heard from adjacent rooms, through exits that [transmit sound](crate::map::ExitType::transmits_sound).
+///
+/// Trying to go in a direction without an exit shows the room's message for that direction, such as
+/// "The hedge is impenetrable to the west.", falling back to the room's message and then the
+/// [world's](crate::World::no_exit_message) one.
+///
+/// Callbacks can be registered to run when the player enters or leaves the room, or every turn they spend in it.
 pub struct Room {
+    /// An optional name for the room, such as "Grand Hall".
+    pub name: Option<String>,
     /// A description of the room.
-    pub description: String,
+    pub description: Description,
+    /// An optional brief description, shown when returning to the room.
+    pub short_description: Option<Description>,
+    /// Whether the player has visited the room.
+    pub visited: bool,
+    /// Small details of the room, only shown when it is fully lit.
+    pub details: Option<Description>,
+    /// What the player hears when they listen in the room.
+    pub sound: Option<Description>,
+    /// What the player hears of the room from an adjacent room, such as "muffled music".
+    pub distant_sound: Option<Description>,
+    /// What the player smells in the room.
+    pub smell: Option<Description>,
+    /// The template shown when the player tries to go in a direction without an exit, unless the direction has its own.
+    pub no_exit_message: Option<String>,
+    /// The templates shown when the player tries to go in a specific direction without an exit.
+    pub no_exit_messages: BTreeMap<Direction, String>,
+    /// The light level of the room without any light sources.
+    pub light: LightLevel,
+    /// Tags of the room, such as "underground" or "manor".
+    pub tags: BTreeSet<String>,
+    /// The [`ItemId`]s of the items in the room.
+    pub contents: Vec<ItemId>,
+    /// The [`Mirror`]s in the room.
+    pub mirrors: Vec<Mirror>,
+    /// Custom properties of the room, such as "is_outdoors" or "danger_level".
+    pub properties: Properties,
+    /// Game-specific data of any type, such as puzzle state or biome data.
+    pub extension: Option<Box<dyn PropertyValue>>,
+    /// Author-only [`Annotations`], never shown to the player.
+    pub annotations: Annotations,
+    /// The callbacks run when the player enters, leaves, or stays in the room.
+    pub hooks: RoomHooks,
 }
 
 impl Room {
-    /// Creates a new `Room` with the given `description`.
+    /// Creates a new `Room` with the given `description` and no name.
     pub fn new(description: String) -> Self {
-        Self { description }
+        Self {
+            name: None,
+            description: description.into(),
+            short_description: None,
+            visited: false,
+            details: None,
+            sound: None,
+            distant_sound: None,
+            smell: None,
+            no_exit_message: None,
+            no_exit_messages: BTreeMap::new(),
+            light: LightLevel::Lit,
+            tags: BTreeSet::new(),
+            contents: Vec::new(),
+            mirrors: Vec::new(),
+            properties: Properties::new(),
+            extension: None,
+            annotations: Annotations::new(),
+            hooks: RoomHooks::new(),
+        }
+    }
+
+    /// Creates a new `Room` with the given `name` and `description`.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::Room;
+    ///
+    /// let room = Room::new_with_name(
+    ///     "Grand Hall".into(),
+    ///     "A roaring fireplace dominates the far wall.".into(),
+    /// );
+    /// assert_eq!(room.name.as_deref(), Some("Grand Hall"));
+    /// assert_eq!(room.description.render(&World::new()), "A roaring fireplace dominates the far wall.");
+    /// ```
+    pub fn new_with_name(name: String, description: String) -> Self {
+        Self {
+            name: Some(name),
+            description: description.into(),
+            short_description: None,
+            visited: false,
+            details: None,
+            sound: None,
+            distant_sound: None,
+            smell: None,
+            no_exit_message: None,
+            no_exit_messages: BTreeMap::new(),
+            light: LightLevel::Lit,
+            tags: BTreeSet::new(),
+            contents: Vec::new(),
+            mirrors: Vec::new(),
+            properties: Properties::new(),
+            extension: None,
+            annotations: Annotations::new(),
+            hooks: RoomHooks::new(),
+        }
+    }
+
+    /// Creates a new `Room` whose description is generated from the state of the [`World`](crate::World).
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::Room;
+    ///
+    /// let room = Room::new_dynamic(|world| {
+    ///     format!("The map has {} rooms.", world.map.graph.node_count())
+    /// });
+    /// assert_eq!(room.description.render(&World::new()), "The map has 0 rooms.");
+    /// ```
+    pub fn new_dynamic(provider: impl Fn(&World) -> String + 'static) -> Self {
+        Self {
+            name: None,
+            description: Description::dynamic(provider),
+            short_description: None,
+            visited: false,
+            details: None,
+            sound: None,
+            distant_sound: None,
+            smell: None,
+            no_exit_message: None,
+            no_exit_messages: BTreeMap::new(),
+            light: LightLevel::Lit,
+            tags: BTreeSet::new(),
+            contents: Vec::new(),
+            mirrors: Vec::new(),
+            properties: Properties::new(),
+            extension: None,
+            annotations: Annotations::new(),
+            hooks: RoomHooks::new(),
+        }
+    }
+
+    /// Checks whether the player has visited this room.
+    pub fn is_visited(&self) -> bool {
+        self.visited
+    }
+
+    /// Sets a custom property of the room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::Room;
+    ///
+    /// let mut room = Room::new("A windswept courtyard.".into());
+    /// room.set("is_outdoors", true);
+    /// room.set("danger_level", 3u8);
+    ///
+    /// assert_eq!(room.get::<bool>("is_outdoors"), Some(&true));
+    /// assert_eq!(room.get::<u8>("danger_level"), Some(&3));
+    /// assert_eq!(room.get::<f32>("temperature"), None);
+    /// ```
+    pub fn set<T: PropertyValue>(&mut self, key: &str, value: T) {
+        self.properties.set(key, value);
+    }
+
+    /// Gets a custom property of the room if it exists and has type `T`.
+    pub fn get<T: PropertyValue>(&self, key: &str) -> Option<&T> {
+        self.properties.get(key)
+    }
+
+    /// Adds a tag to the room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::Room;
+    ///
+    /// let mut room = Room::new("A damp tunnel.".into());
+    /// room.add_tag("underground");
+    /// assert!(room.has_tag("underground"));
+    /// assert!(!room.has_tag("manor"));
+    /// ```
+    pub fn add_tag(&mut self, tag: &str) {
+        self.tags.insert(tag.into());
+    }
+
+    /// Removes a tag from the room, returning whether it had it.
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        self.tags.remove(tag)
+    }
+
+    /// Checks whether the room has a tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// Registers a callback run when the player enters the room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::Room;
+    ///
+    /// let mut world = World::new();
+    /// let mut library = Room::new("Shelves of forgotten books.".into());
+    /// library.on_enter(|world, _| world.output.say("Dust swirls as you step inside.".into()));
+    /// let library = world.map.add_room(library);
+    ///
+    /// world.run_enter_hooks(library);
+    /// assert_eq!(world.output.drain(), vec!["Dust swirls as you step inside."]);
+    /// ```
+    pub fn on_enter(&mut self, hook: impl Fn(&mut World, NodeIndex) + 'static) {
+        self.hooks.on_enter.push(Rc::new(hook));
+    }
+
+    /// Registers a callback run when the player leaves the room.
+    pub fn on_exit(&mut self, hook: impl Fn(&mut World, NodeIndex) + 'static) {
+        self.hooks.on_exit.push(Rc::new(hook));
+    }
+
+    /// Registers a callback run every turn while the player is in the room.
+    pub fn each_turn(&mut self, hook: impl Fn(&mut World, NodeIndex) + 'static) {
+        self.hooks.each_turn.push(Rc::new(hook));
+    }
+
+    /// Sets the extension of the room to a value of any type, replacing the previous one.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::map::Room;
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct Biome {
+    ///     humidity: u8,
+    /// }
+    ///
+    /// let mut room = Room::new("A steaming jungle clearing.".into());
+    /// room.set_extension(Biome { humidity: 90 });
+    /// assert_eq!(room.extension::<Biome>().unwrap().humidity, 90);
+    ///
+    /// room.extension_mut::<Biome>().unwrap().humidity = 70;
+    /// assert_eq!(room.extension::<Biome>().unwrap().humidity, 70);
+    /// assert!(room.extension::<String>().is_none());
+    /// ```
+    pub fn set_extension<T: PropertyValue>(&mut self, extension: T) {
+        self.extension = Some(Box::new(extension));
+    }
+
+    /// Gets the extension of the room if it has type `T`.
+    pub fn extension<T: PropertyValue>(&self) -> Option<&T> {
+        (**self.extension.as_ref()?).as_any().downcast_ref()
+    }
+
+    /// Gets a mutable reference to the extension of the room if it has type `T`.
+    pub fn extension_mut<T: PropertyValue>(&mut self) -> Option<&mut T> {
+        (**self.extension.as_mut()?).as_any_mut().downcast_mut()
     }
 }
@@ -0,0 +1,306 @@
+use crate::data::WorldData;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A version number of a game or a [`Mod`], such as "1.2.0".
+///
+/// Versions are compatible with the versions before them that have the same major version, as in semantic versioning.
+///
+/// # Examples
+/// ```
+/// use worldwright::mods::Version;
+///
+/// let version: Version = "1.4.2".parse().unwrap();
+/// assert!(version.satisfies(&"1.2".parse().unwrap()));
+/// assert!(!version.satisfies(&"1.5".parse().unwrap()));
+/// assert!(!version.satisfies(&"2.0".parse().unwrap()));
+/// assert_eq!(version.to_string(), "1.4.2");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    /// The major version, changed by incompatible changes.
+    pub major: u32,
+    /// The minor version, changed by additions.
+    pub minor: u32,
+    /// The patch version, changed by fixes.
+    pub patch: u32,
+}
+
+impl Version {
+    /// Creates a new `Version`.
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Checks whether this version can be used where `required` is asked for: it has the same major version and is at
+    /// least as recent.
+    pub fn satisfies(&self, required: &Version) -> bool {
+        self.major == required.major && self >= required
+    }
+}
+
+impl core::str::FromStr for Version {
+    type Err = String;
+
+    /// Parses a version such as "1.2.0", where the minor and patch versions can be left out.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parts = [0; 3];
+        for (index, part) in text.trim().split('.').enumerate() {
+            let number = parts
+                .get_mut(index)
+                .ok_or_else(|| format!("\"{text}\" isn't a version"))?;
+            *number = part
+                .parse()
+                .map_err(|_| format!("\"{text}\" isn't a version"))?;
+        }
+        Ok(Self::new(parts[0], parts[1], parts[2]))
+    }
+}
+
+impl core::fmt::Display for Version {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// A game or [`Mod`] that a mod needs, with the oldest compatible version.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dependency {
+    /// The name of the game or mod.
+    pub name: String,
+    /// The oldest version that works, newer versions with the same major version work too.
+    pub version: Version,
+}
+
+/// A package of community content laid over a game: new regions, NPCs, dialogue and verbs in [`WorldData`].
+///
+/// Mods are written in JSON with their name, version and dependencies, and the world data they add under `"world"`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Mod {
+    /// The unique name of the mod.
+    pub name: String,
+    /// The version of the mod.
+    pub version: Version,
+    /// The game and the other mods the mod needs, loaded before it.
+    #[serde(default)]
+    pub requires: Vec<Dependency>,
+    /// What the mod adds to or changes in the world, [overlaid](WorldData::overlay) on the game.
+    #[serde(default)]
+    pub world: WorldData,
+}
+
+impl Mod {
+    /// Loads a `Mod` from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// The reason [`Mods`] couldn't be loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModError {
+    /// Two mods have the same name.
+    Duplicate(String),
+    /// A mod needs a game or mod that isn't there. Holds the name of the mod and the missing dependency.
+    Missing(String, Dependency),
+    /// A mod needs another version of a game or mod. Holds the name of the mod, the dependency and the version found.
+    Incompatible(String, Dependency, Version),
+    /// Mods need each other. Holds their names.
+    Cycle(Vec<String>),
+}
+
+impl core::fmt::Display for ModError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ModError::Duplicate(name) => write!(f, "mod \"{name}\" is installed more than once"),
+            ModError::Missing(name, dependency) => write!(
+                f,
+                "mod \"{name}\" needs \"{}\" {}, which isn't installed",
+                dependency.name, dependency.version
+            ),
+            ModError::Incompatible(name, dependency, found) => write!(
+                f,
+                "mod \"{name}\" needs \"{}\" {}, but {found} is installed",
+                dependency.name, dependency.version
+            ),
+            ModError::Cycle(names) => write!(f, "mods {} need each other", names.join(", ")),
+        }
+    }
+}
+
+/// The [`Mod`]s installed for a game, which the runner lays over the game's [`WorldData`] at startup.
+///
+/// Mods are checked against each other and against the game, and applied after the mods they need.
+///
+/// # Examples
+/// ```
+/// use worldwright::data::WorldData;
+/// use worldwright::mods::{Mod, Mods};
+///
+/// let mut mods = Mods::new("manor", "1.3".parse().unwrap());
+/// mods.add(Mod::from_json(r#"{
+///     "name": "haunted-wing", "version": "2.0.0",
+///     "requires": [{ "name": "manor", "version": "1.0" }, { "name": "ghosts", "version": "1.1" }],
+///     "world": {
+///         "rooms": [{ "name": "Haunted Wing", "description": "Cold air drifts through the wing." }],
+///         "exits": [{ "from": "Foyer", "to": "Haunted Wing", "direction": "east" }]
+///     }
+/// }"#).unwrap());
+/// mods.add(Mod::from_json(r#"{
+///     "name": "ghosts", "version": "1.2.0",
+///     "world": { "verbs": [{ "words": ["boo"], "response": "Something shrieks in the dark." }] }
+/// }"#).unwrap());
+///
+/// let mut data = WorldData::from_json(r#"{
+///     "rooms": [{ "name": "Foyer", "description": "The dusty foyer of an old manor." }]
+/// }"#).unwrap();
+/// assert_eq!(mods.apply(&mut data), Ok(vec!["ghosts".to_string(), "haunted-wing".to_string()]));
+/// assert_eq!(data.rooms.len(), 2);
+/// assert_eq!(data.verbs.len(), 1);
+///
+/// let mut old_game = Mods::new("manor", "0.9".parse().unwrap());
+/// old_game.add(mods.get("haunted-wing").unwrap().clone());
+/// let errors: Vec<String> = old_game.resolve().unwrap_err().iter().map(ToString::to_string).collect();
+/// assert_eq!(
+///     errors,
+///     vec![
+///         "mod \"haunted-wing\" needs \"manor\" 1.0.0, but 0.9.0 is installed",
+///         "mod \"haunted-wing\" needs \"ghosts\" 1.1.0, which isn't installed",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Mods {
+    game: String,
+    game_version: Version,
+    mods: Vec<Mod>,
+}
+
+impl Mods {
+    /// Creates a new `Mods` for a game, with its name and version, without any mods.
+    pub fn new(game: &str, game_version: Version) -> Self {
+        Self {
+            game: game.into(),
+            game_version,
+            mods: Vec::new(),
+        }
+    }
+
+    /// Installs a mod.
+    pub fn add(&mut self, installed: Mod) {
+        self.mods.push(installed);
+    }
+
+    /// Loads a mod from a JSON file and installs it, following the includes of its world data from the file.
+    #[cfg(feature = "std")]
+    pub fn load(&mut self, path: impl AsRef<std::path::Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|error| error.to_string());
+        let mut loaded = json
+            .and_then(|json| Mod::from_json(&json).map_err(|error| error.to_string()))
+            .map_err(|error| format!("{}: {error}", path.display()))?;
+        let directory = path.parent().unwrap_or(std::path::Path::new(""));
+        let mut world = WorldData::new();
+        for include in core::mem::take(&mut loaded.world.include) {
+            world.overlay(WorldData::load(directory.join(include))?);
+        }
+        world.overlay(loaded.world);
+        loaded.world = world;
+        self.add(loaded);
+        Ok(())
+    }
+
+    /// Gets an installed mod by name.
+    pub fn get(&self, name: &str) -> Option<&Mod> {
+        self.mods.iter().find(|installed| installed.name == name)
+    }
+
+    /// Checks the dependencies of the installed mods and returns them in the order they are applied, every mod after
+    /// the mods it needs.
+    pub fn resolve(&self) -> Result<Vec<&Mod>, Vec<ModError>> {
+        let mut errors = Vec::new();
+        let mut by_name = BTreeMap::new();
+        for installed in &self.mods {
+            if by_name.insert(installed.name.as_str(), installed).is_some() {
+                errors.push(ModError::Duplicate(installed.name.clone()));
+            }
+        }
+        for installed in &self.mods {
+            for dependency in &installed.requires {
+                let found = if dependency.name == self.game {
+                    Some(self.game_version)
+                } else {
+                    by_name
+                        .get(dependency.name.as_str())
+                        .map(|other| other.version)
+                };
+                match found {
+                    None => errors.push(ModError::Missing(
+                        installed.name.clone(),
+                        dependency.clone(),
+                    )),
+                    Some(version) if !version.satisfies(&dependency.version) => errors.push(
+                        ModError::Incompatible(installed.name.clone(), dependency.clone(), version),
+                    ),
+                    Some(_) => {}
+                }
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        // Applies the mods whose dependencies have all been applied, in the order they were installed.
+        let mut order: Vec<&Mod> = Vec::new();
+        let mut pending: Vec<&Mod> = self.mods.iter().collect();
+        while !pending.is_empty() {
+            let ready = pending.iter().position(|installed| {
+                installed.requires.iter().all(|dependency| {
+                    dependency.name == self.game
+                        || order.iter().any(|applied| applied.name == dependency.name)
+                })
+            });
+            match ready {
+                Some(index) => order.push(pending.remove(index)),
+                None => {
+                    let names = pending.iter().map(|pending| pending.name.clone()).collect();
+                    return Err(vec![ModError::Cycle(names)]);
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// Lays the installed mods over the world data of the game, in [dependency order](Mods::resolve).
+    ///
+    /// Returns the names of the applied mods in order, or every problem found, in which case the data isn't changed.
+    pub fn apply(&self, data: &mut WorldData) -> Result<Vec<String>, Vec<ModError>> {
+        let order = self.resolve()?;
+        for installed in &order {
+            data.overlay(installed.world.clone());
+        }
+        Ok(order
+            .into_iter()
+            .map(|installed| installed.name.clone())
+            .collect())
+    }
+}
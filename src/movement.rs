@@ -0,0 +1,237 @@
+#[cfg(feature = "parser")]
+use crate::EnterError;
+use crate::World;
+use crate::item::{ItemId, Thing};
+use crate::map::{Direction, ExitId};
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+#[cfg(feature = "parser")]
+use crate::rules::Action;
+
+/// The verbs that only say the player is moving, such as "go" in "go back" or "head" in "head north".
+pub const MOVEMENT_VERBS: [&str; 6] = ["go", "walk", "head", "move", "run", "travel"];
+
+/// Where a movement phrase such as "go back" or "enter the boat" takes the player, as
+/// [resolved](World::resolve_movement) in the current state of the world.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MovementTarget {
+    /// Going through the exit in a direction.
+    Go(Direction),
+    /// Getting inside an [enterable](crate::item::Item::enterable) item in the room.
+    Enter(ItemId),
+    /// Going through an exit of the room found by [noun](crate::map::Map::exit_by_noun), such as a portal.
+    Exit(ExitId),
+    /// Getting out of the item the player is inside of.
+    Leave,
+}
+
+impl World {
+    /// Gets the direction of the room the player came from, following their
+    /// [breadcrumbs](crate::Player::breadcrumbs), if it is next to their room.
+    pub fn direction_back(&self) -> Option<Direction> {
+        let previous = *self.player.breadcrumbs.last()?;
+        self.map
+            .adjacent_rooms(self.player.current_room)
+            .into_iter()
+            .find(|(_, _, room_id)| *room_id == previous)
+            .map(|(_, direction, _)| direction)
+    }
+
+    /// Resolves a movement phrase into a [`MovementTarget`], or explains why it can't be.
+    ///
+    /// Besides directions, such as "north" or "go n", the phrases are:
+    /// - "back" or "retrace", which go back to the room the player came from;
+    /// - "exit", "leave" or "out", which get the player out of the item they are in, or otherwise take the only way
+    ///   out of the room, or the way back;
+    /// - "enter" or "in" followed by the name of an item the player can see, such as "enter the boat", or else of an
+    ///   exit of the room, such as "enter the portal".
+    ///
+    /// Phrases can start with a verb of movement, such as "go back".
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::item::Item;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::{RegularExit, Teleporter};
+    /// use worldwright::movement::MovementTarget;
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.new_room("A long hall.".into());
+    /// let study = world.map.new_room_in_direction(hall, Direction::North, Box::new(RegularExit), "A study.".into());
+    /// world.map.new_room_in_direction(hall, Direction::East, Box::new(RegularExit), "A kitchen.".into());
+    /// let tower = world.map.new_room("A lonely tower.".into());
+    /// world.map.connect_rooms_without_direction(study, tower, Box::new(Teleporter::new("shimmering portal".into())));
+    /// let wardrobe = world.items.add(Item::new("wardrobe".into(), "A tall wardrobe.".into()));
+    /// world.items[wardrobe].enterable = true;
+    /// world.map.place_item(study, wardrobe);
+    /// world.player.current_room = hall;
+    ///
+    /// assert_eq!(world.resolve_movement("go back"), Err("You haven't been anywhere else yet.".into()));
+    /// assert_eq!(world.resolve_movement("exit"), Err("Which way do you want to go?".into()));
+    /// assert_eq!(world.resolve_movement("go n"), Ok(MovementTarget::Go(Direction::North)));
+    ///
+    /// world.move_player(Direction::North).unwrap();
+    /// assert_eq!(world.resolve_movement("enter the wardrobe"), Ok(MovementTarget::Enter(wardrobe)));
+    /// assert_eq!(world.resolve_movement("enter the piano"), Err("You can't see any such thing.".into()));
+    /// let (portal, _) = world.map.exit_by_noun(study, "portal").unwrap();
+    /// assert_eq!(world.resolve_movement("enter the portal"), Ok(MovementTarget::Exit(portal)));
+    /// // The only way out of the study in a direction is south.
+    /// assert_eq!(world.resolve_movement("leave"), Ok(MovementTarget::Go(Direction::South)));
+    ///
+    /// world.enter(wardrobe).unwrap();
+    /// assert_eq!(world.resolve_movement("exit"), Ok(MovementTarget::Leave));
+    /// world.leave();
+    ///
+    /// world.move_player(Direction::South).unwrap();
+    /// world.move_player(Direction::East).unwrap();
+    /// assert_eq!(world.resolve_movement("retrace"), Ok(MovementTarget::Go(Direction::West)));
+    /// ```
+    pub fn resolve_movement(&self, phrase: &str) -> Result<MovementTarget, String> {
+        let lowercase = phrase.to_lowercase();
+        let mut words: Vec<&str> = lowercase
+            .split_whitespace()
+            .filter(|word| !matches!(*word, "a" | "an" | "the"))
+            .collect();
+        if words
+            .first()
+            .is_some_and(|word| MOVEMENT_VERBS.contains(word))
+        {
+            words.remove(0);
+        }
+        let Some((&first, rest)) = words.split_first() else {
+            return Err("Where do you want to go?".into());
+        };
+        let back = || {
+            if self.player.breadcrumbs.is_empty() {
+                return Err(String::from("You haven't been anywhere else yet."));
+            }
+            self.direction_back()
+                .map(MovementTarget::Go)
+                .ok_or_else(|| "You can't find the way back.".into())
+        };
+
+        match first {
+            "back" | "retrace" => back(),
+            "exit" | "leave" | "out" if self.player.inside.is_some() => Ok(MovementTarget::Leave),
+            "exit" | "leave" | "out" => {
+                if let [(_, direction, _)] = self.map.adjacent_rooms(self.player.current_room)[..] {
+                    return Ok(MovementTarget::Go(direction));
+                }
+                back().map_err(|_| "Which way do you want to go?".into())
+            }
+            "enter" | "in" | "into" if rest.is_empty() => Err("What do you want to enter?".into()),
+            "enter" | "in" | "into" => {
+                let name = rest.join(" ");
+                self.scope(self.player.current_room)
+                    .things
+                    .into_iter()
+                    .find_map(|thing| match thing {
+                        Thing::Item(item) if self.items[item].name.eq_ignore_ascii_case(&name) => {
+                            Some(MovementTarget::Enter(item))
+                        }
+                        _ => None,
+                    })
+                    .or_else(|| {
+                        self.map
+                            .exit_by_noun(self.player.current_room, &name)
+                            .map(|(exit_id, _)| MovementTarget::Exit(exit_id))
+                    })
+                    .ok_or_else(|| "You can't see any such thing.".into())
+            }
+            _ => words
+                .join(" ")
+                .parse()
+                .map(MovementTarget::Go)
+                .map_err(|_| "You can't go that way.".into()),
+        }
+    }
+}
+
+/// A command handler for movement phrases, such as "go back", "retrace", "exit", "leave" and "enter the boat".
+///
+/// Register it under every verb it should understand. The phrase is [resolved](World::resolve_movement) from the
+/// whole command, then the player goes, enters or leaves, and a turn passes if they did.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+/// use worldwright::movement::movement_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["go", "walk"], movement_command);
+/// world.parser.add_verb(&["retrace"], movement_command);
+/// let hall = world.map.new_room_with_name("Hall".into(), "A grand hall.".into());
+/// let garden = world.map.new_room_in_direction(hall, Direction::West, Box::new(RegularExit), "Roses.".into());
+/// world.map.graph[garden].name = Some("Garden".into());
+/// world.player.current_room = hall;
+///
+/// world.execute("walk west").unwrap();
+/// world.execute("go back").unwrap();
+/// assert_eq!(world.player.current_room, hall);
+/// world.execute("retrace").unwrap();
+/// assert_eq!(
+///     world.output.drain(),
+///     vec!["Garden\nRoses.", "Hall\nA grand hall.", "You haven't been anywhere else yet."]
+/// );
+/// assert_eq!(world.turn, 2);
+/// ```
+#[cfg(feature = "parser")]
+pub fn movement_command(world: &mut World, command: &Command) {
+    let phrase = core::iter::once(&command.verb)
+        .chain(&command.words)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let target = match world.resolve_movement(&phrase) {
+        Ok(target) => target,
+        Err(message) => {
+            world.output.say(message);
+            return;
+        }
+    };
+
+    let outcome = match target {
+        MovementTarget::Go(direction) => world.perform(Action::Go(direction)).map(|mut report| {
+            report.push(world.describe_room(world.player.current_room).to_string());
+            report
+        }),
+        MovementTarget::Exit(exit_id) => {
+            world.perform(Action::GoThrough(exit_id)).map(|mut report| {
+                report.push(world.describe_room(world.player.current_room).to_string());
+                report
+            })
+        }
+        MovementTarget::Enter(item) => match world.enter(item) {
+            Ok(()) => Ok(vec![format!(
+                "You get into the {}.",
+                world.items[item].name
+            )]),
+            Err(EnterError::NotHere) => Err("You can't see any such thing.".into()),
+            Err(EnterError::NotEnterable) => Err("That's not something you can enter.".into()),
+        },
+        MovementTarget::Leave => match world.leave() {
+            Some(item) => Ok(vec![format!(
+                "You get out of the {}.",
+                world.items[item].name
+            )]),
+            None => Err("You aren't in anything.".into()),
+        },
+    };
+    let messages = match outcome {
+        Ok(messages) => messages,
+        Err(message) => {
+            world.output.say(message);
+            return;
+        }
+    };
+    for message in messages {
+        world.output.say(message);
+    }
+    for message in world.advance_turn().messages {
+        world.output.say(message);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::map::{Map, Region};
+use crate::prelude::*;
+use petgraph::prelude::NodeIndex;
+
+/// When a queued narration is shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NarrationTrigger {
+    /// After the given number of turns.
+    AfterTurns(u32),
+    /// When the player next enters a room of the [`Region`].
+    OnEnter(Region),
+}
+
+/// A message waiting in the [`NarrationQueue`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Narration {
+    /// When the message is shown.
+    pub trigger: NarrationTrigger,
+    /// The text of the message.
+    pub text: String,
+}
+
+/// A queue of background narration shown later in the game.
+///
+/// Messages are queued to appear a number of turns later or when the player next enters a [`Region`],
+/// independently of any other game logic. Each message is shown once.
+///
+/// The game loop calls [`tick`](NarrationQueue::tick) every turn, and the movement code calls
+/// [`entered`](NarrationQueue::entered) whenever the player enters a room.
+///
+/// # Examples
+/// ```
+/// use worldwright::map::{Map, Region};
+/// use worldwright::narration::NarrationQueue;
+///
+/// let mut map = Map::new();
+/// let gate = map.new_room("The old gate.".into());
+///
+/// let mut narration = NarrationQueue::new();
+/// narration.after_turns(2, "A bell tolls in the distance.".into());
+/// narration.on_enter(Region::from(gate), "As you cross the threshold, you remember the warning...".into());
+///
+/// assert!(narration.tick().is_empty());
+/// assert_eq!(narration.tick(), vec!["A bell tolls in the distance."]);
+///
+/// assert_eq!(narration.entered(&map, gate).len(), 1);
+/// assert!(narration.entered(&map, gate).is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NarrationQueue {
+    pending: Vec<Narration>,
+}
+
+impl NarrationQueue {
+    /// Creates a new, empty `NarrationQueue`.
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+        }
+    }
+
+    /// Queues a message to be shown after the given number of turns.
+    pub fn after_turns(&mut self, turns: u32, text: String) {
+        self.pending.push(Narration {
+            trigger: NarrationTrigger::AfterTurns(turns),
+            text,
+        });
+    }
+
+    /// Queues a message to be shown when the player next enters a room of the [`Region`].
+    pub fn on_enter(&mut self, region: Region, text: String) {
+        self.pending.push(Narration {
+            trigger: NarrationTrigger::OnEnter(region),
+            text,
+        });
+    }
+
+    /// Returns the messages that have not been shown yet.
+    pub fn pending(&self) -> &[Narration] {
+        &self.pending
+    }
+
+    /// Advances the queue by one turn and returns the messages that are due.
+    pub fn tick(&mut self) -> Vec<String> {
+        for narration in &mut self.pending {
+            if let NarrationTrigger::AfterTurns(turns) = &mut narration.trigger {
+                *turns = turns.saturating_sub(1);
+            }
+        }
+        self.take(|trigger| matches!(trigger, NarrationTrigger::AfterTurns(0)))
+    }
+
+    /// Reports that the player entered a room and returns the messages for it.
+    pub fn entered(&mut self, map: &Map, room_id: NodeIndex) -> Vec<String> {
+        self.take(
+            |trigger| matches!(trigger, NarrationTrigger::OnEnter(region) if region.contains(map, room_id)),
+        )
+    }
+
+    fn take(&mut self, is_due: impl Fn(&NarrationTrigger) -> bool) -> Vec<String> {
+        let mut due = Vec::new();
+        self.pending.retain(|narration| {
+            if is_due(&narration.trigger) {
+                due.push(narration.text.clone());
+                false
+            } else {
+                true
+            }
+        });
+        due
+    }
+}
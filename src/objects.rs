@@ -0,0 +1,150 @@
+use crate::World;
+use crate::item::{ItemId, Thing};
+use crate::map::Direction;
+use crate::parser::Command;
+use crate::prelude::*;
+use crate::rules::Action;
+
+/// The words that may come between an object and a direction, such as "to" in "throw the ball to the north".
+const LINKING_WORDS: [&str; 5] = ["to", "into", "across", "over", "through"];
+
+/// Splits the words of a command such as "throw ball north" into the item, carried or seen by the player, and the
+/// direction.
+fn item_and_direction(world: &World, command: &Command) -> Result<(ItemId, Direction), String> {
+    let Some((last, rest)) = command.words.split_last() else {
+        return Err(format!("What do you want to {}?", command.verb));
+    };
+    let Ok(direction) = last.parse::<Direction>() else {
+        return Err(format!("Which way do you want to {} it?", command.verb));
+    };
+    let mut rest = rest;
+    while let Some((last, before)) = rest.split_last()
+        && LINKING_WORDS.contains(&last.as_str())
+    {
+        rest = before;
+    }
+    if rest.is_empty() {
+        return Err(format!("What do you want to {}?", command.verb));
+    }
+    let name = rest.join(" ");
+    if let Some(&item) = world
+        .player
+        .inventory
+        .iter()
+        .find(|&&item| world.items[item].name.eq_ignore_ascii_case(&name))
+    {
+        return Ok((item, direction));
+    }
+    match world.find_thing(world.player.current_room, &name) {
+        Some(Thing::Item(item)) => Ok((item, direction)),
+        Some(_) => Err(format!("You can't {} that.", command.verb)),
+        None => Err("You can't see any such thing.".into()),
+    }
+}
+
+/// Performs an action and says its report, and a turn passes if it happened.
+fn perform_and_report(world: &mut World, action: Action) {
+    match world.perform(action) {
+        Ok(report) => {
+            for message in report {
+                world.output.say(message);
+            }
+            for message in world.advance_turn().messages {
+                world.output.say(message);
+            }
+        }
+        Err(message) => world.output.say(message),
+    }
+}
+
+/// A command handler that pushes an item in a direction, with the player following it, such as "push the crate
+/// north", as an [`Action::Push`]. The room the player arrives in is described.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+/// use worldwright::objects::push_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["push", "shove"], push_command);
+/// let barn = world.map.new_room_with_name("Barn".into(), "A creaky barn.".into());
+/// let yard = world.map.new_room_in_direction(barn, Direction::South, Box::new(RegularExit), "Mud everywhere.".into());
+/// world.map.graph[yard].name = Some("Yard".into());
+/// let mut crate_ = Item::new("crate".into(), "A heavy wooden crate.".into());
+/// crate_.pushable = true;
+/// let crate_ = world.items.add(crate_);
+/// world.map.place_item(barn, crate_);
+/// world.player.current_room = barn;
+///
+/// world.execute("push crate west").unwrap();
+/// world.execute("shove the crate south").unwrap();
+/// assert_eq!(
+///     world.output.drain(),
+///     vec!["You can't go that way.", "You push the crate south.", "Yard\nMud everywhere.\nYou can see a crate here."]
+/// );
+/// assert_eq!(world.map.find_item(crate_), Some(yard));
+/// ```
+pub fn push_command(world: &mut World, command: &Command) {
+    let (item, direction) = match item_and_direction(world, command) {
+        Ok(target) => target,
+        Err(message) => {
+            world.output.say(message);
+            return;
+        }
+    };
+    let report = match world.perform(Action::Push(item, direction)) {
+        Ok(report) => report,
+        Err(message) => {
+            world.output.say(message);
+            return;
+        }
+    };
+    for message in report {
+        world.output.say(message);
+    }
+    world
+        .output
+        .say(world.describe_room(world.player.current_room).to_string());
+    for message in world.advance_turn().messages {
+        world.output.say(message);
+    }
+}
+
+/// A command handler that throws a carried item in a direction, such as "throw the rope across east", as an
+/// [`Action::Throw`].
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::Chasm;
+/// use worldwright::objects::throw_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["throw", "toss"], throw_command);
+/// let ledge = world.map.new_room("A narrow ledge.".into());
+/// let far_side = world.map.new_room_in_direction(ledge, Direction::East, Box::new(Chasm), "The far side.".into());
+/// let rope = world.items.add(Item::new("rope".into(), "A coil of rope.".into()));
+/// world.player.current_room = ledge;
+/// world.player.inventory.push(rope);
+///
+/// world.execute("throw the rope").unwrap();
+/// world.execute("throw the rope west").unwrap();
+/// world.execute("toss the rope across east").unwrap();
+/// assert_eq!(
+///     world.output.drain(),
+///     vec!["Which way do you want to throw it?", "You can't throw anything that way.", "You throw the rope east."]
+/// );
+/// assert_eq!(world.map.find_item(rope), Some(far_side));
+/// assert_eq!(world.turn, 1);
+/// ```
+pub fn throw_command(world: &mut World, command: &Command) {
+    match item_and_direction(world, command) {
+        Ok((item, direction)) => perform_and_report(world, Action::Throw(item, direction)),
+        Err(message) => world.output.say(message),
+    }
+}
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+/// Messages produced by the game for the player, waiting to be shown by the front end.
+///
+/// # Examples
+/// ```
+/// use worldwright::Output;
+///
+/// let mut output = Output::new();
+/// output.say("The clock strikes midnight.".into());
+/// assert_eq!(output.drain(), vec!["The clock strikes midnight."]);
+/// assert!(output.is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Output {
+    messages: Vec<String>,
+}
+
+impl Output {
+    /// Creates a new, empty `Output`.
+    pub fn new() -> Self {
+        Self {
+            messages: Vec::new(),
+        }
+    }
+
+    /// Adds a message for the player.
+    pub fn say(&mut self, message: String) {
+        self.messages.push(message);
+    }
+
+    /// Returns the messages waiting to be shown.
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    /// Checks whether there are no messages waiting.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Removes and returns every waiting message, oldest first.
+    pub fn drain(&mut self) -> Vec<String> {
+        core::mem::take(&mut self.messages)
+    }
+}
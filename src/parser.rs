@@ -0,0 +1,318 @@
+use crate::World;
+use crate::movement::MOVEMENT_VERBS;
+use crate::prelude::*;
+use alloc::rc::Rc;
+
+/// A command typed by the player, split into a verb and the words after it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Command {
+    /// The verb of the command. Synonyms are replaced with the first word the verb was registered with.
+    pub verb: String,
+    /// The words after the verb, in lowercase and without articles.
+    pub words: Vec<String>,
+}
+
+impl Command {
+    /// Returns the words after the verb joined by spaces, or `None` if there are none.
+    pub fn noun(&self) -> Option<String> {
+        if self.words.is_empty() {
+            None
+        } else {
+            Some(self.words.join(" "))
+        }
+    }
+}
+
+/// The reason the [`Parser`] couldn't understand a command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The command was empty.
+    Empty,
+    /// The first word of the command isn't a known verb.
+    UnknownVerb(String),
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "I beg your pardon?"),
+            ParseError::UnknownVerb(verb) => {
+                write!(f, "I don't know the word \"{verb}\".")
+            }
+        }
+    }
+}
+
+/// A function that carries out a [`Command`] with mutable access to the [`World`].
+pub type VerbHandler = Rc<dyn Fn(&mut World, &Command)>;
+
+/// Identifies a verb registered in a [`Parser`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VerbId(usize);
+
+#[derive(Clone)]
+struct Verb {
+    words: Vec<String>,
+    handler: VerbHandler,
+    known: bool,
+}
+
+/// Turns the player's input into [`Command`]s and routes them to the handlers of their verbs.
+///
+/// Verbs can be learnable: magic words like "xyzzy" aren't understood until the player learns them,
+/// after which they are parsed and routed like any other verb.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::parser::ParseError;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["take", "get"], |world, command| {
+///     world.output.say(format!("You take the {}.", command.noun().unwrap()));
+/// });
+/// world.parser.add_learnable_verb(&["xyzzy"], |world, _| {
+///     world.output.say("A hollow voice says \"Fool.\"".into());
+/// });
+///
+/// world.execute("GET the lamp").unwrap();
+/// assert_eq!(world.output.drain(), vec!["You take the lamp."]);
+///
+/// assert_eq!(world.execute("xyzzy"), Err(ParseError::UnknownVerb("xyzzy".into())));
+/// world.parser.learn("xyzzy");
+/// world.execute("xyzzy").unwrap();
+/// assert_eq!(world.output.drain(), vec!["A hollow voice says \"Fool.\""]);
+/// ```
+#[derive(Clone, Default)]
+pub struct Parser {
+    /// Whether the input is [normalized](normalize_spoken) as speech before being parsed, for voice front ends.
+    pub spoken: bool,
+    verbs: Vec<Verb>,
+}
+
+impl Parser {
+    /// Creates a new `Parser` without any verbs.
+    pub fn new() -> Self {
+        Self {
+            spoken: false,
+            verbs: Vec::new(),
+        }
+    }
+
+    /// Registers a verb with its synonyms and the handler that carries it out.
+    ///
+    /// The first word is used as the verb of parsed [`Command`]s.
+    pub fn add_verb(
+        &mut self,
+        words: &[&str],
+        handler: impl Fn(&mut World, &Command) + 'static,
+    ) -> VerbId {
+        self.push_verb(words, Rc::new(handler), true)
+    }
+
+    /// Registers a verb that isn't understood until the player [learns](Parser::learn) it.
+    pub fn add_learnable_verb(
+        &mut self,
+        words: &[&str],
+        handler: impl Fn(&mut World, &Command) + 'static,
+    ) -> VerbId {
+        self.push_verb(words, Rc::new(handler), false)
+    }
+
+    fn push_verb(&mut self, words: &[&str], handler: VerbHandler, known: bool) -> VerbId {
+        self.verbs.push(Verb {
+            words: words.iter().map(|word| word.to_lowercase()).collect(),
+            handler,
+            known,
+        });
+        VerbId(self.verbs.len() - 1)
+    }
+
+    /// Teaches the player a learnable verb by one of its words.
+    ///
+    /// Returns `true` if the verb exists and wasn't known before.
+    pub fn learn(&mut self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        match self
+            .verbs
+            .iter_mut()
+            .find(|verb| verb.words.contains(&word))
+        {
+            Some(verb) if !verb.known => {
+                verb.known = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks whether a word is a verb the player knows.
+    pub fn is_known(&self, word: &str) -> bool {
+        self.find_verb(&word.to_lowercase()).is_some()
+    }
+
+    /// Parses the player's input into a [`Command`].
+    ///
+    /// The input is lowercased and split on whitespace, and the articles "a", "an" and "the" are dropped.
+    /// If the parser is [spoken](Parser::spoken), the input is [normalized](normalize_spoken) first.
+    pub fn parse(&self, input: &str) -> Result<Command, ParseError> {
+        let normalized;
+        let input = if self.spoken {
+            normalized = normalize_spoken(input);
+            &normalized
+        } else {
+            input
+        };
+        let mut words = input
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .filter(|word| !matches!(word.as_str(), "a" | "an" | "the"));
+        let first = words.next().ok_or(ParseError::Empty)?;
+        let verb = self
+            .find_verb(&first)
+            .ok_or_else(|| ParseError::UnknownVerb(first.clone()))?;
+        Ok(Command {
+            verb: verb.words[0].clone(),
+            words: words.collect(),
+        })
+    }
+
+    /// Returns an iterator over the first word of every registered verb, including learnable ones.
+    pub fn verbs(&self) -> impl Iterator<Item = &str> {
+        self.verbs.iter().map(|verb| verb.words[0].as_str())
+    }
+
+    /// Gets the handler of the verb of a [`Command`].
+    pub fn handler(&self, command: &Command) -> Option<VerbHandler> {
+        self.find_verb(&command.verb)
+            .map(|verb| verb.handler.clone())
+    }
+
+    fn find_verb(&self, word: &str) -> Option<&Verb> {
+        self.verbs
+            .iter()
+            .find(|verb| verb.known && verb.words.iter().any(|verb_word| verb_word == word))
+    }
+}
+
+impl core::fmt::Debug for Parser {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for verb in &self.verbs {
+            list.entry(&(&verb.words, verb.known));
+        }
+        list.finish()
+    }
+}
+
+const FILLER_WORDS: [&str; 10] = [
+    "um", "umm", "uh", "uhm", "er", "erm", "hmm", "please", "okay", "ok",
+];
+
+/// Normalizes a command transcribed from speech, so that the [`Parser`] understands it like a typed one.
+///
+/// Punctuation and filler words such as "um" and "please" are dropped, numbers spoken as words are written with digits,
+/// and spoken movement such as "go to the north" is shortened to the direction.
+///
+/// # Examples
+/// ```
+/// use worldwright::parser::normalize_spoken;
+///
+/// assert_eq!(normalize_spoken("Um, go to the north, please."), "north");
+/// assert_eq!(normalize_spoken("head upstairs"), "up");
+/// assert_eq!(normalize_spoken("Wait for twenty five minutes"), "wait for 25 minutes");
+/// assert_eq!(normalize_spoken("uh take the lamp"), "take the lamp");
+/// ```
+pub fn normalize_spoken(input: &str) -> String {
+    let lowercase = input.to_lowercase();
+    let words: Vec<&str> = lowercase
+        .split(|c: char| c.is_whitespace() || (c.is_ascii_punctuation() && c != '-' && c != '\''))
+        .filter(|word| !word.is_empty() && !FILLER_WORDS.contains(word))
+        .collect();
+
+    let mut normalized: Vec<String> = Vec::new();
+    let mut index = 0;
+    while index < words.len() {
+        let Some(number) = spoken_number(words[index]) else {
+            normalized.push(words[index].into());
+            index += 1;
+            continue;
+        };
+        // "twenty five" is one number.
+        match words.get(index + 1).and_then(|&next| spoken_number(next)) {
+            Some(ones) if number >= 20 && number.is_multiple_of(10) && ones < 10 => {
+                normalized.push((number + ones).to_string());
+                index += 2;
+            }
+            _ => {
+                normalized.push(number.to_string());
+                index += 1;
+            }
+        }
+    }
+
+    let rest: Vec<&str> = normalized
+        .iter()
+        .map(String::as_str)
+        .skip_while(|word| MOVEMENT_VERBS.contains(word))
+        .skip_while(|word| matches!(*word, "to" | "towards" | "the"))
+        .collect();
+    if rest.len() < normalized.len()
+        && let [direction] = rest.as_slice()
+        && let Some(direction) = spoken_direction(direction)
+    {
+        return direction.into();
+    }
+    normalized.join(" ")
+}
+
+/// Understands a direction spoken on its own, such as "north" or "upstairs".
+fn spoken_direction(word: &str) -> Option<&'static str> {
+    Some(match word {
+        "north" => "north",
+        "east" => "east",
+        "south" => "south",
+        "west" => "west",
+        "up" | "upstairs" => "up",
+        "down" | "downstairs" => "down",
+        _ => return None,
+    })
+}
+
+/// Understands a number spoken as a word, such as "seven", "forty" or "forty-two".
+fn spoken_number(word: &str) -> Option<u32> {
+    const NUMBERS: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 8] = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    if let Some((tens, ones)) = word.split_once('-') {
+        return Some(spoken_number(tens)? + spoken_number(ones)?);
+    }
+    if let Some(number) = NUMBERS.iter().position(|&number| number == word) {
+        return Some(number as u32);
+    }
+    TENS.iter()
+        .position(|&tens| tens == word)
+        .map(|index| (index as u32 + 2) * 10)
+}
@@ -1,5 +1,217 @@
+use crate::item::{ItemId, Items};
+use crate::map::{ExitId, PassageError};
+use crate::prelude::*;
+use alloc::collections::{BTreeMap, BTreeSet};
+use petgraph::prelude::NodeIndex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The player of the game.
+///
+/// The player is in a room, and may also be inside an [enterable](crate::item::Item::enterable) item in that room,
+/// such as a wardrobe or a cart.
+///
+/// A [default](Player::default) player starts in the first room of the map. Until the map has rooms, the
+/// [`World`](crate::World) methods acting in the player's room fail instead of panicking.
+///
+/// # Examples
+/// ```
+/// use worldwright::item::Item;
+/// use worldwright::map::Direction;
+/// use worldwright::{EnterError, World};
+///
+/// let mut world = World::new();
+/// let crate_ = world.items.add(Item::new("crate".into(), "A wooden crate.".into()));
+///
+/// assert_eq!(world.move_player(Direction::North).map(|movement| movement.to), Err("You can't go that way.".into()));
+/// assert_eq!(world.enter(crate_), Err(EnterError::NotHere));
+/// ```
 #[derive(Clone, Debug)]
 pub struct Player {
+    /// The name of the player.
     pub name: String,
-    pub current_room: petgraph::prelude::NodeIndex,
+    /// The room the player is in.
+    pub current_room: NodeIndex,
+    /// The enterable item the player is inside of, if any.
+    pub inside: Option<ItemId>,
+    /// The items the player is carrying.
+    pub inventory: Vec<ItemId>,
+    /// The most total [weight](crate::item::Item::weight) the player can carry, or `None` if it is unlimited.
+    pub capacity: Option<u32>,
+    /// The player's [`Stats`], such as strength or lockpicking.
+    pub stats: Stats,
+    /// The roles of the player, such as "staff", which let them into [restricted](crate::access::AccessControl) rooms and exits.
+    pub roles: BTreeSet<String>,
+    /// The rooms the player came from, the most recent last, so they can [go back](crate::World::direction_back).
+    ///
+    /// Going back to the last room takes it off the trail. Only the last 100 rooms are kept.
+    pub breadcrumbs: Vec<NodeIndex>,
+}
+
+/// The most rooms kept in the [`breadcrumbs`](Player::breadcrumbs) of the [`Player`].
+const BREADCRUMB_LIMIT: usize = 100;
+
+impl Player {
+    /// Creates a new `Player` with the given `name` in the given room.
+    pub fn new(name: String, current_room: NodeIndex) -> Self {
+        Self {
+            name,
+            current_room,
+            inside: None,
+            inventory: Vec::new(),
+            capacity: None,
+            stats: Stats::new(),
+            roles: BTreeSet::new(),
+            breadcrumbs: Vec::new(),
+        }
+    }
+
+    /// Leaves a breadcrumb for a move from one room to another, or takes the last one back if the player went back to it.
+    pub fn leave_breadcrumb(&mut self, from: NodeIndex, to: NodeIndex) {
+        if self.breadcrumbs.last() == Some(&to) {
+            self.breadcrumbs.pop();
+            return;
+        }
+        if self.breadcrumbs.len() == BREADCRUMB_LIMIT {
+            self.breadcrumbs.remove(0);
+        }
+        self.breadcrumbs.push(from);
+    }
+
+    /// Checks whether the player is carrying the given item.
+    pub fn carries(&self, item: ItemId) -> bool {
+        self.inventory.contains(&item)
+    }
+
+    /// Adds up the [weight](crate::item::Item::weight) of every item the player is carrying, including what is in or on them.
+    pub fn carried_weight(&self, items: &Items) -> u32 {
+        self.inventory
+            .iter()
+            .map(|&item| items.total_weight(item))
+            .sum()
+    }
+}
+
+/// What happened when the player moved, returned by [`World::move_player`](crate::World::move_player).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Movement {
+    /// The room the player left.
+    pub from: NodeIndex,
+    /// The room the player arrived in.
+    pub to: NodeIndex,
+    /// The exit the player went through.
+    pub exit: ExitId,
+    /// The messages shown on the way, such as the [passage message](crate::map::ExitType::passage_message) of the exit
+    /// and the [narration](crate::narration::NarrationQueue::on_enter) of the room the player arrived in.
+    pub messages: Vec<String>,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self::new("player".into(), NodeIndex::new(0))
+    }
+}
+
+/// The numeric stats or skills of the [`Player`], such as "strength" or "lockpicking", used by skill checks.
+///
+/// Stats that were never set are `0`.
+///
+/// # Examples
+/// ```
+/// use worldwright::Stats;
+///
+/// let mut stats = Stats::new();
+/// stats.set("strength", 12);
+/// stats.modify("strength", -2);
+/// assert_eq!(stats.get("strength"), 10);
+/// assert_eq!(stats.get("charisma"), 0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Stats {
+    values: BTreeMap<String, i32>,
+}
+
+impl Stats {
+    /// Creates a new `Stats` where every stat is `0`.
+    pub fn new() -> Self {
+        Self {
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Gets the value of a stat.
+    pub fn get(&self, stat: &str) -> i32 {
+        self.values.get(stat).copied().unwrap_or(0)
+    }
+
+    /// Sets the value of a stat.
+    pub fn set(&mut self, stat: &str, value: i32) {
+        self.values.insert(stat.into(), value);
+    }
+
+    /// Adds `amount` to a stat, which may be negative.
+    pub fn modify(&mut self, stat: &str, amount: i32) {
+        *self.values.entry(stat.into()).or_default() += amount;
+    }
+
+    /// Returns an iterator over every stat that was set and its value.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i32)> {
+        self.values
+            .iter()
+            .map(|(stat, &value)| (stat.as_str(), value))
+    }
+}
+
+/// The reason the player can't enter an item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnterError {
+    /// The item isn't in the player's room.
+    NotHere,
+    /// The item can't be entered.
+    NotEnterable,
+}
+
+/// The reason the player can't take an item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TakeError {
+    /// The item isn't in the player's room.
+    NotHere,
+    /// The item can't be taken.
+    NotTakeable,
+    /// The item would make the player carry more than their capacity.
+    TooHeavy,
+}
+
+/// The reason the player can't throw an item in a direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThrowError {
+    /// The player isn't carrying the item.
+    NotCarried,
+    /// The item can't be thrown in that direction.
+    Passage(PassageError),
+}
+
+/// The reason the player can't push an item in a direction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PushError {
+    /// The item isn't in the player's room.
+    NotHere,
+    /// The item can't be pushed.
+    NotPushable,
+    /// The item can't be pushed through the exit in that direction.
+    Passage(PassageError),
+    /// The player can't go that way, with the reason, such as "The door is locked."
+    Refused(String),
+}
+
+/// The reason the player can't ride in a direction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RideError {
+    /// The player isn't inside a vehicle.
+    NotInVehicle,
+    /// The vehicle can't go in that direction.
+    Passage(PassageError),
+    /// The player can't go that way, with the reason, such as "The door is locked."
+    Refused(String),
 }
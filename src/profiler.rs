@@ -0,0 +1,246 @@
+#[cfg(feature = "parser")]
+use crate::World;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use alloc::collections::{BTreeMap, VecDeque};
+use core::cell::RefCell;
+use core::time::Duration;
+
+/// A part of the engine whose time is measured by the [`Profiler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Subsystem {
+    /// Parsing the player's commands.
+    Parsing,
+    /// Carrying out commands and the game's rules.
+    Rules,
+    /// NPC behavior, such as patrols and barks.
+    NpcAi,
+    /// Generating descriptions.
+    Descriptions,
+}
+
+impl core::fmt::Display for Subsystem {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = match self {
+            Subsystem::Parsing => "parsing",
+            Subsystem::Rules => "rules",
+            Subsystem::NpcAi => "NPC AI",
+            Subsystem::Descriptions => "descriptions",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A measurement started by [`Profiler::start`].
+///
+/// Without the `std` feature there is no clock, so nothing is measured.
+#[derive(Clone, Copy, Debug)]
+pub struct Stopwatch {
+    /// When the measurement started, and how many measurements were running then, including it.
+    #[cfg(feature = "std")]
+    start: Option<(std::time::Instant, usize)>,
+}
+
+/// Measures the time spent in each [`Subsystem`] over the last turns, to find per-turn hotspots in large games.
+///
+/// Time is recorded through a shared reference, so code that only has read access to the [`World`] can be measured.
+/// Time is only measured with the `std` feature, but it can always be [recorded](Profiler::record) by hand.
+/// The game loop should call [`end_turn`](Profiler::end_turn) at the end of every turn.
+///
+/// Measurements can be nested, such as descriptions generated while carrying out a command. The time of a nested
+/// measurement only counts towards its own subsystem, and is left out of the time of the one around it.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use worldwright::profiler::{Profiler, Subsystem};
+///
+/// let mut profiler = Profiler::new(10);
+/// profiler.enabled = true;
+///
+/// profiler.record(Subsystem::Parsing, Duration::from_millis(2));
+/// profiler.record(Subsystem::Rules, Duration::from_millis(6));
+/// profiler.end_turn();
+/// profiler.record(Subsystem::Parsing, Duration::from_millis(4));
+/// profiler.end_turn();
+///
+/// let report = profiler.report(10);
+/// assert_eq!(report.turns, 2);
+/// assert_eq!(report.totals[&Subsystem::Parsing], Duration::from_millis(6));
+/// assert_eq!(report.totals[&Subsystem::Rules], Duration::from_millis(6));
+///
+/// # #[cfg(feature = "std")] {
+/// profiler.measure(Subsystem::Rules, || {
+///     profiler.measure(Subsystem::Descriptions, || std::thread::sleep(Duration::from_millis(20)));
+/// });
+/// profiler.end_turn();
+/// let report = profiler.report(1);
+/// assert!(report.totals[&Subsystem::Descriptions] >= Duration::from_millis(20));
+/// assert!(report.totals[&Subsystem::Rules] < Duration::from_millis(20));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Profiler {
+    /// Whether time is recorded at all. Profiling is disabled by default.
+    pub enabled: bool,
+    /// How many past turns are kept.
+    pub window: usize,
+    current: RefCell<BTreeMap<Subsystem, Duration>>,
+    turns: VecDeque<BTreeMap<Subsystem, Duration>>,
+    /// The time spent in nested measurements so far, for each running measurement, innermost last.
+    #[cfg(feature = "std")]
+    nested: RefCell<Vec<Duration>>,
+}
+
+impl Profiler {
+    /// Creates a new, disabled `Profiler` that keeps the given number of past turns.
+    pub fn new(window: usize) -> Self {
+        Self {
+            enabled: false,
+            window,
+            current: RefCell::new(BTreeMap::new()),
+            turns: VecDeque::new(),
+            #[cfg(feature = "std")]
+            nested: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Adds time spent in a [`Subsystem`] during the current turn.
+    pub fn record(&self, subsystem: Subsystem, duration: Duration) {
+        if self.enabled {
+            *self.current.borrow_mut().entry(subsystem).or_default() += duration;
+        }
+    }
+
+    /// Starts measuring time, if the profiler is enabled.
+    pub fn start(&self) -> Stopwatch {
+        Stopwatch {
+            #[cfg(feature = "std")]
+            start: self.enabled.then(|| {
+                let mut nested = self.nested.borrow_mut();
+                nested.push(Duration::ZERO);
+                (std::time::Instant::now(), nested.len())
+            }),
+        }
+    }
+
+    /// Adds the time since `stopwatch` was [started](Profiler::start) to a [`Subsystem`], leaving out the time of the
+    /// measurements started and stopped in the meantime.
+    pub fn stop(&self, subsystem: Subsystem, stopwatch: Stopwatch) {
+        #[cfg(feature = "std")]
+        if let Some((start, depth)) = stopwatch.start {
+            let elapsed = start.elapsed();
+            let mut nested = self.nested.borrow_mut();
+            // Measurements started after this one and never stopped are dropped along with it.
+            nested.truncate(depth);
+            let inner = nested.pop().unwrap_or_default();
+            if let Some(outer) = nested.last_mut() {
+                *outer += elapsed;
+            }
+            drop(nested);
+            self.record(subsystem, elapsed.saturating_sub(inner));
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = (subsystem, stopwatch);
+    }
+
+    /// Runs `f`, adding the time it took to a [`Subsystem`], and returns its result.
+    pub fn measure<T>(&self, subsystem: Subsystem, f: impl FnOnce() -> T) -> T {
+        if !self.enabled {
+            return f();
+        }
+        let stopwatch = self.start();
+        let result = f();
+        self.stop(subsystem, stopwatch);
+        result
+    }
+
+    /// Ends the current turn, forgetting the oldest turn if more than [`window`](Profiler::window) turns are kept.
+    pub fn end_turn(&mut self) {
+        if !self.enabled {
+            return;
+        }
+        self.turns.push_back(self.current.take());
+        while self.turns.len() > self.window {
+            self.turns.pop_front();
+        }
+    }
+
+    /// Sums the time spent in each [`Subsystem`] over the last `turns` turns.
+    pub fn report(&self, turns: usize) -> ProfileReport {
+        let recent: Vec<_> = self.turns.iter().rev().take(turns).collect();
+        let mut totals = BTreeMap::new();
+        for turn in &recent {
+            for (&subsystem, &duration) in *turn {
+                *totals.entry(subsystem).or_default() += duration;
+            }
+        }
+        ProfileReport {
+            turns: recent.len(),
+            totals,
+        }
+    }
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+/// The time spent in each [`Subsystem`] over a number of turns, as reported by the [`Profiler`].
+///
+/// The `Display` implementation lists the total and average time per turn of every subsystem, slowest first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileReport {
+    /// The number of turns the report covers.
+    pub turns: usize,
+    /// The total time spent in each subsystem.
+    pub totals: BTreeMap<Subsystem, Duration>,
+}
+
+impl core::fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Profile of the last {} turns:", self.turns)?;
+        let mut totals: Vec<_> = self.totals.iter().collect();
+        totals.sort_by(|a, b| b.1.cmp(a.1));
+        for (subsystem, total) in totals {
+            let average = *total / self.turns.max(1) as u32;
+            write!(f, "\n  {subsystem}: {total:?} total, {average:?} per turn")?;
+        }
+        Ok(())
+    }
+}
+
+/// A debug command handler that reports the [`Profiler`] results to the [`Output`](crate::Output).
+///
+/// The number of turns can be given after the verb, such as "profile 20". Otherwise, every kept turn is reported.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::profiler::profile_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["profile"], profile_command);
+/// world.profiler.enabled = true;
+///
+/// world.execute("profile").unwrap();
+/// world.profiler.end_turn();
+/// world.execute("profile 5").unwrap();
+///
+/// let messages = world.output.drain();
+/// assert_eq!(messages[0], "Profile of the last 0 turns:");
+/// assert!(messages[1].starts_with("Profile of the last 1 turns:"));
+/// ```
+#[cfg(feature = "parser")]
+pub fn profile_command(world: &mut World, command: &Command) {
+    let turns = command
+        .words
+        .first()
+        .and_then(|word| word.parse().ok())
+        .unwrap_or(world.profiler.window);
+    let report = world.profiler.report(turns);
+    world.output.say(report.to_string());
+}
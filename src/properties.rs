@@ -0,0 +1,97 @@
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use core::any::Any;
+
+/// A value that can be stored in [`Properties`].
+///
+/// This is implemented for every type that is `Clone` and `Debug`, so it never needs to be implemented by hand.
+pub trait PropertyValue: Any + core::fmt::Debug {
+    /// Clones the value into a new box.
+    fn clone_box(&self) -> Box<dyn PropertyValue>;
+    /// Returns the value as [`Any`] so it can be downcast.
+    fn as_any(&self) -> &dyn Any;
+    /// Returns the value as mutable [`Any`] so it can be downcast.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any + Clone + core::fmt::Debug> PropertyValue for T {
+    fn clone_box(&self) -> Box<dyn PropertyValue> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Clone for Box<dyn PropertyValue> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+/// A typed key-value store for attaching arbitrary data to game objects.
+///
+/// # Examples
+/// ```
+/// use worldwright::properties::Properties;
+///
+/// let mut properties = Properties::new();
+/// properties.set("temperature", 18i32);
+/// properties.set("is_outdoors", true);
+///
+/// assert_eq!(properties.get::<i32>("temperature"), Some(&18));
+/// assert_eq!(properties.get::<bool>("is_outdoors"), Some(&true));
+/// // A value is only returned when asking for the type it was stored as.
+/// assert_eq!(properties.get::<u8>("temperature"), None);
+///
+/// *properties.get_mut::<i32>("temperature").unwrap() -= 5;
+/// assert_eq!(properties.get::<i32>("temperature"), Some(&13));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Properties {
+    values: BTreeMap<String, Box<dyn PropertyValue>>,
+}
+
+impl Properties {
+    /// Creates a new, empty `Properties` store.
+    pub fn new() -> Self {
+        Self {
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the value of a property, replacing any previous value regardless of its type.
+    pub fn set<T: PropertyValue>(&mut self, key: &str, value: T) {
+        self.values.insert(key.into(), Box::new(value));
+    }
+
+    /// Gets the value of a property if it exists and has type `T`.
+    pub fn get<T: PropertyValue>(&self, key: &str) -> Option<&T> {
+        (**self.values.get(key)?).as_any().downcast_ref()
+    }
+
+    /// Gets a mutable reference to the value of a property if it exists and has type `T`.
+    pub fn get_mut<T: PropertyValue>(&mut self, key: &str) -> Option<&mut T> {
+        (**self.values.get_mut(key)?).as_any_mut().downcast_mut()
+    }
+
+    /// Checks whether a property exists, regardless of its type.
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// Removes a property, returning whether it existed.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.values.remove(key).is_some()
+    }
+
+    /// Returns the names of all properties in alphabetical order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(feature = "parser")]
+use crate::World;
+use crate::actor::NpcId;
+use crate::item::ItemId;
+use crate::map::RoomId;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+#[cfg(feature = "parser")]
+use crate::prelude::*;
+use alloc::collections::{BTreeMap, VecDeque};
+
+/// Where an [`Item`](crate::item::Item) is, or who is holding it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Whereabouts {
+    /// The item is lying in a room.
+    Room(RoomId),
+    /// The player is holding the item.
+    Player,
+    /// An NPC is holding the item.
+    Npc(NpcId),
+    /// The item is in or on another item, such as a chest or a table.
+    Item(ItemId),
+}
+
+/// A past location of an item, recorded by [`Provenance`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sighting {
+    /// The turn the item got there.
+    pub turn: u32,
+    /// Where the item was.
+    pub whereabouts: Whereabouts,
+}
+
+/// The history of where each item has been and who has held it, such as for a detective to notice that a vase was moved.
+///
+/// To bound memory, at most [`limit`](Provenance::limit) sightings are kept per item. When there are more,
+/// the oldest ones are dropped, except for the first one, so the origin of an item is never forgotten.
+///
+/// # Examples
+/// ```
+/// use worldwright::item::{Item, Items};
+/// use worldwright::map::Map;
+/// use worldwright::provenance::{Provenance, Whereabouts};
+///
+/// let mut map = Map::new();
+/// let parlor = map.new_room("A stuffy parlor.".into());
+/// let mut items = Items::new();
+/// let vase = items.add(Item::new("vase".into(), "A Ming vase.".into()));
+///
+/// let mut provenance = Provenance::new(8);
+/// provenance.record(vase, 0, Whereabouts::Room(parlor));
+/// assert!(!provenance.has_moved(vase));
+///
+/// provenance.record(vase, 3, Whereabouts::Player);
+/// provenance.record(vase, 5, Whereabouts::Room(parlor));
+/// assert!(provenance.has_been_at(vase, Whereabouts::Player));
+/// assert!(!provenance.has_moved(vase));
+/// assert_eq!(provenance.history(vase).count(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Provenance {
+    /// The most sightings kept per item.
+    pub limit: usize,
+    histories: BTreeMap<ItemId, VecDeque<Sighting>>,
+}
+
+impl Provenance {
+    /// Creates a new, empty `Provenance` keeping at most `limit` sightings per item.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            histories: BTreeMap::new(),
+        }
+    }
+
+    /// Records that an item got somewhere on the given turn.
+    ///
+    /// Nothing is recorded if the item was already there.
+    pub fn record(&mut self, item: ItemId, turn: u32, whereabouts: Whereabouts) {
+        let history = self.histories.entry(item).or_default();
+        if history.back().map(|sighting| sighting.whereabouts) == Some(whereabouts) {
+            return;
+        }
+        history.push_back(Sighting { turn, whereabouts });
+        while history.len() > self.limit.max(2) {
+            history.remove(1);
+        }
+    }
+
+    /// Returns an iterator over the kept sightings of an item, oldest first.
+    pub fn history(&self, item: ItemId) -> impl Iterator<Item = &Sighting> {
+        self.histories.get(&item).into_iter().flatten()
+    }
+
+    /// Gets the first recorded sighting of an item.
+    pub fn origin(&self, item: ItemId) -> Option<&Sighting> {
+        self.histories.get(&item)?.front()
+    }
+
+    /// Gets the last recorded sighting of an item.
+    pub fn latest(&self, item: ItemId) -> Option<&Sighting> {
+        self.histories.get(&item)?.back()
+    }
+
+    /// Checks whether an item is somewhere else than where it was first recorded.
+    pub fn has_moved(&self, item: ItemId) -> bool {
+        match (self.origin(item), self.latest(item)) {
+            (Some(origin), Some(latest)) => origin.whereabouts != latest.whereabouts,
+            _ => false,
+        }
+    }
+
+    /// Checks whether an item has ever been somewhere, or held by someone, among the kept sightings.
+    pub fn has_been_at(&self, item: ItemId, whereabouts: Whereabouts) -> bool {
+        self.history(item)
+            .any(|sighting| sighting.whereabouts == whereabouts)
+    }
+
+    /// Forgets the history of an item.
+    pub fn forget(&mut self, item: ItemId) {
+        self.histories.remove(&item);
+    }
+}
+
+impl Default for Provenance {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+/// A debug command handler that reports the [`Provenance`] of an item to the [`Output`](crate::Output), such as "history vase".
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::provenance::{Whereabouts, provenance_command};
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["history"], provenance_command);
+/// let parlor = world.map.new_room_with_name("Parlor".into(), "A stuffy parlor.".into());
+/// let vase = world.items.add(Item::new("vase".into(), "A Ming vase.".into()));
+/// world.track_item(vase, Whereabouts::Room(parlor));
+/// world.turn = 4;
+/// world.track_item(vase, Whereabouts::Player);
+///
+/// world.execute("history vase").unwrap();
+/// world.execute("history teapot").unwrap();
+/// assert_eq!(
+///     world.output.drain(),
+///     vec![
+///         "History of the vase:\n  turn 0: in Parlor\n  turn 4: carried by the player",
+///         "There is no item called \"teapot\".",
+///     ]
+/// );
+/// ```
+#[cfg(feature = "parser")]
+pub fn provenance_command(world: &mut World, command: &Command) {
+    let name = command.noun().unwrap_or_default();
+    let Some(item) = world.items.find(&name) else {
+        world
+            .output
+            .say(format!("There is no item called \"{name}\"."));
+        return;
+    };
+    let mut report = format!("History of the {}:", world.items[item].name);
+    for sighting in world.provenance.history(item) {
+        let place = match sighting.whereabouts {
+            Whereabouts::Room(room_id) => match &world.map.graph[room_id].name {
+                Some(name) => format!("in {name}"),
+                None => format!("in room {}", room_id.index()),
+            },
+            Whereabouts::Player => "carried by the player".into(),
+            Whereabouts::Npc(npc) => format!("carried by the {}", world.npcs[npc].name),
+            Whereabouts::Item(holder) => {
+                let holder = &world.items[holder];
+                let preposition = if holder.supporter.is_some() {
+                    "on"
+                } else {
+                    "in"
+                };
+                format!("{preposition} the {}", holder.name)
+            }
+        };
+        report.push_str(&format!("\n  turn {}: {place}", sighting.turn));
+    }
+    world.output.say(report);
+}
@@ -0,0 +1,274 @@
+use crate::World;
+use crate::item::ItemId;
+use crate::map::{ExitId, RoomId};
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use core::fmt::Write;
+
+/// Something the player needs to get through an exit, as reported by [`ExitType::requirements`](crate::map::ExitType::requirements).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Requirement {
+    /// A key item that unlocks the exit.
+    Key(ItemId),
+    /// Any other condition, described for authors, such as "strength 12".
+    Condition(String),
+}
+
+/// The kind of a node in a [`PuzzleGraph`], which decides how it is drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PuzzleNodeKind {
+    /// A room.
+    Room,
+    /// An item, such as a key.
+    Item,
+    /// An exit the player needs something to get through.
+    Gate,
+    /// A goal added by the author, such as a quest or an ending.
+    Goal,
+}
+
+/// A node of a [`PuzzleGraph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleNode {
+    /// What the node stands for.
+    pub kind: PuzzleNodeKind,
+    /// The label of the node.
+    pub label: String,
+}
+
+/// An edge of a [`PuzzleGraph`], meaning that the `from` node leads to or unlocks the `to` node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleEdge {
+    /// The index of the node the edge starts at.
+    pub from: usize,
+    /// The index of the node the edge ends at.
+    pub to: usize,
+    /// The label of the edge, such as "unlocks".
+    pub label: Option<String>,
+}
+
+/// A graph of what unlocks what in a game, for authors to spot unintended sequence breaks or bottlenecks.
+///
+/// [`from_world`](PuzzleGraph::from_world) builds the graph of rooms, the items found in them, and the exits that
+/// need keys or conditions. Other dependencies, such as quest prerequisites, can be added with
+/// [`add_goal`](PuzzleGraph::add_goal) and [`add_edge`](PuzzleGraph::add_edge). The graph is exported to
+/// [DOT](https://graphviz.org/doc/info/lang.html) with [`to_dot`](PuzzleGraph::to_dot).
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::{Door, RegularExit};
+/// use worldwright::puzzle::PuzzleGraph;
+///
+/// let mut world = World::new();
+/// let hall = world.map.new_room_with_name("Hall".into(), "A long hall.".into());
+/// let kitchen = world.map.new_room_in_direction(hall, Direction::East, Box::new(RegularExit), "A kitchen.".into());
+/// world.map.room_mut(kitchen).name = Some("Kitchen".into());
+/// let key = world.items.add(Item::new("brass key".into(), "A small brass key.".into()));
+/// world.map.place_item(kitchen, key);
+/// let study = world.map.new_room_in_direction(hall, Direction::North, Box::new(Door::new_with_key(key)), "A study.".into());
+/// world.map.room_mut(study).name = Some("Study".into());
+///
+/// let mut graph = PuzzleGraph::from_world(&world);
+/// let escape = graph.add_goal("Escape the manor");
+/// let study_node = graph.room(study).unwrap();
+/// graph.add_edge(study_node, escape, None);
+///
+/// assert_eq!(
+///     graph.to_dot(),
+///     r#"digraph puzzle {
+///     n0 [label="Hall", shape=ellipse];
+///     n1 [label="Kitchen", shape=ellipse];
+///     n2 [label="Study", shape=ellipse];
+///     n3 [label="brass key", shape=note];
+///     n4 [label="a locked door", shape=box];
+///     n5 [label="Escape the manor", shape=doubleoctagon];
+///     n0 -> n1 [dir=both];
+///     n0 -> n4;
+///     n4 -> n2;
+///     n1 -> n3 [label="contains"];
+///     n3 -> n4 [label="unlocks"];
+///     n2 -> n5;
+/// }
+/// "#
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PuzzleGraph {
+    /// The nodes of the graph.
+    pub nodes: Vec<PuzzleNode>,
+    /// The edges of the graph.
+    pub edges: Vec<PuzzleEdge>,
+    /// Free passages between rooms, drawn as edges in both directions.
+    pub passages: Vec<(usize, usize)>,
+    rooms: BTreeMap<RoomId, usize>,
+    items: BTreeMap<ItemId, usize>,
+    gates: BTreeMap<ExitId, usize>,
+}
+
+impl PuzzleGraph {
+    /// Creates a new, empty `PuzzleGraph`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the graph of a [`World`]: its rooms, the items found in them, and the exits that need keys or conditions.
+    ///
+    /// Exits without [requirements](crate::map::ExitType::requirements) are drawn as free passages between rooms.
+    /// Exits with requirements become gates, with an edge from every key that unlocks them and a note of every other condition.
+    pub fn from_world(world: &World) -> Self {
+        let mut graph = Self::new();
+        let map = &world.map;
+        for room_id in map.graph.node_indices() {
+            let label = match &map.graph[room_id].name {
+                Some(name) => name.clone(),
+                None => format!("room {}", room_id.index()),
+            };
+            let node = graph.add_node(PuzzleNodeKind::Room, label);
+            graph.rooms.insert(room_id, node);
+        }
+        for (item_id, item) in world.items.iter() {
+            let node = graph.add_node(PuzzleNodeKind::Item, item.name.clone());
+            graph.items.insert(item_id, node);
+        }
+
+        for exit_id in map.graph.edge_indices() {
+            let Some((source, target)) = map.exit_endpoints(exit_id) else {
+                continue;
+            };
+            let (from, to) = (graph.rooms[&source], graph.rooms[&target]);
+            let exit = &map.graph[exit_id];
+            let requirements = exit.exit_type.requirements();
+            if requirements.is_empty() {
+                graph.passages.push((from, to));
+                continue;
+            }
+
+            let mut label = match exit.direction {
+                Some(direction) => exit.exit_type.description(direction),
+                None => exit.exit_type.undirected_description(),
+            };
+            // Drop the direction, which is meaningless outside of the room.
+            if let Some(direction) = exit.direction {
+                label = label
+                    .strip_suffix(&format!(" {direction}"))
+                    .map(String::from)
+                    .unwrap_or(label);
+            }
+            let conditions: Vec<String> = requirements
+                .iter()
+                .filter_map(|requirement| match requirement {
+                    Requirement::Condition(condition) => Some(condition.clone()),
+                    Requirement::Key(_) => None,
+                })
+                .collect();
+            if !conditions.is_empty() {
+                label = format!("{label}\nneeds {}", crate::join_list(&conditions));
+            }
+            let gate = graph.add_node(PuzzleNodeKind::Gate, label);
+            graph.gates.insert(exit_id, gate);
+            graph.add_edge(from, gate, None);
+            graph.add_edge(gate, to, None);
+        }
+
+        for room_id in map.graph.node_indices() {
+            for item_id in &map.graph[room_id].contents {
+                if let Some(&item) = graph.items.get(item_id) {
+                    graph.add_edge(graph.rooms[&room_id], item, Some("contains".into()));
+                }
+            }
+        }
+        for exit_id in map.graph.edge_indices() {
+            let Some(&gate) = graph.gates.get(&exit_id) else {
+                continue;
+            };
+            for requirement in map.graph[exit_id].exit_type.requirements() {
+                if let Requirement::Key(key) = requirement
+                    && let Some(&item) = graph.items.get(&key)
+                {
+                    graph.add_edge(item, gate, Some("unlocks".into()));
+                }
+            }
+        }
+        graph
+    }
+
+    /// Adds a node and returns its index.
+    pub fn add_node(&mut self, kind: PuzzleNodeKind, label: String) -> usize {
+        self.nodes.push(PuzzleNode { kind, label });
+        self.nodes.len() - 1
+    }
+
+    /// Adds a goal, such as a quest or an ending, and returns its index.
+    pub fn add_goal(&mut self, label: &str) -> usize {
+        self.add_node(PuzzleNodeKind::Goal, label.into())
+    }
+
+    /// Adds an edge meaning that the `from` node leads to or unlocks the `to` node.
+    pub fn add_edge(&mut self, from: usize, to: usize, label: Option<String>) {
+        self.edges.push(PuzzleEdge { from, to, label });
+    }
+
+    /// Gets the index of the node of a room.
+    pub fn room(&self, room_id: RoomId) -> Option<usize> {
+        self.rooms.get(&room_id).copied()
+    }
+
+    /// Gets the index of the node of an item.
+    pub fn item(&self, item_id: ItemId) -> Option<usize> {
+        self.items.get(&item_id).copied()
+    }
+
+    /// Gets the index of the node of a gated exit.
+    pub fn gate(&self, exit_id: ExitId) -> Option<usize> {
+        self.gates.get(&exit_id).copied()
+    }
+
+    /// Exports the graph to the [DOT](https://graphviz.org/doc/info/lang.html) language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph puzzle {\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let shape = match node.kind {
+                PuzzleNodeKind::Room => "ellipse",
+                PuzzleNodeKind::Item => "note",
+                PuzzleNodeKind::Gate => "box",
+                PuzzleNodeKind::Goal => "doubleoctagon",
+            };
+            let _ = writeln!(
+                dot,
+                "    n{index} [label=\"{}\", shape={shape}];",
+                escape(&node.label)
+            );
+        }
+        for (from, to) in &self.passages {
+            let _ = writeln!(dot, "    n{from} -> n{to} [dir=both];");
+        }
+        for edge in &self.edges {
+            match &edge.label {
+                Some(label) => {
+                    let _ = writeln!(
+                        dot,
+                        "    n{} -> n{} [label=\"{}\"];",
+                        edge.from,
+                        edge.to,
+                        escape(label)
+                    );
+                }
+                None => {
+                    let _ = writeln!(dot, "    n{} -> n{};", edge.from, edge.to);
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
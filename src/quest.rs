@@ -0,0 +1,285 @@
+use crate::World;
+use crate::item::ItemId;
+use crate::journal::EntryKind;
+use crate::map::RoomId;
+use crate::prelude::*;
+use alloc::rc::Rc;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A condition on the [`World`] checked by a [`Trigger::Condition`].
+pub type QuestCondition = Rc<dyn Fn(&World) -> bool>;
+
+/// Something that happens in the [`World`], which starts a [`Quest`] or completes one of its steps.
+#[derive(Clone)]
+pub enum Trigger {
+    /// A [flag](World::set_flag) is set.
+    FlagSet(String),
+    /// The player carries an item.
+    ItemObtained(ItemId),
+    /// The player is in a room, or has been in it before.
+    RoomVisited(RoomId),
+    /// Any other condition.
+    Condition(QuestCondition),
+}
+
+impl core::fmt::Debug for Trigger {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Trigger::FlagSet(flag) => f.debug_tuple("FlagSet").field(flag).finish(),
+            Trigger::ItemObtained(item) => f.debug_tuple("ItemObtained").field(item).finish(),
+            Trigger::RoomVisited(room) => f.debug_tuple("RoomVisited").field(room).finish(),
+            Trigger::Condition(_) => f.write_str("Condition"),
+        }
+    }
+}
+
+impl Trigger {
+    /// Checks whether the trigger has happened.
+    pub fn is_met(&self, world: &World) -> bool {
+        match self {
+            Trigger::FlagSet(flag) => world.flag(flag),
+            Trigger::ItemObtained(item) => world.player.carries(*item),
+            Trigger::RoomVisited(room) => {
+                world.player.current_room == *room
+                    || world
+                        .map
+                        .graph
+                        .node_weight(*room)
+                        .is_some_and(|room| room.visited)
+            }
+            Trigger::Condition(condition) => condition(world),
+        }
+    }
+}
+
+/// A step of a [`Quest`], completed when its [`Trigger`] happens.
+#[derive(Clone, Debug)]
+pub struct QuestStep {
+    /// What the player has to do, such as "Find the key to the vault."
+    pub description: String,
+    /// What completes the step.
+    pub trigger: Trigger,
+}
+
+impl QuestStep {
+    /// Creates a new `QuestStep`.
+    pub fn new(description: &str, trigger: Trigger) -> Self {
+        Self {
+            description: description.into(),
+            trigger,
+        }
+    }
+}
+
+/// A quest made of [`QuestStep`]s completed in order.
+#[derive(Clone, Debug)]
+pub struct Quest {
+    /// The name of the quest, such as "The Missing Heir".
+    pub name: String,
+    /// The triggers that must all have happened for the quest to start. A quest without any starts right away.
+    pub preconditions: Vec<Trigger>,
+    /// The steps of the quest, in order.
+    pub steps: Vec<QuestStep>,
+}
+
+impl Quest {
+    /// Creates a new `Quest` without steps or preconditions.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            preconditions: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds a precondition that must have happened for the quest to start.
+    pub fn requires(mut self, trigger: Trigger) -> Self {
+        self.preconditions.push(trigger);
+        self
+    }
+
+    /// Adds a step at the end of the quest.
+    pub fn step(mut self, description: &str, trigger: Trigger) -> Self {
+        self.steps.push(QuestStep::new(description, trigger));
+        self
+    }
+}
+
+/// How far along a [`Quest`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QuestStatus {
+    /// The quest hasn't started yet.
+    Inactive,
+    /// The quest is under way, at the step with the given index.
+    Active(usize),
+    /// Every step of the quest has been completed.
+    Completed,
+}
+
+/// Identifies a [`Quest`] in the [`Quests`] registry of the [`World`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuestId(usize);
+
+/// The registry of every [`Quest`] in the [`World`] and how far along it is.
+///
+/// Quests are [updated](World::update_quests) every turn: they start once their preconditions are met and move on
+/// through their steps as their triggers happen. The player is told about the progress, and it is logged in the
+/// [`Journal`](crate::journal::Journal).
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::quest::{Quest, QuestStatus, Trigger};
+///
+/// let mut world = World::new();
+/// let study = world.map.new_room_with_name("Study".into(), "A quiet study.".into());
+/// let vault = world.map.new_room_with_name("Vault".into(), "An empty vault.".into());
+/// world.player.current_room = study;
+/// let key = world.items.add(Item::new("vault key".into(), "A heavy key.".into()));
+/// world.map.place_item(study, key);
+///
+/// let heist = world.quests.add(
+///     Quest::new("The Heist")
+///         .requires(Trigger::FlagSet("hired".into()))
+///         .step("Find the key to the vault.", Trigger::ItemObtained(key))
+///         .step("Get into the vault.", Trigger::RoomVisited(vault)),
+/// );
+///
+/// assert!(world.update_quests().is_empty());
+/// world.set_flag("hired", true);
+/// assert_eq!(world.update_quests(), vec!["New quest: The Heist. Find the key to the vault."]);
+/// world.take_item(key).unwrap();
+/// assert_eq!(world.update_quests(), vec!["Quest updated: The Heist. Get into the vault."]);
+/// assert_eq!(world.quests.active(), vec![heist]);
+///
+/// world.player.current_room = vault;
+/// assert_eq!(world.update_quests(), vec!["Quest completed: The Heist."]);
+/// assert_eq!(world.quests.status(heist), QuestStatus::Completed);
+/// assert_eq!(world.quests.completed(), vec![heist]);
+/// assert_eq!(world.journal.entries().len(), 3);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Quests {
+    quests: Vec<(Quest, QuestStatus)>,
+}
+
+impl Quests {
+    /// Creates a new, empty `Quests` registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an inactive [`Quest`] to the registry and returns its [`QuestId`].
+    pub fn add(&mut self, quest: Quest) -> QuestId {
+        self.quests.push((quest, QuestStatus::Inactive));
+        QuestId(self.quests.len() - 1)
+    }
+
+    /// Gets the [`Quest`] with the given [`QuestId`].
+    pub fn get(&self, id: QuestId) -> Option<&Quest> {
+        self.quests.get(id.0).map(|(quest, _)| quest)
+    }
+
+    /// Gets how far along a quest is.
+    pub fn status(&self, id: QuestId) -> QuestStatus {
+        self.quests
+            .get(id.0)
+            .map_or(QuestStatus::Inactive, |(_, status)| *status)
+    }
+
+    /// Sets how far along a quest is, such as to start it from a script.
+    pub fn set_status(&mut self, id: QuestId, status: QuestStatus) {
+        if let Some((_, current)) = self.quests.get_mut(id.0) {
+            *current = status;
+        }
+    }
+
+    /// Gets the current step of a quest, if it is active.
+    pub fn current_step(&self, id: QuestId) -> Option<&QuestStep> {
+        match self.status(id) {
+            QuestStatus::Active(step) => self.get(id)?.steps.get(step),
+            _ => None,
+        }
+    }
+
+    /// Returns every [`Quest`] along with its [`QuestId`] and status.
+    pub fn iter(&self) -> impl Iterator<Item = (QuestId, &Quest, QuestStatus)> {
+        self.quests
+            .iter()
+            .enumerate()
+            .map(|(index, (quest, status))| (QuestId(index), quest, *status))
+    }
+
+    /// Returns the quests that are under way.
+    pub fn active(&self) -> Vec<QuestId> {
+        self.iter()
+            .filter(|(_, _, status)| matches!(status, QuestStatus::Active(_)))
+            .map(|(id, _, _)| id)
+            .collect()
+    }
+
+    /// Returns the quests that have been completed.
+    pub fn completed(&self) -> Vec<QuestId> {
+        self.iter()
+            .filter(|(_, _, status)| *status == QuestStatus::Completed)
+            .map(|(id, _, _)| id)
+            .collect()
+    }
+}
+
+impl World {
+    /// Starts the quests whose preconditions are met and completes the steps whose triggers have happened.
+    ///
+    /// Returns a notification for every quest that made progress, such as "Quest updated: The Heist. Get into the
+    /// vault.", which is also logged in the [`Journal`](crate::journal::Journal).
+    pub fn update_quests(&mut self) -> Vec<String> {
+        let mut messages = Vec::new();
+        let ids: Vec<QuestId> = self.quests.iter().map(|(id, _, _)| id).collect();
+        for id in ids {
+            let (quest, status) = &self.quests.quests[id.0];
+            let mut next = *status;
+            if next == QuestStatus::Inactive
+                && quest
+                    .preconditions
+                    .iter()
+                    .all(|trigger| trigger.is_met(self))
+            {
+                next = QuestStatus::Active(0);
+            }
+            while let QuestStatus::Active(step) = next {
+                match quest.steps.get(step) {
+                    Some(current) if !current.trigger.is_met(self) => break,
+                    Some(_) => next = QuestStatus::Active(step + 1),
+                    None => next = QuestStatus::Completed,
+                }
+            }
+            if next == *status {
+                continue;
+            }
+
+            let message = match (status, next) {
+                (_, QuestStatus::Completed) => format!("Quest completed: {}.", quest.name),
+                (QuestStatus::Inactive, QuestStatus::Active(step)) => {
+                    format!(
+                        "New quest: {}. {}",
+                        quest.name, quest.steps[step].description
+                    )
+                }
+                (_, QuestStatus::Active(step)) => {
+                    format!(
+                        "Quest updated: {}. {}",
+                        quest.name, quest.steps[step].description
+                    )
+                }
+                (_, QuestStatus::Inactive) => continue,
+            };
+            self.quests.set_status(id, next);
+            self.journal.log(EntryKind::Quest, message.clone());
+            messages.push(message);
+        }
+        messages
+    }
+}
@@ -0,0 +1,272 @@
+use crate::World;
+use crate::dialogue::DialogueTopic;
+use crate::map::Region;
+use crate::prelude::*;
+use core::fmt::Write;
+
+/// Statistics about a [`World`] for authors to track their progress on large games, exportable to Markdown or HTML.
+///
+/// Descriptions are rendered for the current state of the world to count their words.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::actor::{BarkPool, Npc};
+/// use worldwright::dialogue::{DialogueTopic, Response};
+/// use worldwright::map::Region;
+/// use worldwright::report::Report;
+///
+/// let mut world = World::new();
+/// let foyer = world.map.new_room_with_name("Foyer".into(), "A dusty foyer.".into());
+/// let garden = world.map.new_room_with_name("Garden".into(), "An overgrown garden full of weeds.".into());
+/// world.map.graph[garden].add_tag("outdoors");
+/// world.map.graph[garden].annotations.todos.push("Plant the roses.".into());
+/// let mut gardener = Npc::new("gardener".into(), garden);
+/// gardener.barks = Some(BarkPool::new(vec!["Mind the roses.".into(), "Lovely weather.".into()]));
+/// gardener.dialogue.add(
+///     DialogueTopic::ask("roses")
+///         .response(Response::new("\"Red and white.\""))
+///         .subtopic(DialogueTopic::ask("thorns").response(Response::new("\"Wear gloves.\""))),
+/// );
+/// world.npcs.add(gardener);
+/// world.npcs.add(Npc::new("cat | stray".into(), foyer));
+///
+/// let report = Report::new(&world, &[("Outdoors", Region::Tagged("outdoors".into()))]);
+/// assert_eq!(report.rooms, 2);
+/// assert_eq!(report.description_words, 9);
+/// assert_eq!(report.rooms_per_region, vec![("Outdoors".into(), 1)]);
+///
+/// let markdown = report.to_markdown();
+/// assert!(markdown.starts_with("# World report\n\n- Rooms: 2\n- Words in descriptions: 9\n"));
+/// assert!(markdown.contains("| Room | Words |\n| --- | --- |\n| Foyer | 3 |\n| Garden | 6 |\n"));
+/// assert!(markdown.contains("| gardener | 2 |"));
+/// assert!(markdown.contains("| cat \\| stray | 0 |"));
+/// assert!(markdown.contains("- Dialogue: 2 topics (2 responses)\n"));
+/// assert!(markdown.contains("- Todos: 1\n"));
+///
+/// let html = report.to_html();
+/// assert!(html.contains("<tr><td>gardener</td><td>2</td></tr>"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The number of rooms in the map.
+    pub rooms: usize,
+    /// The number of rooms in each region given to [`new`](Report::new), by name.
+    pub rooms_per_region: Vec<(String, usize)>,
+    /// The number of words in every room description.
+    pub description_words: usize,
+    /// The number of words in the description of each room, by name.
+    pub room_words: Vec<(String, usize)>,
+    /// The verbs understood by the [`Parser`](crate::parser::Parser), by their first word.
+    pub verbs: Vec<String>,
+    /// The number of lines each NPC can bark, by name.
+    pub npc_lines: Vec<(String, usize)>,
+    /// The number of [`Banter`](crate::banter::Banter)s.
+    pub banters: usize,
+    /// The number of lines in every banter.
+    pub banter_lines: usize,
+    /// The number of [dialogue topics](crate::dialogue::DialogueTopic) each NPC can talk about, subtopics included,
+    /// by name.
+    pub npc_topics: Vec<(String, usize)>,
+    /// The number of dialogue topics of every NPC.
+    pub dialogue_topics: usize,
+    /// The number of [responses](crate::dialogue::Response) to every dialogue topic.
+    pub dialogue_responses: usize,
+    /// The number of todos in the [`Annotations`](crate::map::Annotations) of rooms and exits.
+    pub todos: usize,
+}
+
+impl Report {
+    /// Gathers the statistics of a [`World`], counting the rooms of each named [`Region`].
+    pub fn new(world: &World, regions: &[(&str, Region)]) -> Self {
+        let map = &world.map;
+        let mut report = Self {
+            rooms: map.graph.node_count(),
+            ..Self::default()
+        };
+
+        for (name, region) in regions {
+            let rooms = map
+                .graph
+                .node_indices()
+                .filter(|&room_id| region.contains(map, room_id))
+                .count();
+            report.rooms_per_region.push((name.to_string(), rooms));
+        }
+
+        for room_id in map.graph.node_indices() {
+            let room = &map.graph[room_id];
+            let name = match &room.name {
+                Some(name) => name.clone(),
+                None => format!("room {}", room_id.index()),
+            };
+            let words = room.description.render(world).split_whitespace().count();
+            report.description_words += words;
+            report.room_words.push((name, words));
+            report.todos += room.annotations.todos.len();
+        }
+        report.todos += map
+            .graph
+            .edge_weights()
+            .map(|exit| exit.annotations.todos.len())
+            .sum::<usize>();
+
+        #[cfg(feature = "parser")]
+        {
+            report.verbs = world.parser.verbs().map(String::from).collect();
+        }
+
+        for (_, npc) in world.npcs.iter() {
+            let lines = npc
+                .barks
+                .iter()
+                .map(|barks| barks.lines.len())
+                .sum::<usize>()
+                + npc
+                    .mood_barks
+                    .values()
+                    .map(|barks| barks.lines.len())
+                    .sum::<usize>()
+                + npc
+                    .reactions
+                    .values()
+                    .map(|barks| barks.lines.len())
+                    .sum::<usize>();
+            report.npc_lines.push((npc.name.clone(), lines));
+
+            let (topics, responses) = count_topics(&npc.dialogue.topics);
+            report.npc_topics.push((npc.name.clone(), topics));
+            report.dialogue_topics += topics;
+            report.dialogue_responses += responses;
+        }
+
+        for banter in world.banter.iter() {
+            report.banters += 1;
+            report.banter_lines += banter.lines.len();
+        }
+        report
+    }
+
+    /// Exports the report to Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("# World report\n\n");
+        for (label, value) in self.summary() {
+            let _ = writeln!(markdown, "- {label}: {value}");
+        }
+        for (title, header, rows) in self.tables() {
+            let _ = write!(
+                markdown,
+                "\n## {title}\n\n| {} | {} |\n| --- | --- |\n",
+                escape_cell(header.0),
+                escape_cell(header.1)
+            );
+            for (name, count) in rows {
+                let _ = writeln!(markdown, "| {} | {count} |", escape_cell(name));
+            }
+        }
+        markdown
+    }
+
+    /// Exports the report to an HTML fragment.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<h1>World report</h1>\n<ul>\n");
+        for (label, value) in self.summary() {
+            let _ = writeln!(
+                html,
+                "<li>{}: {}</li>",
+                escape_html(label),
+                escape_html(&value)
+            );
+        }
+        html.push_str("</ul>\n");
+        for (title, header, rows) in self.tables() {
+            let _ = writeln!(
+                html,
+                "<h2>{}</h2>\n<table>\n<tr><th>{}</th><th>{}</th></tr>",
+                escape_html(title),
+                escape_html(header.0),
+                escape_html(header.1)
+            );
+            for (name, count) in rows {
+                let _ = writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{count}</td></tr>",
+                    escape_html(name)
+                );
+            }
+            html.push_str("</table>\n");
+        }
+        html
+    }
+
+    fn summary(&self) -> [(&'static str, String); 6] {
+        [
+            ("Rooms", self.rooms.to_string()),
+            ("Words in descriptions", self.description_words.to_string()),
+            (
+                "Verbs",
+                if self.verbs.is_empty() {
+                    "0".into()
+                } else {
+                    format!("{} ({})", self.verbs.len(), self.verbs.join(", "))
+                },
+            ),
+            (
+                "Banters",
+                format!("{} ({} lines)", self.banters, self.banter_lines),
+            ),
+            (
+                "Dialogue",
+                format!(
+                    "{} topics ({} responses)",
+                    self.dialogue_topics, self.dialogue_responses
+                ),
+            ),
+            ("Todos", self.todos.to_string()),
+        ]
+    }
+
+    /// The non-empty tables of the report, with their titles and column headers.
+    #[allow(clippy::type_complexity)]
+    fn tables(
+        &self,
+    ) -> Vec<(
+        &'static str,
+        (&'static str, &'static str),
+        &[(String, usize)],
+    )> {
+        [
+            ("Regions", ("Region", "Rooms"), &self.rooms_per_region),
+            ("Rooms", ("Room", "Words"), &self.room_words),
+            ("NPCs", ("NPC", "Lines"), &self.npc_lines),
+            ("Dialogue", ("NPC", "Topics"), &self.npc_topics),
+        ]
+        .into_iter()
+        .filter(|(_, _, rows)| !rows.is_empty())
+        .map(|(title, header, rows)| (title, header, rows.as_slice()))
+        .collect()
+    }
+}
+
+/// Counts dialogue topics, subtopics included, and their responses.
+fn count_topics(topics: &[DialogueTopic]) -> (usize, usize) {
+    topics.iter().fold((0, 0), |(topics, responses), topic| {
+        let (subtopics, subresponses) = count_topics(&topic.subtopics);
+        (
+            topics + 1 + subtopics,
+            responses + topic.responses.len() + subresponses,
+        )
+    })
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Escapes the pipes of a Markdown table cell, which would otherwise end the cell.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
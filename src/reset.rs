@@ -0,0 +1,243 @@
+use crate::World;
+use crate::item::ItemId;
+use crate::map::{ExitId, ExitType};
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use crate::provenance::Whereabouts;
+use alloc::collections::BTreeMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Whether an [`Item`](crate::item::Item) or an [`Exit`](crate::map::Exit) goes back to its initial state when the world resets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Persistence {
+    /// Items respawn at their home and exits go back to their initial state, such as doors relocking.
+    #[default]
+    Resets,
+    /// Nothing is undone, such as for a quest item or a door that stays broken.
+    Persistent,
+}
+
+/// How the world of a server game resets, putting items back in their homes and exits back in their initial state.
+///
+/// The homes of items and the initial state of exits are [captured](World::capture_reset_state) once the world is built.
+/// Items and exits that are [`Persistence::Persistent`] are left alone, and so are items carried by the player or an NPC,
+/// even inside other items.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::map::exit_types::Door;
+/// use worldwright::map::Direction;
+///
+/// let mut world = World::new();
+/// let hall = world.map.new_room("A long hall.".into());
+/// let vault = world.map.new_room_in_direction(hall, Direction::North, Box::new(Door::new(true)), "A vault.".into());
+/// let gold = world.items.add(Item::new("gold".into(), "A pile of gold.".into()));
+/// world.map.place_item(vault, gold);
+/// world.capture_reset_state();
+/// world.resets.interval = Some(10);
+/// world.resets.message = Some("The world shimmers.".into());
+///
+/// let door = world.map.exit_id_in_direction(hall, Direction::North).unwrap();
+/// world.map.exit_as_mut::<Door>(door).unwrap().locked = false;
+/// world.map.move_item(gold, vault, hall);
+///
+/// let outcome = world.wait(10);
+/// assert_eq!(outcome.messages, vec!["The world shimmers."]);
+/// assert!(world.map.exit_as::<Door>(door).unwrap().locked);
+/// assert_eq!(world.map.find_item(gold), Some(vault));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ResetPolicy {
+    /// Reset the world every this many turns, or never if `None`.
+    pub interval: Option<u32>,
+    /// The message shown when the world resets on its own, if any.
+    pub message: Option<String>,
+    homes: BTreeMap<ItemId, Whereabouts>,
+    exits: BTreeMap<ExitId, Box<dyn ExitType>>,
+}
+
+impl ResetPolicy {
+    /// Creates a new `ResetPolicy` that never resets on its own, with nothing captured.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks whether the world is due to reset on the given turn.
+    pub fn is_due(&self, turn: u32) -> bool {
+        self.interval
+            .is_some_and(|interval| interval > 0 && turn.is_multiple_of(interval))
+    }
+
+    /// Gets the home of an item, where it respawns when the world resets.
+    pub fn home(&self, item: ItemId) -> Option<Whereabouts> {
+        self.homes.get(&item).copied()
+    }
+
+    /// Sets the home of an item, where it respawns when the world resets.
+    pub fn set_home(&mut self, item: ItemId, home: Whereabouts) {
+        self.homes.insert(item, home);
+    }
+}
+
+impl World {
+    /// Records where every item is as its home and the state of every exit, to go back to when the world resets.
+    ///
+    /// Anything captured before is forgotten.
+    pub fn capture_reset_state(&mut self) {
+        self.resets.homes = self
+            .items
+            .iter()
+            .filter_map(|(item, _)| Some((item, self.whereabouts(item)?)))
+            .collect();
+        self.resets.exits = self
+            .map
+            .graph
+            .edge_indices()
+            .map(|exit_id| (exit_id, self.map.graph[exit_id].exit_type.clone()))
+            .collect();
+    }
+
+    /// Resets the world, putting items back in their homes and exits back in their initial state.
+    ///
+    /// Returns how many items and exits were reset, leaving out those that were already as they started.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::item::{Container, Item};
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::Door;
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.new_room("A long hall.".into());
+    /// world.map.new_room_in_direction(hall, Direction::North, Box::new(Door::new(true)), "A vault.".into());
+    /// world.map.new_room_in_direction(hall, Direction::South, Box::new(Door::new(false)), "A porch.".into());
+    /// let mut bag = Item::new("bag".into(), "A leather bag.".into());
+    /// bag.container = Some(Container::new(true));
+    /// let bag = world.items.add(bag);
+    /// let coin = world.items.add(Item::new("coin".into(), "A gold coin.".into()));
+    /// world.map.place_item(hall, bag);
+    /// world.map.place_item(hall, coin);
+    /// world.capture_reset_state();
+    ///
+    /// world.map.remove_item(hall, bag);
+    /// world.map.remove_item(hall, coin);
+    /// world.player.inventory.push(bag);
+    /// world.items.insert_into(coin, bag);
+    /// assert_eq!(world.reset_world(), 0);
+    /// assert!(world.items.encloses(bag, coin));
+    ///
+    /// let door = world.map.exit_id_in_direction(hall, Direction::North).unwrap();
+    /// world.map.exit_as_mut::<Door>(door).unwrap().locked = false;
+    /// assert_eq!(world.reset_world(), 1);
+    /// ```
+    pub fn reset_world(&mut self) -> usize {
+        let mut count = 0;
+        let homes: Vec<(ItemId, Whereabouts)> = self
+            .resets
+            .homes
+            .iter()
+            .map(|(&item, &home)| (item, home))
+            .collect();
+        for (item, home) in homes {
+            if self
+                .items
+                .get(item)
+                .is_none_or(|item| item.persistence == Persistence::Persistent)
+            {
+                continue;
+            }
+            let whereabouts = self.whereabouts(item);
+            if whereabouts == Some(home) || self.is_possessed(item) {
+                continue;
+            }
+            match whereabouts {
+                Some(Whereabouts::Room(room_id)) => {
+                    self.map.remove_item(room_id, item);
+                }
+                Some(Whereabouts::Item(_)) => {
+                    self.items.remove_from_holder(item);
+                }
+                _ => {}
+            }
+            match home {
+                Whereabouts::Room(room_id) => self.map.place_item(room_id, item),
+                Whereabouts::Item(holder) => {
+                    self.items.insert_into(item, holder);
+                }
+                Whereabouts::Player => self.player.inventory.push(item),
+                Whereabouts::Npc(npc) => self.npcs[npc].inventory.push(item),
+            }
+            self.track_item(item, home);
+            count += 1;
+        }
+
+        let exits: Vec<(ExitId, Box<dyn ExitType>)> = self
+            .resets
+            .exits
+            .iter()
+            .map(|(&exit_id, exit_type)| (exit_id, exit_type.clone()))
+            .collect();
+        for (exit_id, exit_type) in exits {
+            let changed = self.map.graph.edge_weight(exit_id).is_some_and(|exit| {
+                exit.persistence == Persistence::Resets
+                    && !exit.exit_type.same_state(exit_type.as_ref())
+            });
+            if changed && let Some(exit) = self.map.exit_weight_mut(exit_id) {
+                exit.exit_type = exit_type;
+                count += 1;
+            }
+        }
+        self.scope_cache.invalidate();
+        count
+    }
+
+    /// Checks whether an item is carried by the player or an NPC, directly or inside other items they carry.
+    fn is_possessed(&self, item: ItemId) -> bool {
+        let mut outermost = item;
+        while let Some(holder) = self.items.holder_of(outermost) {
+            outermost = holder;
+        }
+        matches!(
+            self.whereabouts(outermost),
+            Some(Whereabouts::Player | Whereabouts::Npc(_))
+        )
+    }
+}
+
+/// An admin command handler that resets the world at once, such as "reset".
+///
+/// Games should only register it for administrators.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::reset::reset_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["reset"], reset_command);
+/// let shrine = world.map.new_room("A quiet shrine.".into());
+/// let candle = world.items.add(Item::new("candle".into(), "A white candle.".into()));
+/// world.map.place_item(shrine, candle);
+/// world.capture_reset_state();
+///
+/// world.map.remove_item(shrine, candle);
+/// world.execute("reset").unwrap();
+/// assert_eq!(world.map.find_item(candle), Some(shrine));
+/// assert_eq!(world.output.drain(), vec!["The world has been reset (1 thing restored)."]);
+/// ```
+#[cfg(feature = "parser")]
+pub fn reset_command(world: &mut World, _command: &Command) {
+    let count = world.reset_world();
+    let things = if count == 1 { "thing" } else { "things" };
+    world.output.say(format!(
+        "The world has been reset ({count} {things} restored)."
+    ));
+}
@@ -0,0 +1,172 @@
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use core::cell::Cell;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A small, seedable pseudo-random number generator.
+///
+/// The same seed always produces the same sequence of numbers, which keeps games reproducible for testing and replays.
+/// The generator advances through a shared reference, so it can be used from code that only has read access to the [`World`](crate::World).
+///
+/// # Examples
+/// ```
+/// use worldwright::Rng;
+///
+/// let a = Rng::new(42);
+/// let b = Rng::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+///
+/// let roll = a.below(6) + 1;
+/// assert!((1..=6).contains(&roll));
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rng {
+    state: Cell<u64>,
+}
+
+impl Rng {
+    /// Creates a new `Rng` from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: Cell::new(seed),
+        }
+    }
+
+    /// Returns the current state of the generator, which can be used as a seed to resume the sequence later.
+    pub fn state(&self) -> u64 {
+        self.state.get()
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&self) -> u64 {
+        // SplitMix64
+        let state = self.state.get().wrapping_add(0x9e37_79b9_7f4a_7c15);
+        self.state.set(state);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number in `0..n`, or `0` if `n` is `0`.
+    pub fn below(&self, n: u32) -> u32 {
+        if n == 0 {
+            return 0;
+        }
+        (self.next_u64() % u64::from(n)) as u32
+    }
+
+    /// Returns `true` with the given probability, between `0.0` and `1.0`.
+    pub fn chance(&self, probability: f64) -> bool {
+        let sample = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        sample < probability
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Independently seeded [`Rng`] streams, one per subsystem, so that a random call added to one subsystem doesn't change
+/// the rolls of the others.
+///
+/// Each stream is seeded from the world seed and its name alone, so streams can be added in any order. The state of
+/// every stream can be serialized with the rest of a saved game.
+///
+/// # Examples
+/// ```
+/// use worldwright::RngStreams;
+///
+/// let a = RngStreams::new(42);
+/// let b = RngStreams::new(42);
+/// // Rolling for combat in one game doesn't change the ambience of the other.
+/// a.combat().next_u64();
+/// assert_eq!(a.ambience().next_u64(), b.ambience().next_u64());
+///
+/// let mut streams = RngStreams::new(42);
+/// streams.add("loot");
+/// assert_ne!(streams.get("loot").unwrap().next_u64(), streams.npc_ai().next_u64());
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RngStreams {
+    seed: u64,
+    streams: BTreeMap<String, Rng>,
+}
+
+impl RngStreams {
+    /// The stream of [ambient](crate::ambience::Ambience) messages.
+    pub const AMBIENCE: &'static str = "ambience";
+    /// The stream of rolls such as [skill checks](crate::map::exit_types::SkillCheck).
+    pub const COMBAT: &'static str = "combat";
+    /// The stream of NPC barks and wandering.
+    pub const NPC_AI: &'static str = "npc_ai";
+    /// The stream of [weather](crate::weather::Weathers) changes, added when the weather first changes.
+    pub const WEATHER: &'static str = "weather";
+    /// The streams every `RngStreams` has, which are [added](RngStreams::add) again if a save lacks them.
+    pub const BUILT_IN: [&'static str; 3] = [Self::AMBIENCE, Self::COMBAT, Self::NPC_AI];
+
+    /// Creates the built-in streams from a world seed.
+    pub fn new(seed: u64) -> Self {
+        let mut streams = Self {
+            seed,
+            streams: BTreeMap::new(),
+        };
+        for name in Self::BUILT_IN {
+            streams.add(name);
+        }
+        streams
+    }
+
+    /// Returns the world seed the streams were created from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Adds a stream for a subsystem of the game, seeded from the world seed and its name, and returns it.
+    ///
+    /// A stream that already exists is returned as it is.
+    pub fn add(&mut self, name: &str) -> &Rng {
+        let seed = self.seed ^ hash(name);
+        self.streams
+            .entry(name.into())
+            .or_insert_with(|| Rng::new(seed))
+    }
+
+    /// Gets a stream by name.
+    pub fn get(&self, name: &str) -> Option<&Rng> {
+        self.streams.get(name)
+    }
+
+    /// Gets the stream of ambient messages.
+    pub fn ambience(&self) -> &Rng {
+        &self.streams[Self::AMBIENCE]
+    }
+
+    /// Gets the stream of combat rolls and skill checks.
+    pub fn combat(&self) -> &Rng {
+        &self.streams[Self::COMBAT]
+    }
+
+    /// Gets the stream of NPC behavior.
+    pub fn npc_ai(&self) -> &Rng {
+        &self.streams[Self::NPC_AI]
+    }
+}
+
+impl Default for RngStreams {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Hashes a stream name with FNV-1a, which stays the same across versions and platforms.
+fn hash(name: &str) -> u64 {
+    name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -0,0 +1,243 @@
+use crate::item::{ItemId, PutError};
+use crate::map::{Direction, ExitId, PassageError};
+use crate::prelude::*;
+use crate::{PushError, TakeError, ThrowError, World};
+use alloc::rc::Rc;
+use core::cmp::Reverse;
+
+/// Something the player tries to do, run through the [`Rulebooks`] by [`World::perform`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Taking an item.
+    Take(ItemId),
+    /// Dropping a carried item.
+    Drop(ItemId),
+    /// Putting a carried item in or on another one.
+    Put(ItemId, ItemId),
+    /// Going in a direction.
+    Go(Direction),
+    /// Going through an exit of the player's room by name, such as a portal that doesn't lead in a direction.
+    GoThrough(ExitId),
+    /// Throwing a carried item in a direction.
+    Throw(ItemId, Direction),
+    /// Pushing an item in a direction, with the player following it.
+    Push(ItemId, Direction),
+    /// An action defined by the game, such as "pray", which only does what its rules do.
+    Custom(String),
+}
+
+impl Action {
+    /// Gets the name of the action, such as "take" or the name of a custom action.
+    ///
+    /// Going up or down is called "climb", and going in other directions "go".
+    pub fn name(&self) -> &str {
+        match self {
+            Action::Take(_) => "take",
+            Action::Drop(_) => "drop",
+            Action::Put(_, _) => "put",
+            Action::Go(Direction::Up | Direction::Down) => "climb",
+            Action::Go(_) | Action::GoThrough(_) => "go",
+            Action::Throw(_, _) => "throw",
+            Action::Push(_, _) => "push",
+            Action::Custom(name) => name,
+        }
+    }
+}
+
+/// The stage of an [`Action`] a rule runs at, in the order they run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    /// Before the action is checked, such as to take off a glove first. Stopping here ends the action.
+    Before,
+    /// Replacing the action with something else. Stopping here ends the action.
+    Instead,
+    /// Checking whether the action can happen. Stopping here makes the action fail.
+    Check,
+    /// Carrying out the action, after the built-in behavior.
+    CarryOut,
+    /// After the action happened. Stopping here replaces the report of the action.
+    After,
+}
+
+/// What a rule decides.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RuleOutcome {
+    /// The rule doesn't apply, or lets the action go on.
+    Continue,
+    /// The rule ends its stage, with a message for the player.
+    Stop(String),
+}
+
+/// A rule run with mutable access to the [`World`] and the [`Action`] being performed.
+pub type Rule = Rc<dyn Fn(&mut World, &Action) -> RuleOutcome>;
+
+/// The rules intercepting [`Action`]s, so custom behavior doesn't require replacing built-in verbs.
+///
+/// Within a [`Stage`], rules run from the highest priority to the lowest, and in the order they were added for equal
+/// priorities, until one stops.
+///
+/// # Examples
+/// ```
+/// use std::rc::Rc;
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::rules::{Action, RuleOutcome, Stage};
+///
+/// let mut world = World::new();
+/// let shrine = world.map.new_room("A quiet shrine.".into());
+/// world.player.current_room = shrine;
+/// let idol = world.items.add(Item::new("idol".into(), "A golden idol.".into()));
+/// let candle = world.items.add(Item::new("candle".into(), "A white candle.".into()));
+/// world.map.place_item(shrine, idol);
+/// world.map.place_item(shrine, candle);
+///
+/// world.rules.add(Stage::Instead, 0, Rc::new(move |_, action| match action {
+///     Action::Take(item) if *item == idol => RuleOutcome::Stop("A voice booms: \"Leave it!\"".into()),
+///     _ => RuleOutcome::Continue,
+/// }));
+/// world.rules.add(Stage::After, 0, Rc::new(move |_, action| match action {
+///     Action::Take(item) if *item == candle => RuleOutcome::Stop("The candle flickers as you pick it up.".into()),
+///     _ => RuleOutcome::Continue,
+/// }));
+///
+/// assert_eq!(world.perform(Action::Take(idol)), Err("A voice booms: \"Leave it!\"".into()));
+/// assert!(!world.player.carries(idol));
+/// assert_eq!(world.perform(Action::Take(candle)), Ok(vec!["The candle flickers as you pick it up.".into()]));
+/// assert_eq!(world.perform(Action::Drop(candle)), Ok(vec!["Dropped.".into()]));
+/// ```
+#[derive(Clone, Default)]
+pub struct Rulebooks {
+    rules: Vec<(Stage, i32, Rule)>,
+}
+
+impl Rulebooks {
+    /// Creates new, empty `Rulebooks`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule to the given stage with a priority. Higher priorities run first.
+    pub fn add(&mut self, stage: Stage, priority: i32, rule: Rule) {
+        let index = self
+            .rules
+            .partition_point(|(other_stage, other_priority, _)| {
+                (*other_stage, Reverse(*other_priority)) <= (stage, Reverse(priority))
+            });
+        self.rules.insert(index, (stage, priority, rule));
+    }
+
+    /// Returns the rules of a stage, in the order they run.
+    pub fn stage(&self, stage: Stage) -> Vec<Rule> {
+        self.rules
+            .iter()
+            .filter(|(rule_stage, _, _)| *rule_stage == stage)
+            .map(|(_, _, rule)| rule.clone())
+            .collect()
+    }
+
+    /// Checks whether there are no rules at all.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+impl core::fmt::Debug for Rulebooks {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Rulebooks")
+            .field("rules", &self.rules.len())
+            .finish()
+    }
+}
+
+impl World {
+    /// Performs an [`Action`] through the [`Rulebooks`]: the before, instead and check rules may stop it, then the
+    /// built-in behavior and the carry-out rules happen, and the after rules may replace its report.
+    ///
+    /// Returns the report of the action, or the message explaining why it didn't happen.
+    pub fn perform(&mut self, action: Action) -> Result<Vec<String>, String> {
+        for stage in [Stage::Before, Stage::Instead, Stage::Check] {
+            if let Some(message) = self.run_rules(stage, &action) {
+                return Err(message);
+            }
+        }
+        let mut report = self.carry_out(&action)?;
+        if let Some(message) = self.run_rules(Stage::CarryOut, &action) {
+            report.push(message);
+        }
+        if let Some(message) = self.run_rules(Stage::After, &action) {
+            report = vec![message];
+        }
+        Ok(report)
+    }
+
+    /// Runs the rules of a stage until one stops, returning its message.
+    fn run_rules(&mut self, stage: Stage, action: &Action) -> Option<String> {
+        for rule in self.rules.stage(stage) {
+            if let RuleOutcome::Stop(message) = rule(self, action) {
+                return Some(message);
+            }
+        }
+        None
+    }
+
+    /// The built-in behavior of an action.
+    fn carry_out(&mut self, action: &Action) -> Result<Vec<String>, String> {
+        match *action {
+            Action::Take(item) => match self.take_item(item) {
+                Ok(()) => Ok(vec!["Taken.".into()]),
+                Err(TakeError::NotHere) => Err("You can't see that here.".into()),
+                Err(TakeError::NotTakeable) => Err("That's fixed in place.".into()),
+                Err(TakeError::TooHeavy) => Err("You're carrying too much already.".into()),
+            },
+            Action::Drop(item) if self.drop_item(item) => Ok(vec!["Dropped.".into()]),
+            Action::Drop(_) => Err("You aren't carrying that.".into()),
+            Action::Put(item, holder) => match self.put_item(item, holder) {
+                Ok(()) => Ok(vec!["Done.".into()]),
+                Err(PutError::NotCarried) => Err("You aren't carrying that.".into()),
+                Err(PutError::NotAHolder) => Err("You can't put things there.".into()),
+                Err(PutError::Closed) => Err("It's closed.".into()),
+                Err(PutError::Full) => Err("There's no more room.".into()),
+                Err(PutError::IntoItself) => Err("You can't put something inside itself.".into()),
+            },
+            Action::Go(direction) => self
+                .move_player(direction)
+                .map(|movement| movement.messages),
+            Action::GoThrough(exit_id) => {
+                self.move_through(exit_id).map(|movement| movement.messages)
+            }
+            Action::Throw(item, direction) => match self.throw_item(item, direction) {
+                Ok(_) => Ok(vec![format!(
+                    "You throw the {} {direction}.",
+                    self.items[item].name
+                )]),
+                Err(ThrowError::NotCarried) => Err("You aren't carrying that.".into()),
+                Err(ThrowError::Passage(PassageError::NoExit)) => {
+                    Err("You can't throw anything that way.".into())
+                }
+                Err(ThrowError::Passage(PassageError::Blocked)) => {
+                    Err("It won't get through that way.".into())
+                }
+            },
+            Action::Push(item, direction) => match self.push_item(item, direction) {
+                Ok(movement) => {
+                    let mut report = vec![format!(
+                        "You push the {} {direction}.",
+                        self.items[item].name
+                    )];
+                    report.extend(movement.messages);
+                    Ok(report)
+                }
+                Err(PushError::NotHere) => Err("You can't see that here.".into()),
+                Err(PushError::NotPushable) => Err("It won't budge.".into()),
+                Err(PushError::Passage(PassageError::NoExit)) => {
+                    Err(self.describe_no_exit(self.player.current_room, direction))
+                }
+                Err(PushError::Passage(PassageError::Blocked)) => {
+                    Err("It won't fit through that way.".into())
+                }
+                Err(PushError::Refused(message)) => Err(message),
+            },
+            Action::Custom(_) => Ok(Vec::new()),
+        }
+    }
+}
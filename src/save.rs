@@ -0,0 +1,803 @@
+use crate::access::Permission;
+use crate::actor::Mood;
+use crate::clues::ClueId;
+use crate::difficulty::Difficulty;
+use crate::flags::Flags;
+use crate::item::{Container, ItemId, Supporter};
+use crate::journal::Journal;
+use crate::knowledge::TopicId;
+use crate::map::{ExitRegistry, ExitSerdeError};
+use crate::player::Stats;
+use crate::prelude::*;
+use crate::quest::QuestStatus;
+use crate::scheduler::Scheduler;
+use crate::score::Score;
+use crate::stamina::Stamina;
+use crate::template::Variables;
+use crate::timers::Timers;
+use crate::weather::Weather;
+use crate::{Rng, RngStreams, World};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::rc::Rc;
+use petgraph::prelude::{EdgeIndex, NodeIndex};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The version of the [`SaveState`] format written by this version of the crate.
+pub const SAVE_FORMAT_VERSION: u32 = 1;
+
+/// The reason a game couldn't be saved or loaded.
+#[derive(Debug)]
+pub enum SaveError {
+    /// The save couldn't be written, read or parsed as JSON.
+    Json(serde_json::Error),
+    /// An exit couldn't be saved or restored by the [`ExitRegistry`].
+    Exit(ExitSerdeError),
+    /// The save was made from a world with another layout, such as another game or a newer version of it.
+    Mismatch(String),
+    /// A [migration](SaveMigrations) failed. Holds the game version it upgrades from and the problem.
+    Migration(u32, String),
+}
+
+impl core::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SaveError::Json(error) => write!(f, "{error}"),
+            SaveError::Exit(error) => write!(f, "{error}"),
+            SaveError::Mismatch(message) => {
+                write!(f, "the save doesn't match the world: {message}")
+            }
+            SaveError::Migration(version, message) => {
+                write!(
+                    f,
+                    "couldn't upgrade the save from version {version}: {message}"
+                )
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(error: serde_json::Error) -> Self {
+        SaveError::Json(error)
+    }
+}
+
+impl From<ExitSerdeError> for SaveError {
+    fn from(error: ExitSerdeError) -> Self {
+        SaveError::Exit(error)
+    }
+}
+
+/// The saved state of the player.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlayerState {
+    /// The index of the room the player is in.
+    pub room: usize,
+    /// The enterable item the player is inside of, if any.
+    pub inside: Option<ItemId>,
+    /// The items the player is carrying.
+    pub inventory: Vec<ItemId>,
+    /// The player's stats.
+    pub stats: Stats,
+    /// The roles of the player.
+    pub roles: BTreeSet<String>,
+    /// The indices of the rooms the player came from, for going back.
+    #[serde(default)]
+    pub breadcrumbs: Vec<usize>,
+}
+
+/// The saved restrictions of the [`AccessControl`](crate::access::AccessControl) of the world.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccessState {
+    /// The [`Permission`] of every restricted room, by index.
+    pub rooms: BTreeMap<usize, Permission>,
+    /// The [`Permission`] of every restricted exit, by index.
+    pub exits: BTreeMap<usize, Permission>,
+}
+
+/// The saved state of a room.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoomState {
+    /// Whether the player has been in the room.
+    pub visited: bool,
+    /// The items in the room.
+    pub contents: Vec<ItemId>,
+}
+
+/// The saved state of an item that holds other items.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ItemState {
+    /// The container of the item, with whether it is open or locked.
+    pub container: Option<Container>,
+    /// The supporter of the item.
+    pub supporter: Option<Supporter>,
+}
+
+/// The saved state of an NPC.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NpcState {
+    /// The index of the room the NPC is in.
+    pub location: usize,
+    /// The mood of the NPC.
+    pub mood: Mood,
+    /// The items the NPC is carrying.
+    pub inventory: Vec<ItemId>,
+    /// The dialogue topics that have been discussed with the NPC.
+    pub discussed: BTreeSet<String>,
+    /// The progress of the NPC along its patrol, if it patrols.
+    #[serde(default)]
+    pub patrol: Option<PatrolState>,
+}
+
+/// The saved progress of an NPC along its [`Patrol`](crate::actor::Patrol).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatrolState {
+    /// The index of the stop the NPC is at.
+    pub stop: usize,
+    /// The number of turns the NPC has stayed at the stop.
+    pub turns_here: u32,
+}
+
+/// The saved state of the [`ClueBoard`](crate::clues::ClueBoard) of the world.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClueState {
+    /// The clues the player has discovered.
+    pub discovered: Vec<ClueId>,
+    /// The pairs of clues the player has correctly connected.
+    pub connections: Vec<(ClueId, ClueId)>,
+}
+
+/// The saved state of a tutorial [`Tip`](crate::tutorial::Tip).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TipState {
+    /// Whether the player has acknowledged the tip.
+    pub acknowledged: bool,
+    /// The number of times the tip has been shown.
+    pub times_shown: u32,
+}
+
+/// A game in progress, as written by [`World::save`] and read by [`World::load`].
+///
+/// The format is JSON. Only what changes during play is saved: the world itself, with its descriptions, hooks and
+/// rules, is built by the game before a save is loaded into it. Rooms, exits, items, NPCs and quests are saved in the
+/// order they were added to the world, so the save only fits a world built the same way. Things added at the end of
+/// the world in a later version of the game keep their initial state when an older save is loaded, and other changes
+/// can be handled by [`SaveMigrations`].
+///
+/// Exits are saved with an [`ExitRegistry`]. Exits of types it registers as
+/// [unsaved](ExitRegistry::register_unsaved) are left as they are, even when wrapped in an exit that is saved, and
+/// other exits of types it doesn't know can't be saved.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SaveState {
+    /// The version of the format, [`SAVE_FORMAT_VERSION`] when written by this version of the crate.
+    pub version: u32,
+    /// The version of the game that wrote the save, as set in its [`SaveMigrations`].
+    #[serde(default)]
+    pub game_version: u32,
+    /// The number of turns taken so far.
+    pub turn: u32,
+    /// The [language](World::language) the game is played in, or `None` for the default language.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// The state of the player.
+    pub player: PlayerState,
+    /// The restricted rooms and exits, if the save has them.
+    #[serde(default)]
+    pub access: Option<AccessState>,
+    /// The state of every room, by index.
+    pub rooms: Vec<RoomState>,
+    /// The state of every exit, by index, tagged by the [`ExitRegistry`], or `null` for unsaved exits.
+    pub exits: Vec<Option<Value>>,
+    /// The state of every item, by index.
+    pub items: Vec<ItemState>,
+    /// The state of every NPC, by index.
+    pub npcs: Vec<NpcState>,
+    /// The status of every quest, by index.
+    pub quests: Vec<QuestStatus>,
+    /// The global flags of the game.
+    pub flags: Flags,
+    /// The template variables of the game.
+    pub variables: Variables,
+    /// The discovered clues and their connections, if the save has them.
+    #[serde(default)]
+    pub clues: Option<ClueState>,
+    /// The [`Topic`](crate::knowledge::Topic)s the player knows, if the save has them.
+    #[serde(default)]
+    pub knowledge: Option<Vec<TopicId>>,
+    /// The state of every tutorial tip, by index.
+    #[serde(default)]
+    pub tips: Vec<TipState>,
+    /// The outcomes of the [undo-safe](World::undo_safe) sections, as JSON by key, if the save has them.
+    #[serde(default)]
+    pub outcomes: Option<BTreeMap<String, Value>>,
+    /// The pending fuses and daemons.
+    pub scheduler: Scheduler,
+    /// The countdown timers.
+    pub timers: Timers,
+    /// The score of the player.
+    pub score: Score,
+    /// The stamina of the player, if the game uses it.
+    #[serde(default)]
+    pub stamina: Option<Stamina>,
+    /// The turn every unlocked achievement was unlocked on, by ID. Loading a save never locks achievements again.
+    #[serde(default)]
+    pub achievements: BTreeMap<String, u32>,
+    /// The weather of every [zone](crate::weather::WeatherZone), by index.
+    #[serde(default)]
+    pub weather: Vec<Weather>,
+    /// The journal of the player.
+    pub journal: Journal,
+    /// The difficulty of the game.
+    pub difficulty: Difficulty,
+    /// The general random number generator.
+    pub rng: Rng,
+    /// The random number generators of the subsystems.
+    pub rng_streams: RngStreams,
+}
+
+impl World {
+    /// Captures the state of the game in progress, saving exits with the given [`ExitRegistry`].
+    ///
+    /// Fails if an exit is of a type the registry doesn't know, unless it is registered as
+    /// [unsaved](ExitRegistry::register_unsaved).
+    pub fn save_state(&self, registry: &ExitRegistry) -> Result<SaveState, SaveError> {
+        let mut exits = Vec::new();
+        for exit in self.map.graph.edge_weights() {
+            let exit_type = exit.exit_type.as_ref();
+            if registry.is_unsaved(exit_type) {
+                exits.push(None);
+            } else {
+                exits.push(Some(registry.serialize(exit_type)?));
+            }
+        }
+        Ok(SaveState {
+            version: SAVE_FORMAT_VERSION,
+            game_version: self.save_migrations.version,
+            turn: self.turn,
+            language: self.language.clone(),
+            player: PlayerState {
+                room: self.player.current_room.index(),
+                inside: self.player.inside,
+                inventory: self.player.inventory.clone(),
+                stats: self.player.stats.clone(),
+                roles: self.player.roles.clone(),
+                breadcrumbs: self
+                    .player
+                    .breadcrumbs
+                    .iter()
+                    .map(|room| room.index())
+                    .collect(),
+            },
+            access: Some(AccessState {
+                rooms: self
+                    .access
+                    .rooms()
+                    .map(|(room_id, permission)| (room_id.index(), permission.clone()))
+                    .collect(),
+                exits: self
+                    .access
+                    .exits()
+                    .map(|(exit_id, permission)| (exit_id.index(), permission.clone()))
+                    .collect(),
+            }),
+            rooms: self
+                .map
+                .graph
+                .node_weights()
+                .map(|room| RoomState {
+                    visited: room.visited,
+                    contents: room.contents.clone(),
+                })
+                .collect(),
+            exits,
+            items: self
+                .items
+                .iter()
+                .map(|(_, item)| ItemState {
+                    container: item.container.clone(),
+                    supporter: item.supporter.clone(),
+                })
+                .collect(),
+            npcs: self
+                .npcs
+                .iter()
+                .map(|(_, npc)| NpcState {
+                    location: npc.location.index(),
+                    mood: npc.mood,
+                    inventory: npc.inventory.clone(),
+                    discussed: npc.dialogue.discussed.clone(),
+                    patrol: npc.patrol.as_ref().map(|patrol| {
+                        let (stop, turns_here) = patrol.progress();
+                        PatrolState { stop, turns_here }
+                    }),
+                })
+                .collect(),
+            quests: self.quests.iter().map(|(_, _, status)| status).collect(),
+            flags: self.flags.clone(),
+            variables: self.variables.clone(),
+            clues: Some(ClueState {
+                discovered: self
+                    .clues
+                    .discovered()
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect(),
+                connections: self.clues.connections().to_vec(),
+            }),
+            knowledge: Some(self.knowledge.known()),
+            tips: self
+                .tutorial
+                .tips()
+                .map(|(_, tip)| TipState {
+                    acknowledged: tip.acknowledged,
+                    times_shown: tip.times_shown,
+                })
+                .collect(),
+            outcomes: Some(self.outcomes.saved().clone()),
+            scheduler: self.scheduler.clone(),
+            timers: self.timers.clone(),
+            score: self.score.clone(),
+            stamina: self.stamina.clone(),
+            achievements: self.achievements.unlocked().clone(),
+            weather: self
+                .weather
+                .zones()
+                .iter()
+                .map(|zone| zone.current)
+                .collect(),
+            journal: self.journal.clone(),
+            difficulty: self.difficulty.clone(),
+            rng: self.rng.clone(),
+            rng_streams: self.rng_streams.clone(),
+        })
+    }
+
+    /// Restores a game in progress into the world, restoring exits with the given [`ExitRegistry`].
+    ///
+    /// The world must have been built the same way as the one that was saved, possibly with more things added at the
+    /// end. Nothing is changed if it wasn't, or if the save refers to rooms, exits or items the world doesn't have.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::ExitRegistry;
+    ///
+    /// let mut world = World::new();
+    /// world.map.new_room("A quiet meadow.".into());
+    /// let registry = ExitRegistry::new();
+    /// let mut state = world.save_state(&registry).unwrap();
+    /// state.player.room = 7;
+    /// state.turn = 3;
+    ///
+    /// assert!(world.restore_state(state, &registry).is_err());
+    /// assert_eq!(world.turn, 0);
+    ///
+    /// // Saves edited by hand or by a migration can lack the built-in random number generators.
+    /// let mut state = serde_json::to_value(world.save_state(&registry).unwrap()).unwrap();
+    /// state["rng_streams"]["streams"] = serde_json::json!({});
+    /// world.restore_state(serde_json::from_value(state).unwrap(), &registry).unwrap();
+    /// world.rng_streams.combat().next_u64();
+    /// ```
+    pub fn restore_state(
+        &mut self,
+        state: SaveState,
+        registry: &ExitRegistry,
+    ) -> Result<(), SaveError> {
+        let counts = [
+            ("rooms", state.rooms.len(), self.map.graph.node_count()),
+            ("exits", state.exits.len(), self.map.graph.edge_count()),
+            ("items", state.items.len(), self.items.len()),
+            ("NPCs", state.npcs.len(), self.npcs.iter().count()),
+            ("quests", state.quests.len(), self.quests.iter().count()),
+            ("tips", state.tips.len(), self.tutorial.tips().count()),
+        ];
+        for (kind, saved, expected) in counts {
+            if saved > expected {
+                return Err(SaveError::Mismatch(format!(
+                    "{saved} {kind} were saved, but the world has {expected}"
+                )));
+            }
+        }
+        self.check_references(&state)?;
+        let mut exits = Vec::new();
+        for (index, exit) in state.exits.into_iter().enumerate() {
+            let exit_id = EdgeIndex::new(index);
+            if let (Some(value), Some(built)) = (exit, self.map.graph.edge_weight(exit_id)) {
+                exits.push((exit_id, registry.restore(value, built.exit_type.as_ref())?));
+            }
+        }
+
+        self.turn = state.turn;
+        self.language = state.language;
+        self.player.current_room = NodeIndex::new(state.player.room);
+        self.player.inside = state.player.inside;
+        self.player.inventory = state.player.inventory;
+        self.player.stats = state.player.stats;
+        self.player.roles = state.player.roles;
+        self.player.breadcrumbs = state
+            .player
+            .breadcrumbs
+            .into_iter()
+            .map(NodeIndex::new)
+            .collect();
+        if let Some(access) = state.access {
+            self.access.clear();
+            for (index, permission) in access.rooms {
+                self.access.restrict_room(NodeIndex::new(index), permission);
+            }
+            for (index, permission) in access.exits {
+                self.access.restrict_exit(EdgeIndex::new(index), permission);
+            }
+        }
+        for (index, room) in state.rooms.into_iter().enumerate() {
+            let saved = self.map.room_mut(NodeIndex::new(index));
+            saved.visited = room.visited;
+            saved.contents = room.contents;
+        }
+        for (exit_id, exit_type) in exits {
+            if let Some(exit) = self.map.exit_weight_mut(exit_id) {
+                exit.exit_type = exit_type;
+            }
+        }
+        let ids: Vec<ItemId> = self.items.iter().map(|(id, _)| id).collect();
+        for (id, item) in ids.into_iter().zip(state.items) {
+            self.items[id].container = item.container;
+            self.items[id].supporter = item.supporter;
+        }
+        let ids: Vec<_> = self.npcs.iter().map(|(id, _)| id).collect();
+        for (id, npc) in ids.into_iter().zip(state.npcs) {
+            self.npcs[id].location = NodeIndex::new(npc.location);
+            self.npcs[id].mood = npc.mood;
+            self.npcs[id].inventory = npc.inventory;
+            self.npcs[id].dialogue.discussed = npc.discussed;
+            if let (Some(patrol), Some(saved)) = (&mut self.npcs[id].patrol, npc.patrol) {
+                patrol.resume(saved.stop, saved.turns_here);
+            }
+        }
+        let ids: Vec<_> = self.quests.iter().map(|(id, _, _)| id).collect();
+        for (id, status) in ids.into_iter().zip(state.quests) {
+            self.quests.set_status(id, status);
+        }
+        self.flags = state.flags;
+        self.variables = state.variables;
+        if let Some(clues) = state.clues {
+            self.clues.restore(clues.discovered, clues.connections);
+        }
+        if let Some(known) = state.knowledge {
+            self.knowledge.restore(known);
+        }
+        let ids: Vec<_> = self.tutorial.tips().map(|(id, _)| id).collect();
+        for (id, saved) in ids.into_iter().zip(state.tips) {
+            if let Some(tip) = self.tutorial.tip_mut(id) {
+                tip.acknowledged = saved.acknowledged;
+                tip.times_shown = saved.times_shown;
+            }
+        }
+        if let Some(outcomes) = state.outcomes {
+            self.outcomes.restore(outcomes);
+        }
+        self.scheduler.restore(state.scheduler);
+        self.timers = state.timers;
+        self.score = state.score;
+        if state.stamina.is_some() {
+            self.stamina = state.stamina;
+        }
+        self.achievements.restore(state.achievements);
+        for (zone, weather) in self.weather.zones_mut().iter_mut().zip(state.weather) {
+            zone.current = weather;
+        }
+        self.journal = state.journal;
+        self.difficulty = state.difficulty;
+        self.rng = state.rng;
+        self.rng_streams = state.rng_streams;
+        for name in RngStreams::BUILT_IN {
+            self.rng_streams.add(name);
+        }
+        Ok(())
+    }
+
+    /// Checks that every room, exit and item a save refers to exists in the world.
+    fn check_references(&self, state: &SaveState) -> Result<(), SaveError> {
+        let (rooms, exits, items) = (
+            self.map.graph.node_count(),
+            self.map.graph.edge_count(),
+            self.items.len(),
+        );
+        let room = |what: &str, index: usize| {
+            if index < rooms {
+                Ok(())
+            } else {
+                Err(SaveError::Mismatch(format!(
+                    "{what} refers to room {index}, but the world has {rooms} rooms"
+                )))
+            }
+        };
+        let item = |what: &str, id: &ItemId| {
+            if self.items.get(*id).is_some() {
+                Ok(())
+            } else {
+                Err(SaveError::Mismatch(format!(
+                    "{what} refers to {id:?}, but the world has {items} items"
+                )))
+            }
+        };
+
+        let player = &state.player;
+        room("the player", player.room)?;
+        player
+            .breadcrumbs
+            .iter()
+            .try_for_each(|&index| room("the player's way back", index))?;
+        player
+            .inside
+            .iter()
+            .chain(&player.inventory)
+            .try_for_each(|id| item("the player", id))?;
+        for (index, saved) in state.rooms.iter().enumerate() {
+            let what = format!("room {index}");
+            saved.contents.iter().try_for_each(|id| item(&what, id))?;
+        }
+        for (index, saved) in state.items.iter().enumerate() {
+            let what = format!("item {index}");
+            let container = saved
+                .container
+                .iter()
+                .flat_map(|container| &container.contents);
+            let supporter = saved
+                .supporter
+                .iter()
+                .flat_map(|supporter| &supporter.contents);
+            container
+                .chain(supporter)
+                .try_for_each(|id| item(&what, id))?;
+        }
+        for (index, saved) in state.npcs.iter().enumerate() {
+            let what = format!("NPC {index}");
+            room(&what, saved.location)?;
+            saved.inventory.iter().try_for_each(|id| item(&what, id))?;
+        }
+        if let Some(clues) = &state.clues {
+            let connected = clues.connections.iter().flat_map(|&(a, b)| [a, b]);
+            if let Some(id) = clues
+                .discovered
+                .iter()
+                .copied()
+                .chain(connected)
+                .find(|&id| self.clues.clue(id).is_none())
+            {
+                return Err(SaveError::Mismatch(format!(
+                    "the save refers to {id:?}, but the world doesn't have it"
+                )));
+            }
+        }
+        if let Some(id) = state
+            .knowledge
+            .iter()
+            .flatten()
+            .find(|&&id| self.knowledge.get(id).is_none())
+        {
+            return Err(SaveError::Mismatch(format!(
+                "the save refers to {id:?}, but the world doesn't have it"
+            )));
+        }
+        if let Some(access) = &state.access {
+            access
+                .rooms
+                .keys()
+                .try_for_each(|&index| room("a restriction", index))?;
+            if let Some(&index) = access.exits.keys().find(|&&index| index >= exits) {
+                return Err(SaveError::Mismatch(format!(
+                    "a restriction refers to exit {index}, but the world has {exits} exits"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Saves the game in progress as JSON, with the built-in exit types. See [`SaveState`] for the format.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::access::Permission;
+    /// use worldwright::clues::ClueId;
+    /// use worldwright::item::Item;
+    /// use worldwright::knowledge::Topic;
+    /// use worldwright::map::{Direction, ExitType};
+    /// use worldwright::map::exit_types::Door;
+    ///
+    /// fn build() -> (World, ClueId, ClueId) {
+    ///     let mut world = World::new();
+    ///     let hall = world.map.new_room_with_name("Hall".into(), "A grand hall.".into());
+    ///     let key = world.items.add(Item::new("key".into(), "A vault key.".into()));
+    ///     world.map.place_item(hall, key);
+    ///     let vault = world.map.new_room_in_direction(hall, Direction::North, Box::new(Door::new_with_key(key)), "A vault.".into());
+    ///     let lamp = world.items.add(Item::new("lamp".into(), "A brass lamp.".into()));
+    ///     world.map.place_item(vault, lamp);
+    ///     world.player.current_room = hall;
+    ///     let glove = world.clues.add_clue("glove".into(), "A bloody glove.".into());
+    ///     let knife = world.clues.add_clue("knife".into(), "A bent knife.".into());
+    ///     world.clues.add_link(glove, knife);
+    ///     world.knowledge.add(Topic::new("the password".into(), "The password is 'swordfish'.".into()));
+    ///     (world, glove, knife)
+    /// }
+    ///
+    /// let (mut world, glove, knife) = build();
+    /// let hall = world.map.room_by_name("Hall").unwrap();
+    /// let key = world.items.find("key").unwrap();
+    /// let lamp = world.items.find("lamp").unwrap();
+    /// world.take_item(key).unwrap();
+    /// world.unlock(Direction::North).unwrap();
+    /// let door = world.map.exit_mut(hall, Direction::North).unwrap();
+    /// door.exit_type.downcast_mut::<Door>().unwrap().open();
+    /// world.move_player(Direction::North).unwrap();
+    /// world.take_item(lamp).unwrap();
+    /// world.set_flag("vault_opened", true);
+    /// world.language = Some("fr".into());
+    /// world.player.roles.insert("staff".into());
+    /// world.access.restrict_room(hall, Permission::role("staff"));
+    /// world.turn = 12;
+    /// world.discover_clue(glove);
+    /// world.discover_clue(knife);
+    /// world.clues.connect(glove, knife);
+    /// let password = world.knowledge.find("the password").unwrap();
+    /// world.acquire_topic(password);
+    ///
+    /// let mut save = Vec::new();
+    /// world.save(&mut save).unwrap();
+    ///
+    /// let (mut restored, _, _) = build();
+    /// restored.load(save.as_slice()).unwrap();
+    /// assert_eq!(restored.turn, 12);
+    /// assert_eq!(restored.language.as_deref(), Some("fr"));
+    /// assert_eq!(restored.player.current_room, world.player.current_room);
+    /// assert!(restored.player.carries(lamp));
+    /// assert!(restored.flag("vault_opened"));
+    /// assert!(restored.map.graph[hall].visited);
+    /// assert!(restored.access.room(hall).is_some());
+    /// assert_eq!(restored.clues.discovered().len(), 2);
+    /// assert!(restored.clues.is_solved());
+    /// assert!(restored.knowledge.knows(password));
+    /// assert_eq!(restored.direction_back(), Some(Direction::South));
+    /// assert!(restored.move_player(Direction::South).is_ok());
+    /// assert!(restored.move_player(Direction::North).is_ok());
+    ///
+    /// assert!(World::new().load(save.as_slice()).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn save(&self, writer: impl std::io::Write) -> Result<(), SaveError> {
+        self.save_with(writer, &ExitRegistry::new())
+    }
+
+    /// Saves the game in progress as JSON, with the exit types of the given [`ExitRegistry`].
+    #[cfg(feature = "std")]
+    pub fn save_with(
+        &self,
+        writer: impl std::io::Write,
+        registry: &ExitRegistry,
+    ) -> Result<(), SaveError> {
+        Ok(serde_json::to_writer(writer, &self.save_state(registry)?)?)
+    }
+
+    /// Loads a game in progress saved by [`save`](World::save) into the world, which must have been built the same way.
+    #[cfg(feature = "std")]
+    pub fn load(&mut self, reader: impl std::io::Read) -> Result<(), SaveError> {
+        self.load_with(reader, &ExitRegistry::new())
+    }
+
+    /// Loads a game in progress saved by [`save_with`](World::save_with), with the exit types of the given
+    /// [`ExitRegistry`].
+    #[cfg(feature = "std")]
+    pub fn load_with(
+        &mut self,
+        reader: impl std::io::Read,
+        registry: &ExitRegistry,
+    ) -> Result<(), SaveError> {
+        let mut save: Value = serde_json::from_reader(reader)?;
+        self.save_migrations.migrate(&mut save)?;
+        self.restore_state(serde_json::from_value(save)?, registry)
+    }
+}
+
+/// A migration that upgrades a save, as JSON, from one version of a game to the next.
+pub type Migration = Rc<dyn Fn(&mut Value) -> Result<(), String>>;
+
+/// The version of a game's saves, and the migrations that upgrade saves from older versions instead of rejecting them.
+///
+/// Saves record the [`version`](SaveMigrations::version) of the game that wrote them. When an older save is
+/// [loaded](World::load), the migration from each version to the next runs in turn on the JSON of the save, such as
+/// to move an item that was renamed or to set a flag a new quest relies on. Versions without a migration are
+/// upgraded as they are.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::rc::Rc;
+/// use worldwright::World;
+///
+/// let mut world = World::new();
+/// world.map.new_room("A dusty foyer.".into());
+/// world.set_flag("lamp_lit", true);
+/// let mut save = Vec::new();
+/// world.save(&mut save).unwrap();
+///
+/// // Version 2 of the game renames the flag.
+/// let mut world = World::new();
+/// world.map.new_room("A dusty foyer.".into());
+/// world.map.new_room("A new wing.".into());
+/// world.save_migrations.version = 2;
+/// world.save_migrations.register(1, Rc::new(|save| {
+///     let flags = save["flags"].as_object_mut().ok_or("no flags")?;
+///     if let Some(lit) = flags.remove("lamp_lit") {
+///         flags.insert("lantern_lit".into(), lit);
+///     }
+///     Ok(())
+/// }));
+/// // Saves written before versions were set are version 0, and upgraded to version 1 as they are.
+/// world.load(save.as_slice()).unwrap();
+/// assert!(world.flag("lantern_lit"));
+///
+/// let mut newer = World::new();
+/// newer.save_migrations.version = 3;
+/// let mut save = Vec::new();
+/// newer.save(&mut save).unwrap();
+/// assert!(world.load(save.as_slice()).is_err());
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct SaveMigrations {
+    /// The version of the game, written in its saves. Increase it whenever a migration is needed.
+    pub version: u32,
+    migrations: BTreeMap<u32, Migration>,
+}
+
+impl SaveMigrations {
+    /// Creates new `SaveMigrations` for the given version of the game, without any migrations.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the migration that upgrades saves from version `from` of the game to the next one.
+    pub fn register(&mut self, from: u32, migration: Migration) {
+        self.migrations.insert(from, migration);
+    }
+
+    /// Upgrades a save, as JSON, to the current version of the game.
+    pub fn migrate(&self, save: &mut Value) -> Result<(), SaveError> {
+        let version = |key: &str| save.get(key).and_then(Value::as_u64).unwrap_or(0);
+        if version("version") > u64::from(SAVE_FORMAT_VERSION) {
+            return Err(SaveError::Mismatch(
+                "the save was written by a newer version of the engine".into(),
+            ));
+        }
+        let saved = version("game_version");
+        if saved > u64::from(self.version) {
+            return Err(SaveError::Mismatch(format!(
+                "the save was written by version {saved} of the game, newer than version {}",
+                self.version
+            )));
+        }
+        for from in saved as u32..self.version {
+            if let Some(migration) = self.migrations.get(&from) {
+                migration(save).map_err(|message| SaveError::Migration(from, message))?;
+            }
+        }
+        if let Value::Object(object) = save {
+            object.insert("game_version".into(), Value::from(self.version));
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for SaveMigrations {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SaveMigrations")
+            .field("version", &self.version)
+            .field("migrations", &self.migrations.keys())
+            .finish()
+    }
+}
@@ -0,0 +1,197 @@
+use crate::World;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A callback run by the [`Scheduler`] with mutable access to the [`World`] and the [`EventId`] of the event.
+pub type EventHandler = Rc<dyn Fn(&mut World, EventId)>;
+
+/// Identifies an event scheduled with the [`Scheduler`], to cancel it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EventId(usize);
+
+/// When a [`ScheduledEvent`] fires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Recurrence {
+    /// A fuse: the event fires once, after the turns left run out.
+    Once,
+    /// A daemon: the event fires every this many turns until it is cancelled.
+    Every(u32),
+}
+
+/// An event waiting to fire, such as "in 3 turns the bomb explodes" or "every turn the candle burns down".
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScheduledEvent {
+    /// The [`EventId`] of the event.
+    pub id: EventId,
+    /// The name of the handler run when the event fires.
+    pub handler: String,
+    /// The number of turns until the event fires next.
+    pub turns_left: u32,
+    /// Whether the event fires once or keeps firing.
+    pub recurrence: Recurrence,
+}
+
+/// Runs events after a number of turns (fuses) or every few turns (daemons), as part of
+/// [`World::advance_turn`](crate::World::advance_turn).
+///
+/// Events refer to their handlers by name, so the scheduled events can be serialized with the rest of a saved game.
+/// The handlers themselves are code, and have to be registered again with [`on`](Scheduler::on) after loading.
+///
+/// # Examples
+/// ```
+/// use std::rc::Rc;
+/// use worldwright::World;
+///
+/// let mut world = World::new();
+/// world.scheduler.on("explode", Rc::new(|world, _| world.output.say("The bomb explodes!".into())));
+/// world.scheduler.on("burn", Rc::new(|world, _| world.output.say("The candle burns down.".into())));
+///
+/// world.scheduler.fuse(2, "explode");
+/// let candle = world.scheduler.daemon(1, "burn");
+///
+/// world.advance_turn();
+/// assert_eq!(world.output.drain(), vec!["The candle burns down."]);
+/// world.advance_turn();
+/// assert_eq!(world.output.drain(), vec!["The bomb explodes!", "The candle burns down."]);
+///
+/// assert!(world.scheduler.cancel(candle));
+/// world.advance_turn();
+/// assert!(world.output.drain().is_empty());
+///
+/// # #[cfg(feature = "serde")] {
+/// // Saved events need their handlers registered again after loading.
+/// world.scheduler.daemon(5, "burn");
+/// let saved = serde_json::to_string(&world.scheduler).unwrap();
+/// let mut loaded: worldwright::scheduler::Scheduler = serde_json::from_str(&saved).unwrap();
+/// assert_eq!(loaded.missing_handlers(), vec!["burn"]);
+///
+/// // Until then, events that are due wait for their handler instead of being dropped.
+/// for _ in 0..8 {
+///     assert!(loaded.tick().is_empty());
+/// }
+/// loaded.on("burn", Rc::new(|world, _| world.output.say("The candle burns down.".into())));
+/// assert_eq!(loaded.tick().len(), 1);
+/// # }
+/// ```
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Scheduler {
+    events: Vec<ScheduledEvent>,
+    next_id: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    handlers: BTreeMap<String, EventHandler>,
+}
+
+impl Scheduler {
+    /// Creates a new `Scheduler` without any events or handlers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the handler run by the events with the given name, replacing any previous one.
+    pub fn on(&mut self, name: &str, handler: EventHandler) {
+        self.handlers.insert(name.into(), handler);
+    }
+
+    /// Schedules a fuse: the handler with the given name runs once, after the given number of turns.
+    pub fn fuse(&mut self, turns: u32, handler: &str) -> EventId {
+        self.schedule(turns, handler, Recurrence::Once)
+    }
+
+    /// Schedules a daemon: the handler with the given name runs every `every` turns until cancelled.
+    pub fn daemon(&mut self, every: u32, handler: &str) -> EventId {
+        let every = every.max(1);
+        self.schedule(every, handler, Recurrence::Every(every))
+    }
+
+    fn schedule(&mut self, turns: u32, handler: &str, recurrence: Recurrence) -> EventId {
+        let id = EventId(self.next_id);
+        self.next_id += 1;
+        self.events.push(ScheduledEvent {
+            id,
+            handler: handler.into(),
+            turns_left: turns,
+            recurrence,
+        });
+        id
+    }
+
+    /// Cancels an event, returning whether it was scheduled.
+    pub fn cancel(&mut self, id: EventId) -> bool {
+        let count = self.events.len();
+        self.events.retain(|event| event.id != id);
+        self.events.len() != count
+    }
+
+    /// Gets a scheduled event.
+    pub fn get(&self, id: EventId) -> Option<&ScheduledEvent> {
+        self.events.iter().find(|event| event.id == id)
+    }
+
+    /// Returns an iterator over every scheduled event, in the order they were scheduled.
+    pub fn iter(&self) -> impl Iterator<Item = &ScheduledEvent> {
+        self.events.iter()
+    }
+
+    /// Takes the events of a loaded scheduler, keeping the handlers registered on this one.
+    pub fn restore(&mut self, loaded: Scheduler) {
+        self.events = loaded.events;
+        self.next_id = loaded.next_id;
+    }
+
+    /// Returns the names of the handlers that scheduled events refer to but that aren't registered, such as after loading.
+    pub fn missing_handlers(&self) -> Vec<&str> {
+        let mut missing: Vec<&str> = self
+            .events
+            .iter()
+            .map(|event| event.handler.as_str())
+            .filter(|handler| !self.handlers.contains_key(*handler))
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    /// Advances every event by one turn, returning the ones that fire along with their handlers.
+    ///
+    /// Fuses that fire are removed and daemons start counting down again. Events whose handler isn't
+    /// [registered](Scheduler::on), such as right after loading, stay due and fire once it is.
+    pub fn tick(&mut self) -> Vec<(EventId, EventHandler, Recurrence)> {
+        let mut due = Vec::new();
+        self.events.retain_mut(|event| {
+            event.turns_left = event.turns_left.saturating_sub(1);
+            if event.turns_left > 0 {
+                return true;
+            }
+            let Some(handler) = self.handlers.get(&event.handler) else {
+                return true;
+            };
+            due.push((event.id, handler.clone(), event.recurrence));
+            match event.recurrence {
+                Recurrence::Once => false,
+                Recurrence::Every(every) => {
+                    event.turns_left = every;
+                    true
+                }
+            }
+        });
+        due
+    }
+}
+
+impl core::fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("events", &self.events)
+            .field("next_id", &self.next_id)
+            .field("handlers", &self.handlers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
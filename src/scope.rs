@@ -0,0 +1,127 @@
+use crate::item::Thing;
+use crate::light::LightLevel;
+use crate::map::RoomId;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use core::cell::{Cell, RefCell};
+
+/// What the player can perceive in a room: its [`LightLevel`] and the [`Thing`]s they can refer to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scope {
+    /// The light level of the room.
+    pub light: LightLevel,
+    /// The items, backdrops and NPCs the player can refer to, or nothing if the room is pitch black.
+    pub things: Vec<Thing>,
+}
+
+/// A cache of the [`Scope`] of each room, so that rooms with hundreds of objects aren't recomputed every turn.
+///
+/// The cache is dirty whenever the [revision](crate::map::Map::revision) of the [`Map`](crate::map::Map), the
+/// [revision](crate::item::Items::revision) of the [`Items`](crate::item::Items) or the
+/// [revision](crate::item::Backdrops::revision) of the [`Backdrops`](crate::item::Backdrops) changes, such as when a
+/// container is opened, and the scope of a room is computed again whenever its light level changes. Direct changes to the graph
+/// can't be tracked, and need an explicit call to [`invalidate`](ScopeCache::invalidate) or
+/// [`invalidate_room`](ScopeCache::invalidate_room). Because of this, the cache is disabled by default.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::{Backdrop, Container, Item, Thing};
+/// use worldwright::light::LightLevel;
+/// use worldwright::map::Region;
+///
+/// let mut world = World::new();
+/// world.scope_cache.enabled = true;
+/// let kitchen = world.map.new_room("The kitchen.".into());
+/// let lamp = world.items.add(Item::new("lamp".into(), "A brass lamp.".into()));
+///
+/// assert!(world.scope(kitchen).things.is_empty());
+/// assert!(world.scope_cache.is_cached(kitchen));
+///
+/// world.map.place_item(kitchen, lamp);
+/// assert_eq!(world.scope(kitchen).things, vec![Thing::Item(lamp)]);
+///
+/// // Direct changes to the graph aren't tracked.
+/// world.map.graph[kitchen].contents.clear();
+/// assert_eq!(world.scope(kitchen).things, vec![Thing::Item(lamp)]);
+/// world.scope_cache.invalidate_room(kitchen);
+/// assert!(world.scope(kitchen).things.is_empty());
+///
+/// // Opening a container shows what is inside.
+/// let mut chest = Item::new("chest".into(), "An oak chest.".into());
+/// chest.container = Some(Container::new(false));
+/// let chest = world.items.add(chest);
+/// let coin = world.items.add(Item::new("coin".into(), "A gold coin.".into()));
+/// world.items.insert_into(coin, chest);
+/// world.map.place_item(kitchen, chest);
+/// assert_eq!(world.scope(kitchen).things, vec![Thing::Item(chest)]);
+/// world.items[chest].container.as_mut().unwrap().open();
+/// assert_eq!(world.scope(kitchen).things, vec![Thing::Item(chest), Thing::Item(coin)]);
+///
+/// // So does adding a backdrop.
+/// let sky = world.backdrops.add(Backdrop::new("sky".into(), "A grey sky.".into(), Region::Rooms(vec![kitchen])));
+/// assert_eq!(world.scope(kitchen).things, vec![Thing::Item(chest), Thing::Item(coin), Thing::Backdrop(sky)]);
+///
+/// // A change of light is noticed too.
+/// world.map.graph[kitchen].light = LightLevel::PitchBlack;
+/// assert!(world.scope(kitchen).things.is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ScopeCache {
+    /// Whether scopes are cached at all.
+    pub enabled: bool,
+    revision: Cell<u64>,
+    rooms: RefCell<BTreeMap<RoomId, Scope>>,
+}
+
+impl ScopeCache {
+    /// Creates a new, disabled and empty `ScopeCache`.
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            revision: Cell::new(0),
+            rooms: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Forgets the cached scope of every room.
+    pub fn invalidate(&self) {
+        self.rooms.borrow_mut().clear();
+    }
+
+    /// Forgets the cached scope of a room.
+    pub fn invalidate_room(&self, room_id: RoomId) {
+        self.rooms.borrow_mut().remove(&room_id);
+    }
+
+    /// Checks whether the scope of a room is cached.
+    pub fn is_cached(&self, room_id: RoomId) -> bool {
+        self.rooms.borrow().contains_key(&room_id)
+    }
+
+    /// Gets the cached scope of a room for the given revision of the world, or computes and caches it.
+    ///
+    /// A cached scope is only used if it was computed with the same `light` level.
+    pub fn get_or_compute(
+        &self,
+        revision: u64,
+        room_id: RoomId,
+        light: LightLevel,
+        compute: impl FnOnce() -> Scope,
+    ) -> Scope {
+        if !self.enabled {
+            return compute();
+        }
+        if self.revision.replace(revision) != revision {
+            self.invalidate();
+        }
+        if let Some(scope) = self.rooms.borrow().get(&room_id)
+            && scope.light == light
+        {
+            return scope.clone();
+        }
+        let scope = compute();
+        self.rooms.borrow_mut().insert(room_id, scope.clone());
+        scope
+    }
+}
@@ -0,0 +1,178 @@
+use crate::World;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Points awarded or taken away, and why.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Award {
+    /// The points awarded, negative if they were taken away.
+    pub points: i32,
+    /// Why the points were awarded, such as "opening the vault".
+    pub reason: String,
+}
+
+/// The score of the player, like in classic interactive fiction.
+///
+/// Points are [awarded](World::award) with a reason, and the player earns a rank as the score rises.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::score::Score;
+///
+/// let mut world = World::new();
+/// world.score = Score::new(Some(100))
+///     .rank(0, "beginner")
+///     .rank(50, "novice adventurer")
+///     .rank(100, "master adventurer");
+///
+/// world.award(5, "finding the lamp");
+/// world.award(50, "opening the vault");
+/// assert!(!world.award_once(5, "finding the lamp"));
+/// world.award(-1, "breaking the vase");
+///
+/// assert_eq!(world.score.points, 54);
+/// assert_eq!(world.score.rank_name(), Some("novice adventurer"));
+/// assert_eq!(
+///     world.output.drain(),
+///     vec![
+///         "Your score has gone up by 5 points.",
+///         "Your score has gone up by 50 points.",
+///         "Your score has gone down by 1 point.",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Score {
+    /// The current score.
+    pub points: i32,
+    /// The highest possible score, if there is one.
+    pub maximum: Option<i32>,
+    /// The ranks of the player and the score needed for each, from the lowest.
+    pub ranks: Vec<(i32, String)>,
+    /// Every [`Award`], oldest first.
+    pub history: Vec<Award>,
+    /// Whether the player is told when the score changes. On by default.
+    pub notify: bool,
+}
+
+impl Score {
+    /// Creates a new score of zero, out of `maximum` if there is one.
+    pub fn new(maximum: Option<i32>) -> Self {
+        Self {
+            points: 0,
+            maximum,
+            ranks: Vec::new(),
+            history: Vec::new(),
+            notify: true,
+        }
+    }
+
+    /// Adds a rank earned from the given score, keeping the ranks in order.
+    pub fn rank(mut self, points: i32, name: &str) -> Self {
+        let index = self.ranks.partition_point(|(needed, _)| *needed <= points);
+        self.ranks.insert(index, (points, name.into()));
+        self
+    }
+
+    /// Gets the name of the highest rank the current score has earned.
+    pub fn rank_name(&self) -> Option<&str> {
+        self.ranks
+            .iter()
+            .rev()
+            .find(|(needed, _)| *needed <= self.points)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Adds points with a reason, or takes them away if `points` is negative.
+    ///
+    /// Returns the notification for the player, if there is one.
+    pub fn award(&mut self, points: i32, reason: &str) -> Option<String> {
+        self.points += points;
+        self.history.push(Award {
+            points,
+            reason: reason.into(),
+        });
+        let change = points.unsigned_abs();
+        let direction = if points > 0 { "up" } else { "down" };
+        let unit = if change == 1 { "point" } else { "points" };
+        (self.notify && points != 0)
+            .then(|| format!("Your score has gone {direction} by {change} {unit}."))
+    }
+
+    /// Checks whether points were ever awarded for a reason.
+    pub fn was_awarded(&self, reason: &str) -> bool {
+        self.history.iter().any(|award| award.reason == reason)
+    }
+
+    /// Describes the score, such as "You have scored 55 out of a possible 100, in 12 turns, earning you the rank of
+    /// novice adventurer."
+    pub fn describe(&self, turns: u32) -> String {
+        let mut description = format!("You have scored {}", self.points);
+        if let Some(maximum) = self.maximum {
+            description.push_str(&format!(" out of a possible {maximum}"));
+        }
+        let unit = if turns == 1 { "turn" } else { "turns" };
+        description.push_str(&format!(", in {turns} {unit}"));
+        if let Some(rank) = self.rank_name() {
+            description.push_str(&format!(", earning you the rank of {rank}"));
+        }
+        description.push('.');
+        description
+    }
+}
+
+impl Default for Score {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl World {
+    /// Awards points with a reason, telling the player through the [`Output`](crate::Output) unless notifications are
+    /// turned off.
+    pub fn award(&mut self, points: i32, reason: &str) {
+        if let Some(message) = self.score.award(points, reason) {
+            self.output.say(message);
+        }
+    }
+
+    /// Awards points for a reason only the first time, returning whether they were awarded.
+    pub fn award_once(&mut self, points: i32, reason: &str) -> bool {
+        if self.score.was_awarded(reason) {
+            return false;
+        }
+        self.award(points, reason);
+        true
+    }
+}
+
+/// A command handler that tells the player their score and rank.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::score::{Score, score_command};
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["score"], score_command);
+/// world.score = Score::new(Some(100)).rank(0, "beginner");
+/// world.score.notify = false;
+/// world.award(10, "lighting the lamp");
+///
+/// world.execute("score").unwrap();
+/// assert_eq!(
+///     world.output.drain(),
+///     vec!["You have scored 10 out of a possible 100, in 0 turns, earning you the rank of beginner."]
+/// );
+/// ```
+#[cfg(feature = "parser")]
+pub fn score_command(world: &mut World, _command: &Command) {
+    let description = world.score.describe(world.turn);
+    world.output.say(description);
+}
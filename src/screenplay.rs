@@ -0,0 +1,123 @@
+use crate::World;
+use crate::actor::BarkPool;
+use crate::banter::BanterTrigger;
+use crate::dialogue::{DialogueTopic, TopicKind};
+use crate::prelude::*;
+use core::fmt::Write;
+
+/// Exports every line of dialogue in a [`World`] to a screenplay in Markdown, for proofreading and voice-acting scripts.
+///
+/// Each [`Banter`](crate::banter::Banter) becomes a scene, with the lines in order and the speakers in capitals.
+/// After the scenes, every NPC gets a section with its [`Dialogue`](crate::dialogue::Dialogue) topics, indented by depth in the tree,
+/// and the lines it can bark, by [`Mood`](crate::actor::Mood) and by event.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::actor::{BarkPool, Npc};
+/// use worldwright::banter::{Banter, BanterTrigger};
+/// use worldwright::dialogue::{DialogueTopic, Response};
+/// use worldwright::screenplay::screenplay;
+///
+/// let mut world = World::new();
+/// let kitchen = world.map.new_room_with_name("Kitchen".into(), "A warm kitchen.".into());
+/// let mut cook = Npc::new("cook".into(), kitchen);
+/// cook.barks = Some(BarkPool::new(vec!["Mind the pot.".into()]));
+/// let cook = world.npcs.add(cook);
+/// let maid = world.npcs.add(Npc::new("kitchen maid".into(), kitchen));
+/// world.banter.add(Banter::new(
+///     vec![(cook, "Is the bread ready?".into()), (maid, "Almost.".into())],
+///     BanterTrigger::Event("dinner".into()),
+/// ));
+///
+/// let script = screenplay(&world);
+/// assert!(script.contains("Scene 1, on \"dinner\"\n\nCOOK: Is the bread ready?\n\nKITCHEN MAID: Almost.\n"));
+/// assert!(script.contains("COOK\n\nAny time:\n\n- Mind the pot.\n"));
+///
+/// let mut butler = Npc::new("butler".into(), kitchen);
+/// butler.dialogue.add(
+///     DialogueTopic::ask("key")
+///         .response(Response::new("\"Ask the master.\""))
+///         .subtopic(DialogueTopic::ask("master").response(Response::new("\"He is upstairs.\""))),
+/// );
+/// world.npcs.add(butler);
+/// assert!(screenplay(&world).contains(
+///     "BUTLER\n\nTopics:\n\n- Asked about \"key\":\n  - \"Ask the master.\"\n  - Asked about \"master\":\n    - \"He is upstairs.\"\n"
+/// ));
+/// ```
+pub fn screenplay(world: &World) -> String {
+    let mut script = String::from("# Screenplay\n");
+
+    let mut scenes = world.banter.iter().peekable();
+    if scenes.peek().is_some() {
+        script.push_str("\n## Scenes\n");
+    }
+    for (number, banter) in scenes.enumerate() {
+        let cue = match &banter.trigger {
+            BanterTrigger::Location(_) => "on location".into(),
+            BanterTrigger::Event(event) => format!("on \"{event}\""),
+        };
+        let _ = writeln!(script, "\n### Scene {}, {cue}", number + 1);
+        for (speaker, line) in &banter.lines {
+            let name = world
+                .npcs
+                .get(*speaker)
+                .map_or_else(|| "UNKNOWN".into(), |npc| npc.name.to_uppercase());
+            let _ = write!(script, "\n{name}: {line}\n");
+        }
+    }
+
+    let mut npcs = world.npcs.iter().peekable();
+    if npcs.peek().is_some() {
+        script.push_str("\n## NPCs\n");
+    }
+    for (_, npc) in npcs {
+        let _ = writeln!(script, "\n### {}", npc.name.to_uppercase());
+        if let Some(greeting) = &npc.dialogue.greeting {
+            let _ = write!(script, "\nGreeting:\n\n- {greeting}\n");
+        }
+        if !npc.dialogue.topics.is_empty() {
+            script.push_str("\nTopics:\n\n");
+            for topic in &npc.dialogue.topics {
+                write_topic(&mut script, topic, 0);
+            }
+        }
+        if let Some(barks) = &npc.barks {
+            write_lines(&mut script, "Any time", barks);
+        }
+        for (mood, barks) in &npc.mood_barks {
+            write_lines(&mut script, &format!("When {mood}"), barks);
+        }
+        for (event, barks) in &npc.reactions {
+            write_lines(&mut script, &format!("On \"{event}\""), barks);
+        }
+    }
+    script
+}
+
+fn write_topic(script: &mut String, topic: &DialogueTopic, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let kind = match topic.kind {
+        TopicKind::Ask => "Asked",
+        TopicKind::Tell => "Told",
+    };
+    let _ = writeln!(script, "{indent}- {kind} about \"{}\":", topic.name);
+    for response in &topic.responses {
+        let condition = if response.condition.is_some() {
+            " (conditional)"
+        } else {
+            ""
+        };
+        let _ = writeln!(script, "{indent}  - {}{condition}", response.text);
+    }
+    for subtopic in &topic.subtopics {
+        write_topic(script, subtopic, depth + 1);
+    }
+}
+
+fn write_lines(script: &mut String, heading: &str, barks: &BarkPool) {
+    let _ = writeln!(script, "\n{heading}:\n");
+    for line in &barks.lines {
+        let _ = writeln!(script, "- {line}");
+    }
+}
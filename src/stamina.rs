@@ -0,0 +1,233 @@
+use crate::World;
+use crate::item::ItemId;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use crate::rules::{Action, RuleOutcome, Stage};
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The stamina of the player, spent by strenuous [`Action`]s such as climbing or fighting, and restored by resting
+/// and eating.
+///
+/// Costs are set per [action name](Action::name). Once [enabled](World::enable_stamina), actions the player is too
+/// exhausted for are stopped by a check rule, and the cost of the others is spent when they are carried out.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+/// use worldwright::rules::Action;
+/// use worldwright::stamina::Stamina;
+///
+/// let mut world = World::new();
+/// let foot = world.map.new_room("The foot of a cliff.".into());
+/// let ledge = world.map.new_room_above(foot, Box::new(RegularExit), "A narrow ledge.".into());
+/// world.map.new_room_above(ledge, Box::new(RegularExit), "The windy clifftop.".into());
+/// world.player.current_room = foot;
+/// world.enable_stamina(Stamina::new(10).cost("climb", 6).cost("fight", 2));
+///
+/// assert!(world.perform(Action::Go(Direction::Up)).is_ok());
+/// assert_eq!(world.stamina.as_ref().unwrap().current, 4);
+/// assert_eq!(world.perform(Action::Go(Direction::Up)), Err("You're too exhausted to do that.".into()));
+/// assert_eq!(world.player.current_room, ledge);
+///
+/// world.perform(Action::Custom("fight".into())).unwrap();
+/// assert_eq!(world.perform(Action::Custom("fight".into())), Ok(vec!["You are exhausted.".into()]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Stamina {
+    /// The stamina left.
+    pub current: u32,
+    /// The most stamina the player can have.
+    pub maximum: u32,
+    /// The stamina spent by each action, by [name](Action::name). Other actions are free.
+    pub costs: BTreeMap<String, u32>,
+    /// The stamina restored by a turn of rest.
+    pub rest: u32,
+    /// The stamina restored by eating each item.
+    pub food: BTreeMap<ItemId, u32>,
+    /// What the player is told when they are too exhausted for an action.
+    pub exhausted_message: String,
+}
+
+impl Stamina {
+    /// Creates a new `Stamina` at its maximum, without any costs, restoring a tenth of the maximum per turn of rest.
+    pub fn new(maximum: u32) -> Self {
+        Self {
+            current: maximum,
+            maximum,
+            costs: BTreeMap::new(),
+            rest: (maximum / 10).max(1),
+            food: BTreeMap::new(),
+            exhausted_message: "You're too exhausted to do that.".into(),
+        }
+    }
+
+    /// Sets the stamina spent by an action, by [name](Action::name).
+    pub fn cost(mut self, action: &str, cost: u32) -> Self {
+        self.costs.insert(action.into(), cost);
+        self
+    }
+
+    /// Makes an item food that restores the given stamina when eaten.
+    pub fn food(mut self, item: ItemId, restores: u32) -> Self {
+        self.food.insert(item, restores);
+        self
+    }
+
+    /// Gets the stamina spent by an action.
+    pub fn cost_of(&self, action: &Action) -> u32 {
+        self.costs.get(action.name()).copied().unwrap_or(0)
+    }
+
+    /// Checks whether the player has no stamina left.
+    pub fn is_exhausted(&self) -> bool {
+        self.current == 0
+    }
+
+    /// Spends stamina, returning whether there was enough. Nothing is spent if there wasn't.
+    pub fn spend(&mut self, amount: u32) -> bool {
+        match self.current.checked_sub(amount) {
+            Some(left) => {
+                self.current = left;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Restores stamina, up to the maximum.
+    pub fn restore(&mut self, amount: u32) {
+        self.current = (self.current + amount).min(self.maximum);
+    }
+}
+
+impl World {
+    /// Gives the player [`Stamina`], and adds the rules that stop actions the player is too exhausted for and spend the
+    /// cost of the others.
+    ///
+    /// The rules are only added once, so the stamina can be replaced by calling this again.
+    pub fn enable_stamina(&mut self, stamina: Stamina) {
+        if self.stamina.replace(stamina).is_some() {
+            return;
+        }
+        self.rules.add(
+            Stage::Check,
+            i32::MAX,
+            Rc::new(|world, action| match &world.stamina {
+                Some(stamina) if stamina.cost_of(action) > stamina.current => {
+                    RuleOutcome::Stop(stamina.exhausted_message.clone())
+                }
+                _ => RuleOutcome::Continue,
+            }),
+        );
+        self.rules.add(
+            Stage::CarryOut,
+            i32::MIN,
+            Rc::new(|world, action| {
+                let Some(stamina) = &mut world.stamina else {
+                    return RuleOutcome::Continue;
+                };
+                let cost = stamina.cost_of(action);
+                if cost > 0 && stamina.spend(cost) && stamina.is_exhausted() {
+                    RuleOutcome::Stop("You are exhausted.".into())
+                } else {
+                    RuleOutcome::Continue
+                }
+            }),
+        );
+    }
+}
+
+/// A command handler that lets the player rest for a turn, restoring some [`Stamina`].
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::stamina::{Stamina, rest_command};
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["rest"], rest_command);
+/// let mut stamina = Stamina::new(20);
+/// stamina.current = 5;
+/// world.enable_stamina(stamina);
+///
+/// world.execute("rest").unwrap();
+/// assert_eq!(world.stamina.as_ref().unwrap().current, 7);
+/// assert_eq!(world.turn, 1);
+/// assert_eq!(world.output.drain(), vec!["You rest for a while."]);
+/// ```
+#[cfg(feature = "parser")]
+pub fn rest_command(world: &mut World, _command: &Command) {
+    let message = match &mut world.stamina {
+        Some(stamina) if stamina.current == stamina.maximum => "You aren't tired.",
+        Some(stamina) => {
+            stamina.restore(stamina.rest);
+            "You rest for a while."
+        }
+        None => "You aren't tired.",
+    };
+    world.output.say(message.into());
+    for message in world.advance_turn().messages {
+        world.output.say(message);
+    }
+}
+
+/// A command handler that lets the player eat a carried item that is food, such as "eat bread", restoring some
+/// [`Stamina`].
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::item::Item;
+/// use worldwright::stamina::{Stamina, eat_command};
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["eat"], eat_command);
+/// let bread = world.items.add(Item::new("bread".into(), "A crusty loaf.".into()));
+/// let stone = world.items.add(Item::new("stone".into(), "A smooth stone.".into()));
+/// world.player.inventory.extend([bread, stone]);
+/// let mut stamina = Stamina::new(20).food(bread, 8);
+/// stamina.current = 2;
+/// world.enable_stamina(stamina);
+///
+/// world.execute("eat stone").unwrap();
+/// world.execute("eat bread").unwrap();
+/// assert_eq!(world.stamina.as_ref().unwrap().current, 10);
+/// assert!(!world.player.carries(bread));
+/// assert_eq!(world.output.drain(), vec!["That's not something you can eat.", "You eat the bread."]);
+/// ```
+#[cfg(feature = "parser")]
+pub fn eat_command(world: &mut World, command: &Command) {
+    let Some(name) = command.noun() else {
+        world.output.say("What do you want to eat?".into());
+        return;
+    };
+    let Some(item) = world
+        .player
+        .inventory
+        .iter()
+        .copied()
+        .find(|&item| world.items[item].name.eq_ignore_ascii_case(&name))
+    else {
+        world.output.say("You aren't carrying that.".into());
+        return;
+    };
+    let Some(stamina) = &mut world.stamina else {
+        world.output.say("You aren't hungry.".into());
+        return;
+    };
+    let Some(restores) = stamina.food.get(&item).copied() else {
+        world.output.say("That's not something you can eat.".into());
+        return;
+    };
+    stamina.restore(restores);
+    world.player.inventory.retain(|&carried| carried != item);
+    let message = format!("You eat the {}.", world.items[item].name);
+    world.output.say(message);
+}
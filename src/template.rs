@@ -0,0 +1,220 @@
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Replaces the `{placeholders}` in a template with the values returned by `lookup`.
+///
+/// Placeholders that `lookup` doesn't know are left as they are, so mistakes stay visible.
+/// Literal braces are written `{{` and `}}`.
+///
+/// # Examples
+/// ```
+/// use worldwright::template::render;
+///
+/// let text = render("The door to the {direction} is {door_state}. {{sic}}", |name| match name {
+///     "direction" => Some("north".into()),
+///     "door_state" => Some("locked".into()),
+///     _ => None,
+/// });
+/// assert_eq!(text, "The door to the north is locked. {sic}");
+///
+/// assert_eq!(render("Hello, {stranger}!", |_| None), "Hello, {stranger}!");
+/// ```
+pub fn render(template: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(index) = rest.find(['{', '}']) {
+        rendered.push_str(&rest[..index]);
+        let brace = rest.as_bytes()[index];
+        rest = &rest[index + 1..];
+
+        if rest.as_bytes().first() == Some(&brace) {
+            rendered.push(brace as char);
+            rest = &rest[1..];
+        } else if brace == b'{'
+            && let Some(end) = rest.find('}')
+        {
+            let name = &rest[..end];
+            match lookup(name.trim()) {
+                Some(value) => rendered.push_str(&value),
+                None => {
+                    rendered.push('{');
+                    rendered.push_str(name);
+                    rendered.push('}');
+                }
+            }
+            rest = &rest[end + 1..];
+        } else {
+            rendered.push(brace as char);
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// A store of named values that templates can refer to.
+///
+/// # Examples
+/// ```
+/// use worldwright::template::{Variables, render};
+///
+/// let mut variables = Variables::new();
+/// variables.set("innkeeper", "Marta");
+/// assert_eq!(render("{innkeeper} waves at you.", |name| variables.get(name).map(String::from)), "Marta waves at you.");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Variables {
+    values: BTreeMap<String, String>,
+}
+
+impl Variables {
+    /// Creates a new, empty `Variables` store.
+    pub fn new() -> Self {
+        Self {
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the value of a variable.
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    /// Gets the value of a variable.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// Removes a variable, returning whether it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.values.remove(name).is_some()
+    }
+}
+
+const MINUTES_PER_HOUR: u32 = 60;
+const MINUTES_PER_DAY: u32 = 24 * MINUTES_PER_HOUR;
+
+/// Spells out a number in words, such as "three" or "forty-two", for numbers below a hundred.
+///
+/// Larger numbers are written with digits.
+///
+/// # Examples
+/// ```
+/// use worldwright::template::number_in_words;
+///
+/// assert_eq!(number_in_words(3), "three");
+/// assert_eq!(number_in_words(42), "forty-two");
+/// assert_eq!(number_in_words(250), "250");
+/// ```
+pub fn number_in_words(number: u32) -> String {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    match number {
+        0..20 => ONES[number as usize].into(),
+        20..100 if number.is_multiple_of(10) => TENS[number as usize / 10].into(),
+        20..100 => format!(
+            "{}-{}",
+            TENS[number as usize / 10],
+            ONES[number as usize % 10]
+        ),
+        _ => number.to_string(),
+    }
+}
+
+/// Rounds a duration to a natural phrase, and whether the phrase is plural.
+fn duration_phrase(minutes: u32) -> (String, bool) {
+    // Rounds to the nearest multiple of `unit`, with "about" unless it's exact.
+    let rounded = |unit: u32, one: &str, many: &str| {
+        let count = ((minutes + unit / 2) / unit).max(1);
+        let about = if minutes.is_multiple_of(unit) {
+            ""
+        } else {
+            "about "
+        };
+        if count == 1 {
+            (format!("{about}{one}"), false)
+        } else {
+            (format!("{about}{} {many}", number_in_words(count)), true)
+        }
+    };
+    match minutes {
+        0..2 => ("a moment".into(), false),
+        2..5 => ("a few minutes".into(), true),
+        5..25 => {
+            let rounded = (minutes + 2) / 5 * 5;
+            let about = if minutes == rounded { "" } else { "about " };
+            (format!("{about}{} minutes", number_in_words(rounded)), true)
+        }
+        25..45 => ("about half an hour".into(), false),
+        45..MINUTES_PER_DAY => rounded(MINUTES_PER_HOUR, "an hour", "hours"),
+        _ => rounded(MINUTES_PER_DAY, "a day", "days"),
+    }
+}
+
+/// Describes a number of minutes as a natural duration, such as "a few minutes", "about an hour" or "three days".
+///
+/// # Examples
+/// ```
+/// use worldwright::template::duration;
+///
+/// assert_eq!(duration(1), "a moment");
+/// assert_eq!(duration(14), "about fifteen minutes");
+/// assert_eq!(duration(65), "about an hour");
+/// assert_eq!(duration(180), "three hours");
+/// assert_eq!(duration(3 * 24 * 60), "three days");
+/// ```
+pub fn duration(minutes: u32) -> String {
+    duration_phrase(minutes).0
+}
+
+/// Describes how much later something happens, such as "about an hour later".
+///
+/// # Examples
+/// ```
+/// use worldwright::template::later;
+///
+/// assert_eq!(later(0), "a moment later");
+/// assert_eq!(later(70), "about an hour later");
+/// ```
+pub fn later(minutes: u32) -> String {
+    format!("{} later", duration(minutes))
+}
+
+/// Describes time passing, such as "an hour passes" or "three days pass".
+///
+/// # Examples
+/// ```
+/// use worldwright::template::passes;
+///
+/// assert_eq!(passes(60), "an hour passes");
+/// assert_eq!(passes(3 * 24 * 60), "three days pass");
+/// ```
+pub fn passes(minutes: u32) -> String {
+    let (phrase, plural) = duration_phrase(minutes);
+    format!("{phrase} {}", if plural { "pass" } else { "passes" })
+}
@@ -0,0 +1,154 @@
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Identifies a [`Timer`] in [`Timers`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimerId(usize);
+
+/// A countdown measured in turns, such as a bomb fuse or the player's remaining oxygen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Timer {
+    /// The label of the timer, such as "Oxygen".
+    pub label: String,
+    /// The number of turns left.
+    pub remaining: u32,
+    /// The number of turns the timer started with.
+    pub total: u32,
+    /// Whether the timer is shown to the player.
+    pub visible: bool,
+}
+
+/// The state of a visible [`Timer`], for front ends to render as a countdown or a progress bar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimerStatus {
+    /// The [`TimerId`] of the timer.
+    pub id: TimerId,
+    /// The label of the timer.
+    pub label: String,
+    /// The number of turns left.
+    pub remaining: u32,
+    /// The number of turns the timer started with.
+    pub total: u32,
+}
+
+impl TimerStatus {
+    /// Returns how much of the timer is left, from `1.0` when it starts to `0.0` when it runs out.
+    pub fn fraction_left(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            f64::from(self.remaining) / f64::from(self.total)
+        }
+    }
+}
+
+/// The active countdowns of the game.
+///
+/// [`visible`](Timers::visible) is a structured output channel, separate from the text output,
+/// that front ends can poll to render the timers the author chose to show to the player.
+///
+/// # Examples
+/// ```
+/// use worldwright::timers::Timers;
+///
+/// let mut timers = Timers::new();
+/// let oxygen = timers.start("Oxygen".into(), 3, true);
+/// let _ambush = timers.start("Ambush".into(), 2, false);
+///
+/// assert!(timers.tick().is_empty());
+/// let visible = timers.visible();
+/// assert_eq!(visible.len(), 1);
+/// assert_eq!(visible[0].label, "Oxygen");
+/// assert_eq!(visible[0].remaining, 2);
+///
+/// assert_eq!(timers.tick(), vec!["Ambush".to_string()]);
+/// assert_eq!(timers.tick(), vec!["Oxygen".to_string()]);
+/// assert!(timers.get(oxygen).is_none());
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Timers {
+    timers: Vec<(TimerId, Timer)>,
+    next_id: usize,
+}
+
+impl Timers {
+    /// Creates a new `Timers` without any active countdowns.
+    pub fn new() -> Self {
+        Self {
+            timers: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Starts a [`Timer`] that runs out after the given number of turns.
+    pub fn start(&mut self, label: String, turns: u32, visible: bool) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id += 1;
+        self.timers.push((
+            id,
+            Timer {
+                label,
+                remaining: turns,
+                total: turns,
+                visible,
+            },
+        ));
+        id
+    }
+
+    /// Gets an active [`Timer`].
+    pub fn get(&self, id: TimerId) -> Option<&Timer> {
+        self.timers
+            .iter()
+            .find(|(timer_id, _)| *timer_id == id)
+            .map(|(_, timer)| timer)
+    }
+
+    /// Gets a mutable reference to an active [`Timer`], for example to add time or change its visibility.
+    pub fn get_mut(&mut self, id: TimerId) -> Option<&mut Timer> {
+        self.timers
+            .iter_mut()
+            .find(|(timer_id, _)| *timer_id == id)
+            .map(|(_, timer)| timer)
+    }
+
+    /// Cancels a [`Timer`], returning whether it was active.
+    pub fn cancel(&mut self, id: TimerId) -> bool {
+        let count = self.timers.len();
+        self.timers.retain(|(timer_id, _)| *timer_id != id);
+        self.timers.len() != count
+    }
+
+    /// Advances every timer by one turn, removing and returning the labels of the ones that ran out.
+    pub fn tick(&mut self) -> Vec<String> {
+        let mut expired = Vec::new();
+        self.timers.retain_mut(|(_, timer)| {
+            timer.remaining = timer.remaining.saturating_sub(1);
+            if timer.remaining == 0 {
+                expired.push(timer.label.clone());
+                false
+            } else {
+                true
+            }
+        });
+        expired
+    }
+
+    /// Returns the status of every visible timer, in the order they were started.
+    pub fn visible(&self) -> Vec<TimerStatus> {
+        self.timers
+            .iter()
+            .filter(|(_, timer)| timer.visible)
+            .map(|(id, timer)| TimerStatus {
+                id: *id,
+                label: timer.label.clone(),
+                remaining: timer.remaining,
+                total: timer.total,
+            })
+            .collect()
+    }
+}
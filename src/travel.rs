@@ -0,0 +1,181 @@
+use crate::World;
+use crate::map::RoomId;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+use crate::rules::Action;
+
+/// What happened while the player traveled, returned by [`World::travel_to`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TravelOutcome {
+    /// The uneventful rooms the player passed through on the way, in order.
+    pub passed: Vec<RoomId>,
+    /// One paragraph summing up the uneventful rooms, such as "You pass through the Gallery and the Library.",
+    /// or `None` if there were none.
+    pub summary: Option<String>,
+    /// The messages of the event that stopped the journey, if any.
+    pub messages: Vec<String>,
+    /// Whether the player reached their destination.
+    pub arrived: bool,
+}
+
+impl World {
+    /// Travels to a distant room one step per turn, summing up the uneventful rooms on the way in one paragraph.
+    ///
+    /// The way only goes through rooms the player has [visited](crate::map::Room::visited) and exits they could go
+    /// through, including exits without a direction such as a [`Teleporter`](crate::map::exit_types::Teleporter).
+    /// Every step is [performed](World::perform) as an [`Action::GoThrough`] and followed by a [turn](World::advance_turn).
+    /// The journey stops, switching back to normal play, as soon as a step is eventful: when it fails or reports anything,
+    /// when the turn produces messages or something significant happens, when anything is added to the [`Output`](crate::Output),
+    /// or when there is an NPC in the room.
+    ///
+    /// Returns an error if there is no known way to the room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::actor::Npc;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::{RegularExit, Teleporter};
+    ///
+    /// let mut world = World::new();
+    /// let mut previous = world.map.new_room_with_name("Hall".into(), "A grand hall.".into());
+    /// world.player.current_room = previous;
+    /// let mut rooms = Vec::new();
+    /// for name in ["Gallery", "Library", "Study"] {
+    ///     previous = world.map.new_room_in_direction(previous, Direction::North, Box::new(RegularExit), "A room.".into());
+    ///     world.map.graph[previous].name = Some(name.into());
+    ///     rooms.push(previous);
+    /// }
+    /// let tower = world.map.new_room_with_name("Tower".into(), "A lonely tower.".into());
+    /// world.map.connect_rooms_without_direction(previous, tower, Box::new(Teleporter::new("portal".into())));
+    /// rooms.push(tower);
+    /// world.npcs.add(Npc::new("librarian".into(), rooms[2]));
+    ///
+    /// assert_eq!(world.travel_to(rooms[3]), Err("You don't know the way there.".into()));
+    /// // The player has explored the manor before.
+    /// for &room in &rooms {
+    ///     world.map.mark_visited(room);
+    /// }
+    ///
+    /// let outcome = world.travel_to(rooms[3]).unwrap();
+    /// assert_eq!(outcome.summary.as_deref(), Some("You pass through the Gallery and the Library."));
+    /// assert!(!outcome.arrived);
+    /// assert_eq!(world.player.current_room, rooms[2]);
+    ///
+    /// let outcome = world.travel_to(rooms[3]).unwrap();
+    /// assert_eq!(outcome.summary, None);
+    /// assert!(outcome.arrived);
+    /// ```
+    pub fn travel_to(&mut self, destination: RoomId) -> Result<TravelOutcome, String> {
+        let route = self
+            .map
+            .find_route(
+                self.player.current_room,
+                destination,
+                |exit_id, exit, next| {
+                    let (source, target) = self.map.exit_endpoints(exit_id).unwrap();
+                    let room = if target == next { source } else { target };
+                    self.map.graph[next].visited
+                        && exit.exit_type.check_passage_from(self, room).is_ok()
+                },
+            )
+            .ok_or_else(|| String::from("You don't know the way there."))?;
+        let mut outcome = TravelOutcome {
+            passed: Vec::new(),
+            summary: None,
+            messages: Vec::new(),
+            arrived: false,
+        };
+
+        for (exit_id, next) in route {
+            let output = self.output.messages().len();
+            let report = match self.perform(Action::GoThrough(exit_id)) {
+                Ok(report) => report,
+                Err(message) => {
+                    outcome.messages.push(message);
+                    break;
+                }
+            };
+            let turn = self.advance_turn();
+            let eventful = !report.is_empty()
+                || !turn.messages.is_empty()
+                || turn.significant
+                || self.output.messages().len() > output
+                || !self.npcs.in_room(next).is_empty();
+            if eventful {
+                outcome.messages.extend(report);
+                outcome.messages.extend(turn.messages);
+                break;
+            }
+            if next != destination {
+                outcome.passed.push(next);
+            }
+        }
+
+        if !outcome.passed.is_empty() {
+            let names: Vec<String> = outcome
+                .passed
+                .iter()
+                .map(|&room_id| match &self.map.graph[room_id].name {
+                    Some(name) => format!("the {name}"),
+                    None => "a room".into(),
+                })
+                .collect();
+            outcome.summary = Some(format!("You pass through {}.", crate::join_list(&names)));
+        }
+        outcome.arrived = self.player.current_room == destination;
+        Ok(outcome)
+    }
+}
+
+/// A command handler that travels to a room by name, such as "travel to library".
+///
+/// The summary of the journey and the messages of whatever stopped it are said, followed by the description of the room
+/// the player ends up in.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::Direction;
+/// use worldwright::map::exit_types::RegularExit;
+/// use worldwright::travel::travel_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["travel"], travel_command);
+/// let hall = world.map.new_room_with_name("Hall".into(), "A grand hall.".into());
+/// let gallery = world.map.new_room_in_direction(hall, Direction::East, Box::new(RegularExit), "Paintings.".into());
+/// world.map.graph[gallery].name = Some("Gallery".into());
+/// let library = world.map.new_room_in_direction(gallery, Direction::East, Box::new(RegularExit), "Books.".into());
+/// world.map.graph[library].name = Some("Library".into());
+/// world.map.mark_visited(gallery);
+/// world.map.mark_visited(library);
+/// world.player.current_room = hall;
+///
+/// world.execute("travel to library").unwrap();
+/// assert_eq!(world.output.drain(), vec!["You pass through the Gallery.", "Library\nBooks."]);
+/// ```
+#[cfg(feature = "parser")]
+pub fn travel_command(world: &mut World, command: &Command) {
+    let words = match command.words.split_first() {
+        Some((first, rest)) if first == "to" => rest,
+        _ => &command.words,
+    };
+    let name = words.join(" ");
+    let Some(destination) = world.map.room_by_name(&name) else {
+        world
+            .output
+            .say(format!("You don't know of anywhere called \"{name}\"."));
+        return;
+    };
+    match world.travel_to(destination) {
+        Ok(outcome) => {
+            for message in outcome.summary.into_iter().chain(outcome.messages) {
+                world.output.say(message);
+            }
+            let description = world.describe_room(world.player.current_room).to_string();
+            world.output.say(description);
+        }
+        Err(message) => world.output.say(message),
+    }
+}
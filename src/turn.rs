@@ -0,0 +1,15 @@
+use crate::prelude::*;
+
+/// What happened during a turn, returned by [`World::advance_turn`](crate::World::advance_turn).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TurnOutcome {
+    /// The number of the turn that just ended.
+    pub turn: u32,
+    /// The messages of the events of the turn, in order.
+    pub messages: Vec<String>,
+    /// The labels of the [`Timer`](crate::timers::Timer)s that ran out.
+    pub expired_timers: Vec<String>,
+    /// Whether anything significant happened, such as narration, an exit changing, an NPC arriving in the player's room,
+    /// a timer running out or a fuse of the [`Scheduler`](crate::scheduler::Scheduler) firing. Barks, banter and ambience are not significant.
+    pub significant: bool,
+}
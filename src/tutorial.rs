@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+/// Something the player did that can trigger a tutorial [`Tip`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TipTrigger {
+    /// The player entered a command that could not be understood.
+    FailedCommand,
+    /// The player tried to go through a locked door.
+    LockedDoor,
+    /// A trigger defined by the game, identified by name.
+    Custom(String),
+}
+
+/// Identifies a [`Tip`] within a [`Tutorial`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TipId(usize);
+
+/// A contextual hint shown to the player when its [`TipTrigger`] happens.
+#[derive(Clone, Debug)]
+pub struct Tip {
+    /// What the player has to do for the tip to be shown.
+    pub trigger: TipTrigger,
+    /// The text of the tip.
+    pub text: String,
+    /// Whether the player has acknowledged the tip. Acknowledged tips are never shown again.
+    pub acknowledged: bool,
+    /// How many times the tip has been shown.
+    pub times_shown: u32,
+}
+
+/// A set of onboarding [`Tip`]s triggered by player behavior.
+///
+/// Tips are shown every time their trigger happens until they are acknowledged, after which they are suppressed.
+///
+/// # Examples
+/// ```
+/// use worldwright::tutorial::{TipTrigger, Tutorial};
+///
+/// let mut tutorial = Tutorial::new();
+/// let tip = tutorial.add_tip(
+///     TipTrigger::LockedDoor,
+///     "Locked doors need a key. Try UNLOCK DOOR WITH KEY.".into(),
+/// );
+///
+/// assert_eq!(
+///     tutorial.trigger(&TipTrigger::LockedDoor),
+///     vec!["Locked doors need a key. Try UNLOCK DOOR WITH KEY."]
+/// );
+/// assert!(tutorial.trigger(&TipTrigger::FailedCommand).is_empty());
+///
+/// tutorial.acknowledge(tip);
+/// assert!(tutorial.trigger(&TipTrigger::LockedDoor).is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tutorial {
+    /// Whether tips are shown at all.
+    pub enabled: bool,
+    /// How many times each tip is shown at most, or `None` to show it until it is acknowledged.
+    pub max_times_shown: Option<u32>,
+    tips: Vec<Tip>,
+}
+
+impl Tutorial {
+    /// Creates a new, enabled `Tutorial` without any tips.
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            max_times_shown: None,
+            tips: Vec::new(),
+        }
+    }
+
+    /// Adds a [`Tip`] shown when `trigger` happens and returns its [`TipId`].
+    pub fn add_tip(&mut self, trigger: TipTrigger, text: String) -> TipId {
+        self.tips.push(Tip {
+            trigger,
+            text,
+            acknowledged: false,
+            times_shown: 0,
+        });
+        TipId(self.tips.len() - 1)
+    }
+
+    /// Gets the [`Tip`] with the given [`TipId`].
+    pub fn tip(&self, id: TipId) -> Option<&Tip> {
+        self.tips.get(id.0)
+    }
+
+    /// Reports that `trigger` happened.
+    ///
+    /// Returns the text of every unacknowledged [`Tip`] for the trigger that hasn't been shown
+    /// [too often](Tutorial::max_times_shown), or nothing if the tutorial is disabled.
+    pub fn trigger(&mut self, trigger: &TipTrigger) -> Vec<String> {
+        if !self.enabled {
+            return Vec::new();
+        }
+
+        let mut texts = Vec::new();
+        for tip in &mut self.tips {
+            let exhausted = self
+                .max_times_shown
+                .is_some_and(|max| tip.times_shown >= max);
+            if !tip.acknowledged && !exhausted && tip.trigger == *trigger {
+                tip.times_shown += 1;
+                texts.push(tip.text.clone());
+            }
+        }
+        texts
+    }
+
+    /// Acknowledges a [`Tip`], suppressing it from now on.
+    pub fn acknowledge(&mut self, id: TipId) {
+        if let Some(tip) = self.tips.get_mut(id.0) {
+            tip.acknowledged = true;
+        }
+    }
+
+    /// Gets a mutable reference to the [`Tip`] with the given [`TipId`].
+    pub fn tip_mut(&mut self, id: TipId) -> Option<&mut Tip> {
+        self.tips.get_mut(id.0)
+    }
+
+    /// Iterates over the [`Tip`]s along with their [`TipId`]s, in the order they were added.
+    pub fn tips(&self) -> impl Iterator<Item = (TipId, &Tip)> {
+        self.tips
+            .iter()
+            .enumerate()
+            .map(|(index, tip)| (TipId(index), tip))
+    }
+
+    /// Acknowledges every [`Tip`], for players that don't want any more help.
+    pub fn acknowledge_all(&mut self) {
+        for tip in &mut self.tips {
+            tip.acknowledged = true;
+        }
+    }
+}
+
+impl Default for Tutorial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
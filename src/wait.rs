@@ -0,0 +1,119 @@
+#[cfg(feature = "parser")]
+use crate::World;
+#[cfg(feature = "parser")]
+use crate::parser::Command;
+use crate::prelude::*;
+
+/// What happened while the player waited, returned by [`World::wait`](crate::World::wait).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WaitOutcome {
+    /// The number of turns that passed.
+    pub turns: u32,
+    /// The messages of the events that happened while waiting, in order.
+    pub messages: Vec<String>,
+    /// Whether waiting stopped early because something significant happened.
+    pub interrupted: bool,
+}
+
+/// Parses how long the player wants to wait, such as "for 10 minutes" or "3 turns", into a number of turns.
+///
+/// Without a duration, the player waits one turn. Returns `None` if the duration can't be understood.
+#[cfg(feature = "parser")]
+fn parse_duration(words: &[String], minutes_per_turn: u32) -> Option<u32> {
+    let words = match words {
+        [first, rest @ ..] if first == "for" => rest,
+        words => words,
+    };
+    let (count, unit) = match words {
+        [] => return Some(1),
+        // The parser drops articles, so "wait for an hour" leaves just the unit.
+        [unit] => (1, unit.as_str()),
+        [count, unit] => (count.parse().ok()?, unit.as_str()),
+        _ => return None,
+    };
+    let minutes: u32 = match unit.trim_end_matches('s') {
+        "turn" => return Some(count),
+        "minute" => count,
+        "hour" => count.checked_mul(60)?,
+        _ => return None,
+    };
+    Some(minutes.div_ceil(minutes_per_turn.max(1)))
+}
+
+/// A command handler that lets the player wait, such as "wait", "wait 3 turns" or "wait for 10 minutes".
+///
+/// Time is skipped turn by turn with [`World::wait`], and waiting stops early when something significant happens.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::wait::wait_command;
+///
+/// let mut world = World::new();
+/// world.parser.add_verb(&["wait", "z"], wait_command);
+/// world.minutes_per_turn = 5;
+/// world.narration.after_turns(4, "The church bell tolls.".into());
+///
+/// world.execute("wait for 10 minutes").unwrap();
+/// assert_eq!(world.output.drain(), vec!["Ten minutes pass."]);
+///
+/// world.execute("wait for an hour").unwrap();
+/// assert_eq!(world.output.drain(), vec!["Ten minutes pass.", "The church bell tolls."]);
+/// assert_eq!(world.turn, 4);
+///
+/// world.execute("wait until dawn").unwrap();
+/// assert_eq!(world.output.drain(), vec!["I don't understand how long you want to wait."]);
+/// ```
+#[cfg(feature = "parser")]
+pub fn wait_command(world: &mut World, command: &Command) {
+    let Some(turns) = parse_duration(&command.words, world.minutes_per_turn) else {
+        world
+            .output
+            .say("I don't understand how long you want to wait.".into());
+        return;
+    };
+    let outcome = world.wait(turns);
+    world.output.say(world.describe_time_passing(outcome.turns));
+    for message in outcome.messages {
+        world.output.say(message);
+    }
+}
+
+/// Creates a command handler that lets the player sleep until `until` is true, such as "sleep until morning".
+///
+/// The player sleeps at most `max_turns` turns, and wakes up early when something significant happens.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::wait::sleep_command;
+///
+/// let mut world = World::new();
+/// world.minutes_per_turn = 30;
+/// world.parser.add_verb(&["sleep"], sleep_command(|world| world.turn % 48 == 14, 48));
+///
+/// world.execute("sleep until morning").unwrap();
+/// assert_eq!(world.output.drain(), vec!["You fall asleep. Seven hours pass."]);
+/// assert_eq!(world.turn, 14);
+/// ```
+#[cfg(feature = "parser")]
+pub fn sleep_command(
+    until: impl Fn(&World) -> bool + 'static,
+    max_turns: u32,
+) -> impl Fn(&mut World, &Command) + 'static {
+    move |world, _| {
+        let outcome = world.wait_until(&until, max_turns);
+        let woken = if outcome.interrupted {
+            " You wake with a start."
+        } else {
+            ""
+        };
+        world.output.say(format!(
+            "You fall asleep. {}{woken}",
+            world.describe_time_passing(outcome.turns)
+        ));
+        for message in outcome.messages {
+            world.output.say(message);
+        }
+    }
+}
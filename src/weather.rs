@@ -0,0 +1,257 @@
+use crate::map::{Map, Region};
+use crate::prelude::*;
+use crate::scheduler::EventId;
+use crate::{Rng, RngStreams, World};
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+use petgraph::prelude::NodeIndex;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The weather in a [`WeatherZone`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Weather {
+    /// Clear skies.
+    Clear,
+    /// Rain.
+    Rain,
+    /// A storm, with thunder and lightning.
+    Storm,
+    /// Snow.
+    Snow,
+}
+
+impl Weather {
+    /// Gets what the player is told when the weather turns to this, such as "It starts to rain."
+    pub fn onset_message(&self) -> &'static str {
+        match self {
+            Weather::Clear => "The sky clears.",
+            Weather::Rain => "It starts to rain.",
+            Weather::Storm => "A storm breaks overhead.",
+            Weather::Snow => "It starts to snow.",
+        }
+    }
+}
+
+impl core::fmt::Display for Weather {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = match self {
+            Weather::Clear => "clear",
+            Weather::Rain => "rain",
+            Weather::Storm => "storm",
+            Weather::Snow => "snow",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A [`Region`] of the map with its own [`Weather`], which changes at random from one state to another.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeatherZone {
+    /// The rooms the weather is in.
+    pub region: Region,
+    /// The current weather.
+    pub current: Weather,
+    /// The weights of the weather that can follow each weather. Weather without any stays as it is.
+    pub transitions: BTreeMap<Weather, BTreeMap<Weather, u32>>,
+}
+
+impl WeatherZone {
+    /// Creates a new `WeatherZone` with the given current weather, where clear skies turn to rain or snow, rain
+    /// turns to storms, and storms and snow pass.
+    pub fn new(region: Region, current: Weather) -> Self {
+        let zone = Self {
+            region,
+            current,
+            transitions: BTreeMap::new(),
+        };
+        zone.transition(Weather::Clear, Weather::Clear, 6)
+            .transition(Weather::Clear, Weather::Rain, 2)
+            .transition(Weather::Clear, Weather::Snow, 1)
+            .transition(Weather::Rain, Weather::Rain, 4)
+            .transition(Weather::Rain, Weather::Clear, 3)
+            .transition(Weather::Rain, Weather::Storm, 2)
+            .transition(Weather::Storm, Weather::Storm, 2)
+            .transition(Weather::Storm, Weather::Rain, 3)
+            .transition(Weather::Snow, Weather::Snow, 4)
+            .transition(Weather::Snow, Weather::Clear, 3)
+    }
+
+    /// Sets the weight of the weather `to` following the weather `from`. A weight of 0 means it never does.
+    pub fn transition(mut self, from: Weather, to: Weather, weight: u32) -> Self {
+        let next = self.transitions.entry(from).or_default();
+        if weight == 0 {
+            next.remove(&to);
+        } else {
+            next.insert(to, weight);
+        }
+        self
+    }
+
+    /// Picks the weather following the current one at random, by the weights of the transitions.
+    pub fn next(&self, rng: &Rng) -> Weather {
+        let Some(next) = self.transitions.get(&self.current) else {
+            return self.current;
+        };
+        let total: u32 = next.values().sum();
+        let mut roll = rng.below(total);
+        for (&weather, &weight) in next {
+            if roll < weight {
+                return weather;
+            }
+            roll -= weight;
+        }
+        self.current
+    }
+}
+
+/// A hook run when the weather of a [`WeatherZone`] changes, with the index of the zone and the old and new weather.
+pub type WeatherHook = Rc<dyn Fn(&mut World, usize, Weather, Weather)>;
+
+/// The weather of the [`World`]: [`WeatherZone`]s advanced by the [`Scheduler`](crate::scheduler::Scheduler) once
+/// [enabled](World::enable_weather).
+///
+/// The weather of a room is the weather of the first zone containing it, so a zone covering
+/// [everywhere](Region::Everywhere) can be added last as the global weather. It can react in several ways:
+/// - the player is told when the weather of their room changes, such as "It starts to rain.";
+/// - templates, such as room descriptions, can refer to it as `{weather}`;
+/// - [ambient messages](crate::ambience::Ambience::add_for_weather) can be shown only in some weather;
+/// - [exits](crate::map::exit_types::Restriction::Weather) can be closed in some weather;
+/// - [hooks](Weathers::on_change) run whenever it changes.
+///
+/// # Examples
+/// ```
+/// use worldwright::World;
+/// use worldwright::map::{Direction, Region};
+/// use worldwright::map::exit_types::{NamedExit, RestrictedExit, Restriction};
+/// use worldwright::weather::{Weather, WeatherZone};
+///
+/// let mut world = World::new();
+/// let bank = world.map.new_room("A muddy riverbank. Weather: {weather}.".into());
+/// let mut ford = RestrictedExit::new(Box::new(NamedExit::new("ford".into())));
+/// ford.restrict(
+///     Restriction::Weather { weather: Weather::Storm, room: bank },
+///     "The ford is impassable in a storm.".into(),
+/// );
+/// world.map.new_room_in_direction(bank, Direction::East, Box::new(ford), "The far bank.".into());
+/// world.player.current_room = bank;
+///
+/// // The weather stays clear for a while, then always turns stormy.
+/// world.weather.add(WeatherZone::new(Region::Everywhere, Weather::Clear)
+///     .transition(Weather::Clear, Weather::Rain, 0)
+///     .transition(Weather::Clear, Weather::Snow, 0)
+///     .transition(Weather::Clear, Weather::Storm, 1)
+///     .transition(Weather::Clear, Weather::Clear, 0));
+/// world.enable_weather(3);
+///
+/// assert!(world.check_passage(bank, Direction::East).is_ok());
+/// world.advance_turn();
+/// world.advance_turn();
+/// world.advance_turn();
+/// assert_eq!(world.weather_at(bank), Some(Weather::Storm));
+/// assert_eq!(world.output.drain(), vec!["A storm breaks overhead."]);
+/// assert_eq!(world.check_passage(bank, Direction::East), Err("The ford is impassable in a storm.".into()));
+/// assert_eq!(world.describe_room(bank).to_string(), "A muddy riverbank. Weather: storm.");
+/// ```
+#[derive(Clone, Default)]
+pub struct Weathers {
+    zones: Vec<WeatherZone>,
+    hooks: Vec<WeatherHook>,
+}
+
+impl core::fmt::Debug for Weathers {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Weathers")
+            .field("zones", &self.zones)
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+impl Weathers {
+    /// The name of the [`Scheduler`](crate::scheduler::Scheduler) handler that changes the weather.
+    pub const HANDLER: &'static str = "weather";
+
+    /// Creates a new `Weathers` without any zones, where there is no weather.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a [`WeatherZone`] and returns its index.
+    pub fn add(&mut self, zone: WeatherZone) -> usize {
+        self.zones.push(zone);
+        self.zones.len() - 1
+    }
+
+    /// Returns the [`WeatherZone`]s, in the order they were added.
+    pub fn zones(&self) -> &[WeatherZone] {
+        &self.zones
+    }
+
+    /// Returns the [`WeatherZone`]s mutably, such as to set the weather from a script.
+    pub fn zones_mut(&mut self) -> &mut [WeatherZone] {
+        &mut self.zones
+    }
+
+    /// Adds a hook run whenever the weather of a zone changes.
+    pub fn on_change(&mut self, hook: WeatherHook) {
+        self.hooks.push(hook);
+    }
+
+    /// Gets the weather in a room, from the first zone containing it.
+    pub fn at(&self, map: &Map, room_id: NodeIndex) -> Option<Weather> {
+        self.zones
+            .iter()
+            .find(|zone| zone.region.contains(map, room_id))
+            .map(|zone| zone.current)
+    }
+}
+
+impl World {
+    /// Gets the [`Weather`] in a room, or `None` if no [`WeatherZone`] contains it.
+    pub fn weather_at(&self, room_id: NodeIndex) -> Option<Weather> {
+        self.weather.at(&self.map, room_id)
+    }
+
+    /// Makes the [`Weathers`] change every `every` turns, with a daemon of the [`Scheduler`](crate::scheduler::Scheduler)
+    /// that runs [`change_weather`](World::change_weather).
+    ///
+    /// The handler of the daemon has to be registered again after loading a saved game, by calling
+    /// `world.scheduler.on(Weathers::HANDLER, ...)` or this again before loading.
+    pub fn enable_weather(&mut self, every: u32) -> EventId {
+        self.scheduler.on(
+            Weathers::HANDLER,
+            Rc::new(|world, _| world.change_weather()),
+        );
+        self.scheduler.daemon(every, Weathers::HANDLER)
+    }
+
+    /// Moves the weather of every [`WeatherZone`] on to the next, picked with the `weather` stream of the
+    /// [`RngStreams`](crate::RngStreams).
+    ///
+    /// The player is told when the weather of their room changes, and the [hooks](Weathers::on_change) run for every zone
+    /// that changed.
+    pub fn change_weather(&mut self) {
+        let before = self.weather_at(self.player.current_room);
+        let rng = self.rng_streams.add(RngStreams::WEATHER);
+        let mut changes = Vec::new();
+        for (index, zone) in self.weather.zones.iter_mut().enumerate() {
+            let next = zone.next(rng);
+            if next != zone.current {
+                changes.push((index, zone.current, next));
+                zone.current = next;
+            }
+        }
+        match self.weather_at(self.player.current_room) {
+            Some(after) if Some(after) != before => self.output.say(after.onset_message().into()),
+            _ => {}
+        }
+        for (index, from, to) in changes {
+            for hook in self.weather.hooks.clone() {
+                hook(self, index, from, to);
+            }
+        }
+    }
+}
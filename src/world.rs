@@ -1,19 +1,2205 @@
-use crate::map::Map;
+use crate::access::AccessControl;
+use crate::achievements::Achievements;
+use crate::actor::{BarkPool, Mood, Npc, NpcBehavior, NpcId, Npcs, Patrol};
+use crate::ambience::Ambience;
+use crate::banter::Banters;
+use crate::clock::Clock;
+use crate::clues::{ClueBoard, ClueId};
+use crate::currents::Currents;
+use crate::difficulty::Difficulty;
+use crate::flags::{FlagType, Flags};
+use crate::history::Outcomes;
+use crate::ids::StableIds;
+use crate::item::{Backdrops, ItemId, Items, PutError, Thing};
+use crate::journal::{EntryKind, Journal};
+use crate::knowledge::{Knowledge, TopicId};
+use crate::language::Translations;
+use crate::light::{LightLevel, Lighting};
+use crate::map::RoomHook;
+use crate::map::{Description, Map, MirrorError, RoomDescription, RoomId};
+use crate::map::{Direction, ExitId, Surroundings, UnlockError};
+use crate::narration::NarrationQueue;
+#[cfg(feature = "parser")]
+use crate::parser::{ParseError, Parser};
+use crate::prelude::*;
+use crate::profiler::{Profiler, Subsystem};
+use crate::provenance::{Provenance, Whereabouts};
+use crate::quest::Quests;
+use crate::reset::ResetPolicy;
+use crate::rules::Rulebooks;
+#[cfg(feature = "serde")]
+use crate::save::SaveMigrations;
+use crate::scheduler::{Recurrence, Scheduler};
+use crate::scope::{Scope, ScopeCache};
+use crate::score::Score;
+use crate::stamina::Stamina;
+use crate::template::{self, Variables};
+use crate::timers::Timers;
+use crate::turn::TurnOutcome;
+use crate::tutorial::{TipTrigger, Tutorial};
+use crate::wait::WaitOutcome;
+use crate::weather::Weathers;
+use crate::{
+    EnterError, Movement, Output, Player, PushError, RideError, Rng, RngStreams, TakeError,
+    ThrowError, capitalize,
+};
+use petgraph::prelude::NodeIndex;
 
 /// The entire game world.
 ///
-/// Currently, this only contains a [`Map`], it will be expanded in the future to include other global game state like items and entites.
-#[derive(Debug)]
+/// It contains the [`Map`] along with the state of every other part of the game, such as the [`Lighting`], the player's [`Journal`] and the [`NarrationQueue`].
+#[derive(Clone, Debug)]
 pub struct World {
     /// The [`Map`] of the world.
     pub map: Map,
+    /// The [`Player`].
+    pub player: Player,
+    /// The number of turns taken so far.
+    pub turn: u32,
+    /// The language of [`Localized`](crate::language::Localized) story content, or `None` for the default language.
+    pub language: Option<String>,
+    /// The [`Translations`](crate::language::Translations) of story content kept as plain text, such as dialogue.
+    pub translations: Translations,
+    /// How many minutes of narrative time a turn lasts, used to describe time passing. By default, a turn lasts a minute.
+    pub minutes_per_turn: u32,
+    /// The [`Clock`] and calendar of the world, advanced by [`minutes_per_turn`](World::minutes_per_turn) every turn.
+    pub clock: Clock,
+    /// The [`Backdrops`] registry of scenery present in many rooms.
+    pub backdrops: Backdrops,
+    /// The [`Items`] registry of every item in the world.
+    pub items: Items,
+    /// The [`StableIds`] of rooms, items and NPCs, such as those given in [`WorldData`](crate::data::WorldData).
+    pub ids: StableIds,
+    /// The [`Provenance`] of items, recorded by [`track_item`](World::track_item).
+    pub provenance: Provenance,
+    /// The global [`Flags`] and variables of the game.
+    pub flags: Flags,
+    /// The [`Rulebooks`] intercepting the actions [performed](World::perform) by the player.
+    pub rules: Rulebooks,
+    /// The [`Scheduler`] of fuses and daemons, run by [`advance_turn`](World::advance_turn).
+    pub scheduler: Scheduler,
+    /// The [`Stamina`] of the player, if the game uses it. Set it with [`enable_stamina`](World::enable_stamina).
+    pub stamina: Option<Stamina>,
+    /// The [`Score`] of the player, raised with [`award`](World::award).
+    pub score: Score,
+    /// The [`Achievements`] of the game, and which ones the player has unlocked.
+    pub achievements: Achievements,
+    /// The [`Difficulty`] of the game, switched with [`set_difficulty`](World::set_difficulty).
+    pub difficulty: Difficulty,
+    /// The [`ResetPolicy`] of the world, for server games.
+    pub resets: ResetPolicy,
+    /// The [`AccessControl`] of restricted rooms and exits.
+    pub access: AccessControl,
+    /// The [`Npcs`] registry of every non-player character.
+    pub npcs: Npcs,
+    /// The [`Banters`] between NPCs.
+    pub banter: Banters,
+    /// The player's [`Knowledge`] of abstract topics.
+    pub knowledge: Knowledge,
+    /// The [`Lighting`] of the world.
+    pub lighting: Lighting,
+    /// The [`Ambience`] of ambient messages.
+    pub ambience: Ambience,
+    /// The [`Weathers`] of the regions of the world.
+    pub weather: Weathers,
+    /// The [`ClueBoard`] of the mystery.
+    pub clues: ClueBoard,
+    /// The [`Currents`] that sweep the player and floating items along.
+    pub currents: Currents,
+    /// The player's [`Journal`].
+    pub journal: Journal,
+    /// The [`Quests`] of the game, updated by [`advance_turn`](World::advance_turn).
+    pub quests: Quests,
+    /// The [`Output`] of messages waiting to be shown to the player.
+    pub output: Output,
+    /// The [`NarrationQueue`] of background narration.
+    pub narration: NarrationQueue,
+    /// The [`Parser`] of the player's commands.
+    #[cfg(feature = "parser")]
+    pub parser: Parser,
+    /// The [`Profiler`] measuring the time spent in each part of the engine.
+    pub profiler: Profiler,
+    /// The [`ScopeCache`] of what the player can perceive in each room.
+    pub scope_cache: ScopeCache,
+    /// The [`Variables`] that templates in descriptions can refer to.
+    pub variables: Variables,
+    /// The template shown when the player tries to go in a direction without an exit, unless the room has its own.
+    ///
+    /// By default, it is "You can't go that way.".
+    pub no_exit_message: String,
+    /// The active countdown [`Timers`].
+    pub timers: Timers,
+    /// The [`Tutorial`] tips shown to the player.
+    pub tutorial: Tutorial,
+    /// The general random number generator of the world, for the game's own use. Replace it with a seeded [`Rng`]
+    /// for reproducible games, or use [`seed`](World::seed).
+    pub rng: Rng,
+    /// The [`RngStreams`] of the subsystems of the world, such as ambience and NPC behavior.
+    pub rng_streams: RngStreams,
+    /// The [`Outcomes`] of the [undo-safe](World::undo_safe) sections of the game.
+    pub outcomes: Outcomes,
+    /// The version of the game written in saves, and the [`SaveMigrations`] that upgrade older saves.
+    #[cfg(feature = "serde")]
+    pub save_migrations: SaveMigrations,
 }
 
 impl World {
     /// Creates a new, empty `World`.
     pub fn new() -> Self {
         let map = Map::new();
-        Self { map }
+        Self {
+            map,
+            player: Player::default(),
+            turn: 0,
+            backdrops: Backdrops::new(),
+            items: Items::new(),
+            ids: StableIds::new(),
+            provenance: Provenance::default(),
+            flags: Flags::new(),
+            rules: Rulebooks::new(),
+            scheduler: Scheduler::new(),
+            stamina: None,
+            score: Score::default(),
+            achievements: Achievements::new(),
+            difficulty: Difficulty::normal(),
+            resets: ResetPolicy::new(),
+            access: AccessControl::new(),
+            npcs: Npcs::new(),
+            banter: Banters::new(),
+            knowledge: Knowledge::new(),
+            lighting: Lighting::new(),
+            ambience: Ambience::new(),
+            weather: Weathers::new(),
+            clues: ClueBoard::new(),
+            currents: Currents::new(),
+            journal: Journal::new(),
+            quests: Quests::new(),
+            output: Output::new(),
+            narration: NarrationQueue::new(),
+            #[cfg(feature = "parser")]
+            parser: Parser::new(),
+            profiler: Profiler::default(),
+            scope_cache: ScopeCache::new(),
+            timers: Timers::new(),
+            tutorial: Tutorial::new(),
+            variables: Variables::new(),
+            no_exit_message: "You can't go that way.".into(),
+            language: None,
+            translations: Translations::new(),
+            minutes_per_turn: 1,
+            clock: Clock::new(),
+            rng: Rng::default(),
+            rng_streams: RngStreams::default(),
+            outcomes: Outcomes::new(),
+            #[cfg(feature = "serde")]
+            save_migrations: SaveMigrations::default(),
+        }
+    }
+
+    /// Gets the player inside an [enterable](crate::item::Item::enterable) item in their room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::item::Item;
+    /// use worldwright::{EnterError, World};
+    ///
+    /// let mut world = World::new();
+    /// let bedroom = world.map.new_room("A dusty bedroom.".into());
+    /// let mut wardrobe = Item::new("wardrobe".into(), "A tall oak wardrobe.".into());
+    /// wardrobe.enterable = true;
+    /// let wardrobe = world.items.add(wardrobe);
+    /// let bed = world.items.add(Item::new("bed".into(), "A narrow bed.".into()));
+    /// world.map.place_item(bedroom, wardrobe);
+    /// world.map.place_item(bedroom, bed);
+    /// world.player.current_room = bedroom;
+    ///
+    /// assert_eq!(world.enter(bed), Err(EnterError::NotEnterable));
+    /// assert_eq!(world.enter(wardrobe), Ok(()));
+    /// assert_eq!(world.player.inside, Some(wardrobe));
+    /// assert_eq!(world.leave(), Some(wardrobe));
+    /// ```
+    pub fn enter(&mut self, item: ItemId) -> Result<(), EnterError> {
+        if !self.is_in_player_room(item) {
+            return Err(EnterError::NotHere);
+        }
+        if !self.items[item].enterable {
+            return Err(EnterError::NotEnterable);
+        }
+        self.player.inside = Some(item);
+        Ok(())
+    }
+
+    /// Gets the player out of the item they are inside of, returning its [`ItemId`].
+    pub fn leave(&mut self) -> Option<ItemId> {
+        self.player.inside.take()
+    }
+
+    /// Checks whether the player can go from a [`Room`](crate::map::Room) in a [`Direction`] in the current state of the world.
+    ///
+    /// Returns the room the exit leads to, or a message explaining why the player can't go that way.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::Door;
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.new_room("A long hall.".into());
+    /// let study = world.map.new_room_in_direction(hall, Direction::North, Box::new(Door::new(false)), "A study.".into());
+    /// world.map.new_room_in_direction(hall, Direction::East, Box::new(Door::new(true)), "A vault.".into());
+    ///
+    /// assert_eq!(world.check_passage(hall, Direction::North), Ok(study));
+    /// assert_eq!(world.check_passage(hall, Direction::East), Err("The door is locked.".into()));
+    /// assert_eq!(world.check_passage(hall, Direction::West), Err("You can't go that way.".into()));
+    /// ```
+    pub fn check_passage(
+        &self,
+        from: NodeIndex,
+        direction: Direction,
+    ) -> Result<NodeIndex, String> {
+        let exit_id = self
+            .map
+            .exit_id_in_direction(from, direction)
+            .ok_or_else(|| self.describe_no_exit(from, direction))?;
+        let (source, target) = self.map.exit_endpoints(exit_id).unwrap();
+        if let Some(interjection) = self.guard_interjection(from, exit_id) {
+            return Err(interjection);
+        }
+        self.map.graph[exit_id]
+            .exit_type
+            .check_passage_from(self, from)?;
+        let to = if source == from { target } else { source };
+        self.access.check(&self.player, exit_id, to)?;
+        Ok(to)
+    }
+
+    /// Finds the shortest path between two [`Room`](crate::map::Room)s through exits the player could go through in the
+    /// current state of the world, as [checked](crate::map::ExitType::check_passage) by each exit.
+    ///
+    /// Unlike [`Map::find_path`], exits that depend on the world, such as a [`ConditionalExit`](crate::map::exit_types::ConditionalExit),
+    /// are taken into account. Returns every room on the path, starting with `from` and ending with `to`.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::ConditionalExit;
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.new_room("A long hall.".into());
+    /// let portal = ConditionalExit::new("portal".into(), |world| world.flag("portal_open"), "The portal is dark.".into());
+    /// let tower = world.map.new_room_in_direction(hall, Direction::North, Box::new(portal), "A lonely tower.".into());
+    ///
+    /// assert_eq!(world.find_path(hall, tower), None);
+    /// world.set_flag("portal_open", true);
+    /// assert_eq!(world.find_path(hall, tower), Some(vec![hall, tower]));
+    /// assert_eq!(world.map.find_path(hall, tower), None);
+    /// ```
+    pub fn find_path(&self, from: NodeIndex, to: NodeIndex) -> Option<Vec<NodeIndex>> {
+        let route = self.map.find_route(from, to, |exit_id, exit, next| {
+            let (source, target) = self.map.exit_endpoints(exit_id).unwrap();
+            let room = if target == next { source } else { target };
+            exit.exit_type.check_passage_from(self, room).is_ok()
+        })?;
+        Some(
+            core::iter::once(from)
+                .chain(route.into_iter().map(|(_, room_id)| room_id))
+                .collect(),
+        )
+    }
+
+    /// Gets what an [`Npc`] guarding an exit says to stop the player from going through it from a room, if any NPC in the
+    /// room has a [guard hook](crate::actor::GuardHook) for the exit that doesn't let them pass.
+    ///
+    /// Guards speak instead of the usual message of a blocked exit, such as a locked door.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use worldwright::World;
+    /// use worldwright::actor::Npc;
+    /// use worldwright::item::Item;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::Door;
+    ///
+    /// let mut world = World::new();
+    /// let gate = world.map.new_room("A castle gate.".into());
+    /// let courtyard = world.map.new_room_in_direction(gate, Direction::North, Box::new(Door::new(true)), "A courtyard.".into());
+    /// let pass = world.items.add(Item::new("pass".into(), "A stamped pass.".into()));
+    /// let door = world.map.exit_id_in_direction(gate, Direction::North).unwrap();
+    /// let mut guard = Npc::new("guard".into(), gate);
+    /// guard.guards.insert(door, Rc::new(move |world, _| {
+    ///     (!world.player.carries(pass)).then(|| "The guard bars your way: 'No entry without a pass.'".into())
+    /// }));
+    /// world.npcs.add(guard);
+    /// world.player.current_room = gate;
+    ///
+    /// let blocked = Err("The guard bars your way: 'No entry without a pass.'".into());
+    /// assert_eq!(world.check_passage(gate, Direction::North), blocked);
+    /// world.player.inventory.push(pass);
+    /// // The guard lets the player by, but the door is still locked.
+    /// assert_eq!(world.check_passage(gate, Direction::North), Err("The door is locked.".into()));
+    /// assert_eq!(world.check_passage(courtyard, Direction::South), Err("The door is locked.".into()));
+    /// ```
+    pub fn guard_interjection(&self, from: NodeIndex, exit_id: ExitId) -> Option<String> {
+        self.npcs.in_room(from).into_iter().find_map(|npc| {
+            let hook = self.npcs[npc].guards.get(&exit_id)?;
+            hook(self, npc)
+        })
+    }
+
+    /// Moves the player from their room through the exit in a [`Direction`].
+    ///
+    /// The exit must let the player through in the current state of the world, no NPC may be
+    /// [guarding](World::guard_interjection) it, and the player must be allowed through by the [`AccessControl`], or
+    /// the message explaining why they can't is returned. Trying to go through a [locked](crate::map::ExitType::is_locked)
+    /// exit, such as a [`Door`](crate::map::exit_types::Door), triggers the
+    /// [tutorial](crate::tutorial::TipTrigger::LockedDoor) tips for it.
+    ///
+    /// On the way, the player gets out of any item they are inside of, the exit hooks of the room they leave
+    /// and the enter hooks of the room they arrive in are run, the room they leave is marked as visited,
+    /// and the exit is [traversed](crate::map::Map::traverse_exit).
+    ///
+    /// # Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use worldwright::World;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::{AutoClosingDoor, Door, RegularExit};
+    /// use worldwright::tutorial::TipTrigger;
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.new_room("A long hall.".into());
+    /// let study = world.map.new_room_in_direction(hall, Direction::North, Box::new(RegularExit), "A study.".into());
+    /// let vault_door = AutoClosingDoor::new(Door::new(true), 3, "The vault door swings shut.".into());
+    /// world.map.new_room_in_direction(hall, Direction::East, Box::new(vault_door), "A vault.".into());
+    /// world.tutorial.add_tip(TipTrigger::LockedDoor, "Locked doors need a key.".into());
+    /// world.map.room_mut(study).hooks.on_enter.push(Rc::new(|world, _| world.output.say("A clock ticks.".into())));
+    /// world.narration.on_enter(study.into(), "You remember this room.".into());
+    /// world.player.current_room = hall;
+    ///
+    /// assert_eq!(world.move_player(Direction::East).map(|movement| movement.to), Err("The door is locked.".into()));
+    /// assert_eq!(world.output.drain(), vec!["Locked doors need a key."]);
+    /// let movement = world.move_player(Direction::North).unwrap();
+    /// assert_eq!((movement.from, movement.to), (hall, study));
+    /// assert_eq!(movement.messages, vec!["You remember this room."]);
+    /// assert_eq!(world.player.current_room, study);
+    /// assert!(world.map.graph[hall].visited);
+    /// assert_eq!(world.output.drain(), vec!["A clock ticks."]);
+    /// ```
+    pub fn move_player(&mut self, direction: Direction) -> Result<Movement, String> {
+        let from = self.player.current_room;
+        let exit_id = self
+            .map
+            .exit_id_in_direction(from, direction)
+            .ok_or_else(|| self.describe_no_exit(from, direction))?;
+        self.move_through(exit_id)
+    }
+
+    /// Moves the player through an exit of their room, whether or not it leads in a [`Direction`], such as a
+    /// [`Teleporter`](crate::map::exit_types::Teleporter) found by [noun](crate::map::Map::exit_by_noun).
+    ///
+    /// The exit is checked the same way as with [`move_player`](World::move_player), which calls this.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::access::Permission;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::{SkillCheck, SkillCheckExit, Teleporter};
+    ///
+    /// let mut world = World::new();
+    /// let lab = world.map.new_room("A wizard's laboratory.".into());
+    /// let tower = world.map.new_room("The top of a lonely tower.".into());
+    /// let cellar = world.map.new_room("A damp cellar.".into());
+    /// world.map.connect_rooms_without_direction(lab, tower, Box::new(Teleporter::new("shimmering portal".into())));
+    /// world.player.current_room = lab;
+    ///
+    /// let (portal, _) = world.map.exit_by_noun(lab, "portal").unwrap();
+    /// assert_eq!(world.move_through(portal).map(|movement| movement.to), Ok(tower));
+    /// assert_eq!(world.move_through(portal).map(|movement| movement.to), Ok(lab));
+    ///
+    /// world.player.current_room = cellar;
+    /// assert_eq!(world.move_through(portal).map(|movement| movement.to), Err("You can't go that way.".into()));
+    /// world.no_exit_message = "There is no way through from here.".into();
+    /// assert_eq!(world.move_through(portal).map(|movement| movement.to), Err("There is no way through from here.".into()));
+    ///
+    /// // Moves the player isn't allowed to make don't use up the roll of a skill check.
+    /// let check = SkillCheck::Roll { stat: "agility".into(), sides: 20, difficulty: 15 };
+    /// let ledge = SkillCheckExit::new("ledge".into(), check, "You edge along.".into(), "You slip back.".into());
+    /// let nest = world.map.new_room_in_direction(cellar, Direction::Up, Box::new(ledge), "A nest.".into());
+    /// let exit = world.map.exit_id_in_direction(cellar, Direction::Up).unwrap();
+    /// world.access.restrict_room(nest, Permission::role("climber"));
+    /// let state = world.rng_streams.combat().state();
+    /// assert!(world.move_through(exit).is_err());
+    /// assert_eq!(world.rng_streams.combat().state(), state);
+    /// ```
+    pub fn move_through(&mut self, exit_id: ExitId) -> Result<Movement, String> {
+        let (to, messages) = self.check_move(exit_id)?;
+        Ok(self.commit_move(exit_id, to, messages, None))
+    }
+
+    /// Checks whether the player can go through an exit of their room, returning the room it leads to and the
+    /// [passage message](crate::map::ExitType::passage_message) of the exit, without moving them.
+    fn check_move(&mut self, exit_id: ExitId) -> Result<(RoomId, Vec<String>), String> {
+        let from = self.player.current_room;
+        let to = match self.map.exit_endpoints(exit_id) {
+            Some((source, target)) if source == from => target,
+            Some((source, target)) if target == from => source,
+            _ => return Err(self.describe_no_exit_through(from, exit_id)),
+        };
+        if self.map.graph[exit_id].exit_type.is_hidden() {
+            return Err(self.describe_no_exit_through(from, exit_id));
+        }
+        if let Some(interjection) = self.guard_interjection(from, exit_id) {
+            return Err(interjection);
+        }
+        // Moves the player isn't allowed to make don't get as far as the exit, so they don't use up its roll.
+        self.access.check(&self.player, exit_id, to)?;
+        let exit_type = &self.map.graph[exit_id].exit_type;
+        let passage = exit_type.check_passage_from(self, from);
+        exit_type.on_attempt(self);
+        if let Err(message) = passage {
+            if exit_type.is_locked() {
+                for tip in self.tutorial.trigger(&TipTrigger::LockedDoor) {
+                    self.output.say(tip);
+                }
+            }
+            return Err(message);
+        }
+        let messages: Vec<String> = exit_type.passage_message().into_iter().collect();
+        Ok((to, messages))
+    }
+
+    /// Moves the player through an exit [checked](World::check_move) to lead to `to`, taking `carried` along, such as
+    /// the vehicle they are inside of. The carried item is in the new room, and the player still inside of it, by the
+    /// time the enter hooks run and the room is narrated.
+    fn commit_move(
+        &mut self,
+        exit_id: ExitId,
+        to: RoomId,
+        mut messages: Vec<String>,
+        carried: Option<ItemId>,
+    ) -> Movement {
+        let from = self.player.current_room;
+        let inside = self.player.inside.filter(|&inside| Some(inside) == carried);
+        self.run_exit_hooks(from);
+        self.player.inside = None;
+        self.map.mark_visited(from);
+        self.player.current_room = to;
+        self.player.leave_breadcrumb(from, to);
+        self.map.traverse_exit(exit_id);
+        if let Some(item) = carried {
+            self.map.move_item(item, from, to);
+            self.track_item(item, Whereabouts::Room(to));
+        }
+        self.player.inside = inside;
+        messages.extend(self.narration.entered(&self.map, to));
+        self.run_enter_hooks(to);
+        Movement {
+            from,
+            to,
+            exit: exit_id,
+            messages,
+        }
+    }
+
+    /// Renders the message shown when the player tries to go from a [`Room`](crate::map::Room) in a [`Direction`] without an exit.
+    ///
+    /// The message of the room for that direction is used if it has one, then the room's own message, then the world's
+    /// [`no_exit_message`](World::no_exit_message). Messages are rendered as templates, where `{direction}` is the
+    /// direction and `{room}` is the name of the room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::{Direction, Room};
+    ///
+    /// let mut world = World::new();
+    /// let mut garden = Room::new_with_name("Garden".into(), "A neat little garden.".into());
+    /// garden.no_exit_message = Some("Flower beds block the way {direction}.".into());
+    /// garden.no_exit_messages.insert(Direction::West, "The hedge is impenetrable to the {direction}.".into());
+    /// let garden = world.map.add_room(garden);
+    /// let shed = world.map.new_room("A cramped shed.".into());
+    ///
+    /// assert_eq!(world.describe_no_exit(garden, Direction::West), "The hedge is impenetrable to the west.");
+    /// assert_eq!(world.describe_no_exit(garden, Direction::North), "Flower beds block the way north.");
+    /// assert_eq!(world.describe_no_exit(shed, Direction::North), "You can't go that way.");
+    ///
+    /// world.no_exit_message = "There is nothing to the {direction}.".into();
+    /// assert_eq!(world.check_passage(shed, Direction::South), Err("There is nothing to the south.".into()));
+    /// ```
+    pub fn describe_no_exit(&self, room_id: NodeIndex, direction: Direction) -> String {
+        let room = self.map.graph.node_weight(room_id);
+        let template = room
+            .and_then(|room| {
+                room.no_exit_messages
+                    .get(&direction)
+                    .or(room.no_exit_message.as_ref())
+            })
+            .unwrap_or(&self.no_exit_message);
+        let locals = [
+            ("direction", direction.to_string()),
+            (
+                "room",
+                room.and_then(|room| room.name.clone()).unwrap_or_default(),
+            ),
+        ];
+        self.render_template(template, &locals)
+    }
+
+    /// Renders the [no-exit message](World::describe_no_exit) for trying to go through an exit that doesn't lead from a
+    /// room, or is hidden. Exits that don't lead from the room in a [`Direction`] are described as leading "that way".
+    fn describe_no_exit_through(&self, room_id: NodeIndex, exit_id: ExitId) -> String {
+        let direction = self
+            .map
+            .exit_endpoints(exit_id)
+            .and_then(|(source, target)| {
+                let direction = self.map.graph[exit_id].direction?;
+                if source == room_id {
+                    Some(direction)
+                } else if target == room_id {
+                    Some(direction.opposite())
+                } else {
+                    None
+                }
+            });
+        match direction {
+            Some(direction) => self.describe_no_exit(room_id, direction),
+            None => {
+                let room = self.map.graph.node_weight(room_id);
+                let template = room
+                    .and_then(|room| room.no_exit_message.as_ref())
+                    .unwrap_or(&self.no_exit_message);
+                let locals = [
+                    ("direction", "that way".to_string()),
+                    (
+                        "room",
+                        room.and_then(|room| room.name.clone()).unwrap_or_default(),
+                    ),
+                ];
+                self.render_template(template, &locals)
+            }
+        }
+    }
+
+    /// Moves the [vehicle](crate::item::Item::vehicle) the player is inside of to the adjacent room in `direction`, with the player in it.
+    ///
+    /// Vehicles can only go through exits that [allow bulky objects](crate::map::ExitType::allows_bulky_objects), and the
+    /// player must be able to [go through](World::move_player) them. The vehicle arrives with the player, before the
+    /// [enter hooks](crate::map::RoomHooks::on_enter) of the new room run.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use worldwright::access::Permission;
+    /// use worldwright::item::Item;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::RegularExit;
+    /// use worldwright::{RideError, World};
+    ///
+    /// let mut world = World::new();
+    /// let mine = world.map.new_room("A mine entrance.".into());
+    /// let shaft = world.map.new_room_in_direction(
+    ///     mine,
+    ///     Direction::East,
+    ///     Box::new(RegularExit),
+    ///     "A deep shaft.".into(),
+    /// );
+    /// let mut cart = Item::new("cart".into(), "A rusty mine cart.".into());
+    /// cart.enterable = true;
+    /// cart.vehicle = true;
+    /// let cart = world.items.add(cart);
+    /// world.map.place_item(mine, cart);
+    /// world.player.current_room = mine;
+    ///
+    /// assert_eq!(world.ride(Direction::East).map(|movement| movement.to), Err(RideError::NotInVehicle));
+    /// world.enter(cart).unwrap();
+    ///
+    /// world.access.restrict_room(shaft, Permission::role("miner"));
+    /// assert_eq!(
+    ///     world.ride(Direction::East).map(|movement| movement.to),
+    ///     Err(RideError::Refused("You aren't allowed in there.".into()))
+    /// );
+    /// assert_eq!(world.player.current_room, mine);
+    ///
+    /// world.player.roles.insert("miner".into());
+    /// world.map.room_mut(shaft).hooks.on_enter.push(Rc::new(move |world, shaft| {
+    ///     if world.player.inside == Some(cart) && world.map.find_item(cart) == Some(shaft) {
+    ///         world.output.say("The cart rattles into the shaft.".into());
+    ///     }
+    /// }));
+    /// assert_eq!(world.ride(Direction::East).map(|movement| movement.to), Ok(shaft));
+    /// assert_eq!(world.output.drain(), vec!["The cart rattles into the shaft."]);
+    /// assert_eq!(world.player.current_room, shaft);
+    /// assert_eq!(world.player.inside, Some(cart));
+    /// assert_eq!(world.map.find_item(cart), Some(shaft));
+    /// ```
+    pub fn ride(&mut self, direction: Direction) -> Result<Movement, RideError> {
+        let vehicle = self
+            .player
+            .inside
+            .filter(|&item| self.items[item].vehicle)
+            .ok_or(RideError::NotInVehicle)?;
+        let from = self.player.current_room;
+        self.map
+            .push_target(from, direction)
+            .map_err(RideError::Passage)?;
+        let exit_id = self
+            .map
+            .exit_id_in_direction(from, direction)
+            .ok_or_else(|| RideError::Refused(self.describe_no_exit(from, direction)))?;
+        let (to, messages) = self.check_move(exit_id).map_err(RideError::Refused)?;
+        Ok(self.commit_move(exit_id, to, messages, Some(vehicle)))
+    }
+
+    /// Picks up an item in the player's room and adds it to their inventory.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::item::Item;
+    /// use worldwright::{TakeError, World};
+    ///
+    /// let mut world = World::new();
+    /// let cellar = world.map.new_room("A damp cellar.".into());
+    /// let lamp = world.items.add(Item::new("lamp".into(), "A brass lamp.".into()));
+    /// let mut barrel = Item::new("barrel".into(), "A barrel of ale.".into());
+    /// barrel.weight = 50;
+    /// let barrel = world.items.add(barrel);
+    /// let mut rack = Item::new("wine rack".into(), "A rack bolted to the wall.".into());
+    /// rack.takeable = false;
+    /// let rack = world.items.add(rack);
+    /// for item in [lamp, barrel, rack] {
+    ///     world.map.place_item(cellar, item);
+    /// }
+    /// world.player.current_room = cellar;
+    /// world.player.capacity = Some(20);
+    ///
+    /// assert_eq!(world.take_item(rack), Err(TakeError::NotTakeable));
+    /// assert_eq!(world.take_item(barrel), Err(TakeError::TooHeavy));
+    /// assert_eq!(world.take_item(lamp), Ok(()));
+    /// assert_eq!(world.take_item(lamp), Err(TakeError::NotHere));
+    /// assert!(world.player.carries(lamp));
+    /// assert_eq!(world.describe_inventory(), "You are carrying a lamp.");
+    ///
+    /// assert!(world.drop_item(lamp));
+    /// assert_eq!(world.map.find_item(lamp), Some(cellar));
+    /// assert_eq!(world.describe_inventory(), "You are empty-handed.");
+    /// ```
+    pub fn take_item(&mut self, item: ItemId) -> Result<(), TakeError> {
+        let room_id = self.player.current_room;
+        if self.player.carries(item) || !self.can_reach(item) {
+            return Err(TakeError::NotHere);
+        }
+        if !self.items[item].takeable {
+            return Err(TakeError::NotTakeable);
+        }
+        if let Some(capacity) = self.player.capacity
+            && self.player.carried_weight(&self.items) + self.items.total_weight(item) > capacity
+            && !self
+                .player
+                .inventory
+                .iter()
+                .any(|&carried| self.items.encloses(carried, item))
+        {
+            return Err(TakeError::TooHeavy);
+        }
+        if !self.items.remove_from_holder(item) {
+            self.map.remove_item(room_id, item);
+        }
+        self.scope_cache.invalidate_room(room_id);
+        self.player.inventory.push(item);
+        self.track_item(item, Whereabouts::Player);
+        Ok(())
+    }
+
+    /// Checks whether the player can reach an item: it is in their room or carried by them, directly or in or on
+    /// other items whose contents are [exposed](crate::item::Item::exposes_contents).
+    pub fn can_reach(&self, item: ItemId) -> bool {
+        let mut current = item;
+        while let Some(holder) = self.items.holder_of(current) {
+            if !self.items[holder].exposes_contents() {
+                return false;
+            }
+            current = holder;
+        }
+        self.player.carries(current) || self.is_in_player_room(current)
+    }
+
+    /// Checks whether an item lies in the player's room. Nothing does while the player isn't in any room yet, such as
+    /// in a world without rooms.
+    fn is_in_player_room(&self, item: ItemId) -> bool {
+        self.map
+            .graph
+            .node_weight(self.player.current_room)
+            .is_some_and(|room| room.contents.contains(&item))
+    }
+
+    /// Puts an item the player is carrying in a [`Container`](crate::item::Container) or on a
+    /// [`Supporter`](crate::item::Supporter) they can [reach](World::can_reach), such as "put the key in the box".
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::item::{Container, Item, PutError, Supporter};
+    ///
+    /// let mut world = World::new();
+    /// let study = world.map.new_room("A quiet study.".into());
+    /// world.player.current_room = study;
+    /// let mut desk = Item::new("desk".into(), "An oak desk.".into());
+    /// desk.supporter = Some(Supporter::new());
+    /// desk.takeable = false;
+    /// let desk = world.items.add(desk);
+    /// let mut box_ = Item::new("box".into(), "A wooden box.".into());
+    /// box_.container = Some(Container::new(true));
+    /// let box_ = world.items.add(box_);
+    /// let coin = world.items.add(Item::new("coin".into(), "A gold coin.".into()));
+    /// world.map.place_item(study, desk);
+    /// world.player.inventory.extend([box_, coin]);
+    ///
+    /// assert_eq!(world.put_item(coin, coin), Err(PutError::IntoItself));
+    /// assert_eq!(world.put_item(coin, box_), Ok(()));
+    /// assert_eq!(world.put_item(box_, desk), Ok(()));
+    /// assert_eq!(world.describe_room(study).contents, vec!["a desk (on which is a box (in which is a coin))"]);
+    ///
+    /// world.items[box_].container.as_mut().unwrap().close();
+    /// assert_eq!(world.describe_inside(box_).unwrap(), "The box is closed.");
+    /// assert_eq!(world.take_item(coin), Err(worldwright::TakeError::NotHere));
+    /// world.items[box_].container.as_mut().unwrap().open();
+    /// assert_eq!(world.take_item(coin), Ok(()));
+    /// assert_eq!(world.describe_inside(box_).unwrap(), "The box is empty.");
+    /// assert_eq!(world.describe_inside(desk).unwrap(), "On the desk is a box.");
+    /// ```
+    pub fn put_item(&mut self, item: ItemId, holder: ItemId) -> Result<(), PutError> {
+        if !self.player.carries(item) {
+            return Err(PutError::NotCarried);
+        }
+        if holder == item || self.items.encloses(item, holder) {
+            return Err(PutError::IntoItself);
+        }
+        let target = &self.items[holder];
+        if !self.can_reach(holder) || (target.container.is_none() && target.supporter.is_none()) {
+            return Err(PutError::NotAHolder);
+        }
+        if target
+            .container
+            .as_ref()
+            .is_some_and(|container| !container.open)
+        {
+            return Err(PutError::Closed);
+        }
+        let capacity = match (&target.container, &target.supporter) {
+            (Some(container), _) => container.capacity,
+            (None, Some(supporter)) => supporter.capacity,
+            (None, None) => None,
+        };
+        let held: u32 = target
+            .contents()
+            .iter()
+            .map(|&content| self.items.total_weight(content))
+            .sum();
+        if capacity.is_some_and(|capacity| held + self.items.total_weight(item) > capacity) {
+            return Err(PutError::Full);
+        }
+
+        self.player.inventory.retain(|&carried| carried != item);
+        self.items.insert_into(item, holder);
+        self.scope_cache.invalidate_room(self.player.current_room);
+        self.track_item(item, Whereabouts::Item(holder));
+        Ok(())
+    }
+
+    /// Describes what is in or on an item, such as "In the chest is a brass key.", for "look in chest".
+    ///
+    /// Returns `None` if the item isn't a [`Container`](crate::item::Container) or a [`Supporter`](crate::item::Supporter).
+    pub fn describe_inside(&self, item: ItemId) -> Option<String> {
+        let holder = &self.items[item];
+        let (preposition, empty) = match (&holder.container, &holder.supporter) {
+            (Some(_), _) => ("In", format!("The {} is empty.", holder.name)),
+            (None, Some(_)) => ("On", format!("There is nothing on the {}.", holder.name)),
+            (None, None) => return None,
+        };
+        if !holder.shows_contents() {
+            return Some(format!("The {} is closed.", holder.name));
+        }
+        let Some(listing) = self.items.list_contents(item) else {
+            return Some(empty);
+        };
+        let verb = if holder.contents().len() == 1 {
+            "is"
+        } else {
+            "are"
+        };
+        Some(format!(
+            "{preposition} the {} {verb} {listing}.",
+            holder.name
+        ))
+    }
+
+    /// Drops an item the player is carrying in their room, returning whether it was dropped: the player must be
+    /// carrying it, and be in a room.
+    pub fn drop_item(&mut self, item: ItemId) -> bool {
+        let room_id = self.player.current_room;
+        if self.map.graph.node_weight(room_id).is_none() {
+            return false;
+        }
+        let Some(index) = self
+            .player
+            .inventory
+            .iter()
+            .position(|&carried| carried == item)
+        else {
+            return false;
+        };
+        self.player.inventory.remove(index);
+        self.map.place_item(room_id, item);
+        self.track_item(item, Whereabouts::Room(room_id));
+        true
+    }
+
+    /// Records in the [`Provenance`] that an item got somewhere on the current [turn](World::turn).
+    ///
+    /// Code that moves items should call this, so rules can tell where items have been.
+    pub fn track_item(&mut self, item: ItemId, whereabouts: Whereabouts) {
+        self.provenance.record(item, self.turn, whereabouts);
+    }
+
+    /// Finds where an item is right now, or `None` if it is nowhere in the world.
+    pub fn whereabouts(&self, item: ItemId) -> Option<Whereabouts> {
+        if self.player.inventory.contains(&item) {
+            return Some(Whereabouts::Player);
+        }
+        if let Some((npc, _)) = self
+            .npcs
+            .iter()
+            .find(|(_, npc)| npc.inventory.contains(&item))
+        {
+            return Some(Whereabouts::Npc(npc));
+        }
+        if let Some(holder) = self.items.holder_of(item) {
+            return Some(Whereabouts::Item(holder));
+        }
+        self.map.find_item(item).map(Whereabouts::Room)
+    }
+
+    /// Tries to unlock the exit in `direction` from the player's room with the keys the player is carrying.
+    ///
+    /// Returns the [`ItemId`] of the key that fit. Otherwise, returns [`UnlockError::NoExit`] if there is no exit in
+    /// that direction, [`UnlockError::NoKeyhole`] if it can't be unlocked with a key, or [`UnlockError::WrongKey`] if
+    /// none of the keys fit, including when the player carries none.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::item::Item;
+    /// use worldwright::map::exit_types::Door;
+    /// use worldwright::map::{Direction, UnlockError};
+    ///
+    /// let mut world = World::new();
+    /// let brass_key = world.items.add(Item::new("brass key".into(), "A small brass key.".into()));
+    /// let hall = world.map.new_room("A long hall.".into());
+    /// world.map.new_room_in_direction(
+    ///     hall,
+    ///     Direction::North,
+    ///     Box::new(Door::new_with_key(brass_key)),
+    ///     "A quiet study.".into(),
+    /// );
+    /// world.map.new_room_in_direction(hall, Direction::South, Box::new(Door::new(true)), "A foyer.".into());
+    /// world.player.current_room = hall;
+    ///
+    /// assert_eq!(world.unlock(Direction::East), Err(UnlockError::NoExit));
+    /// assert_eq!(world.unlock(Direction::South), Err(UnlockError::NoKeyhole));
+    /// assert_eq!(world.unlock(Direction::North), Err(UnlockError::WrongKey));
+    /// world.player.inventory.push(brass_key);
+    /// assert_eq!(world.unlock(Direction::North), Ok(brass_key));
+    /// ```
+    pub fn unlock(&mut self, direction: Direction) -> Result<ItemId, UnlockError> {
+        let exit_id = self
+            .map
+            .exit_id_in_direction(self.player.current_room, direction)
+            .ok_or(UnlockError::NoExit)?;
+        if !self.map.graph[exit_id].exit_type.has_keyhole() {
+            return Err(UnlockError::NoKeyhole);
+        }
+        for &key in &self.player.inventory {
+            match self
+                .map
+                .unlock_exit(self.player.current_room, direction, key)
+            {
+                Ok(()) => return Ok(key),
+                Err(UnlockError::WrongKey) => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Err(UnlockError::WrongKey)
+    }
+
+    /// Parses the player's input and carries out the command with the handler of its verb.
+    ///
+    /// If the input can't be parsed, the tutorial tips for [failed commands](TipTrigger::FailedCommand) are added to the
+    /// [`Output`].
+    #[cfg(feature = "parser")]
+    pub fn execute(&mut self, input: &str) -> Result<(), ParseError> {
+        let parsed = self
+            .profiler
+            .measure(Subsystem::Parsing, || self.parser.parse(input));
+        let command = match parsed {
+            Ok(command) => command,
+            Err(error) => {
+                for tip in self.tutorial.trigger(&TipTrigger::FailedCommand) {
+                    self.output.say(tip);
+                }
+                return Err(error);
+            }
+        };
+        if let Some(handler) = self.parser.handler(&command) {
+            let stopwatch = self.profiler.start();
+            handler(self, &command);
+            self.profiler.stop(Subsystem::Rules, stopwatch);
+        }
+        Ok(())
+    }
+
+    /// Reseeds the world: the general [`Rng`] and every stream of the [`RngStreams`].
+    pub fn seed(&mut self, seed: u64) {
+        self.rng = Rng::new(seed);
+        self.rng_streams = RngStreams::new(seed);
+    }
+
+    /// Possibly returns an ambient message for the given room, using the [ambience stream](RngStreams::ambience).
+    ///
+    /// The game loop should call this once per turn with the player's room.
+    pub fn ambient_message(&mut self, room_id: NodeIndex) -> Option<String> {
+        let weather = self.weather_at(room_id);
+        self.ambience
+            .tick_in_weather(&self.map, room_id, weather, self.rng_streams.ambience())
+    }
+
+    /// Gets what the player can perceive in a [`Room`](crate::map::Room): its light level, and the items and backdrops
+    /// they can refer to if it is [lit](World::is_lit).
+    ///
+    /// The scope is cached in the [`ScopeCache`] when it is enabled.
+    pub fn scope(&self, room_id: NodeIndex) -> Scope {
+        // The revisions only ever grow, so their sum changes whenever any of them does.
+        let revision = self.map.revision() + self.items.revision() + self.backdrops.revision();
+        let light = self.light_level(room_id);
+        let mut scope = self
+            .scope_cache
+            .get_or_compute(revision, room_id, light, || {
+                let mut things = Vec::new();
+                if light > LightLevel::PitchBlack {
+                    for &item in &self.map.graph[room_id].contents {
+                        things.push(Thing::Item(item));
+                        things.extend(
+                            self.items
+                                .visible_contents(item)
+                                .into_iter()
+                                .map(Thing::Item),
+                        );
+                    }
+                    things.extend(
+                        self.backdrops
+                            .in_room(&self.map, room_id)
+                            .into_iter()
+                            .map(Thing::Backdrop),
+                    );
+                }
+                Scope { light, things }
+            });
+        // NPCs move around on their own, so they are never cached.
+        if scope.light > LightLevel::PitchBlack {
+            scope
+                .things
+                .extend(self.npcs.in_room(room_id).into_iter().map(Thing::Npc));
+        }
+        scope
+    }
+
+    /// Lists the things visible in a [`Room`](crate::map::Room), with their articles, such as "a brass key".
+    ///
+    /// Nothing is visible if the room isn't [lit](World::is_lit). Items in or on other items are listed after them,
+    /// such as "a table (on which is a lamp)".
+    pub fn visible_contents(&self, room_id: NodeIndex) -> Vec<String> {
+        let contents = &self.map.graph[room_id].contents;
+        self.scope(room_id)
+            .things
+            .into_iter()
+            .filter_map(|thing| match thing {
+                Thing::Item(item) if contents.contains(&item) => {
+                    Some(self.items.describe_with_contents(item))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Describes what the player is carrying, such as "You are carrying a lamp and a brass key."
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::item::Item;
+    ///
+    /// let mut world = World::new();
+    /// assert_eq!(world.describe_inventory(), "You are empty-handed.");
+    ///
+    /// let lamp = world.items.add(Item::new("lamp".into(), "A brass lamp.".into()));
+    /// let apple = world.items.add(Item::new("apple".into(), "A red apple.".into()));
+    /// world.player.inventory.extend([lamp, apple]);
+    /// assert_eq!(world.describe_inventory(), "You are carrying a lamp and an apple.");
+    /// ```
+    pub fn describe_inventory(&self) -> String {
+        let names: Vec<String> = self
+            .player
+            .inventory
+            .iter()
+            .filter_map(|&item| self.items.get(item))
+            .map(|item| crate::with_article(&item.name))
+            .collect();
+        if names.is_empty() {
+            "You are empty-handed.".into()
+        } else {
+            format!("You are carrying {}.", crate::join_list(&names))
+        }
+    }
+
+    /// Renders every piece of generated prose in the world into a single text, for snapshot testing.
+    ///
+    /// The text contains the description and exits of every room, in the order they were added, followed by the player's inventory.
+    /// Rendering doesn't change the state of the world, so the text can be compared against a stored snapshot,
+    /// for example with [`insta`](https://insta.rs), to catch regressions in the prose when the engine's formatting changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::RegularExit;
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.new_room_with_name("Hall".into(), "A long hall.".into());
+    /// world.map.new_room_in_direction(hall, Direction::North, Box::new(RegularExit), "A kitchen.".into());
+    ///
+    /// assert_eq!(
+    ///     world.text_snapshot(),
+    ///     "== Room 0 ==\nHall\nA long hall.\nExits: an exit north.\n\n\
+    ///      == Room 1 ==\nA kitchen.\nExits: an exit south.\n\n\
+    ///      == Inventory ==\nYou are empty-handed.\n"
+    /// );
+    /// ```
+    pub fn text_snapshot(&self) -> String {
+        let mut text = String::new();
+        for room_id in self.map.graph.node_indices() {
+            let description = self.describe_room(room_id);
+            text.push_str(&format!("== Room {} ==\n{description}\n", room_id.index()));
+            if !description.exits.is_empty() {
+                text.push_str(&format!(
+                    "Exits: {}.\n",
+                    crate::join_list(&description.exits)
+                ));
+            }
+            text.push('\n');
+        }
+        text.push_str(&format!("== Inventory ==\n{}\n", self.describe_inventory()));
+        text
+    }
+
+    /// Finds a [`Thing`] the player can refer to by name in a [`Room`](crate::map::Room), ignoring case.
+    ///
+    /// Items in the contents of the room are searched first, then the backdrops present in the room.
+    /// Nothing can be found if the room isn't [lit](World::is_lit).
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::item::{Backdrop, Item, Thing};
+    /// use worldwright::map::Region;
+    ///
+    /// let mut world = World::new();
+    /// let bank = world.map.new_room("A muddy riverbank.".into());
+    /// let river = world.backdrops.add(Backdrop::new(
+    ///     "river".into(),
+    ///     "The river flows lazily past.".into(),
+    ///     Region::from(bank),
+    /// ));
+    /// let rod = world.items.add(Item::new("fishing rod".into(), "A bamboo rod.".into()));
+    /// world.map.place_item(bank, rod);
+    ///
+    /// assert_eq!(world.find_thing(bank, "River"), Some(Thing::Backdrop(river)));
+    /// assert_eq!(world.find_thing(bank, "fishing rod"), Some(Thing::Item(rod)));
+    /// assert_eq!(world.describe_thing(Thing::Backdrop(river)), "The river flows lazily past.");
+    /// ```
+    pub fn find_thing(&self, room_id: NodeIndex, name: &str) -> Option<Thing> {
+        self.scope(room_id)
+            .things
+            .into_iter()
+            .find(|&thing| match thing {
+                Thing::Item(item) => self
+                    .items
+                    .get(item)
+                    .is_some_and(|item| item.name.eq_ignore_ascii_case(name)),
+                Thing::Backdrop(backdrop) => self
+                    .backdrops
+                    .get(backdrop)
+                    .is_some_and(|backdrop| backdrop.name.eq_ignore_ascii_case(name)),
+                Thing::Npc(npc) => self
+                    .npcs
+                    .get(npc)
+                    .is_some_and(|npc| npc.name.eq_ignore_ascii_case(name)),
+            })
+    }
+
+    /// Renders the description of a [`Thing`].
+    pub fn describe_thing(&self, thing: Thing) -> String {
+        let description = match thing {
+            Thing::Item(item) => &self.items[item].description,
+            Thing::Backdrop(backdrop) => &self.backdrops[backdrop].description,
+            Thing::Npc(npc) => return self.describe_npc(npc),
+        };
+        description.render(self)
+    }
+
+    /// Looks into a [`Mirror`](crate::map::Mirror) in a [`Room`](crate::map::Room), returning the description of the room it shows.
+    ///
+    /// The mirror is found by name, ignoring case. The reflection is only visible if the room with the mirror is lit,
+    /// and shows darkness if the target room isn't.
+    pub fn look_in_mirror(
+        &self,
+        room_id: NodeIndex,
+        name: &str,
+    ) -> Result<RoomDescription, MirrorError> {
+        let mirror = self.map.graph[room_id]
+            .mirrors
+            .iter()
+            .find(|mirror| mirror.name.eq_ignore_ascii_case(name))
+            .ok_or(MirrorError::NoMirror)?;
+        if !self.is_lit(room_id) {
+            return Err(MirrorError::TooDark);
+        }
+
+        let mut reflection = self.describe_room(mirror.target);
+        if let Some(reveals) = &mirror.reveals
+            && self.is_lit(mirror.target)
+        {
+            reflection.body.push(' ');
+            reflection.body.push_str(&reveals.render(self));
+        }
+        Ok(reflection)
+    }
+
+    /// Runs the hooks registered to run when the player enters a [`Room`](crate::map::Room).
+    pub fn run_enter_hooks(&mut self, room_id: NodeIndex) {
+        let hooks = self.map.graph[room_id].hooks.on_enter.clone();
+        self.run_hooks(&hooks, room_id);
+    }
+
+    /// Runs the hooks registered to run when the player leaves a [`Room`](crate::map::Room).
+    pub fn run_exit_hooks(&mut self, room_id: NodeIndex) {
+        let hooks = self.map.graph[room_id].hooks.on_exit.clone();
+        self.run_hooks(&hooks, room_id);
+    }
+
+    /// Runs the hooks registered to run every turn while the player is in a [`Room`](crate::map::Room).
+    pub fn run_turn_hooks(&mut self, room_id: NodeIndex) {
+        let hooks = self.map.graph[room_id].hooks.each_turn.clone();
+        self.run_hooks(&hooks, room_id);
+    }
+
+    fn run_hooks(&mut self, hooks: &[RoomHook], room_id: NodeIndex) {
+        for hook in hooks {
+            hook(self, room_id);
+        }
+    }
+
+    /// Discovers a clue on the [`ClueBoard`] and logs it in the [`Journal`].
+    ///
+    /// Returns `true` if the clue was not discovered before.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    ///
+    /// let mut world = World::new();
+    /// let glove = world.clues.add_clue("bloody glove".into(), "A glove stained with blood.".into());
+    ///
+    /// assert!(world.discover_clue(glove));
+    /// assert!(!world.discover_clue(glove));
+    /// assert_eq!(world.journal.recall("glove").len(), 1);
+    /// ```
+    pub fn discover_clue(&mut self, id: ClueId) -> bool {
+        if !self.clues.discover(id) {
+            return false;
+        }
+        if let Some(clue) = self.clues.clue(id) {
+            let text = format!("Clue found: {}. {}", clue.name, clue.description);
+            self.journal.log(EntryKind::Clue, text);
+        }
+        true
+    }
+
+    /// Pushes a [pushable](crate::item::Item::pushable) item in the player's room through the exit in a direction,
+    /// with the player following it, such as "push the crate north".
+    ///
+    /// The exit must [allow bulky objects](crate::map::ExitType::allows_bulky_objects), and the player must be able to
+    /// [go through](World::move_player) it. The item is already in the new room when its
+    /// [enter hooks](crate::map::RoomHooks::on_enter) run.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use worldwright::item::Item;
+    /// use worldwright::map::exit_types::{Door, RegularExit};
+    /// use worldwright::map::{Direction, PassageError};
+    /// use worldwright::{PushError, World};
+    ///
+    /// let mut world = World::new();
+    /// let barn = world.map.new_room("A creaky barn.".into());
+    /// let yard = world.map.new_room_in_direction(barn, Direction::South, Box::new(RegularExit), "The yard.".into());
+    /// world.map.new_room_in_direction(barn, Direction::East, Box::new(Door::new(true)), "A shed.".into());
+    /// let mut crate_ = Item::new("crate".into(), "A heavy wooden crate.".into());
+    /// crate_.takeable = false;
+    /// let crate_ = world.items.add(crate_);
+    /// world.map.place_item(barn, crate_);
+    /// world.player.current_room = barn;
+    ///
+    /// assert_eq!(world.push_item(crate_, Direction::South).map(|movement| movement.to), Err(PushError::NotPushable));
+    /// world.items[crate_].pushable = true;
+    /// assert_eq!(
+    ///     world.push_item(crate_, Direction::East).map(|movement| movement.to),
+    ///     Err(PushError::Passage(PassageError::Blocked))
+    /// );
+    /// world.map.room_mut(yard).hooks.on_enter.push(Rc::new(move |world, yard| {
+    ///     if world.map.find_item(crate_) == Some(yard) {
+    ///         world.output.say("The crate scrapes across the cobbles.".into());
+    ///     }
+    /// }));
+    /// assert_eq!(world.push_item(crate_, Direction::South).map(|movement| movement.to), Ok(yard));
+    /// assert_eq!(world.player.current_room, yard);
+    /// assert_eq!(world.map.find_item(crate_), Some(yard));
+    /// assert_eq!(world.output.drain(), vec!["The crate scrapes across the cobbles."]);
+    /// ```
+    pub fn push_item(&mut self, item: ItemId, direction: Direction) -> Result<Movement, PushError> {
+        let from = self.player.current_room;
+        if !self.is_in_player_room(item) {
+            return Err(PushError::NotHere);
+        }
+        if !self.items[item].pushable {
+            return Err(PushError::NotPushable);
+        }
+        self.map
+            .push_target(from, direction)
+            .map_err(PushError::Passage)?;
+        let exit_id = self
+            .map
+            .exit_id_in_direction(from, direction)
+            .ok_or_else(|| PushError::Refused(self.describe_no_exit(from, direction)))?;
+        let (to, messages) = self.check_move(exit_id).map_err(PushError::Refused)?;
+        Ok(self.commit_move(exit_id, to, messages, Some(item)))
+    }
+
+    /// Throws an item the player is carrying through the exit in a direction, into the next room, if the exit
+    /// [lets objects through](crate::map::ExitType::can_throw_through).
+    ///
+    /// Returns the room the item lands in.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::item::Item;
+    /// use worldwright::map::exit_types::{Chasm, Door};
+    /// use worldwright::map::{Direction, PassageError};
+    /// use worldwright::{ThrowError, World};
+    ///
+    /// let mut world = World::new();
+    /// let ledge = world.map.new_room("A narrow ledge.".into());
+    /// let far_side = world.map.new_room_in_direction(ledge, Direction::East, Box::new(Chasm), "The far side.".into());
+    /// world.map.new_room_in_direction(ledge, Direction::North, Box::new(Door::new(true)), "A cave.".into());
+    /// let rope = world.items.add(Item::new("rope".into(), "A coil of rope.".into()));
+    /// world.player.current_room = ledge;
+    ///
+    /// assert_eq!(world.throw_item(rope, Direction::East), Err(ThrowError::NotCarried));
+    /// world.player.inventory.push(rope);
+    /// assert_eq!(world.throw_item(rope, Direction::North), Err(ThrowError::Passage(PassageError::Blocked)));
+    /// assert_eq!(world.throw_item(rope, Direction::East), Ok(far_side));
+    /// assert!(!world.player.carries(rope));
+    /// assert_eq!(world.map.find_item(rope), Some(far_side));
+    /// ```
+    pub fn throw_item(
+        &mut self,
+        item: ItemId,
+        direction: Direction,
+    ) -> Result<NodeIndex, ThrowError> {
+        if !self.player.carries(item) {
+            return Err(ThrowError::NotCarried);
+        }
+        let to = self
+            .map
+            .throw_target(self.player.current_room, direction)
+            .map_err(ThrowError::Passage)?;
+        self.player.inventory.retain(|&carried| carried != item);
+        self.map.place_item(to, item);
+        self.track_item(item, Whereabouts::Room(to));
+        Ok(to)
+    }
+
+    /// Computes the [`LightLevel`] of a [`Room`](crate::map::Room) from its own light, the light sources, and adjacent rooms.
+    pub fn light_level(&self, room_id: NodeIndex) -> LightLevel {
+        self.lighting.level(self, room_id)
+    }
+
+    /// Checks whether there is enough light to see in a [`Room`](crate::map::Room).
+    pub fn is_lit(&self, room_id: NodeIndex) -> bool {
+        self.light_level(room_id) > LightLevel::PitchBlack
+    }
+
+    /// Checks whether there is enough light to read in a [`Room`](crate::map::Room).
+    pub fn can_read(&self, room_id: NodeIndex) -> bool {
+        self.light_level(room_id) == LightLevel::Lit
+    }
+
+    /// Makes the player acquire a [`Topic`](crate::knowledge::Topic) of [`Knowledge`], showing its acquire message.
+    ///
+    /// Returns `true` if the player didn't know the topic before.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::knowledge::Topic;
+    ///
+    /// let mut world = World::new();
+    /// let mut topic = Topic::new("password".into(), "It was \"swordfish\".".into());
+    /// topic.on_acquire = Some("You commit the password to memory.".into());
+    /// topic.on_forget = Some("The password slips from your mind.".into());
+    /// let password = world.knowledge.add(topic);
+    ///
+    /// assert!(world.acquire_topic(password));
+    /// assert!(world.forget_topic(password));
+    /// assert_eq!(
+    ///     world.output.drain(),
+    ///     vec!["You commit the password to memory.", "The password slips from your mind."]
+    /// );
+    /// ```
+    pub fn acquire_topic(&mut self, id: TopicId) -> bool {
+        if !self.knowledge.acquire(id) {
+            return false;
+        }
+        if let Some(message) = self
+            .knowledge
+            .get(id)
+            .and_then(|topic| topic.on_acquire.clone())
+        {
+            self.output.say(message);
+        }
+        true
+    }
+
+    /// Makes the player forget a [`Topic`](crate::knowledge::Topic) of [`Knowledge`], showing its forget message.
+    ///
+    /// Returns `true` if the player knew the topic before.
+    pub fn forget_topic(&mut self, id: TopicId) -> bool {
+        if !self.knowledge.forget(id) {
+            return false;
+        }
+        if let Some(message) = self
+            .knowledge
+            .get(id)
+            .and_then(|topic| topic.on_forget.clone())
+        {
+            self.output.say(message);
+        }
+        true
+    }
+
+    /// Fills the `{placeholders}` of a template.
+    ///
+    /// Placeholders are looked up in `locals` first, then in the world's [`Variables`], then in its [`Flags`], and
+    /// finally among the placeholders of the [`Clock`], such as `{time}`, and `{weather}`, the [weather](Weathers) in
+    /// the player's room.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    ///
+    /// let mut world = World::new();
+    /// world.variables.set("weather", "raining");
+    /// assert_eq!(
+    ///     world.render_template("It is {weather} in {town}.", &[("town", "Dunwich".into())]),
+    ///     "It is raining in Dunwich."
+    /// );
+    /// ```
+    pub fn render_template(&self, text: &str, locals: &[(&str, String)]) -> String {
+        template::render(text, |name| {
+            locals
+                .iter()
+                .find(|(local, _)| *local == name)
+                .map(|(_, value)| value.clone())
+                .or_else(|| self.variables.get(name).map(String::from))
+                .or_else(|| self.flags.value(name).map(ToString::to_string))
+                .or_else(|| self.clock_placeholder(name))
+                .or_else(|| match name {
+                    "weather" => self
+                        .weather_at(self.player.current_room)
+                        .map(|weather| weather.to_string()),
+                    _ => None,
+                })
+        })
+    }
+
+    /// Sets a flag in the world's [`Flags`].
+    pub fn set_flag(&mut self, flag: &str, value: bool) {
+        self.flags.set(flag, value);
+    }
+
+    /// Checks whether a flag is set in the world's [`Flags`].
+    pub fn flag(&self, flag: &str) -> bool {
+        self.flags.is_set(flag)
+    }
+
+    /// Sets a value in the world's [`Flags`], such as a score.
+    pub fn set<T: FlagType>(&mut self, key: &str, value: T) {
+        self.flags.set(key, value);
+    }
+
+    /// Gets a value from the world's [`Flags`] if it exists and can be read as `T`.
+    pub fn get<T: FlagType>(&self, key: &str) -> Option<T> {
+        self.flags.get(key)
+    }
+
+    /// Skips the given number of turns, stopping early when something significant happens.
+    ///
+    /// Every turn is [advanced](World::advance_turn) in full. Narration, exit messages, NPCs arriving in or leaving the
+    /// player's room, the world resetting and a timer running out are significant.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    ///
+    /// let mut world = World::new();
+    /// let fuse = world.timers.start("Fuse".into(), 3, true);
+    ///
+    /// let outcome = world.wait(10);
+    /// assert_eq!(outcome.turns, 3);
+    /// assert!(outcome.interrupted);
+    /// assert_eq!(world.turn, 3);
+    /// assert!(world.timers.get(fuse).is_none());
+    ///
+    /// assert!(!world.wait(10).interrupted);
+    /// assert_eq!(world.turn, 13);
+    /// ```
+    pub fn wait(&mut self, turns: u32) -> WaitOutcome {
+        self.wait_until(|_| false, turns)
+    }
+
+    /// Skips turns until `until` is true, at most `max_turns` turns, stopping early when something significant happens.
+    ///
+    /// See [`wait`](World::wait) for what is significant.
+    pub fn wait_until(&mut self, until: impl Fn(&World) -> bool, max_turns: u32) -> WaitOutcome {
+        let mut outcome = WaitOutcome {
+            turns: 0,
+            messages: Vec::new(),
+            interrupted: false,
+        };
+        while outcome.turns < max_turns && !until(self) {
+            outcome.turns += 1;
+            let turn = self.advance_turn();
+            outcome.messages.extend(turn.messages);
+            if turn.significant && outcome.turns < max_turns && !until(self) {
+                outcome.interrupted = true;
+                break;
+            }
+        }
+        outcome
+    }
+
+    /// Advances the world by one turn: the heartbeat of the game, to call after every action that takes time.
+    ///
+    /// In order, the turn counter is incremented, the [`Timers`] are advanced, the events of the [`Scheduler`] that are due
+    /// fire, the [`NarrationQueue`] is advanced, the exits of the
+    /// [`Map`] are ticked, and those of the player's room announce their changes, the NPCs move and run their behaviors, the [`Currents`](crate::currents::Currents) move the
+    /// player and floating items, the world [resets](World::reset_world) if it is due,
+    /// the [`Quests`] are [updated](World::update_quests), [`Achievements`] are [unlocked](World::update_achievements), NPCs bark and banter, an [ambient](crate::ambience::Ambience) message may be shown, the
+    /// [turn hooks](crate::map::RoomHooks::each_turn) of the player's room are run and the [`Profiler`] ends the turn.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use worldwright::World;
+    /// use worldwright::actor::{BarkPool, Npc};
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::{AutoClosingDoor, Door};
+    ///
+    /// let mut world = World::new();
+    /// let yard = world.map.new_room("A muddy yard.".into());
+    /// world.player.current_room = yard;
+    /// world.map.room_mut(yard).hooks.each_turn.push(Rc::new(|world, _| world.output.say("A hen clucks.".into())));
+    /// let mut farmer = Npc::new("farmer".into(), yard);
+    /// farmer.barks = Some(BarkPool::new(vec!["Rain's coming.".into()]));
+    /// let farmer = world.npcs.add(farmer);
+    /// world.timers.start("Storm".into(), 2, false);
+    ///
+    /// let outcome = world.advance_turn();
+    /// assert_eq!(outcome.turn, 1);
+    /// assert_eq!(outcome.messages, vec!["The farmer says, \"Rain's coming.\""]);
+    /// assert!(!outcome.significant);
+    /// assert_eq!(world.output.drain(), vec!["A hen clucks."]);
+    ///
+    /// let outcome = world.advance_turn();
+    /// assert_eq!(outcome.expired_timers, vec!["Storm"]);
+    /// assert!(outcome.significant);
+    ///
+    /// // Exits only announce their changes to a player next to them.
+    /// world.npcs[farmer].barks = None;
+    /// let door = AutoClosingDoor::new(Door::new(false), 1, "The barn door bangs shut.".into());
+    /// let barn = world.map.new_room_in_direction(yard, Direction::North, Box::new(door), "A barn.".into());
+    /// let hatch = AutoClosingDoor::new(Door::new(false), 1, "The hatch drops shut.".into());
+    /// world.map.new_room_in_direction(barn, Direction::Up, Box::new(hatch), "A hayloft.".into());
+    /// assert_eq!(world.advance_turn().messages, vec!["The barn door bangs shut."]);
+    /// ```
+    pub fn advance_turn(&mut self) -> TurnOutcome {
+        self.turn += 1;
+        let expired_timers = self.timers.tick();
+        let mut fuse_fired = false;
+        for (id, handler, recurrence) in self.scheduler.tick() {
+            fuse_fired |= recurrence == Recurrence::Once;
+            handler(self, id);
+        }
+        let mut messages = self.narration.tick();
+        for (exit_id, message) in self.map.tick_exits() {
+            let (source, target) = self.map.exit_endpoints(exit_id).unwrap();
+            if self.player.current_room == source || self.player.current_room == target {
+                messages.push(message);
+            }
+        }
+
+        let stopwatch = self.profiler.start();
+        messages.extend(self.move_npcs());
+        self.run_npc_behaviors();
+        self.profiler.stop(Subsystem::NpcAi, stopwatch);
+        messages.extend(self.apply_currents());
+        if self.resets.is_due(self.turn) {
+            self.reset_world();
+            messages.extend(self.resets.message.clone());
+        }
+        messages.extend(self.update_quests());
+        messages.extend(self.update_achievements());
+        let significant = !expired_timers.is_empty() || fuse_fired || !messages.is_empty();
+
+        let room_id = self.player.current_room;
+        if self.map.graph.node_weight(room_id).is_some() {
+            messages.extend(self.tick_barks(room_id));
+            messages.extend(self.tick_banter());
+            messages.extend(self.ambient_message(room_id));
+            self.run_turn_hooks(room_id);
+        }
+        self.profiler.end_turn();
+        TurnOutcome {
+            turn: self.turn,
+            messages,
+            expired_timers,
+            significant,
+        }
+    }
+
+    /// Gets the time of day in minutes since midnight on the [`Clock`], counting
+    /// [`minutes_per_turn`](World::minutes_per_turn) for every turn.
+    pub fn time_of_day(&self) -> u32 {
+        self.now().minute_of_day()
+    }
+
+    /// Moves the NPCs that [patrol](crate::actor::Npc::patrol), [wander](crate::actor::Npc::wander) or follow a
+    /// [schedule](crate::actor::Npc::schedule) by up to one room each, through exits that let them through.
+    ///
+    /// Patrolling NPCs stay at each stop of their route for its dwell, then head for the next one, and say one of the
+    /// [barks](crate::actor::PatrolStop::barks) of a stop when they reach it within earshot of the player.
+    /// Returns what the player sees of it, such as "The butler leaves to the north."
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::actor::{Npc, NpcSchedule, Patrol, PatrolRoute, PatrolStop};
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::{Door, RegularExit};
+    ///
+    /// let mut world = World::new();
+    /// world.minutes_per_turn = 60;
+    /// let hall = world.map.new_room_with_name("Hall".into(), "A grand hall.".into());
+    /// let pantry = world.map.new_room_in_direction(hall, Direction::North, Box::new(RegularExit), "A pantry.".into());
+    /// world.map.graph[pantry].name = Some("Pantry".into());
+    /// let cellar = world.map.new_room_below(pantry, Box::new(RegularExit), "A damp cellar.".into());
+    /// world.map.graph[cellar].name = Some("Cellar".into());
+    ///
+    /// let mut butler = Npc::new("butler".into(), hall);
+    /// butler.schedule = Some(NpcSchedule::new().at(0, 0, hall).at(1, 0, cellar));
+    /// let butler = world.npcs.add(butler);
+    /// world.player.current_room = hall;
+    ///
+    /// world.turn = 1;
+    /// assert_eq!(world.move_npcs(), vec!["The butler leaves to the north."]);
+    /// assert_eq!(world.move_npcs(), Vec::<String>::new());
+    /// assert_eq!(world.npcs[butler].location, cellar);
+    ///
+    /// world.turn = 24;
+    /// world.move_npcs();
+    /// assert_eq!(world.move_npcs(), vec!["The butler arrives from the north."]);
+    ///
+    /// // Patrols walk their route a room at a time, and wait while the way is shut.
+    /// world.npcs[butler].schedule = None;
+    /// let vault = world.map.new_room_in_direction(hall, Direction::South, Box::new(Door::new(true)), "A vault.".into());
+    /// world.map.graph[vault].name = Some("Vault".into());
+    /// let mut guard = Npc::new("guard".into(), cellar);
+    /// let mut vault_stop = PatrolStop::new("Vault".into());
+    /// vault_stop.barks = vec!["All locked up.".into()];
+    /// guard.patrol = Some(Patrol::new(PatrolRoute::new(vec![PatrolStop::new("Cellar".into()), vault_stop])));
+    /// let guard = world.npcs.add(guard);
+    /// world.move_npcs();
+    /// assert_eq!(world.npcs[guard].location, cellar);
+    ///
+    /// let door = world.map.exit_id_in_direction(hall, Direction::South).unwrap();
+    /// world.map.exit_as_mut::<Door>(door).unwrap().unlock();
+    /// world.map.exit_as_mut::<Door>(door).unwrap().open();
+    /// world.move_npcs();
+    /// assert_eq!(world.npcs[guard].location, pantry);
+    /// assert_eq!(world.move_npcs(), vec!["The guard arrives from the north."]);
+    /// assert_eq!(
+    ///     world.move_npcs(),
+    ///     vec!["The guard leaves to the south.", "You overhear the guard to the south: \"All locked up.\""]
+    /// );
+    /// assert_eq!(world.npcs[guard].location, vault);
+    /// ```
+    pub fn move_npcs(&mut self) -> Vec<String> {
+        let mut messages = Vec::new();
+        let time = self.time_of_day();
+        let ids: Vec<NpcId> = self.npcs.iter().map(|(id, _)| id).collect();
+        for id in ids {
+            let location = self.npcs[id].location;
+            let mut stop_reached = false;
+            let step = if self.npcs[id].patrol.is_some() {
+                self.patrol_destination(id).and_then(|room_id| {
+                    let step = self.npc_step(id, location, room_id)?;
+                    stop_reached = step.1 == room_id;
+                    Some(step)
+                })
+            } else if let Some(schedule) = &self.npcs[id].schedule {
+                schedule
+                    .room_at(time)
+                    .and_then(|room_id| self.npc_step(id, location, room_id))
+            } else if let Some(wander) = &self.npcs[id].wander
+                && self.rng_streams.npc_ai().chance(wander.chance)
+            {
+                let choices: Vec<(Direction, RoomId)> = self
+                    .map
+                    .adjacent_rooms(location)
+                    .into_iter()
+                    .filter(|(exit, _, room_id)| {
+                        wander.region.contains(&self.map, *room_id)
+                            && exit.exit_type.check_npc_passage(self, id, location).is_ok()
+                    })
+                    .map(|(_, direction, room_id)| (direction, room_id))
+                    .collect();
+                (!choices.is_empty()).then(|| {
+                    let (direction, room_id) =
+                        choices[self.rng_streams.npc_ai().below(choices.len() as u32) as usize];
+                    (Some(direction), room_id)
+                })
+            } else {
+                None
+            };
+            let Some((direction, destination)) = step else {
+                continue;
+            };
+
+            let name = &self.npcs[id].name;
+            if location == self.player.current_room {
+                messages.push(match direction {
+                    Some(direction @ (Direction::Up | Direction::Down)) => {
+                        format!("The {name} goes {direction}.")
+                    }
+                    Some(direction) => {
+                        format!("The {name} leaves {}.", direction.relative_phrase())
+                    }
+                    None => format!("The {name} leaves."),
+                });
+            } else if destination == self.player.current_room {
+                messages.push(match direction.as_ref().map(Direction::opposite) {
+                    Some(Direction::Up) => format!("The {name} arrives from above."),
+                    Some(Direction::Down) => format!("The {name} arrives from below."),
+                    Some(other) => format!("The {name} arrives from the {other}."),
+                    None => format!("The {name} arrives."),
+                });
+            }
+            self.npcs[id].location = destination;
+
+            let stop = self.npcs[id].patrol.as_ref().and_then(Patrol::current_stop);
+            if let Some(stop) = stop.filter(|stop| stop_reached && !stop.barks.is_empty())
+                && let Some(direction) = self
+                    .map
+                    .hearing_direction(self.player.current_room, destination)
+            {
+                let line =
+                    &stop.barks[self.rng_streams.npc_ai().below(stop.barks.len() as u32) as usize];
+                messages.push(self.heard_line(&self.npcs[id].name, direction, line));
+            }
+        }
+        messages
+    }
+
+    /// Advances the patrol of an NPC while it is at its current stop, and gets the room of the stop it is heading for,
+    /// or `None` if it stays where it is. Stops whose room doesn't exist are skipped over.
+    fn patrol_destination(&mut self, id: NpcId) -> Option<RoomId> {
+        let location = self.npcs[id].location;
+        let patrol = self.npcs[id].patrol.as_mut()?;
+        let mut ticked = false;
+        for _ in 0..=patrol.route.stops.len() {
+            match self.map.room_by_name(&patrol.current_stop()?.room) {
+                None => {
+                    patrol.skip()?;
+                }
+                Some(room_id) if room_id != location => return Some(room_id),
+                Some(_) if ticked => return None,
+                Some(_) => {
+                    ticked = true;
+                    patrol.tick()?;
+                }
+            }
+        }
+        None
+    }
+
+    /// Finds the first step an NPC takes from one room towards another, through exits that let it through, as the
+    /// direction it leaves in and the room it arrives in.
+    fn npc_step(&self, id: NpcId, from: RoomId, to: RoomId) -> Option<(Option<Direction>, RoomId)> {
+        let route = self.map.find_route(from, to, |exit_id, exit, next| {
+            let (source, target) = self.map.exit_endpoints(exit_id).unwrap();
+            let from = if target == next { source } else { target };
+            !exit.exit_type.is_hidden() && exit.exit_type.check_npc_passage(self, id, from).is_ok()
+        })?;
+        let (exit_id, room_id) = route.first().copied()?;
+        let (source, _) = self.map.exit_endpoints(exit_id).unwrap();
+        let direction = self.map.graph[exit_id].direction.map(|direction| {
+            if source == from {
+                direction
+            } else {
+                direction.opposite()
+            }
+        });
+        Some((direction, room_id))
+    }
+
+    /// Runs the [behaviors](crate::actor::Npc::behaviors) of every NPC, in the order they were added.
+    pub fn run_npc_behaviors(&mut self) {
+        let behaviors: Vec<(NpcId, NpcBehavior)> = self
+            .npcs
+            .iter()
+            .flat_map(|(id, npc)| {
+                npc.behaviors
+                    .iter()
+                    .map(move |behavior| (id, behavior.clone()))
+            })
+            .collect();
+        for (npc, behavior) in behaviors {
+            behavior(self, npc);
+        }
+    }
+
+    /// Describes a number of turns passing as narrative time, such as "About an hour passes.",
+    /// using the [`minutes_per_turn`](World::minutes_per_turn) of the world.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    ///
+    /// let mut world = World::new();
+    /// assert_eq!(world.describe_time_passing(3), "A few minutes pass.");
+    ///
+    /// world.minutes_per_turn = 10;
+    /// assert_eq!(world.describe_time_passing(7), "About an hour passes.");
+    /// assert_eq!(world.describe_time_passing(432), "Three days pass.");
+    /// ```
+    pub fn describe_time_passing(&self, turns: u32) -> String {
+        let minutes = turns.saturating_mul(self.minutes_per_turn);
+        format!("{}.", capitalize(&template::passes(minutes)))
+    }
+
+    /// Lists what the player hears when listening in a [`Room`](crate::map::Room).
+    ///
+    /// The sound of the room comes first, followed by the distant sound of every adjacent room
+    /// whose exit [transmits sound](crate::map::ExitType::transmits_sound), such as "You hear muffled music to the north."
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::{Door, RegularExit};
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.new_room("A long hall.".into());
+    /// let ballroom = world.map.new_room_in_direction(
+    ///     hall,
+    ///     Direction::North,
+    ///     Box::new(RegularExit),
+    ///     "A glittering ballroom.".into(),
+    /// );
+    /// let vault = world.map.new_room_in_direction(
+    ///     hall,
+    ///     Direction::South,
+    ///     Box::new(Door::new(true)),
+    ///     "A bank vault.".into(),
+    /// );
+    /// world.map.graph[hall].sound = Some("The floorboards creak.".into());
+    /// world.map.graph[ballroom].distant_sound = Some("muffled music".into());
+    /// world.map.graph[vault].distant_sound = Some("a ticking clock".into());
+    ///
+    /// assert_eq!(
+    ///     world.listen(hall),
+    ///     vec!["The floorboards creak.", "You hear muffled music to the north."]
+    /// );
+    /// ```
+    pub fn listen(&self, room_id: NodeIndex) -> Vec<String> {
+        let mut sounds: Vec<String> = self.map.graph[room_id]
+            .sound
+            .iter()
+            .map(|sound| sound.render(self))
+            .collect();
+        for (exit, direction, adjacent_id) in self.map.adjacent_rooms(room_id) {
+            if !exit.exit_type.transmits_sound() {
+                continue;
+            }
+            if let Some(sound) = &self.map.graph[adjacent_id].distant_sound {
+                sounds.push(format!(
+                    "You hear {} {}.",
+                    sound.render(self),
+                    direction.relative_phrase()
+                ));
+            }
+        }
+        sounds
+    }
+
+    /// Lets every NPC with [barks](crate::actor::Npc::barks) possibly say a line from the pool for its [mood](crate::actor::Npc::mood_barks), and returns what the player hears from the given room.
+    ///
+    /// NPCs in the player's room are heard directly. NPCs in adjacent rooms are overheard
+    /// through exits that [transmit sound](crate::map::ExitType::transmits_sound). Other NPCs stay silent.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::actor::{BarkPool, Npc};
+    /// use worldwright::map::Direction;
+    /// use worldwright::map::exit_types::RegularExit;
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.new_room("A long hall.".into());
+    /// let kitchen = world.map.new_room_in_direction(
+    ///     hall,
+    ///     Direction::North,
+    ///     Box::new(RegularExit),
+    ///     "A busy kitchen.".into(),
+    /// );
+    ///
+    /// let mut cook = Npc::new("cook".into(), kitchen);
+    /// cook.barks = Some(BarkPool::new(vec!["Who ate the pie?".into()]));
+    /// world.npcs.add(cook);
+    ///
+    /// assert_eq!(world.tick_barks(kitchen), vec![r#"The cook says, "Who ate the pie?""#]);
+    /// assert_eq!(
+    ///     world.tick_barks(hall),
+    ///     vec![r#"You overhear the cook to the north: "Who ate the pie?""#]
+    /// );
+    /// ```
+    pub fn tick_barks(&mut self, listener_id: NodeIndex) -> Vec<String> {
+        self.say_barks(listener_id, Npc::current_barks)
+    }
+
+    /// Lets every NPC with a [reaction](crate::actor::Npc::reactions) to `event` say a line, and returns what the player hears from the given room.
+    ///
+    /// Reactions are heard the same way as [barks](World::tick_barks).
+    pub fn react(&mut self, event: &str, listener_id: NodeIndex) -> Vec<String> {
+        self.say_barks(listener_id, |npc| npc.reactions.get_mut(event))
+    }
+
+    fn say_barks(
+        &mut self,
+        listener_id: NodeIndex,
+        pool: impl Fn(&mut Npc) -> Option<&mut BarkPool>,
+    ) -> Vec<String> {
+        let stopwatch = self.profiler.start();
+        let mut heard = Vec::new();
+        let speakers: Vec<NpcId> = self.npcs.iter().map(|(id, _)| id).collect();
+        for id in speakers {
+            let npc = &mut self.npcs[id];
+            let Some(direction) = self.map.hearing_direction(listener_id, npc.location) else {
+                continue;
+            };
+            let name = npc.name.clone();
+            let Some(barks) = pool(npc) else {
+                continue;
+            };
+            if !self.rng_streams.npc_ai().chance(barks.chance) {
+                continue;
+            }
+            let Some(line) = barks.pick(self.rng_streams.npc_ai()) else {
+                continue;
+            };
+            heard.push(self.heard_line(&name, direction, &line));
+        }
+        self.profiler.stop(Subsystem::NpcAi, stopwatch);
+        heard
+    }
+
+    /// Phrases a line said by an NPC as the player hears it, in their language, from the
+    /// [direction](crate::map::Map::hearing_direction) it comes from.
+    fn heard_line(&self, name: &str, direction: Option<Direction>, line: &str) -> String {
+        let line = self.translations.get(self.language.as_deref(), line);
+        match direction {
+            None => format!("The {name} says, \"{line}\""),
+            Some(direction) => {
+                format!(
+                    "You overhear the {name} {}: \"{line}\"",
+                    direction.relative_phrase()
+                )
+            }
+        }
+    }
+
+    /// Advances every [banter](crate::banter::Banter) by one turn in the player's room, and returns the lines said,
+    /// such as `The bard says, "Another song, friend?"`.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::actor::Npc;
+    /// use worldwright::banter::{Banter, BanterTrigger};
+    ///
+    /// let mut world = World::new();
+    /// let tavern = world.map.new_room("A smoky tavern.".into());
+    /// world.player.current_room = tavern;
+    /// let bard = world.npcs.add(Npc::new("bard".into(), tavern));
+    /// let innkeeper = world.npcs.add(Npc::new("innkeeper".into(), tavern));
+    /// world.banter.add(Banter::new(
+    ///     vec![
+    ///         (bard, "Did you hear that?".into()),
+    ///         (innkeeper, "Just the wind.".into()),
+    ///     ],
+    ///     BanterTrigger::Event("thunder".into()),
+    /// ));
+    ///
+    /// assert!(world.tick_banter().is_empty());
+    /// world.banter.trigger("thunder");
+    /// assert_eq!(world.tick_banter(), vec![r#"The bard says, "Did you hear that?""#]);
+    /// assert_eq!(world.tick_banter(), vec![r#"The innkeeper says, "Just the wind.""#]);
+    /// ```
+    pub fn tick_banter(&mut self) -> Vec<String> {
+        let stopwatch = self.profiler.start();
+        let said = self
+            .banter
+            .tick(&self.map, &self.npcs, self.player.current_room);
+        self.profiler.stop(Subsystem::NpcAi, stopwatch);
+        said.into_iter()
+            .map(|(speaker, line)| format!("The {} says, \"{line}\"", self.npcs[speaker].name))
+            .collect()
+    }
+
+    /// Sets the [`Mood`] of an [`Npc`], changing the lines it says and how it is described.
+    pub fn set_mood(&mut self, npc: NpcId, mood: Mood) {
+        self.npcs[npc].mood = mood;
+    }
+
+    /// Renders the description of an [`Npc`], where `{name}` and `{mood}` can be used along with the world's [`Variables`].
+    ///
+    /// NPCs without a description are described as "You see nothing special about the {name}."
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::actor::{BarkPool, Mood, Npc};
+    ///
+    /// let mut world = World::new();
+    /// let gate = world.map.new_room("A cold stone gatehouse.".into());
+    /// world.player.current_room = gate;
+    /// let mut guard = Npc::new("guard".into(), gate);
+    /// guard.description = Some("The {name} looks {mood}.".into());
+    /// guard.barks = Some(BarkPool::new(vec!["Move along.".into()]));
+    /// guard.mood_barks.insert(Mood::Angry, BarkPool::new(vec!["Get out of my sight!".into()]));
+    /// let guard = world.npcs.add(guard);
+    ///
+    /// assert_eq!(world.describe_npc(guard), "The guard looks calm.");
+    /// assert_eq!(world.tick_barks(gate), vec![r#"The guard says, "Move along.""#]);
+    ///
+    /// world.set_mood(guard, Mood::Angry);
+    /// assert_eq!(world.describe_npc(guard), "The guard looks angry.");
+    /// assert_eq!(world.tick_barks(gate), vec![r#"The guard says, "Get out of my sight!""#]);
+    /// ```
+    pub fn describe_npc(&self, npc: NpcId) -> String {
+        let npc = &self.npcs[npc];
+        let locals = [("name", npc.name.clone()), ("mood", npc.mood.to_string())];
+        match &npc.description {
+            Some(description) => self.render_template(&self.translate(description), &locals),
+            None => format!("You see nothing special about the {}.", npc.name),
+        }
+    }
+
+    /// Gets what the player smells in a [`Room`](crate::map::Room), if anything.
+    pub fn smell(&self, room_id: NodeIndex) -> Option<String> {
+        self.map.graph[room_id]
+            .smell
+            .as_ref()
+            .map(|smell| smell.render(self))
+    }
+
+    /// Gets the [`Surroundings`] exits are seen in from a [`Room`](crate::map::Room): its light, whether it is night, and
+    /// its weather.
+    pub fn surroundings(&self, room_id: NodeIndex) -> Surroundings {
+        Surroundings {
+            light: self.light_level(room_id),
+            night: self.is_night(),
+            weather: self.weather_at(room_id),
+        }
+    }
+
+    /// Describes the exits of a [`Room`](crate::map::Room) relative to it, such as "a door north".
+    ///
+    /// Directed exits are described [in their surroundings](crate::map::ExitType::description_in), such as
+    /// "a moonlit archway east".
+    /// Exits without a direction use their [undirected description](crate::map::ExitType::undirected_description).
+    /// Exit descriptions are rendered as templates, where `{direction}` and the
+    /// [template variables](crate::map::ExitType::template_variables) of the exit can be used.
+    pub fn describe_exits(&self, room_id: NodeIndex) -> Vec<String> {
+        let surroundings = self.surroundings(room_id);
+        self.map
+            .get_exits(room_id)
+            .into_iter()
+            .map(|(exit, exit_way)| {
+                let variables = exit.exit_type.template_variables();
+                let mut locals: Vec<(&str, String)> = variables
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.clone()))
+                    .collect();
+                let description = match self.map.get_relative_direction(exit, exit_way) {
+                    Some(direction) => {
+                        locals.push(("direction", direction.to_string()));
+                        exit.exit_type.description_in(direction, &surroundings)
+                    }
+                    None => exit.exit_type.undirected_description(),
+                };
+                self.render_template(&description, &locals)
+            })
+            .collect()
+    }
+
+    /// Renders the description of a [`Room`](crate::map::Room) for the current state of the world.
+    ///
+    /// Descriptions are rendered as templates, where `{room}` is the name of the room. The text of
+    /// [dynamic](Description::Dynamic) descriptions is used as it is generated.
+    /// If the room has been visited and has a short description, the short description is used instead of the full one.
+    /// If the room is not [lit](World::is_lit), its description is replaced with darkness.
+    /// The details of the room are only added when it is fully lit.
+    /// The contents of the room are listed whenever it is lit.
+    ///
+    /// # Examples
+    /// ```
+    /// use worldwright::World;
+    /// use worldwright::item::Item;
+    /// use worldwright::light::LightLevel;
+    /// use worldwright::map::exit_types::Door;
+    /// use worldwright::map::{Description, Direction, Room};
+    ///
+    /// let mut world = World::new();
+    /// let hall = world.map.add_room(Room::new_with_name(
+    ///     "Grand Hall".into(),
+    ///     "A roaring fireplace dominates the far wall.".into(),
+    /// ));
+    ///
+    /// let description = world.describe_room(hall);
+    /// assert_eq!(description.title.as_deref(), Some("Grand Hall"));
+    /// assert_eq!(
+    ///     description.to_string(),
+    ///     "Grand Hall\nA roaring fireplace dominates the far wall."
+    /// );
+    ///
+    /// world.map.graph[hall].short_description = Some("The fireplace crackles.".into());
+    /// world.map.mark_visited(hall);
+    /// assert_eq!(world.describe_room(hall).body, "The fireplace crackles.");
+    ///
+    /// world.map.graph[hall].details = Some("Initials are carved into the mantel.".into());
+    /// assert_eq!(
+    ///     world.describe_room(hall).body,
+    ///     "The fireplace crackles. Initials are carved into the mantel."
+    /// );
+    ///
+    /// world.map.graph[hall].details = Some(Description::dynamic(|_| "A note reads \"{room}\".".into()));
+    /// assert_eq!(world.describe_room(hall).body, "The fireplace crackles. A note reads \"{room}\".");
+    ///
+    /// world.map.graph[hall].light = LightLevel::Dim;
+    /// assert_eq!(world.describe_room(hall).body, "The fireplace crackles.");
+    ///
+    /// let poker = world.items.add(Item::new("iron poker".into(), "A heavy iron poker.".into()));
+    /// world.map.place_item(hall, poker);
+    /// assert_eq!(
+    ///     world.describe_room(hall).to_string(),
+    ///     "Grand Hall\nThe fireplace crackles.\nYou can see an iron poker here."
+    /// );
+    ///
+    /// let _study = world.map.new_room_in_direction(
+    ///     hall,
+    ///     Direction::East,
+    ///     Box::new(Door::new(true)),
+    ///     "A quiet study.".into(),
+    /// );
+    /// assert_eq!(world.describe_room(hall).exits, vec!["a locked door east"]);
+    ///
+    /// world.map.graph[hall].light = LightLevel::PitchBlack;
+    /// assert_eq!(world.describe_room(hall).to_string(), "Darkness\nIt is pitch black.");
+    /// ```
+    pub fn describe_room(&self, room_id: NodeIndex) -> RoomDescription {
+        self.profiler
+            .measure(Subsystem::Descriptions, || self.render_room(room_id))
+    }
+
+    fn render_room(&self, room_id: NodeIndex) -> RoomDescription {
+        let light_level = self.light_level(room_id);
+        if light_level == LightLevel::PitchBlack {
+            return RoomDescription {
+                title: Some("Darkness".into()),
+                body: "It is pitch black.".into(),
+                contents: Vec::new(),
+                npcs: Vec::new(),
+                exits: Vec::new(),
+            };
+        }
+
+        let room = &self.map.graph[room_id];
+        let description = match &room.short_description {
+            Some(short_description) if room.visited => short_description,
+            _ => &room.description,
+        };
+        let locals = [("room", room.name.clone().unwrap_or_default())];
+        let mut body = self.render_description(description, &locals);
+        if let Some(details) = &room.details
+            && light_level == LightLevel::Lit
+        {
+            body.push(' ');
+            body.push_str(&self.render_description(details, &locals));
+        }
+        RoomDescription {
+            title: room.name.clone(),
+            body,
+            contents: self.visible_contents(room_id),
+            npcs: self
+                .npcs
+                .in_room(room_id)
+                .into_iter()
+                .map(|npc| format!("the {}", self.npcs[npc].name))
+                .collect(),
+            exits: self.describe_exits(room_id),
+        }
+    }
+
+    /// Renders a [`Description`] of a room. Fixed descriptions are rendered as templates, while the text generated by
+    /// dynamic ones is used as it is, braces included.
+    fn render_description(&self, description: &Description, locals: &[(&str, String)]) -> String {
+        match description {
+            Description::Static(text) => self.render_template(text, locals),
+            Description::Dynamic(provider) => provider(self),
+        }
     }
 }
 
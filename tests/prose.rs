@@ -0,0 +1,58 @@
+use worldwright::World;
+use worldwright::item::Item;
+use worldwright::map::Direction;
+use worldwright::map::exit_types::{Chasm, Door, NamedExit};
+
+fn manor() -> World {
+    let mut world = World::new();
+    let foyer = world.map.new_room_with_name(
+        "Foyer".into(),
+        "You are in the dusty foyer of an old manor. A grand staircase leads up to a landing, but it's roped off.".into(),
+    );
+    let hall = world.map.new_room_in_direction(
+        foyer,
+        Direction::North,
+        Box::new(Door::new_with_name(false, "heavy wooden door".into())),
+        "You step into the magnificent Grand Hall. A roaring fireplace dominates the far wall."
+            .into(),
+    );
+    world.map.graph[hall].name = Some("Grand Hall".into());
+    let library = world.map.new_room_in_direction(
+        hall,
+        Direction::East,
+        Box::new(Door::new(true)),
+        "The air here is thick with the scent of old paper.".into(),
+    );
+    world.map.new_room_in_direction(
+        hall,
+        Direction::West,
+        Box::new(NamedExit::new("archway".into())),
+        "A conservatory full of wilting ferns.".into(),
+    );
+    world.map.new_room_in_direction(
+        library,
+        Direction::North,
+        Box::new(Chasm),
+        "A crumbling balcony.".into(),
+    );
+
+    let poker = world
+        .items
+        .add(Item::new("iron poker".into(), "A heavy iron poker.".into()));
+    let apple = world
+        .items
+        .add(Item::new("apple".into(), "A red apple.".into()));
+    world.map.place_item(hall, poker);
+    world.map.place_item(hall, apple);
+
+    let lamp = world
+        .items
+        .add(Item::new("lamp".into(), "A brass lamp.".into()));
+    world.player.inventory.push(lamp);
+    world
+}
+
+#[test]
+fn manor_prose() {
+    insta::assert_snapshot!(manor().text_snapshot());
+}
@@ -0,0 +1,110 @@
+//! Derive macros for [worldwright](https://docs.rs/worldwright).
+//!
+//! These are re-exported by worldwright with the `derive` feature, so they don't need to be depended on directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Expr, Fields, LitStr, parse_macro_input};
+
+/// Derives `ExitType` for exits whose description is a format string and whose passability is an expression.
+///
+/// The `#[exit(...)]` attribute takes:
+/// - `description` (required): a format string, where `{direction}` and the named fields of the struct can be used.
+///   `{direction}` is always the direction of the exit, even if the struct has a field with that name.
+/// - `passable` (optional): an expression of `self` giving whether the player can go through. Exits are passable by default.
+///
+/// Every other method of `ExitType` keeps its default.
+///
+/// # Examples
+/// ```ignore
+/// use worldwright::map::ExitType;
+///
+/// #[derive(Clone, Debug, ExitType)]
+/// #[exit(description = "a {material} bridge {direction}", passable = !self.collapsed)]
+/// struct Bridge {
+///     material: String,
+///     collapsed: bool,
+/// }
+/// ```
+#[proc_macro_derive(ExitType, attributes(exit))]
+pub fn derive_exit_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_exit_type(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_exit_type(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let mut description: Option<LitStr> = None;
+    let mut passable: Option<Expr> = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("exit"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("description") {
+                description = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("passable") {
+                passable = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `description` or `passable`"))
+            }
+        })?;
+    }
+    let Some(description) = description else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "missing `#[exit(description = \"...\")]` attribute",
+        ));
+    };
+    let passable = passable.map_or_else(|| quote!(true), |expr| quote!(#expr));
+
+    // Bind the named fields so the description can refer to them like `{direction}`. A field named `direction` is left
+    // out, as `{direction}` is always the direction of the exit.
+    let bindings = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let names = fields
+                    .named
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+                    .filter(|name| *name != "direction");
+                quote! {
+                    #[allow(unused_variables)]
+                    let Self { #(#names,)* .. } = self;
+                }
+            }
+            _ => quote!(),
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`ExitType` can only be derived for structs",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::worldwright::map::ExitType for #name #ty_generics #where_clause {
+            fn can_go_through(&self) -> bool {
+                #passable
+            }
+
+            fn description(
+                &self,
+                __worldwright_direction: ::worldwright::map::Direction,
+            ) -> ::worldwright::__private::String {
+                #bindings
+                #[allow(unused_variables)]
+                let direction = __worldwright_direction;
+                ::worldwright::__private::format!(#description)
+            }
+        }
+    })
+}